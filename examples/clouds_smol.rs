@@ -23,7 +23,6 @@
 use aiven_rs::{cloud::types::ResClouds, AivenClient};
 use anyhow::{Error, Result};
 use async_compat::Compat;
-use smol;
 
 fn main() -> Result<()> {
 	smol::block_on(Compat::new(async {
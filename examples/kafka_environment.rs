@@ -0,0 +1,51 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use aiven_rs::{
+	scenarios::{self, KafkaEnvironment},
+	service::types_kafka::KafkaTopicCreateRequest,
+	AivenClient,
+};
+use std::env;
+use tracing::info;
+
+// cargo run --example kafka_environment --features examples -- <project>
+#[tokio::main]
+async fn main() {
+	tracing_subscriber::fmt::init();
+	let token = env::var("AIVEN_TOKEN").expect("Please set env variable to read AIVEN_TOKEN");
+	let project = env::args().nth(1).expect("Please pass the project name");
+	let client = AivenClient::from_token("https://api.aiven.io", "v1", &token);
+
+	let environment = KafkaEnvironment::new("events", "aws-eu-central-1", "business-4")
+		.topic(KafkaTopicCreateRequest::new("orders").partitions(6))
+		.topic(KafkaTopicCreateRequest::new("payments").partitions(3))
+		.user("orders-app", "orders", "readwrite")
+		.user("payments-app", "payments", "readwrite");
+	let report = scenarios::bootstrap_kafka_environment(&client, &project, &environment)
+		.await
+		.unwrap();
+	info!(
+		"service created: {}, topics: {:?}, users: {:?}",
+		report.service_created, report.created_topics, report.created_users
+	);
+}
@@ -23,7 +23,6 @@
 use aiven_rs::AivenClient;
 use anyhow::Result;
 use async_compat::Compat;
-use smol;
 use std::env;
 
 fn main() -> Result<()> {
@@ -23,10 +23,12 @@
 use crate::{
 	account::types,
 	client::{encode_param, HTTPClient},
+	deprecation,
 	errors::AivenError,
 	make_json_request, make_request,
 };
 use serde::Serialize;
+use serde_json::json;
 
 pub struct AccountApi {
 	http_client: HTTPClient,
@@ -74,7 +76,7 @@ impl AccountApi {
 			account_id = encode_param(account_id)
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List authentication methods
@@ -108,7 +110,7 @@ impl AccountApi {
 			account_id = encode_param(account_id)
 		);
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete authentication method
@@ -180,7 +182,7 @@ impl AccountApi {
 			account_auth_method_id = encode_param(account_auth_method_id),
 		);
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Update authentication method
@@ -216,7 +218,114 @@ impl AccountApi {
 			account_auth_method_id = encode_param(account_auth_method_id),
 		);
 		let response = make_request!(self, reqwest::Method::PUT, url)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// Enable an authentication method
+	///
+	/// https://api.aiven.io/doc/#operation/AccountAuthenticationMethodUpdate
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// # #[tokio::main]
+	/// # async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .account()
+	///         .enable_auth_method("my-account-id", "account-method-id").await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn enable_auth_method(
+		&self,
+		account_id: &str,
+		account_auth_method_id: &str,
+	) -> Result<types::AuthenticationMethodResponse, AivenError> {
+		self.set_auth_method_enabled(account_id, account_auth_method_id, true)
+			.await
+	}
+
+	/// Disable an authentication method
+	///
+	/// https://api.aiven.io/doc/#operation/AccountAuthenticationMethodUpdate
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// # #[tokio::main]
+	/// # async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .account()
+	///         .disable_auth_method("my-account-id", "account-method-id").await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn disable_auth_method(
+		&self,
+		account_id: &str,
+		account_auth_method_id: &str,
+	) -> Result<types::AuthenticationMethodResponse, AivenError> {
+		self.set_auth_method_enabled(account_id, account_auth_method_id, false)
+			.await
+	}
+
+	/// Update the just-in-time provisioning options of an authentication
+	/// method, e.g. the team new users join
+	///
+	/// https://api.aiven.io/doc/#operation/AccountAuthenticationMethodUpdate
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::account::types::AuthMethodProvisioning;
+	///
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let provisioning = AuthMethodProvisioning::default()
+	///     .auto_join_team_id("my-team-id")
+	///     .saml_join_groups(true);
+	/// let response = client
+	///         .account()
+	///         .update_auth_method_provisioning("my-account-id", "account-method-id", &provisioning)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn update_auth_method_provisioning(
+		&self,
+		account_id: &str,
+		account_auth_method_id: &str,
+		provisioning: &types::AuthMethodProvisioning,
+	) -> Result<types::AuthenticationMethodResponse, AivenError> {
+		let url = &format!(
+			"account/{account_id}/authentication/{account_auth_method_id}",
+			account_id = encode_param(account_id),
+			account_auth_method_id = encode_param(account_auth_method_id),
+		);
+		let response = make_json_request!(self, reqwest::Method::PUT, url, provisioning)?;
+		response.json().await
+	}
+
+	async fn set_auth_method_enabled(
+		&self,
+		account_id: &str,
+		account_auth_method_id: &str,
+		enabled: bool,
+	) -> Result<types::AuthenticationMethodResponse, AivenError> {
+		let url = &format!(
+			"account/{account_id}/authentication/{account_auth_method_id}",
+			account_id = encode_param(account_id),
+			account_auth_method_id = encode_param(account_auth_method_id),
+		);
+		let body = &json!({ "authentication_method_enabled": enabled });
+		let response = make_json_request!(self, reqwest::Method::PUT, url, body)?;
+		response.json().await
 	}
 
 	/// Create a new account
@@ -248,7 +357,7 @@ impl AccountApi {
 		let url = "account";
 		let json_data = &[("account_name", account_name)];
 		let response = make_json_request!(self, reqwest::Method::POST, url, json_data)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Create a new account
@@ -276,7 +385,7 @@ impl AccountApi {
 	pub async fn list_accessible_accounts(&self) -> Result<types::Accounts, AivenError> {
 		let url = "account";
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete empty account
@@ -341,12 +450,12 @@ impl AccountApi {
 			account_id = encode_param(account_id)
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
-	/// Get account details
+	/// Update account details
 	///
-	/// https://api.aiven.io/doc/#operation/AccountGet
+	/// https://api.aiven.io/doc/#operation/AccountUpdate
 	///
 	/// # Examples
 	/// Basic usage:
@@ -379,7 +488,7 @@ impl AccountApi {
 			account_id = encode_param(account_id)
 		);
 		let response = make_json_request!(self, reqwest::Method::PUT, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List account events
@@ -410,7 +519,26 @@ impl AccountApi {
 			account_id = encode_param(account_id)
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// A page of `list_events`, newest first, skipping the `offset` newest
+	/// events
+	#[cfg(feature = "forwarders")]
+	pub(crate) async fn list_events_page(
+		&self,
+		account_id: &str,
+		limit: usize,
+		offset: usize,
+	) -> Result<types::Events, AivenError> {
+		let url = format!(
+			"account/{account_id}/events?limit={limit}&offset={offset}",
+			account_id = encode_param(account_id),
+			limit = limit,
+			offset = offset,
+		);
+		let response = make_request!(self, reqwest::Method::GET, &url)?;
+		response.json().await
 	}
 
 	/// List projects belonging to account
@@ -441,7 +569,7 @@ impl AccountApi {
 			account_id = encode_param(account_id)
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List account teams associated to a project
@@ -471,13 +599,14 @@ impl AccountApi {
 		account_id: &str,
 		project_name: &str,
 	) -> Result<types::Teams, AivenError> {
+		deprecation::warn_deprecated("AccountApi", "list_teams_for_project");
 		let url = format!(
 			"account/{account_id}/project/{project_name}/teams",
 			account_id = encode_param(account_id),
 			project_name = encode_param(project_name)
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Create a new team
@@ -508,12 +637,13 @@ impl AccountApi {
 		account_id: &str,
 		json_data: &T,
 	) -> Result<types::TeamResponse, AivenError> {
+		deprecation::warn_deprecated("AccountApi", "create_team");
 		let url = format!(
 			"account/{account_id}/teams",
 			account_id = encode_param(account_id)
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_data)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List teams belonging to an account
@@ -539,12 +669,13 @@ impl AccountApi {
 	/// # }
 	/// ```
 	pub async fn list_teams(&self, account_id: &str) -> Result<types::Teams, AivenError> {
+		deprecation::warn_deprecated("AccountApi", "list_teams");
 		let url = format!(
 			"account/{account_id}/teams",
 			account_id = encode_param(account_id)
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete a team
@@ -570,6 +701,7 @@ impl AccountApi {
 	/// # }
 	/// ```
 	pub async fn delete_team(&self, account_id: &str, team_id: &str) -> Result<(), AivenError> {
+		deprecation::warn_deprecated("AccountApi", "delete_team");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}",
 			account_id = encode_param(account_id),
@@ -606,13 +738,14 @@ impl AccountApi {
 		account_id: &str,
 		team_id: &str,
 	) -> Result<types::TeamResponse, AivenError> {
+		deprecation::warn_deprecated("AccountApi", "get_team_details");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}",
 			account_id = encode_param(account_id),
 			team_id = encode_param(team_id),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Update team details
@@ -644,13 +777,14 @@ impl AccountApi {
 		team_id: &str,
 		json_body: &T,
 	) -> Result<types::TeamResponse, AivenError> {
+		deprecation::warn_deprecated("AccountApi", "update_team_details");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}",
 			account_id = encode_param(account_id),
 			team_id = encode_param(team_id),
 		);
 		let response = make_json_request!(self, reqwest::Method::PUT, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List pending invites
@@ -680,13 +814,14 @@ impl AccountApi {
 		account_id: &str,
 		team_id: &str,
 	) -> Result<types::Invites, AivenError> {
+		deprecation::warn_deprecated("AccountApi", "list_pending_invites");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}/invites",
 			account_id = encode_param(account_id),
 			team_id = encode_param(team_id),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Cancel pending user invite
@@ -717,6 +852,7 @@ impl AccountApi {
 		team_id: &str,
 		user_email: &str,
 	) -> Result<(), AivenError> {
+		deprecation::warn_deprecated("AccountApi", "cancel_pending_invite");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}/invites/{email}",
 			account_id = encode_param(account_id),
@@ -754,18 +890,19 @@ impl AccountApi {
 		account_id: &str,
 		invite_verification_code: &str,
 	) -> Result<types::InviteDetails, AivenError> {
+		deprecation::warn_deprecated("AccountApi", "confirm_team_invite");
 		let url = format!(
 			"/account/{account_id}/invite/{invite_verification_code}",
 			account_id = encode_param(account_id),
 			invite_verification_code = encode_param(invite_verification_code),
 		);
 		let response = make_request!(self, reqwest::Method::POST, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Remove a member from the team
 	///
-	/// https://api.aiven.io/doc/#operation/AccountTeamMemberVerifyInvite
+	/// https://api.aiven.io/doc/#operation/AccountTeamMemberDelete
 	///
 	/// # Examples
 	/// Basic usage:
@@ -791,6 +928,7 @@ impl AccountApi {
 		team_id: &str,
 		user_id: &str,
 	) -> Result<(), AivenError> {
+		deprecation::warn_deprecated("AccountApi", "remove_member");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}/member/{user_id}",
 			account_id = encode_param(account_id),
@@ -830,6 +968,7 @@ impl AccountApi {
 		team_id: &str,
 		json_body: &T,
 	) -> Result<(), AivenError> {
+		deprecation::warn_deprecated("AccountApi", "invite_member");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}/members",
 			account_id = encode_param(account_id),
@@ -866,13 +1005,14 @@ impl AccountApi {
 		account_id: &str,
 		team_id: &str,
 	) -> Result<types::Members, AivenError> {
+		deprecation::warn_deprecated("AccountApi", "list_team_members");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}/members",
 			account_id = encode_param(account_id),
 			team_id = encode_param(team_id),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Associate team to a project
@@ -905,6 +1045,7 @@ impl AccountApi {
 		project: &str,
 		json_body: &T,
 	) -> Result<(), AivenError> {
+		deprecation::warn_deprecated("AccountApi", "associate_team_to_project");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}/project/{project}",
 			account_id = encode_param(account_id),
@@ -948,6 +1089,7 @@ impl AccountApi {
 		project: &str,
 		json_body: &T,
 	) -> Result<(), AivenError> {
+		deprecation::warn_deprecated("AccountApi", "update_team_project_association");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}/project/{project}",
 			account_id = encode_param(account_id),
@@ -986,6 +1128,7 @@ impl AccountApi {
 		team_id: &str,
 		project: &str,
 	) -> Result<(), AivenError> {
+		deprecation::warn_deprecated("AccountApi", "dissociate_team_from_project");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}/project/{project}",
 			account_id = encode_param(account_id),
@@ -1023,13 +1166,14 @@ impl AccountApi {
 		account_id: &str,
 		team_id: &str,
 	) -> Result<types::TeamProjects, AivenError> {
+		deprecation::warn_deprecated("AccountApi", "list_projects_by_team");
 		let url = format!(
 			"/account/{account_id}/team/{team_id}/projects",
 			account_id = encode_param(account_id),
 			team_id = encode_param(team_id),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List projects associated with this account that user has access to
@@ -1065,7 +1209,7 @@ impl AccountApi {
 			user_id = encode_param(user_id),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List all teams for user
@@ -1095,13 +1239,14 @@ impl AccountApi {
 		account_id: &str,
 		user_id: &str,
 	) -> Result<types::Teams, AivenError> {
+		deprecation::warn_deprecated("AccountApi", "list_teams_for_user");
 		let url = format!(
 			"/account/{account_id}/user/{user_id}/teams",
 			account_id = encode_param(account_id),
 			user_id = encode_param(user_id),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List/search users who are members of any team on this account
@@ -1138,13 +1283,17 @@ impl AccountApi {
 			account_id = encode_param(account_id),
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::{client::encode_param, testutil};
+	use crate::{
+		account::types::{AuthMethodProvisioning, AuthMethodState},
+		client::encode_param,
+		testutil,
+	};
 	use serde_json::json;
 
 	#[tokio::test]
@@ -1162,7 +1311,7 @@ mod tests {
 			.await
 		{
 			Ok(resp) => assert!(resp.authentication_method.account_id == "string"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1176,7 +1325,7 @@ mod tests {
 
 		match client.account().list_auth_methods("someaccountid").await {
 			Ok(resp) => assert!(resp.authentication_methods[0].account_id == "string"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1197,7 +1346,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1221,7 +1370,7 @@ mod tests {
 			Ok(response) => {
 				assert!(response.authentication_method.account_id == "unique-account-id")
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1245,7 +1394,68 @@ mod tests {
 			Ok(response) => {
 				assert!(response.authentication_method.account_id == "updated-account-id")
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_account_disable_auth_method() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/account/disableaccount/authentication/someid";
+		let test_data = testutil::get_test_data("tests/testdata/account/disable_auth_method.json");
+		let _m = mockito::mock("PUT", query_url)
+			.match_header("authorization", "aivenv1 abc")
+			.match_body(mockito::Matcher::Json(
+				json!({"authentication_method_enabled": false}),
+			))
+			.with_status(200)
+			.with_body(&test_data)
+			.create();
+
+		match client
+			.account()
+			.disable_auth_method("disableaccount", "someid")
+			.await
+		{
+			Ok(response) => {
+				let method = response.authentication_method;
+				assert!(!method.authentication_method_enabled);
+				assert!(method.state == AuthMethodState::Active);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_account_update_auth_method_provisioning() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/account/provisioningaccount/authentication/someid";
+		let test_data =
+			testutil::get_test_data("tests/testdata/account/update_auth_method_provisioning.json");
+		let _m = mockito::mock("PUT", query_url)
+			.match_body(mockito::Matcher::Json(
+				json!({"auto_join_user_group_id": "ug22ba494e096", "saml_join_groups": true}),
+			))
+			.with_status(200)
+			.with_body(&test_data)
+			.create();
+
+		let provisioning = AuthMethodProvisioning::default()
+			.auto_join_user_group_id("ug22ba494e096")
+			.saml_join_groups(true);
+		match client
+			.account()
+			.update_auth_method_provisioning("provisioningaccount", "someid", &provisioning)
+			.await
+		{
+			Ok(response) => {
+				let method = response.authentication_method;
+				assert!(method.auto_join_user_group_id.as_deref() == Some("ug22ba494e096"));
+				assert!(method.saml_join_groups);
+				assert!(!method.saml_idp_login_allowed);
+				assert!(method.saml_field_mapping.group.as_deref() == Some("memberOf"));
+			}
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1255,11 +1465,11 @@ mod tests {
 		let query_url = "/account";
 
 		let test_data = testutil::get_test_data("tests/testdata/account/create_new.json");
-		let _m = testutil::create_mock_server(&query_url, &test_data, "POST");
+		let _m = testutil::create_mock_server(query_url, &test_data, "POST");
 
 		match client.account().create_new("account_name").await {
 			Ok(response) => assert!(response.account.account_name == "new-account-name"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1270,11 +1480,11 @@ mod tests {
 
 		let test_data =
 			testutil::get_test_data("tests/testdata/account/list_accessible_accounts.json");
-		let _m = testutil::create_mock_server(&query_url, &test_data, "GET");
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
 
 		match client.account().list_accessible_accounts().await {
-			Ok(response) => assert!(response.accounts.len() > 0),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Ok(response) => assert!(!response.accounts.is_empty()),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1291,7 +1501,7 @@ mod tests {
 			.await
 		{
 			Ok(_response) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1308,7 +1518,7 @@ mod tests {
 
 		match client.account().get_details("some-account-details").await {
 			Ok(response) => assert!(response.account.account_name == "some-account-name"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1330,7 +1540,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.account.account_name == "updated_name"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1347,7 +1557,7 @@ mod tests {
 
 		match client.account().list_events("some-account-id").await {
 			Ok(response) => assert!(response.events[0].account_id == "unique-account-id"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1364,7 +1574,7 @@ mod tests {
 
 		match client.account().list_projects("some-account-id").await {
 			Ok(response) => assert!(response.projects[0].account_id == "unique-account-id"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1387,7 +1597,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.teams[0].account_id == "unique-account-id"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1409,7 +1619,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.team.account_id == "unique-account-id"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1426,7 +1636,7 @@ mod tests {
 
 		match client.account().list_teams("unique-account-id").await {
 			Ok(response) => assert!(response.teams[0].account_id == "unique-account-id"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1447,7 +1657,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1469,7 +1679,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.team.account_id == "unique-account-id"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1494,7 +1704,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.team.account_id == "updated-account-id"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1517,7 +1727,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.account_invites[0].account_id == "invited-account-id"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1539,7 +1749,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1562,7 +1772,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.invite_details.user_email == "approved-email"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1584,7 +1794,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1606,7 +1816,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1629,7 +1839,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.members[0].create_time == "timestamp"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1655,7 +1865,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1681,7 +1891,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1703,7 +1913,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1726,7 +1936,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.projects[0].project_name == "my-team-project-one"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1751,7 +1961,7 @@ mod tests {
 			Ok(response) => {
 				assert!(response.user_projects[0].project_name == "my-user-project-one")
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1774,7 +1984,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.teams[0].team_id == "unique-team-id"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1796,7 +2006,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.users[0].real_name == "real_user"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 }
@@ -29,6 +29,21 @@ pub struct SamlFieldMapping {
 	pub identity: String,
 	pub last_name: String,
 	pub real_name: String,
+	/// Attribute holding the groups of a user, used with `saml_join_groups`
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub group: Option<String>,
+}
+
+/// State of an account authentication method
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthMethodState {
+	Active,
+	Deleted,
+	PendingConfiguration,
+	#[default]
+	#[serde(other)]
+	Unknown,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
@@ -54,8 +69,65 @@ pub struct AuthenticationMethod {
 	pub saml_metadata_url: String,
 	pub saml_signature_algorithm: String,
 	pub saml_variant: String,
-	pub state: String,
+	pub state: AuthMethodState,
 	pub update_time: String,
+	/// User group which users signing in the first time join
+	#[serde(default)]
+	pub auto_join_user_group_id: Option<String>,
+	/// Add users to the user groups named in the SAML group attribute
+	#[serde(default)]
+	pub saml_join_groups: bool,
+	/// Allow sign in started from the identity provider
+	#[serde(default)]
+	pub saml_idp_login_allowed: bool,
+}
+
+/// Just-in-time provisioning options of an authentication method, only the
+/// set fields are changed
+///
+/// ```rust
+/// use aiven_rs::account::types::AuthMethodProvisioning;
+///
+/// let provisioning = AuthMethodProvisioning::default()
+///     .auto_join_team_id("at1f2d3e4a5b6")
+///     .saml_join_groups(true);
+/// ```
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct AuthMethodProvisioning {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub auto_join_team_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub auto_join_user_group_id: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub saml_join_groups: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub saml_idp_login_allowed: Option<bool>,
+}
+
+impl AuthMethodProvisioning {
+	/// Team which users join when they sign in the first time
+	pub fn auto_join_team_id<S: Into<String>>(mut self, team_id: S) -> Self {
+		self.auto_join_team_id = Some(team_id.into());
+		self
+	}
+
+	/// User group which users join when they sign in the first time
+	pub fn auto_join_user_group_id<S: Into<String>>(mut self, user_group_id: S) -> Self {
+		self.auto_join_user_group_id = Some(user_group_id.into());
+		self
+	}
+
+	/// Add users to the user groups named in the SAML group attribute
+	pub fn saml_join_groups(mut self, join: bool) -> Self {
+		self.saml_join_groups = Some(join);
+		self
+	}
+
+	/// Allow sign in started from the identity provider
+	pub fn saml_idp_login_allowed(mut self, allowed: bool) -> Self {
+		self.saml_idp_login_allowed = Some(allowed);
+		self
+	}
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
@@ -81,6 +153,11 @@ pub struct Account {
 	pub create_time: String,
 	pub is_account_owner: bool,
 	pub update_time: String,
+	/// Set for organization units, the account of their organization
+	#[serde(default)]
+	pub parent_account_id: Option<String>,
+	#[serde(default)]
+	pub organization_id: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
@@ -22,6 +22,7 @@
 
 use crate::{
 	billing::types,
+	billing_group::types::{InvoiceLines, ResponseInvoiceLines},
 	client::{encode_param, HTTPClient},
 	errors::AivenError,
 	make_json_request, make_request,
@@ -71,7 +72,7 @@ impl ProjectBillingApi {
 		json_body.insert("code", code.to_string());
 		let data = &json_body;
 		let response = make_json_request!(self, reqwest::Method::POST, &url, data)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Download PDF invoice
@@ -114,8 +115,100 @@ impl ProjectBillingApi {
 			invoice_number = encode_param(invoice_number),
 			download_cookie = encode_param(download_cookie),
 		);
-		let response: reqwest::Response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.bytes().await?)
+		let response = make_request!(self, reqwest::Method::GET, &url)?;
+		response.bytes().await
+	}
+
+	/// Download the PDF of an invoice returned by `list_project_invoices`
+	///
+	/// https://api.aiven.io/doc/#operation/ProjectInvoiceGet
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `invoice` - Invoice to download
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let project_billing = client.project_billing();
+	/// for invoice in project_billing.list_project_invoices("project").await?.invoices {
+	///     let pdf = project_billing.download_invoice("project", &invoice).await?;
+	///     std::fs::write(format!("{}.pdf", invoice.invoice_number), &pdf[..])?;
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn download_invoice(
+		&self,
+		project: &str,
+		invoice: &types::Invoice,
+	) -> Result<Bytes, AivenError> {
+		self.download_pdf_invoice(project, &invoice.invoice_number, &invoice.download_cookie)
+			.await
+	}
+
+	/// List the lines of an invoice which belong to a project.
+	///
+	/// Invoices are issued per billing group, so this looks up the billing
+	/// group which issued the invoice and keeps the lines of the project.
+	///
+	/// https://api.aiven.io/doc/#operation/BillingGroupInvoiceLinesList
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `invoice_number` - Invoice number
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let lines = client
+	///   .project_billing()
+	///   .list_invoice_lines("project", "a1ea-52")
+	///   .await?;
+	/// for line in lines {
+	///     println!("{} {}", line.service_name, line.line_total_usd);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_invoice_lines(
+		&self,
+		project: &str,
+		invoice_number: &str,
+	) -> Result<Vec<InvoiceLines>, AivenError> {
+		let billing_group_id = self
+			.list_project_invoices(project)
+			.await?
+			.invoices
+			.into_iter()
+			.find(|invoice| invoice.invoice_number == invoice_number)
+			.and_then(|invoice| invoice.billing_group_id)
+			.ok_or_else(|| AivenError::NotFound {
+				resource: format!("billing group of invoice {}", invoice_number),
+			})?;
+		let url = format!(
+			"billing-group/{billing_group}/invoice/{invoice_number}/lines",
+			billing_group = encode_param(&billing_group_id),
+			invoice_number = encode_param(invoice_number),
+		);
+		let response: ResponseInvoiceLines = make_request!(self, reqwest::Method::GET, &url)?
+			.json()
+			.await?;
+		Ok(response
+			.lines
+			.into_iter()
+			.filter(|line| line.project_name == project)
+			.collect())
 	}
 
 	/// List project credits
@@ -141,7 +234,7 @@ impl ProjectBillingApi {
 	pub async fn list_project_credits(&self, project: &str) -> Result<types::Credits, AivenError> {
 		let url = format!("project/{project}/credits", project = encode_param(project),);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List project invoices
@@ -170,7 +263,7 @@ impl ProjectBillingApi {
 	) -> Result<types::Invoices, AivenError> {
 		let url = format!("project/{project}/invoice", project = encode_param(project),);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 }
 
@@ -193,7 +286,7 @@ mod tests {
 			.await
 		{
 			Ok(resp) => assert!(resp.credit.code == "AVN2015"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -211,8 +304,8 @@ mod tests {
 			.list_project_credits("myproject")
 			.await
 		{
-			Ok(resp) => assert!(resp.credits.len() > 0),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Ok(resp) => assert!(!resp.credits.is_empty()),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -230,8 +323,57 @@ mod tests {
 			.list_project_invoices("myproject")
 			.await
 		{
-			Ok(resp) => assert!(resp.invoices.len() > 0),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Ok(resp) => assert!(!resp.invoices.is_empty()),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_project_billing_download_invoice() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/invoiceproject/invoice/a1ea-52/cookie";
+		let _m = testutil::create_mock_server(query_url, "pdfdata", "GET");
+
+		let invoice = crate::billing::types::Invoice {
+			invoice_number: "a1ea-52".into(),
+			download_cookie: "cookie".into(),
+			..Default::default()
+		};
+		match client
+			.project_billing()
+			.download_invoice("invoiceproject", &invoice)
+			.await
+		{
+			Ok(response) => assert!(&response[..] == b"pdfdata"),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_project_billing_list_invoice_lines() {
+		let client = testutil::prepare_test_client();
+		let invoices =
+			testutil::get_test_data("tests/testdata/project_billing/invoice_lines_invoices.json");
+		let _invoices =
+			testutil::create_mock_server("/project/linesproject/invoice", &invoices, "GET");
+		let test_data =
+			testutil::get_test_data("tests/testdata/project_billing/invoice_lines.json");
+		let _lines = testutil::create_mock_server(
+			"/billing-group/bg-1/invoice/a1ea-52/lines",
+			&test_data,
+			"GET",
+		);
+
+		match client
+			.project_billing()
+			.list_invoice_lines("linesproject", "a1ea-52")
+			.await
+		{
+			Ok(lines) => {
+				assert!(lines.len() == 1);
+				assert!(lines[0].service_name == "mypg");
+			}
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -250,9 +392,9 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(&response[..] == b"somedata", format!("{:?}", response));
+				assert!(&response[..] == b"somedata", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 }
@@ -39,6 +39,9 @@ pub struct Credit {
 
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct Invoice {
+	/// Billing group which issued the invoice
+	#[serde(default)]
+	pub billing_group_id: Option<String>,
 	pub currency: String,
 	pub download_cookie: String,
 	pub invoice_number: String,
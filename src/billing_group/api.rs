@@ -23,12 +23,20 @@
 use crate::{
 	billing_group::types,
 	client::{encode_param, HTTPClient},
+	deadline::Deadline,
 	errors::AivenError,
 	make_json_request, make_request,
 };
 use bytes::Bytes;
+use futures_util::stream::{self, StreamExt, TryStreamExt};
 use serde::Serialize;
-use std::iter::IntoIterator;
+use std::{
+	iter::IntoIterator,
+	sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// How many billing groups `list_all_invoices` queries at a time
+const INVOICE_LIST_CONCURRENCY: usize = 4;
 
 pub struct BillingGroupApi {
 	http_client: HTTPClient,
@@ -77,7 +85,7 @@ impl BillingGroupApi {
 	) -> Result<types::ResponseBillingGroup, AivenError> {
 		let url = "/billing-group";
 		let response = make_json_request!(self, reqwest::Method::POST, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List billing groups
@@ -104,7 +112,7 @@ impl BillingGroupApi {
 	pub async fn list(&self) -> Result<types::ResponseBillingGroups, AivenError> {
 		let url = "/billing-group";
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Claim a credit code
@@ -142,7 +150,7 @@ impl BillingGroupApi {
 			"code": credit_code,
 		});
 		let response = make_json_request!(self, reqwest::Method::POST, url, body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List billing group credits
@@ -176,7 +184,7 @@ impl BillingGroupApi {
 		);
 
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete billing group
@@ -241,7 +249,7 @@ impl BillingGroupApi {
 		);
 
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Update billing group
@@ -282,7 +290,7 @@ impl BillingGroupApi {
 		);
 
 		let response = make_json_request!(self, reqwest::Method::PUT, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List billing group events
@@ -317,7 +325,7 @@ impl BillingGroupApi {
 		);
 
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Download PDF invoice
@@ -360,7 +368,7 @@ impl BillingGroupApi {
 		);
 
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.bytes().await?)
+		response.bytes().await
 	}
 
 	/// Get a single invoice
@@ -396,7 +404,7 @@ impl BillingGroupApi {
 		);
 
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Get invoice lines for a single invoice
@@ -432,7 +440,39 @@ impl BillingGroupApi {
 		);
 
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// Get the lines of an invoice summed up per project and per service
+	///
+	/// https://api.aiven.io/doc/#operation/BillingGroupInvoiceLinesList
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// # #[tokio::main]
+	/// # async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	///
+	/// let breakdown = client
+	///         .billing_group()
+	///         .cost_breakdown("billing-group-id", "invoice-num").await?;
+	/// for service in &breakdown.services {
+	///     println!("{}/{}: {:.2} USD", service.project_name, service.service_name, service.total_usd);
+	/// }
+	/// Ok(())
+	/// # }
+	/// ```
+	pub async fn cost_breakdown(
+		&self,
+		billing_group_id: &str,
+		invoice_number: &str,
+	) -> Result<types::CostBreakdown, AivenError> {
+		let lines = self
+			.get_invoice_lines(billing_group_id, invoice_number)
+			.await?;
+		types::CostBreakdown::from_lines(&lines.lines)
 	}
 
 	/// Get invoices generated for billing group
@@ -465,7 +505,99 @@ impl BillingGroupApi {
 		);
 
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// Get the invoices of all billing groups the user can access
+	///
+	/// The invoices of the billing groups are fetched concurrently and
+	/// returned as one list, sorted by billing period and then by billing
+	/// group name. Fails if the invoices of any billing group can't be
+	/// listed.
+	///
+	/// Once `deadline` expires the remaining requests are dropped and
+	/// `AivenError::DeadlineExceeded` reports how many billing groups were
+	/// listed completely.
+	///
+	/// https://api.aiven.io/doc/#operation/BillingGroupInvoiceList
+	///
+	/// # Arguments
+	///
+	/// * `deadline` - Give up after this, `Deadline::none()` waits for all
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::deadline::Deadline;
+	/// use std::time::Duration;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	///
+	/// let invoices = client
+	///     .billing_group()
+	///     .list_all_invoices(Deadline::after(Duration::from_secs(60)))
+	///     .await?;
+	/// for entry in invoices {
+	///     println!(
+	///         "{},{},{},{} {}",
+	///         entry.billing_group_name,
+	///         entry.invoice.invoice_number,
+	///         entry.invoice.period_begin,
+	///         entry.invoice.total_inc_vat,
+	///         entry.invoice.currency
+	///     );
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_all_invoices(
+		&self,
+		deadline: Deadline,
+	) -> Result<Vec<types::BillingGroupInvoice>, AivenError> {
+		deadline.check(0)?;
+		let groups = deadline.run(0, self.list()).await?.billing_groups;
+		let listed = AtomicUsize::new(0);
+		let listed = &listed;
+		let fetch = stream::iter(groups)
+			.map(|group| async move {
+				let invoices = self.get_invoices(&group.billing_group_id).await?.invoices;
+				listed.fetch_add(1, Ordering::Relaxed);
+				Ok::<_, AivenError>(
+					invoices
+						.into_iter()
+						.map(|invoice| types::BillingGroupInvoice {
+							billing_group_id: group.billing_group_id.clone(),
+							billing_group_name: group.billing_group_name.clone(),
+							invoice,
+						})
+						.collect::<Vec<_>>(),
+				)
+			})
+			.buffer_unordered(INVOICE_LIST_CONCURRENCY)
+			.try_collect::<Vec<_>>();
+		let per_group = match deadline.run(0, fetch).await {
+			Err(AivenError::DeadlineExceeded { .. }) => {
+				return Err(deadline.exceeded(listed.load(Ordering::Relaxed)))
+			}
+			result => result?,
+		};
+
+		let mut invoices: Vec<_> = per_group.into_iter().flatten().collect();
+		invoices.sort_by(|a, b| {
+			(
+				&a.invoice.period_begin,
+				&a.billing_group_name,
+				&a.invoice.invoice_number,
+			)
+				.cmp(&(
+					&b.invoice.period_begin,
+					&b.billing_group_name,
+					&b.invoice.invoice_number,
+				))
+		});
+		Ok(invoices)
 	}
 
 	/// Assign project to billing group
@@ -533,7 +665,7 @@ impl BillingGroupApi {
 		);
 
 		let response = make_request!(self, reqwest::Method::POST, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Assign projects to billing group
@@ -584,6 +716,7 @@ mod tests {
 	use super::*;
 	use crate::testutil;
 	use serde_json::json;
+	use std::time::Duration;
 
 	#[tokio::test]
 	async fn test_billing_group_create() {
@@ -603,10 +736,11 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.billing_group.account_id == "some-unique-accountid",
-					format!("{:?}", response)
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -622,10 +756,11 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.billing_groups[0].account_id == "some-unique-accountid",
-					format!("{:?}", response)
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -646,12 +781,9 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(
-					response.credit.code == "unique-code",
-					format!("{:?}", response)
-				);
+				assert!(response.credit.code == "unique-code", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -672,12 +804,9 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(
-					response.credits[0].code == "unique-code",
-					format!("{:?}", response)
-				);
+				assert!(response.credits[0].code == "unique-code", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -695,7 +824,7 @@ mod tests {
 			Ok(_response) => {
 				assert!(true);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -715,10 +844,11 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.billing_group.account_id == "unique-account-id",
-					format!("{:?}", response)
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -764,10 +894,11 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.billing_group.account_id == "unique-updated-account",
-					format!("{:?}", response)
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -785,12 +916,9 @@ mod tests {
 
 		match client.billing_group().list_events("my-billing-group").await {
 			Ok(response) => {
-				assert!(
-					response.events[0].actor == "unique-actor",
-					format!("{:?}", response)
-				);
+				assert!(response.events[0].actor == "unique-actor", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -805,7 +933,7 @@ mod tests {
 		);
 
 		let test_data = "fake-invoice-data";
-		let _m = testutil::create_mock_server(url, &test_data, "GET");
+		let _m = testutil::create_mock_server(url, test_data, "GET");
 
 		match client
 			.billing_group()
@@ -813,12 +941,9 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(
-					&response[..] == b"fake-invoice-data",
-					format!("{:?}", response)
-				);
+				assert!(&response[..] == b"fake-invoice-data", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -842,12 +967,9 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(
-					response.invoice.invoice_number == "12345",
-					format!("{:?}", response)
-				);
+				assert!(response.invoice.invoice_number == "12345", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -871,12 +993,64 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(
-					response.lines[0].cloud_name == "aws-east",
-					format!("{:?}", response)
-				);
+				assert!(response.lines[0].cloud_name == "aws-east", "{:?}", response);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_billing_group_list_all_invoices() {
+		// A dedicated api version keeps `/billing-group` apart from the mock
+		// of `test_billing_group_list`
+		let client = crate::AivenClient::from_token(mockito::server_url(), "allinvoices", "abc");
+		let mocks: Vec<mockito::Mock> = [
+			("/allinvoices/billing-group", "all_invoices_groups.json"),
+			(
+				"/allinvoices/billing-group/bg-emea/invoice",
+				"all_invoices_emea.json",
+			),
+			(
+				"/allinvoices/billing-group/bg-us/invoice",
+				"all_invoices_us.json",
+			),
+		]
+		.iter()
+		.map(|(url, file)| {
+			let data = testutil::get_test_data(&format!("tests/testdata/billing_group/{}", file));
+			testutil::create_mock_server(url, &data, "GET")
+		})
+		.collect();
+
+		match client
+			.billing_group()
+			.list_all_invoices(Deadline::none())
+			.await
+		{
+			Ok(invoices) => {
+				let numbers: Vec<&str> = invoices
+					.iter()
+					.map(|entry| entry.invoice.invoice_number.as_str())
+					.collect();
+				assert!(numbers == vec!["emea-0001", "us-0001", "emea-0002"]);
+				assert!(invoices[1].billing_group_id == "bg-us");
+				assert!(invoices[1].billing_group_name == "US");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		drop(mocks);
+	}
+
+	#[tokio::test]
+	async fn test_billing_group_list_all_invoices_deadline() {
+		let client = testutil::prepare_test_client();
+		match client
+			.billing_group()
+			.list_all_invoices(Deadline::after(Duration::from_secs(0)))
+			.await
+		{
+			Err(AivenError::DeadlineExceeded { completed, .. }) => assert!(completed == 0),
+			other => assert!(false, "{:?}", other),
 		}
 	}
 
@@ -898,12 +1072,9 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(
-					response.invoices[0].currency == "AUD",
-					format!("{:?}", response)
-				);
+				assert!(response.invoices[0].currency == "AUD", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -926,7 +1097,7 @@ mod tests {
 			.await
 		{
 			Ok(_response) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -951,10 +1122,11 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.projects[0].available_credits == "32.5",
-					format!("{:?}", response)
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -979,7 +1151,7 @@ mod tests {
 			Ok(_response) => {
 				assert!(true);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 }
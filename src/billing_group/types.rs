@@ -20,7 +20,9 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::errors::AivenError;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct Email {
 	pub email: String,
@@ -144,6 +146,106 @@ pub struct InvoiceLines {
 	pub timestamp_end: String,
 }
 
+/// Cost of a single service, or of project wide charges such as support
+/// when `service_name` is empty
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct ServiceCost {
+	pub project_name: String,
+	pub service_name: String,
+	pub total_usd: f64,
+	pub total_local: f64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct ProjectCost {
+	pub project_name: String,
+	pub total_usd: f64,
+	pub total_local: f64,
+}
+
+/// Invoice lines summed up per project and per service
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct CostBreakdown {
+	pub local_currency: String,
+	/// Earliest `timestamp_begin` of the lines
+	pub period_begin: String,
+	/// Latest `timestamp_end` of the lines
+	pub period_end: String,
+	/// Sorted by project and service name
+	pub services: Vec<ServiceCost>,
+	/// Sorted by project name
+	pub projects: Vec<ProjectCost>,
+	pub total_usd: f64,
+	pub total_local: f64,
+}
+
+impl CostBreakdown {
+	/// Sum up invoice lines. Fails if a line total is not a number.
+	pub fn from_lines(lines: &[InvoiceLines]) -> Result<CostBreakdown, AivenError> {
+		let mut services: BTreeMap<(&str, &str), (f64, f64)> = BTreeMap::new();
+		let mut breakdown = CostBreakdown::default();
+		for line in lines {
+			let usd = parse_amount(&line.line_total_usd)?;
+			let local = parse_amount(&line.line_total_local)?;
+			let entry = services
+				.entry((&line.project_name, &line.service_name))
+				.or_default();
+			entry.0 += usd;
+			entry.1 += local;
+			breakdown.total_usd += usd;
+			breakdown.total_local += local;
+
+			if breakdown.local_currency.is_empty() {
+				breakdown.local_currency = line.local_currency.clone();
+			}
+			if breakdown.period_begin.is_empty() || line.timestamp_begin < breakdown.period_begin {
+				breakdown.period_begin = line.timestamp_begin.clone();
+			}
+			if line.timestamp_end > breakdown.period_end {
+				breakdown.period_end = line.timestamp_end.clone();
+			}
+		}
+
+		for ((project_name, service_name), (total_usd, total_local)) in services {
+			match breakdown.projects.last_mut() {
+				Some(project) if project.project_name == project_name => {
+					project.total_usd += total_usd;
+					project.total_local += total_local;
+				}
+				_ => breakdown.projects.push(ProjectCost {
+					project_name: project_name.to_string(),
+					total_usd,
+					total_local,
+				}),
+			}
+			breakdown.services.push(ServiceCost {
+				project_name: project_name.to_string(),
+				service_name: service_name.to_string(),
+				total_usd,
+				total_local,
+			});
+		}
+		Ok(breakdown)
+	}
+
+	/// Costs of the services of a single project
+	pub fn services_of<'a>(
+		&'a self,
+		project_name: &'a str,
+	) -> impl Iterator<Item = &'a ServiceCost> {
+		self.services
+			.iter()
+			.filter(move |service| service.project_name == project_name)
+	}
+}
+
+fn parse_amount(amount: &str) -> Result<f64, AivenError> {
+	if amount.is_empty() {
+		return Ok(0.0);
+	}
+	Ok(serde_json::from_str(amount)?)
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct InvoiceBillingGroup {
 	pub currency: String,
@@ -156,6 +258,15 @@ pub struct InvoiceBillingGroup {
 	pub total_vat_zero: String,
 }
 
+/// An invoice together with the billing group it was issued for
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct BillingGroupInvoice {
+	pub billing_group_id: String,
+	pub billing_group_name: String,
+	#[serde(flatten)]
+	pub invoice: InvoiceBillingGroup,
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResponseInvoiceBillingGroup {
 	pub invoices: Vec<InvoiceBillingGroup>,
@@ -172,3 +283,37 @@ pub struct Project {
 pub struct ResponseAssociatedProjects {
 	pub projects: Vec<Project>,
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+
+	#[test]
+	fn test_cost_breakdown_from_lines() {
+		let data = testutil::get_test_data("tests/testdata/billing_group/invoice_cost_lines.json");
+		let lines: ResponseInvoiceLines = serde_json::from_str(&data).unwrap();
+		let breakdown = CostBreakdown::from_lines(&lines.lines).unwrap();
+
+		assert!(breakdown.local_currency == "EUR");
+		assert!(breakdown.period_begin == "2021-02-01T00:00:00Z");
+		assert!(breakdown.period_end == "2021-03-01T00:00:00Z");
+		assert!(breakdown.total_usd == 900.0);
+		assert!(breakdown.services.len() == 3);
+		assert!(breakdown.services[0].service_name == "kafka-events");
+		assert!(breakdown.services[0].total_usd == 750.0);
+		assert!(breakdown.projects.len() == 2);
+		assert!(breakdown.projects[1].project_name == "webshop");
+		assert!(breakdown.projects[1].total_usd == 150.0);
+		assert!(breakdown.services_of("webshop").count() == 2);
+	}
+
+	#[test]
+	fn test_cost_breakdown_invalid_amount() {
+		let line = InvoiceLines {
+			line_total_usd: "n/a".into(),
+			..Default::default()
+		};
+		assert!(CostBreakdown::from_lines(&[line]).is_err());
+	}
+}
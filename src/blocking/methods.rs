@@ -0,0 +1,3633 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Blocking versions of the methods of every API struct.
+
+mod account {
+	use super::super::{AccountApi, Blocking};
+	use crate::{account::types, errors::AivenError};
+	use serde::Serialize;
+
+	impl Blocking<AccountApi> {
+		/// Blocking version of [`AccountApi::create_new_auth_method`]
+		pub fn create_new_auth_method<T: Serialize + ?Sized>(
+			&self,
+			account_id: &str,
+			json_body: &T,
+		) -> Result<types::AuthenticationMethodResponse, AivenError> {
+			self.runtime
+				.block_on(self.api.create_new_auth_method(account_id, json_body))
+		}
+
+		/// Blocking version of [`AccountApi::list_auth_methods`]
+		pub fn list_auth_methods(
+			&self,
+			account_id: &str,
+		) -> Result<types::AuthenticationMethodsResponse, AivenError> {
+			self.runtime
+				.block_on(self.api.list_auth_methods(account_id))
+		}
+
+		/// Blocking version of [`AccountApi::delete_auth_method`]
+		pub fn delete_auth_method(
+			&self,
+			account_id: &str,
+			account_auth_method_id: &str,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(
+				self.api
+					.delete_auth_method(account_id, account_auth_method_id),
+			)
+		}
+
+		/// Blocking version of [`AccountApi::get_auth_method_details`]
+		pub fn get_auth_method_details(
+			&self,
+			account_id: &str,
+			account_auth_method_id: &str,
+		) -> Result<types::AuthenticationMethodResponse, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.get_auth_method_details(account_id, account_auth_method_id),
+			)
+		}
+
+		/// Blocking version of [`AccountApi::update_auth_method`]
+		pub fn update_auth_method(
+			&self,
+			account_id: &str,
+			account_auth_method_id: &str,
+		) -> Result<types::AuthenticationMethodResponse, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.update_auth_method(account_id, account_auth_method_id),
+			)
+		}
+
+		/// Blocking version of [`AccountApi::enable_auth_method`]
+		pub fn enable_auth_method(
+			&self,
+			account_id: &str,
+			account_auth_method_id: &str,
+		) -> Result<types::AuthenticationMethodResponse, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.enable_auth_method(account_id, account_auth_method_id),
+			)
+		}
+
+		/// Blocking version of [`AccountApi::disable_auth_method`]
+		pub fn disable_auth_method(
+			&self,
+			account_id: &str,
+			account_auth_method_id: &str,
+		) -> Result<types::AuthenticationMethodResponse, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.disable_auth_method(account_id, account_auth_method_id),
+			)
+		}
+
+		/// Blocking version of [`AccountApi::update_auth_method_provisioning`]
+		pub fn update_auth_method_provisioning(
+			&self,
+			account_id: &str,
+			account_auth_method_id: &str,
+			provisioning: &types::AuthMethodProvisioning,
+		) -> Result<types::AuthenticationMethodResponse, AivenError> {
+			self.runtime
+				.block_on(self.api.update_auth_method_provisioning(
+					account_id,
+					account_auth_method_id,
+					provisioning,
+				))
+		}
+
+		/// Blocking version of [`AccountApi::create_new`]
+		pub fn create_new(&self, account_name: &str) -> Result<types::AccountResponse, AivenError> {
+			self.runtime.block_on(self.api.create_new(account_name))
+		}
+
+		/// Blocking version of [`AccountApi::list_accessible_accounts`]
+		pub fn list_accessible_accounts(&self) -> Result<types::Accounts, AivenError> {
+			self.runtime.block_on(self.api.list_accessible_accounts())
+		}
+
+		/// Blocking version of [`AccountApi::delete_account`]
+		pub fn delete_account(&self, account_id: &str) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.delete_account(account_id))
+		}
+
+		/// Blocking version of [`AccountApi::get_details`]
+		pub fn get_details(&self, account_id: &str) -> Result<types::AccountResponse, AivenError> {
+			self.runtime.block_on(self.api.get_details(account_id))
+		}
+
+		/// Blocking version of [`AccountApi::update_account`]
+		pub fn update_account<T: ?Sized + Serialize>(
+			&self,
+			account_id: &str,
+			json_body: &T,
+		) -> Result<types::AccountResponse, AivenError> {
+			self.runtime
+				.block_on(self.api.update_account(account_id, json_body))
+		}
+
+		/// Blocking version of [`AccountApi::list_events`]
+		pub fn list_events(&self, account_id: &str) -> Result<types::Events, AivenError> {
+			self.runtime.block_on(self.api.list_events(account_id))
+		}
+
+		/// Blocking version of [`AccountApi::list_projects`]
+		pub fn list_projects(&self, account_id: &str) -> Result<types::Projects, AivenError> {
+			self.runtime.block_on(self.api.list_projects(account_id))
+		}
+
+		/// Blocking version of [`AccountApi::list_teams_for_project`]
+		pub fn list_teams_for_project(
+			&self,
+			account_id: &str,
+			project_name: &str,
+		) -> Result<types::Teams, AivenError> {
+			self.runtime
+				.block_on(self.api.list_teams_for_project(account_id, project_name))
+		}
+
+		/// Blocking version of [`AccountApi::create_team`]
+		pub fn create_team<T: ?Sized + Serialize>(
+			&self,
+			account_id: &str,
+			json_data: &T,
+		) -> Result<types::TeamResponse, AivenError> {
+			self.runtime
+				.block_on(self.api.create_team(account_id, json_data))
+		}
+
+		/// Blocking version of [`AccountApi::list_teams`]
+		pub fn list_teams(&self, account_id: &str) -> Result<types::Teams, AivenError> {
+			self.runtime.block_on(self.api.list_teams(account_id))
+		}
+
+		/// Blocking version of [`AccountApi::delete_team`]
+		pub fn delete_team(&self, account_id: &str, team_id: &str) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_team(account_id, team_id))
+		}
+
+		/// Blocking version of [`AccountApi::get_team_details`]
+		pub fn get_team_details(
+			&self,
+			account_id: &str,
+			team_id: &str,
+		) -> Result<types::TeamResponse, AivenError> {
+			self.runtime
+				.block_on(self.api.get_team_details(account_id, team_id))
+		}
+
+		/// Blocking version of [`AccountApi::update_team_details`]
+		pub fn update_team_details<T: ?Sized + Serialize>(
+			&self,
+			account_id: &str,
+			team_id: &str,
+			json_body: &T,
+		) -> Result<types::TeamResponse, AivenError> {
+			self.runtime
+				.block_on(self.api.update_team_details(account_id, team_id, json_body))
+		}
+
+		/// Blocking version of [`AccountApi::list_pending_invites`]
+		pub fn list_pending_invites(
+			&self,
+			account_id: &str,
+			team_id: &str,
+		) -> Result<types::Invites, AivenError> {
+			self.runtime
+				.block_on(self.api.list_pending_invites(account_id, team_id))
+		}
+
+		/// Blocking version of [`AccountApi::cancel_pending_invite`]
+		pub fn cancel_pending_invite(
+			&self,
+			account_id: &str,
+			team_id: &str,
+			user_email: &str,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(
+				self.api
+					.cancel_pending_invite(account_id, team_id, user_email),
+			)
+		}
+
+		/// Blocking version of [`AccountApi::confirm_team_invite`]
+		pub fn confirm_team_invite(
+			&self,
+			account_id: &str,
+			invite_verification_code: &str,
+		) -> Result<types::InviteDetails, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.confirm_team_invite(account_id, invite_verification_code),
+			)
+		}
+
+		/// Blocking version of [`AccountApi::remove_member`]
+		pub fn remove_member(
+			&self,
+			account_id: &str,
+			team_id: &str,
+			user_id: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.remove_member(account_id, team_id, user_id))
+		}
+
+		/// Blocking version of [`AccountApi::invite_member`]
+		pub fn invite_member<T: ?Sized + Serialize>(
+			&self,
+			account_id: &str,
+			team_id: &str,
+			json_body: &T,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.invite_member(account_id, team_id, json_body))
+		}
+
+		/// Blocking version of [`AccountApi::list_team_members`]
+		pub fn list_team_members(
+			&self,
+			account_id: &str,
+			team_id: &str,
+		) -> Result<types::Members, AivenError> {
+			self.runtime
+				.block_on(self.api.list_team_members(account_id, team_id))
+		}
+
+		/// Blocking version of [`AccountApi::associate_team_to_project`]
+		pub fn associate_team_to_project<T: ?Sized + Serialize>(
+			&self,
+			account_id: &str,
+			team_id: &str,
+			project: &str,
+			json_body: &T,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(
+				self.api
+					.associate_team_to_project(account_id, team_id, project, json_body),
+			)
+		}
+
+		/// Blocking version of [`AccountApi::update_team_project_association`]
+		pub fn update_team_project_association<T: ?Sized + Serialize>(
+			&self,
+			account_id: &str,
+			team_id: &str,
+			project: &str,
+			json_body: &T,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(
+				self.api
+					.update_team_project_association(account_id, team_id, project, json_body),
+			)
+		}
+
+		/// Blocking version of [`AccountApi::dissociate_team_from_project`]
+		pub fn dissociate_team_from_project(
+			&self,
+			account_id: &str,
+			team_id: &str,
+			project: &str,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(
+				self.api
+					.dissociate_team_from_project(account_id, team_id, project),
+			)
+		}
+
+		/// Blocking version of [`AccountApi::list_projects_by_team`]
+		pub fn list_projects_by_team(
+			&self,
+			account_id: &str,
+			team_id: &str,
+		) -> Result<types::TeamProjects, AivenError> {
+			self.runtime
+				.block_on(self.api.list_projects_by_team(account_id, team_id))
+		}
+
+		/// Blocking version of [`AccountApi::list_projects_by_user`]
+		pub fn list_projects_by_user(
+			&self,
+			account_id: &str,
+			user_id: &str,
+		) -> Result<types::UserProjects, AivenError> {
+			self.runtime
+				.block_on(self.api.list_projects_by_user(account_id, user_id))
+		}
+
+		/// Blocking version of [`AccountApi::list_teams_for_user`]
+		pub fn list_teams_for_user(
+			&self,
+			account_id: &str,
+			user_id: &str,
+		) -> Result<types::Teams, AivenError> {
+			self.runtime
+				.block_on(self.api.list_teams_for_user(account_id, user_id))
+		}
+
+		/// Blocking version of [`AccountApi::search_users`]
+		pub fn search_users<T: ?Sized + Serialize>(
+			&self,
+			account_id: &str,
+			json_body: &T,
+		) -> Result<types::Users, AivenError> {
+			self.runtime
+				.block_on(self.api.search_users(account_id, json_body))
+		}
+	}
+}
+
+mod billing {
+	use super::super::{Blocking, ProjectBillingApi};
+	use crate::{billing::types, billing_group::types::InvoiceLines, errors::AivenError};
+	use bytes::Bytes;
+
+	impl Blocking<ProjectBillingApi> {
+		/// Blocking version of [`ProjectBillingApi::claim_credit_code`]
+		pub fn claim_credit_code(
+			&self,
+			project: &str,
+			code: &str,
+		) -> Result<types::ResCredit, AivenError> {
+			self.runtime
+				.block_on(self.api.claim_credit_code(project, code))
+		}
+
+		/// Blocking version of [`ProjectBillingApi::download_pdf_invoice`]
+		pub fn download_pdf_invoice(
+			&self,
+			project: &str,
+			invoice_number: &str,
+			download_cookie: &str,
+		) -> Result<Bytes, AivenError> {
+			self.runtime.block_on(self.api.download_pdf_invoice(
+				project,
+				invoice_number,
+				download_cookie,
+			))
+		}
+
+		/// Blocking version of [`ProjectBillingApi::download_invoice`]
+		pub fn download_invoice(
+			&self,
+			project: &str,
+			invoice: &types::Invoice,
+		) -> Result<Bytes, AivenError> {
+			self.runtime
+				.block_on(self.api.download_invoice(project, invoice))
+		}
+
+		/// Blocking version of [`ProjectBillingApi::list_invoice_lines`]
+		pub fn list_invoice_lines(
+			&self,
+			project: &str,
+			invoice_number: &str,
+		) -> Result<Vec<InvoiceLines>, AivenError> {
+			self.runtime
+				.block_on(self.api.list_invoice_lines(project, invoice_number))
+		}
+
+		/// Blocking version of [`ProjectBillingApi::list_project_credits`]
+		pub fn list_project_credits(&self, project: &str) -> Result<types::Credits, AivenError> {
+			self.runtime
+				.block_on(self.api.list_project_credits(project))
+		}
+
+		/// Blocking version of [`ProjectBillingApi::list_project_invoices`]
+		pub fn list_project_invoices(&self, project: &str) -> Result<types::Invoices, AivenError> {
+			self.runtime
+				.block_on(self.api.list_project_invoices(project))
+		}
+	}
+}
+
+mod billing_group {
+	use super::super::{BillingGroupApi, Blocking};
+	use crate::{billing_group::types, deadline::Deadline, errors::AivenError};
+	use bytes::Bytes;
+	use serde::Serialize;
+	use std::iter::IntoIterator;
+
+	impl Blocking<BillingGroupApi> {
+		/// Blocking version of [`BillingGroupApi::create`]
+		pub fn create<T: Serialize + ?Sized>(
+			&self,
+			json_body: &T,
+		) -> Result<types::ResponseBillingGroup, AivenError> {
+			self.runtime.block_on(self.api.create(json_body))
+		}
+
+		/// Blocking version of [`BillingGroupApi::list`]
+		pub fn list(&self) -> Result<types::ResponseBillingGroups, AivenError> {
+			self.runtime.block_on(self.api.list())
+		}
+
+		/// Blocking version of [`BillingGroupApi::claim_credit_code`]
+		pub fn claim_credit_code(
+			&self,
+			billing_group_id: &str,
+			credit_code: &str,
+		) -> Result<types::ResponseClaimCredit, AivenError> {
+			self.runtime
+				.block_on(self.api.claim_credit_code(billing_group_id, credit_code))
+		}
+
+		/// Blocking version of [`BillingGroupApi::list_billing_group_credits`]
+		pub fn list_billing_group_credits(
+			&self,
+			billing_group_id: &str,
+		) -> Result<types::ResponseCredits, AivenError> {
+			self.runtime
+				.block_on(self.api.list_billing_group_credits(billing_group_id))
+		}
+
+		/// Blocking version of [`BillingGroupApi::delete`]
+		pub fn delete(&self, billing_group_id: &str) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.delete(billing_group_id))
+		}
+
+		/// Blocking version of [`BillingGroupApi::details`]
+		pub fn details(
+			&self,
+			billing_group_id: &str,
+		) -> Result<types::ResponseBillingGroup, AivenError> {
+			self.runtime.block_on(self.api.details(billing_group_id))
+		}
+
+		/// Blocking version of [`BillingGroupApi::update`]
+		pub fn update<T: Serialize + ?Sized>(
+			&self,
+			billing_group_id: &str,
+			json_body: &T,
+		) -> Result<types::ResponseBillingGroup, AivenError> {
+			self.runtime
+				.block_on(self.api.update(billing_group_id, json_body))
+		}
+
+		/// Blocking version of [`BillingGroupApi::list_events`]
+		pub fn list_events(
+			&self,
+			billing_group_id: &str,
+		) -> Result<types::ResponseEvents, AivenError> {
+			self.runtime
+				.block_on(self.api.list_events(billing_group_id))
+		}
+
+		/// Blocking version of [`BillingGroupApi::download_invoice`]
+		pub fn download_invoice(
+			&self,
+			billing_group_id: &str,
+			invoice_number: &str,
+			download_cookie: &str,
+		) -> Result<Bytes, AivenError> {
+			self.runtime.block_on(self.api.download_invoice(
+				billing_group_id,
+				invoice_number,
+				download_cookie,
+			))
+		}
+
+		/// Blocking version of [`BillingGroupApi::get_invoice`]
+		pub fn get_invoice(
+			&self,
+			billing_group_id: &str,
+			invoice_number: &str,
+		) -> Result<types::ResponseInvoice, AivenError> {
+			self.runtime
+				.block_on(self.api.get_invoice(billing_group_id, invoice_number))
+		}
+
+		/// Blocking version of [`BillingGroupApi::get_invoice_lines`]
+		pub fn get_invoice_lines(
+			&self,
+			billing_group_id: &str,
+			invoice_number: &str,
+		) -> Result<types::ResponseInvoiceLines, AivenError> {
+			self.runtime
+				.block_on(self.api.get_invoice_lines(billing_group_id, invoice_number))
+		}
+
+		/// Blocking version of [`BillingGroupApi::cost_breakdown`]
+		pub fn cost_breakdown(
+			&self,
+			billing_group_id: &str,
+			invoice_number: &str,
+		) -> Result<types::CostBreakdown, AivenError> {
+			self.runtime
+				.block_on(self.api.cost_breakdown(billing_group_id, invoice_number))
+		}
+
+		/// Blocking version of [`BillingGroupApi::get_invoices`]
+		pub fn get_invoices(
+			&self,
+			billing_group_id: &str,
+		) -> Result<types::ResponseInvoiceBillingGroup, AivenError> {
+			self.runtime
+				.block_on(self.api.get_invoices(billing_group_id))
+		}
+
+		/// Blocking version of [`BillingGroupApi::list_all_invoices`]
+		pub fn list_all_invoices(
+			&self,
+			deadline: Deadline,
+		) -> Result<Vec<types::BillingGroupInvoice>, AivenError> {
+			self.runtime.block_on(self.api.list_all_invoices(deadline))
+		}
+
+		/// Blocking version of [`BillingGroupApi::assign_project`]
+		pub fn assign_project(
+			&self,
+			billing_group_id: &str,
+			project_name: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.assign_project(billing_group_id, project_name))
+		}
+
+		/// Blocking version of [`BillingGroupApi::get_associated_projects`]
+		pub fn get_associated_projects(
+			&self,
+			billing_group_id: &str,
+		) -> Result<types::ResponseAssociatedProjects, AivenError> {
+			self.runtime
+				.block_on(self.api.get_associated_projects(billing_group_id))
+		}
+
+		/// Blocking version of [`BillingGroupApi::assign_projects`]
+		pub fn assign_projects<T, I>(
+			&self,
+			billing_group_id: &str,
+			projects: I,
+		) -> Result<(), AivenError>
+		where
+			I: IntoIterator<Item = T>,
+			T: Into<String>,
+		{
+			self.runtime
+				.block_on(self.api.assign_projects(billing_group_id, projects))
+		}
+	}
+}
+
+mod raw {
+	use super::super::{Blocking, RawApi};
+	use crate::client::RawResponse;
+	use crate::errors::AivenError;
+
+	use reqwest::Method;
+	use serde::Serialize;
+
+	impl Blocking<RawApi> {
+		/// Blocking version of [`RawApi::get`]
+		pub fn get(&self, path: &str) -> Result<RawResponse, AivenError> {
+			self.runtime.block_on(self.api.get(path))
+		}
+
+		/// Blocking version of [`RawApi::post`]
+		pub fn post<T: Serialize + ?Sized>(
+			&self,
+			path: &str,
+			body: &T,
+		) -> Result<RawResponse, AivenError> {
+			self.runtime.block_on(self.api.post(path, body))
+		}
+
+		/// Blocking version of [`RawApi::put`]
+		pub fn put<T: Serialize + ?Sized>(
+			&self,
+			path: &str,
+			body: &T,
+		) -> Result<RawResponse, AivenError> {
+			self.runtime.block_on(self.api.put(path, body))
+		}
+
+		/// Blocking version of [`RawApi::delete`]
+		pub fn delete(&self, path: &str) -> Result<RawResponse, AivenError> {
+			self.runtime.block_on(self.api.delete(path))
+		}
+
+		/// Blocking version of [`RawApi::delete_json`]
+		pub fn delete_json<T: Serialize + ?Sized>(
+			&self,
+			path: &str,
+			body: &T,
+		) -> Result<RawResponse, AivenError> {
+			self.runtime.block_on(self.api.delete_json(path, body))
+		}
+
+		/// Blocking version of [`RawApi::request`]
+		pub fn request<T: Serialize + ?Sized>(
+			&self,
+			method: Method,
+			path: &str,
+			body: Option<&T>,
+		) -> Result<RawResponse, AivenError> {
+			self.runtime.block_on(self.api.request(method, path, body))
+		}
+	}
+}
+
+mod cloud {
+	use super::super::{Blocking, CloudApi};
+	use crate::cloud::types;
+	use crate::errors::AivenError;
+
+	impl Blocking<CloudApi> {
+		/// Blocking version of [`CloudApi::list_by_project`]
+		pub fn list_by_project(&self, project: &str) -> Result<types::ResClouds, AivenError> {
+			self.runtime.block_on(self.api.list_by_project(project))
+		}
+
+		/// Blocking version of [`CloudApi::list_all`]
+		pub fn list_all(&self) -> Result<types::ResClouds, AivenError> {
+			self.runtime.block_on(self.api.list_all())
+		}
+
+		/// Blocking version of [`CloudApi::capability_matrix`]
+		pub fn capability_matrix(
+			&self,
+			project: Option<&str>,
+		) -> Result<types::CapabilityMatrix, AivenError> {
+			self.runtime.block_on(self.api.capability_matrix(project))
+		}
+	}
+}
+
+mod key_mgmt {
+	use super::super::{Blocking, ProjectKeyManagementApi};
+	use crate::{
+		errors::AivenError,
+		key_mgmt::types,
+		service::types_service::{ResServiceCA, ResServiceKeyPair},
+	};
+
+	impl Blocking<ProjectKeyManagementApi> {
+		/// Blocking version of [`ProjectKeyManagementApi::retrieve_ca_cert`]
+		pub fn retrieve_ca_cert(&self, project: &str) -> Result<types::ResCertificate, AivenError> {
+			self.runtime.block_on(self.api.retrieve_ca_cert(project))
+		}
+
+		/// Blocking version of [`ProjectKeyManagementApi::get_service_ca`]
+		pub fn get_service_ca(
+			&self,
+			project: &str,
+			service_name: &str,
+			ca_name: &str,
+		) -> Result<ResServiceCA, AivenError> {
+			self.runtime
+				.block_on(self.api.get_service_ca(project, service_name, ca_name))
+		}
+
+		/// Blocking version of [`ProjectKeyManagementApi::get_service_keypair`]
+		pub fn get_service_keypair(
+			&self,
+			project: &str,
+			service_name: &str,
+			keypair_name: &str,
+		) -> Result<ResServiceKeyPair, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.get_service_keypair(project, service_name, keypair_name),
+			)
+		}
+	}
+}
+
+mod organization {
+	use super::super::{Blocking, OrganizationApi};
+	use crate::{account::types::Account, errors::AivenError, organization::types};
+
+	impl Blocking<OrganizationApi> {
+		/// Blocking version of [`OrganizationApi::list_organizations`]
+		pub fn list_organizations(&self) -> Result<types::Organizations, AivenError> {
+			self.runtime.block_on(self.api.list_organizations())
+		}
+
+		/// Blocking version of [`OrganizationApi::get_organization`]
+		pub fn get_organization(
+			&self,
+			organization_id: &str,
+		) -> Result<types::Organization, AivenError> {
+			self.runtime
+				.block_on(self.api.get_organization(organization_id))
+		}
+
+		/// Blocking version of [`OrganizationApi::update_organization`]
+		pub fn update_organization(
+			&self,
+			organization_id: &str,
+			update: &types::OrganizationUpdate,
+		) -> Result<types::Organization, AivenError> {
+			self.runtime
+				.block_on(self.api.update_organization(organization_id, update))
+		}
+
+		/// Blocking version of [`OrganizationApi::list_organization_units`]
+		pub fn list_organization_units(
+			&self,
+			organization_id: &str,
+		) -> Result<Vec<Account>, AivenError> {
+			self.runtime
+				.block_on(self.api.list_organization_units(organization_id))
+		}
+
+		/// Blocking version of [`OrganizationApi::list_users`]
+		pub fn list_users(
+			&self,
+			organization_id: &str,
+		) -> Result<types::OrganizationUsers, AivenError> {
+			self.runtime.block_on(self.api.list_users(organization_id))
+		}
+
+		/// Blocking version of [`OrganizationApi::invite_user`]
+		pub fn invite_user(&self, organization_id: &str, email: &str) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.invite_user(organization_id, email))
+		}
+
+		/// Blocking version of [`OrganizationApi::remove_user`]
+		pub fn remove_user(&self, organization_id: &str, user_id: &str) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.remove_user(organization_id, user_id))
+		}
+	}
+}
+
+mod organization_user_groups {
+	use super::super::{Blocking, OrganizationUserGroupApi};
+	use crate::{errors::AivenError, organization::types};
+
+	impl Blocking<OrganizationUserGroupApi> {
+		/// Blocking version of [`OrganizationUserGroupApi::list_user_groups`]
+		pub fn list_user_groups(
+			&self,
+			organization_id: &str,
+		) -> Result<types::UserGroups, AivenError> {
+			self.runtime
+				.block_on(self.api.list_user_groups(organization_id))
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::create_user_group`]
+		pub fn create_user_group(
+			&self,
+			organization_id: &str,
+			name: &str,
+			description: &str,
+		) -> Result<types::UserGroup, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.create_user_group(organization_id, name, description),
+			)
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::get_user_group`]
+		pub fn get_user_group(
+			&self,
+			organization_id: &str,
+			user_group_id: &str,
+		) -> Result<types::UserGroup, AivenError> {
+			self.runtime
+				.block_on(self.api.get_user_group(organization_id, user_group_id))
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::update_user_group`]
+		pub fn update_user_group(
+			&self,
+			organization_id: &str,
+			user_group_id: &str,
+			update: &types::UserGroupUpdate,
+		) -> Result<types::UserGroup, AivenError> {
+			self.runtime.block_on(self.api.update_user_group(
+				organization_id,
+				user_group_id,
+				update,
+			))
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::delete_user_group`]
+		pub fn delete_user_group(
+			&self,
+			organization_id: &str,
+			user_group_id: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_user_group(organization_id, user_group_id))
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::list_user_group_members`]
+		pub fn list_user_group_members(
+			&self,
+			organization_id: &str,
+			user_group_id: &str,
+		) -> Result<types::UserGroupMembers, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.list_user_group_members(organization_id, user_group_id),
+			)
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::add_user_group_members`]
+		pub fn add_user_group_members(
+			&self,
+			organization_id: &str,
+			user_group_id: &str,
+			user_ids: &[&str],
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.add_user_group_members(
+				organization_id,
+				user_group_id,
+				user_ids,
+			))
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::remove_user_group_members`]
+		pub fn remove_user_group_members(
+			&self,
+			organization_id: &str,
+			user_group_id: &str,
+			user_ids: &[&str],
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.remove_user_group_members(
+				organization_id,
+				user_group_id,
+				user_ids,
+			))
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::list_project_permissions`]
+		pub fn list_project_permissions(
+			&self,
+			organization_id: &str,
+			project: &str,
+		) -> Result<types::ResourcePermissions, AivenError> {
+			self.runtime
+				.block_on(self.api.list_project_permissions(organization_id, project))
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::set_project_permissions`]
+		pub fn set_project_permissions(
+			&self,
+			organization_id: &str,
+			project: &str,
+			permissions: &[types::PermissionEntry],
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.set_project_permissions(
+				organization_id,
+				project,
+				permissions,
+			))
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::assign_user_group_to_project`]
+		pub fn assign_user_group_to_project(
+			&self,
+			organization_id: &str,
+			user_group_id: &str,
+			project: &str,
+			permissions: &[types::PermissionType],
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.assign_user_group_to_project(
+				organization_id,
+				user_group_id,
+				project,
+				permissions,
+			))
+		}
+
+		/// Blocking version of [`OrganizationUserGroupApi::revoke_user_group_from_project`]
+		pub fn revoke_user_group_from_project(
+			&self,
+			organization_id: &str,
+			user_group_id: &str,
+			project: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.revoke_user_group_from_project(
+					organization_id,
+					user_group_id,
+					project,
+				))
+		}
+	}
+}
+
+mod payment {
+	use super::super::{Blocking, PaymentApi};
+	use crate::{errors::AivenError, payment::types};
+	use serde::Serialize;
+
+	impl Blocking<PaymentApi> {
+		/// Blocking version of [`PaymentApi::add_credit_card`]
+		pub fn add_credit_card<T: Serialize + ?Sized>(
+			&self,
+			json_body: &T,
+		) -> Result<types::ResCard, AivenError> {
+			self.runtime.block_on(self.api.add_credit_card(json_body))
+		}
+
+		/// Blocking version of [`PaymentApi::delete_credit_card`]
+		pub fn delete_credit_card(&self, card_id: &str) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.delete_credit_card(card_id))
+		}
+
+		/// Blocking version of [`PaymentApi::list_credit_cards`]
+		pub fn list_credit_cards(&self) -> Result<types::ResCards, AivenError> {
+			self.runtime.block_on(self.api.list_credit_cards())
+		}
+
+		/// Blocking version of [`PaymentApi::update_credit_card`]
+		pub fn update_credit_card<T: Serialize + ?Sized>(
+			&self,
+			card_id: &str,
+			json_body: &T,
+		) -> Result<types::ResCard, AivenError> {
+			self.runtime
+				.block_on(self.api.update_credit_card(card_id, json_body))
+		}
+	}
+}
+
+mod project {
+	use super::super::{Blocking, ProjectApi};
+	use crate::{deadline::Deadline, errors::AivenError, project::types};
+	use serde::Serialize;
+
+	impl Blocking<ProjectApi> {
+		/// Blocking version of [`ProjectApi::confirm_project_invite`]
+		pub fn confirm_project_invite(
+			&self,
+			project: &str,
+			verification_code: &str,
+		) -> Result<types::ResProjectInvite, AivenError> {
+			self.runtime
+				.block_on(self.api.confirm_project_invite(project, verification_code))
+		}
+
+		/// Blocking version of [`ProjectApi::create`]
+		pub fn create<T: Serialize + ?Sized>(
+			&self,
+			json_body: &T,
+		) -> Result<types::ResProject, AivenError> {
+			self.runtime.block_on(self.api.create(json_body))
+		}
+
+		/// Blocking version of [`ProjectApi::delete_peering_connection`]
+		pub fn delete_peering_connection(
+			&self,
+			project: &str,
+			project_vpc_id: &str,
+			peer_cloud_account: &str,
+			peer_vpc: &str,
+		) -> Result<types::PeeringConnection, AivenError> {
+			self.runtime.block_on(self.api.delete_peering_connection(
+				project,
+				project_vpc_id,
+				peer_cloud_account,
+				peer_vpc,
+			))
+		}
+
+		/// Blocking version of [`ProjectApi::delete_peering_connection_with_region`]
+		pub fn delete_peering_connection_with_region(
+			&self,
+			project: &str,
+			project_vpc_id: &str,
+			peer_cloud_account: &str,
+			peer_vpc: &str,
+			peer_region: &str,
+		) -> Result<types::PeeringConnection, AivenError> {
+			self.runtime
+				.block_on(self.api.delete_peering_connection_with_region(
+					project,
+					project_vpc_id,
+					peer_cloud_account,
+					peer_vpc,
+					peer_region,
+				))
+		}
+
+		/// Blocking version of [`ProjectApi::delete_project_vpc`]
+		pub fn delete_project_vpc(
+			&self,
+			project: &str,
+			project_vpc_id: &str,
+		) -> Result<types::ProjectVPC, AivenError> {
+			self.runtime
+				.block_on(self.api.delete_project_vpc(project, project_vpc_id))
+		}
+
+		/// Blocking version of [`ProjectApi::delete_project_invitation`]
+		pub fn delete_project_invitation(
+			&self,
+			project: &str,
+			invited_email: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_project_invitation(project, invited_email))
+		}
+
+		/// Blocking version of [`ProjectApi::delete_project`]
+		pub fn delete_project(&self, project: &str) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.delete_project(project))
+		}
+
+		/// Blocking version of [`ProjectApi::get_vpc_info`]
+		pub fn get_vpc_info(
+			&self,
+			project: &str,
+			project_vpc_id: &str,
+		) -> Result<types::ProjectVPC, AivenError> {
+			self.runtime
+				.block_on(self.api.get_vpc_info(project, project_vpc_id))
+		}
+
+		/// Blocking version of [`ProjectApi::get_project_details`]
+		pub fn get_project_details(&self, project: &str) -> Result<types::ResProject, AivenError> {
+			self.runtime.block_on(self.api.get_project_details(project))
+		}
+
+		/// Blocking version of [`ProjectApi::get_event_log_entries`]
+		pub fn get_event_log_entries(&self, project: &str) -> Result<types::ResEvents, AivenError> {
+			self.runtime
+				.block_on(self.api.get_event_log_entries(project))
+		}
+
+		/// Blocking version of [`ProjectApi::list_vpcs`]
+		pub fn list_vpcs(&self, project: &str) -> Result<types::ProjectVPCs, AivenError> {
+			self.runtime.block_on(self.api.list_vpcs(project))
+		}
+
+		/// Blocking version of [`ProjectApi::list_active_alerts`]
+		pub fn list_active_alerts(&self, project: &str) -> Result<types::ActiveAlerts, AivenError> {
+			self.runtime.block_on(self.api.list_active_alerts(project))
+		}
+
+		/// Blocking version of [`ProjectApi::collect_service_notifications`]
+		pub fn collect_service_notifications(
+			&self,
+			project: &str,
+		) -> Result<Vec<types::ProjectServiceNotification>, AivenError> {
+			self.runtime
+				.block_on(self.api.collect_service_notifications(project))
+		}
+
+		/// Blocking version of [`ProjectApi::list_projects`]
+		pub fn list_projects(&self) -> Result<types::ProjectList, AivenError> {
+			self.runtime.block_on(self.api.list_projects())
+		}
+
+		/// Blocking version of [`ProjectApi::list_users_for_project`]
+		pub fn list_users_for_project(
+			&self,
+			project: &str,
+		) -> Result<types::ProjectUserList, AivenError> {
+			self.runtime
+				.block_on(self.api.list_users_for_project(project))
+		}
+
+		/// Blocking version of [`ProjectApi::remove_user`]
+		pub fn remove_user(&self, project: &str, email: &str) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.remove_user(project, email))
+		}
+
+		/// Blocking version of [`ProjectApi::request_vpc_for_project`]
+		pub fn request_vpc_for_project<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			json_body: &T,
+		) -> Result<types::ProjectVPC, AivenError> {
+			self.runtime
+				.block_on(self.api.request_vpc_for_project(project, json_body))
+		}
+
+		/// Blocking version of [`ProjectApi::request_peering_connection`]
+		pub fn request_peering_connection<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			project_vpc_id: &str,
+			json_body: &T,
+		) -> Result<types::PeeringConnection, AivenError> {
+			self.runtime.block_on(self.api.request_peering_connection(
+				project,
+				project_vpc_id,
+				json_body,
+			))
+		}
+
+		/// Blocking version of [`ProjectApi::send_membership_invitation`]
+		pub fn send_membership_invitation(
+			&self,
+			project: &str,
+			user_email: &str,
+			member_type: Option<&str>,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.send_membership_invitation(
+				project,
+				user_email,
+				member_type,
+			))
+		}
+
+		/// Blocking version of [`ProjectApi::update_user`]
+		pub fn update_user(
+			&self,
+			project: &str,
+			user_email: &str,
+			member_type: types::MemberType,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.update_user(project, user_email, member_type))
+		}
+
+		/// Blocking version of [`ProjectApi::plan_member_sync`]
+		pub fn plan_member_sync<S, I>(
+			&self,
+			project: &str,
+			desired: I,
+		) -> Result<types::MembershipPlan, AivenError>
+		where
+			I: IntoIterator<Item = (S, types::MemberType)>,
+			S: Into<String>,
+		{
+			self.runtime
+				.block_on(self.api.plan_member_sync(project, desired))
+		}
+
+		/// Blocking version of [`ProjectApi::apply_member_sync`]
+		pub fn apply_member_sync(
+			&self,
+			project: &str,
+			plan: &types::MembershipPlan,
+			deadline: Deadline,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.apply_member_sync(project, plan, deadline))
+		}
+
+		/// Blocking version of [`ProjectApi::update_project`]
+		pub fn update_project<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			json_body: &T,
+		) -> Result<types::ResProject, AivenError> {
+			self.runtime
+				.block_on(self.api.update_project(project, json_body))
+		}
+
+		/// Blocking version of [`ProjectApi::update_userdefined_network_cidrs`]
+		pub fn update_userdefined_network_cidrs<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			project_vpc_id: &str,
+			json_body: &T,
+		) -> Result<types::ProjectVPC, AivenError> {
+			self.runtime
+				.block_on(self.api.update_userdefined_network_cidrs(
+					project,
+					project_vpc_id,
+					json_body,
+				))
+		}
+	}
+}
+
+mod service {
+	use super::super::{Blocking, BlockingIter, ServiceApi};
+	use crate::service::types_service::*;
+	use crate::{
+		client::{Completion, Conditional},
+		errors::AivenError,
+		poll::PollOpts,
+	};
+	use futures_util::stream::Stream;
+
+	use serde::{de::DeserializeOwned, Serialize};
+
+	impl Blocking<ServiceApi> {
+		/// Blocking version of [`ServiceApi::cancel_query`]
+		pub fn cancel_query<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<ServiceCancelQuery, AivenError> {
+			self.runtime
+				.block_on(self.api.cancel_query(project, service_name, json_body))
+		}
+
+		/// Blocking version of [`ServiceApi::create_user`]
+		pub fn create_user<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<ResServiceUser, AivenError> {
+			self.runtime
+				.block_on(self.api.create_user(project, service_name, json_body))
+		}
+
+		/// Blocking version of [`ServiceApi::create_logical_database`]
+		pub fn create_logical_database<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.create_logical_database(
+				project,
+				service_name,
+				json_body,
+			))
+		}
+
+		/// Blocking version of [`ServiceApi::create_new_task`]
+		pub fn create_new_task<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<ResTask, AivenError> {
+			self.runtime
+				.block_on(self.api.create_new_task(project, service_name, json_body))
+		}
+
+		/// Blocking version of [`ServiceApi::create_service`]
+		pub fn create_service<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			json_body: &T,
+		) -> Result<ResService, AivenError> {
+			self.runtime
+				.block_on(self.api.create_service(project, json_body))
+		}
+
+		/// Blocking version of [`ServiceApi::list_backups`]
+		pub fn list_backups(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResBackups, AivenError> {
+			self.runtime
+				.block_on(self.api.list_backups(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::fork_service_from_backup`]
+		pub fn fork_service_from_backup(
+			&self,
+			project: &str,
+			request: &ServiceForkRequest,
+		) -> Result<ResService, AivenError> {
+			self.runtime
+				.block_on(self.api.fork_service_from_backup(project, request))
+		}
+
+		/// Blocking version of [`ServiceApi::delete_logical_db`]
+		pub fn delete_logical_db(
+			&self,
+			project: &str,
+			service_name: &str,
+			db_name: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_logical_db(project, service_name, db_name))
+		}
+
+		/// Blocking version of [`ServiceApi::delete_user`]
+		pub fn delete_user(
+			&self,
+			project: &str,
+			service_name: &str,
+			service_username: &str,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(
+				self.api
+					.delete_user(project, service_name, service_username),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::fetch_current_queries`]
+		pub fn fetch_current_queries<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<ResQueries, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.fetch_current_queries(project, service_name, json_body),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::fetch_current_postgres_queries`]
+		pub fn fetch_current_postgres_queries<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<ResPostgresQueries, AivenError> {
+			self.runtime
+				.block_on(
+					self.api
+						.fetch_current_postgres_queries(project, service_name, json_body),
+				)
+		}
+
+		/// Blocking version of [`ServiceApi::fetch_current_mysql_queries`]
+		pub fn fetch_current_mysql_queries<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<ResMysqlQueries, AivenError> {
+			self.runtime.block_on(self.api.fetch_current_mysql_queries(
+				project,
+				service_name,
+				json_body,
+			))
+		}
+
+		/// Blocking version of [`ServiceApi::fetch_service_metrics`]
+		pub fn fetch_service_metrics<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<serde_json::Value, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.fetch_service_metrics(project, service_name, json_body),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::get_user_details`]
+		pub fn get_user_details(
+			&self,
+			project: &str,
+			service_name: &str,
+			service_username: &str,
+		) -> Result<ResServiceUser, AivenError> {
+			self.runtime.block_on(self.api.get_user_details(
+				project,
+				service_name,
+				service_username,
+			))
+		}
+
+		/// Blocking version of [`ServiceApi::get_service_info_if_modified`]
+		pub fn get_service_info_if_modified(
+			&self,
+			project: &str,
+			service_name: &str,
+			etag: Option<&str>,
+		) -> Result<Conditional<ResService>, AivenError> {
+			self.runtime.block_on(self.api.get_service_info_if_modified(
+				project,
+				service_name,
+				etag,
+			))
+		}
+
+		/// Blocking version of [`ServiceApi::get_service_info`]
+		pub fn get_service_info(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResService, AivenError> {
+			self.runtime
+				.block_on(self.api.get_service_info(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::get_service_info_as`]
+		pub fn get_service_info_as<T: DeserializeOwned>(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<T, AivenError> {
+			self.runtime
+				.block_on(self.api.get_service_info_as(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::get_log_entries`]
+		pub fn get_log_entries<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<ResLogs, AivenError> {
+			self.runtime
+				.block_on(self.api.get_log_entries(project, service_name, json_body))
+		}
+
+		/// Blocking version of [`ServiceApi::tail_logs`]
+		pub fn tail_logs<'a>(
+			&'a self,
+			project: &'a str,
+			service_name: &'a str,
+			start: LogStart,
+			opts: &'a PollOpts,
+		) -> BlockingIter<impl Stream<Item = Result<Log, AivenError>> + 'a> {
+			BlockingIter::new(
+				self.api.tail_logs(project, service_name, start, opts),
+				self.runtime.clone(),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::get_task_result`]
+		pub fn get_task_result(
+			&self,
+			project: &str,
+			service_name: &str,
+			task_id: &str,
+		) -> Result<ResTask, AivenError> {
+			self.runtime
+				.block_on(self.api.get_task_result(project, service_name, task_id))
+		}
+
+		/// Blocking version of [`ServiceApi::list_public_service_types`]
+		pub fn list_public_service_types(&self) -> Result<ResServiceTypes, AivenError> {
+			self.runtime.block_on(self.api.list_public_service_types())
+		}
+
+		/// Blocking version of [`ServiceApi::list_service_versions`]
+		pub fn list_service_versions(&self) -> Result<ResServiceVersions, AivenError> {
+			self.runtime.block_on(self.api.list_service_versions())
+		}
+
+		/// Blocking version of [`ServiceApi::list_service_types`]
+		pub fn list_service_types(&self, project: &str) -> Result<ResServiceTypes, AivenError> {
+			self.runtime.block_on(self.api.list_service_types(project))
+		}
+
+		/// Blocking version of [`ServiceApi::list_service_databases`]
+		pub fn list_service_databases(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResDatabaseNames, AivenError> {
+			self.runtime
+				.block_on(self.api.list_service_databases(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::list_active_alerts`]
+		pub fn list_active_alerts(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResAlerts, AivenError> {
+			self.runtime
+				.block_on(self.api.list_active_alerts(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::list_services`]
+		pub fn list_services(&self, project: &str) -> Result<ResServices, AivenError> {
+			self.runtime.block_on(self.api.list_services(project))
+		}
+
+		/// Blocking version of [`ServiceApi::list_services_as`]
+		pub fn list_services_as<T: DeserializeOwned>(
+			&self,
+			project: &str,
+		) -> Result<Vec<T>, AivenError> {
+			self.runtime.block_on(self.api.list_services_as(project))
+		}
+
+		/// Blocking version of [`ServiceApi::list_services_if_modified`]
+		pub fn list_services_if_modified(
+			&self,
+			project: &str,
+			etag: Option<&str>,
+		) -> Result<Conditional<ResServices>, AivenError> {
+			self.runtime
+				.block_on(self.api.list_services_if_modified(project, etag))
+		}
+
+		/// Blocking version of [`ServiceApi::list_service_summaries`]
+		pub fn list_service_summaries(
+			&self,
+			project: &str,
+			filter: &ServiceFilter,
+		) -> Result<ResServiceSummaries, AivenError> {
+			self.runtime
+				.block_on(self.api.list_service_summaries(project, filter))
+		}
+
+		/// Blocking version of [`ServiceApi::modify_service_user_credential`]
+		pub fn modify_service_user_credential(
+			&self,
+			project: &str,
+			service_name: &str,
+			service_username: &str,
+		) -> Result<ResService, AivenError> {
+			self.runtime
+				.block_on(self.api.modify_service_user_credential(
+					project,
+					service_name,
+					service_username,
+				))
+		}
+
+		/// Blocking version of [`ServiceApi::reset_service_user_credential`]
+		pub fn reset_service_user_credential(
+			&self,
+			project: &str,
+			service_name: &str,
+			service_username: &str,
+		) -> Result<ResService, AivenError> {
+			self.runtime
+				.block_on(self.api.reset_service_user_credential(
+					project,
+					service_name,
+					service_username,
+				))
+		}
+
+		/// Blocking version of [`ServiceApi::reset_service_user_password`]
+		pub fn reset_service_user_password(
+			&self,
+			project: &str,
+			service_name: &str,
+			service_username: &str,
+		) -> Result<ServiceUserCredentials, AivenError> {
+			self.runtime.block_on(self.api.reset_service_user_password(
+				project,
+				service_name,
+				service_username,
+			))
+		}
+
+		/// Blocking version of [`ServiceApi::reset_query_stats`]
+		pub fn reset_query_stats(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResResetQueryStats, AivenError> {
+			self.runtime
+				.block_on(self.api.reset_query_stats(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::get_service_ca`]
+		pub fn get_service_ca(
+			&self,
+			project: &str,
+			service_name: &str,
+			ca_name: &str,
+		) -> Result<ResServiceCA, AivenError> {
+			self.runtime
+				.block_on(self.api.get_service_ca(project, service_name, ca_name))
+		}
+
+		/// Blocking version of [`ServiceApi::get_service_keypair`]
+		pub fn get_service_keypair(
+			&self,
+			project: &str,
+			service_name: &str,
+			keypair_name: &str,
+		) -> Result<ResServiceKeyPair, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.get_service_keypair(project, service_name, keypair_name),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::start_maintenance_updates`]
+		pub fn start_maintenance_updates(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<Completion<()>, AivenError> {
+			self.runtime
+				.block_on(self.api.start_maintenance_updates(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::enable_writes`]
+		pub fn enable_writes(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResEnableWrites, AivenError> {
+			self.runtime
+				.block_on(self.api.enable_writes(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::get_migration_status`]
+		pub fn get_migration_status(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResMigrationStatus, AivenError> {
+			self.runtime
+				.block_on(self.api.get_migration_status(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::check_migration`]
+		pub fn check_migration(
+			&self,
+			project: &str,
+			service_name: &str,
+			source_service_uri: &str,
+		) -> Result<ResTask, AivenError> {
+			self.runtime.block_on(self.api.check_migration(
+				project,
+				service_name,
+				source_service_uri,
+			))
+		}
+
+		/// Blocking version of [`ServiceApi::start_migration`]
+		pub fn start_migration(
+			&self,
+			project: &str,
+			service_name: &str,
+			migration: &ExternalMigration,
+		) -> Result<ResService, AivenError> {
+			self.runtime
+				.block_on(self.api.start_migration(project, service_name, migration))
+		}
+
+		/// Blocking version of [`ServiceApi::cancel_migration`]
+		pub fn cancel_migration(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResService, AivenError> {
+			self.runtime
+				.block_on(self.api.cancel_migration(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::update_configuration`]
+		pub fn update_configuration<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<ResService, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.update_configuration(project, service_name, json_body),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::update_disk_space`]
+		pub fn update_disk_space(
+			&self,
+			project: &str,
+			service_name: &str,
+			disk_space_mb: u64,
+		) -> Result<ResService, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.update_disk_space(project, service_name, disk_space_mb),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::get_ip_filter`]
+		pub fn get_ip_filter(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<Vec<String>, AivenError> {
+			self.runtime
+				.block_on(self.api.get_ip_filter(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceApi::set_ip_filter`]
+		pub fn set_ip_filter<S: AsRef<str>>(
+			&self,
+			project: &str,
+			service_name: &str,
+			networks: &[S],
+		) -> Result<ResService, AivenError> {
+			self.runtime
+				.block_on(self.api.set_ip_filter(project, service_name, networks))
+		}
+
+		/// Blocking version of [`ServiceApi::add_ip_filter_entries`]
+		pub fn add_ip_filter_entries<S: AsRef<str>>(
+			&self,
+			project: &str,
+			service_name: &str,
+			networks: &[S],
+		) -> Result<ResService, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.add_ip_filter_entries(project, service_name, networks),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::remove_ip_filter_entries`]
+		pub fn remove_ip_filter_entries<S: AsRef<str>>(
+			&self,
+			project: &str,
+			service_name: &str,
+			networks: &[S],
+		) -> Result<ResService, AivenError> {
+			self.runtime.block_on(self.api.remove_ip_filter_entries(
+				project,
+				service_name,
+				networks,
+			))
+		}
+
+		/// Blocking version of [`ServiceApi::change_plan`]
+		pub fn change_plan(
+			&self,
+			project: &str,
+			service_name: &str,
+			new_plan: &str,
+			new_cloud: Option<&str>,
+		) -> Result<ResService, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.change_plan(project, service_name, new_plan, new_cloud),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::change_plan_and_wait`]
+		pub fn change_plan_and_wait(
+			&self,
+			project: &str,
+			service_name: &str,
+			new_plan: &str,
+			new_cloud: Option<&str>,
+			opts: &PollOpts,
+		) -> Result<Service, AivenError> {
+			self.runtime.block_on(self.api.change_plan_and_wait(
+				project,
+				service_name,
+				new_plan,
+				new_cloud,
+				opts,
+			))
+		}
+
+		/// Blocking version of [`ServiceApi::wait_for_service_running`]
+		pub fn wait_for_service_running(
+			&self,
+			project: &str,
+			service_name: &str,
+			opts: &PollOpts,
+		) -> Result<ResService, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.wait_for_service_running(project, service_name, opts),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::estimate_days_until_disk_full`]
+		pub fn estimate_days_until_disk_full(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<Option<f64>, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.estimate_days_until_disk_full(project, service_name),
+			)
+		}
+
+		/// Blocking version of [`ServiceApi::terminate`]
+		pub fn terminate(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<Completion<()>, AivenError> {
+			self.runtime
+				.block_on(self.api.terminate(project, service_name))
+		}
+	}
+}
+
+mod elasticsearch {
+	use super::super::{Blocking, ServiceElastiSearchApi};
+	use crate::{errors::AivenError, service::types_elasticsearch::*};
+
+	impl Blocking<ServiceElastiSearchApi> {
+		/// Blocking version of [`ServiceElastiSearchApi::delete_index`]
+		pub fn delete_index(
+			&self,
+			project: &str,
+			service_name: &str,
+			index_name: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_index(project, service_name, index_name))
+		}
+
+		/// Blocking version of [`ServiceElastiSearchApi::list_indexes`]
+		pub fn list_indexes(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<Indexes, AivenError> {
+			self.runtime
+				.block_on(self.api.list_indexes(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceElastiSearchApi::set_acl_configuration`]
+		pub fn set_acl_configuration(
+			&self,
+			project: &str,
+			service_name: &str,
+			acl_config: &ElasticSearchACLConfig,
+		) -> Result<ElasticSearchACLConfig, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.set_acl_configuration(project, service_name, acl_config),
+			)
+		}
+
+		/// Blocking version of [`ServiceElastiSearchApi::show_acl_configuration`]
+		pub fn show_acl_configuration(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ElasticSearchACLConfig, AivenError> {
+			self.runtime
+				.block_on(self.api.show_acl_configuration(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceElastiSearchApi::update_acl_configuration`]
+		pub fn update_acl_configuration(
+			&self,
+			project: &str,
+			service_name: &str,
+			acl_config: &ElasticSearchACLConfig,
+		) -> Result<ElasticSearchACLConfig, AivenError> {
+			self.runtime.block_on(self.api.update_acl_configuration(
+				project,
+				service_name,
+				acl_config,
+			))
+		}
+	}
+}
+
+mod flink {
+	use super::super::{Blocking, ServiceFlinkApi};
+	use crate::{errors::AivenError, service::types_flink::*};
+	use serde::Serialize;
+
+	impl Blocking<ServiceFlinkApi> {
+		/// Blocking version of [`ServiceFlinkApi::create_application`]
+		pub fn create_application<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<FlinkApplication, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.create_application(project, service_name, json_body),
+			)
+		}
+
+		/// Blocking version of [`ServiceFlinkApi::list_applications`]
+		pub fn list_applications(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResFlinkApplications, AivenError> {
+			self.runtime
+				.block_on(self.api.list_applications(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceFlinkApi::get_application`]
+		pub fn get_application(
+			&self,
+			project: &str,
+			service_name: &str,
+			application_id: &str,
+		) -> Result<FlinkApplication, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.get_application(project, service_name, application_id),
+			)
+		}
+
+		/// Blocking version of [`ServiceFlinkApi::delete_application`]
+		pub fn delete_application(
+			&self,
+			project: &str,
+			service_name: &str,
+			application_id: &str,
+		) -> Result<FlinkApplication, AivenError> {
+			self.runtime.block_on(self.api.delete_application(
+				project,
+				service_name,
+				application_id,
+			))
+		}
+
+		/// Blocking version of [`ServiceFlinkApi::create_deployment`]
+		pub fn create_deployment(
+			&self,
+			project: &str,
+			service_name: &str,
+			application_id: &str,
+			request: &FlinkDeploymentRequest,
+		) -> Result<FlinkDeployment, AivenError> {
+			self.runtime.block_on(self.api.create_deployment(
+				project,
+				service_name,
+				application_id,
+				request,
+			))
+		}
+
+		/// Blocking version of [`ServiceFlinkApi::stop_deployment`]
+		pub fn stop_deployment(
+			&self,
+			project: &str,
+			service_name: &str,
+			application_id: &str,
+			deployment_id: &str,
+		) -> Result<FlinkDeployment, AivenError> {
+			self.runtime.block_on(self.api.stop_deployment(
+				project,
+				service_name,
+				application_id,
+				deployment_id,
+			))
+		}
+
+		/// Blocking version of [`ServiceFlinkApi::list_jobs`]
+		pub fn list_jobs(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResFlinkJobs, AivenError> {
+			self.runtime
+				.block_on(self.api.list_jobs(project, service_name))
+		}
+	}
+}
+
+mod grafana {
+	use super::super::{Blocking, ServiceGrafanaApi};
+	use crate::{
+		errors::AivenError,
+		service::{types_grafana::*, types_service::ResService},
+	};
+
+	impl Blocking<ServiceGrafanaApi> {
+		/// Blocking version of [`ServiceGrafanaApi::get_user_config`]
+		pub fn get_user_config(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<GrafanaUserConfig, AivenError> {
+			self.runtime
+				.block_on(self.api.get_user_config(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceGrafanaApi::update_user_config`]
+		pub fn update_user_config(
+			&self,
+			project: &str,
+			service_name: &str,
+			user_config: &GrafanaUserConfig,
+		) -> Result<ResService, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.update_user_config(project, service_name, user_config),
+			)
+		}
+	}
+}
+
+mod influxdb {
+	use super::super::{Blocking, ServiceInfluxApi};
+	use crate::{
+		errors::AivenError,
+		service::{
+			types_influxdb::*,
+			types_service::{ResDatabaseNames, ResService},
+		},
+	};
+
+	impl Blocking<ServiceInfluxApi> {
+		/// Blocking version of [`ServiceInfluxApi::get_user_config`]
+		pub fn get_user_config(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<InfluxDbUserConfig, AivenError> {
+			self.runtime
+				.block_on(self.api.get_user_config(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceInfluxApi::update_user_config`]
+		pub fn update_user_config(
+			&self,
+			project: &str,
+			service_name: &str,
+			user_config: &InfluxDbUserConfig,
+		) -> Result<ResService, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.update_user_config(project, service_name, user_config),
+			)
+		}
+
+		/// Blocking version of [`ServiceInfluxApi::list_databases`]
+		pub fn list_databases(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResDatabaseNames, AivenError> {
+			self.runtime
+				.block_on(self.api.list_databases(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceInfluxApi::create_database`]
+		pub fn create_database(
+			&self,
+			project: &str,
+			service_name: &str,
+			database: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.create_database(project, service_name, database))
+		}
+
+		/// Blocking version of [`ServiceInfluxApi::delete_database`]
+		pub fn delete_database(
+			&self,
+			project: &str,
+			service_name: &str,
+			database: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_database(project, service_name, database))
+		}
+	}
+}
+
+mod integrations {
+	use super::super::{Blocking, ServiceIntegrationsApi};
+	use crate::errors::AivenError;
+	use serde::Serialize;
+
+	use crate::service::types_integrations::*;
+
+	impl Blocking<ServiceIntegrationsApi> {
+		/// Blocking version of [`ServiceIntegrationsApi::create_integration_endpoint`]
+		pub fn create_integration_endpoint<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			json_body: &T,
+		) -> Result<ResServiceIntegrationEndPoint, AivenError> {
+			self.runtime
+				.block_on(self.api.create_integration_endpoint(project, json_body))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::create_integration`]
+		pub fn create_integration<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			json_body: &T,
+		) -> Result<ResServiceIntegration, AivenError> {
+			self.runtime
+				.block_on(self.api.create_integration(project, json_body))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::delete_integration_endpoint`]
+		pub fn delete_integration_endpoint(
+			&self,
+			project: &str,
+			integration_endpoint_id: &str,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(
+				self.api
+					.delete_integration_endpoint(project, integration_endpoint_id),
+			)
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::delete_integration`]
+		pub fn delete_integration(
+			&self,
+			project: &str,
+			integration_id: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_integration(project, integration_id))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::get`]
+		pub fn get(
+			&self,
+			project: &str,
+			integration_id: &str,
+		) -> Result<ResServiceIntegration, AivenError> {
+			self.runtime.block_on(self.api.get(project, integration_id))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::list_endpoints_by_project`]
+		pub fn list_endpoints_by_project(
+			&self,
+			project: &str,
+		) -> Result<ResServiceIntegrationEndPoints, AivenError> {
+			self.runtime
+				.block_on(self.api.list_endpoints_by_project(project))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::list_integrations_for_service`]
+		pub fn list_integrations_for_service(
+			&self,
+			project: &str,
+			service: &str,
+		) -> Result<ResServiceIntegrations, AivenError> {
+			self.runtime
+				.block_on(self.api.list_integrations_for_service(project, service))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::list_integration_endpoint_types`]
+		pub fn list_integration_endpoint_types(
+			&self,
+			project: &str,
+		) -> Result<ResEndpointTypes, AivenError> {
+			self.runtime
+				.block_on(self.api.list_integration_endpoint_types(project))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::list_available_integration_types`]
+		pub fn list_available_integration_types(
+			&self,
+			project: &str,
+		) -> Result<ResIntegrationTypes, AivenError> {
+			self.runtime
+				.block_on(self.api.list_available_integration_types(project))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::list_integration_types_between`]
+		pub fn list_integration_types_between(
+			&self,
+			project: &str,
+			source_service_type: &str,
+			dest_service_type: &str,
+		) -> Result<Vec<IntegrationType>, AivenError> {
+			self.runtime
+				.block_on(self.api.list_integration_types_between(
+					project,
+					source_service_type,
+					dest_service_type,
+				))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::list_integration_types_for_services`]
+		pub fn list_integration_types_for_services(
+			&self,
+			project: &str,
+			source_service: &str,
+			dest_service: &str,
+		) -> Result<Vec<IntegrationType>, AivenError> {
+			self.runtime
+				.block_on(self.api.list_integration_types_for_services(
+					project,
+					source_service,
+					dest_service,
+				))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::update_integration`]
+		pub fn update_integration<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			integration_id: &str,
+			user_config: &T,
+		) -> Result<ResServiceIntegration, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.update_integration(project, integration_id, user_config),
+			)
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::update_integration_endpoint`]
+		pub fn update_integration_endpoint<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			endpoint_id: &str,
+			user_config: &T,
+		) -> Result<ResServiceIntegrationEndPoint, AivenError> {
+			self.runtime.block_on(self.api.update_integration_endpoint(
+				project,
+				endpoint_id,
+				user_config,
+			))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::get_integration_endpoint`]
+		pub fn get_integration_endpoint(
+			&self,
+			project: &str,
+			endpoint_id: &str,
+		) -> Result<ResServiceIntegrationEndPoint, AivenError> {
+			self.runtime
+				.block_on(self.api.get_integration_endpoint(project, endpoint_id))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::create_endpoint`]
+		pub fn create_endpoint(
+			&self,
+			project: &str,
+			endpoint_name: &str,
+			config: &IntegrationEndpointConfig,
+		) -> Result<ResServiceIntegrationEndPoint, AivenError> {
+			self.runtime
+				.block_on(self.api.create_endpoint(project, endpoint_name, config))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::update_endpoint`]
+		pub fn update_endpoint(
+			&self,
+			project: &str,
+			endpoint_id: &str,
+			config: &IntegrationEndpointConfig,
+		) -> Result<ResServiceIntegrationEndPoint, AivenError> {
+			self.runtime
+				.block_on(self.api.update_endpoint(project, endpoint_id, config))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::create_dedicated_kafka_connect`]
+		pub fn create_dedicated_kafka_connect(
+			&self,
+			project: &str,
+			request: &DedicatedKafkaConnectRequest,
+		) -> Result<ResDedicatedKafkaConnect, AivenError> {
+			self.runtime
+				.block_on(self.api.create_dedicated_kafka_connect(project, request))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::topology`]
+		pub fn topology(&self, project: &str) -> Result<Topology, AivenError> {
+			self.runtime.block_on(self.api.topology(project))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::create_prometheus_integration`]
+		pub fn create_prometheus_integration(
+			&self,
+			project: &str,
+			endpoint_name: &str,
+			username: &str,
+			password: &str,
+			services: &[&str],
+		) -> Result<ResPrometheusIntegration, AivenError> {
+			self.runtime
+				.block_on(self.api.create_prometheus_integration(
+					project,
+					endpoint_name,
+					username,
+					password,
+					services,
+				))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::create_datadog_endpoint`]
+		pub fn create_datadog_endpoint(
+			&self,
+			project: &str,
+			endpoint_name: &str,
+			config: &DatadogEndpointConfig,
+		) -> Result<ResServiceIntegrationEndPoint, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.create_datadog_endpoint(project, endpoint_name, config),
+			)
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::create_datadog_integration`]
+		pub fn create_datadog_integration(
+			&self,
+			project: &str,
+			service_name: &str,
+			endpoint_id: &str,
+			config: &DatadogIntegrationConfig,
+		) -> Result<ResServiceIntegration, AivenError> {
+			self.runtime.block_on(self.api.create_datadog_integration(
+				project,
+				service_name,
+				endpoint_id,
+				config,
+			))
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::enable_autoscaler`]
+		pub fn enable_autoscaler(
+			&self,
+			project: &str,
+			service_name: &str,
+			endpoint_id: &str,
+		) -> Result<ResServiceIntegration, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.enable_autoscaler(project, service_name, endpoint_id),
+			)
+		}
+
+		/// Blocking version of [`ServiceIntegrationsApi::get_autoscaler_config`]
+		pub fn get_autoscaler_config(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<Option<ServiceAutoscaler>, AivenError> {
+			self.runtime
+				.block_on(self.api.get_autoscaler_config(project, service_name))
+		}
+	}
+}
+
+mod kafka {
+	use super::super::{Blocking, ServiceKafkaApi};
+	use crate::service::types_kafka::*;
+	use crate::{
+		client::Completion, deadline::Deadline, errors::AivenError, poll::PollOpts,
+		service::SchemaRegistryApi,
+	};
+
+	use serde::Serialize;
+
+	impl Blocking<ServiceKafkaApi> {
+		/// Blocking version of [`ServiceKafkaApi::schema_registry`]
+		pub fn schema_registry(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Blocking<SchemaRegistryApi> {
+			Blocking {
+				api: self.api.schema_registry(project, service_name),
+				runtime: self.runtime.clone(),
+			}
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::add_kafka_acl_entry`]
+		pub fn add_kafka_acl_entry<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<Acl, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.add_kafka_acl_entry(project, service_name, json_body),
+			)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::check_compatibility_schema_registry`]
+		pub fn check_compatibility_schema_registry<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			subject_name: &str,
+			version_id: &str,
+			json_body: &T,
+		) -> Result<SchemaCompatibility, AivenError> {
+			self.runtime
+				.block_on(self.api.check_compatibility_schema_registry(
+					project,
+					service_name,
+					subject_name,
+					version_id,
+					json_body,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::create_kafka_connector`]
+		pub fn create_kafka_connector<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<RespKafkaConnector, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.create_kafka_connector(project, service_name, json_body),
+			)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::create_kafka_connector_and_wait`]
+		pub fn create_kafka_connector_and_wait<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+			opts: &PollOpts,
+		) -> Result<ConnectorStatus, AivenError> {
+			self.runtime
+				.block_on(self.api.create_kafka_connector_and_wait(
+					project,
+					service_name,
+					json_body,
+					opts,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::create_kafka_topic`]
+		pub fn create_kafka_topic<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<Completion<()>, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.create_kafka_topic(project, service_name, json_body),
+			)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::create_kafka_topic_with`]
+		pub fn create_kafka_topic_with(
+			&self,
+			project: &str,
+			service_name: &str,
+			request: &KafkaTopicCreateRequest,
+		) -> Result<Completion<()>, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.create_kafka_topic_with(project, service_name, request),
+			)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::delete_kafka_connector`]
+		pub fn delete_kafka_connector(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.delete_kafka_connector(
+				project,
+				service_name,
+				connector_name,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::delete_schema_registry_subject_version`]
+		pub fn delete_schema_registry_subject_version(
+			&self,
+			project: &str,
+			service_name: &str,
+			subject_name: &str,
+			version_id: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_schema_registry_subject_version(
+					project,
+					service_name,
+					subject_name,
+					version_id,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::delete_schema_registry_subject`]
+		pub fn delete_schema_registry_subject(
+			&self,
+			project: &str,
+			service_name: &str,
+			subject_name: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_schema_registry_subject(
+					project,
+					service_name,
+					subject_name,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::delete_subjects_matching`]
+		pub fn delete_subjects_matching(
+			&self,
+			project: &str,
+			service_name: &str,
+			pattern: &str,
+			mode: DeleteMode,
+			deadline: Deadline,
+		) -> Result<SubjectDeletion, AivenError> {
+			self.runtime.block_on(self.api.delete_subjects_matching(
+				project,
+				service_name,
+				pattern,
+				mode,
+				deadline,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::delete_acl_entry`]
+		pub fn delete_acl_entry(
+			&self,
+			project: &str,
+			service_name: &str,
+			kafka_acl_id: &str,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(
+				self.api
+					.delete_acl_entry(project, service_name, kafka_acl_id),
+			)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::delete_topic`]
+		pub fn delete_topic(
+			&self,
+			project: &str,
+			service_name: &str,
+			topic_name: &str,
+		) -> Result<Completion<()>, AivenError> {
+			self.runtime
+				.block_on(self.api.delete_topic(project, service_name, topic_name))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::edit_kafka_connector`]
+		pub fn edit_kafka_connector<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+			json_body: &T,
+		) -> Result<RespKafkaConnector, AivenError> {
+			self.runtime.block_on(self.api.edit_kafka_connector(
+				project,
+				service_name,
+				connector_name,
+				json_body,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::edit_schema_registry_config`]
+		pub fn edit_schema_registry_config<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			subject_name: &str,
+			json_body: &T,
+		) -> Result<RespKafkaConnectorEdit, AivenError> {
+			self.runtime.block_on(self.api.edit_schema_registry_config(
+				project,
+				service_name,
+				subject_name,
+				json_body,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::edit_schema_registry_config_global`]
+		pub fn edit_schema_registry_config_global<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<RespKafkaConnectorEdit, AivenError> {
+			self.runtime
+				.block_on(self.api.edit_schema_registry_config_global(
+					project,
+					service_name,
+					json_body,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_kafka_connect_configuration_schema`]
+		pub fn get_kafka_connect_configuration_schema(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+		) -> Result<RespKafkaConnectorConfigSchema, AivenError> {
+			self.runtime
+				.block_on(self.api.get_kafka_connect_configuration_schema(
+					project,
+					service_name,
+					connector_name,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_topic_info`]
+		pub fn get_topic_info(
+			&self,
+			project: &str,
+			service_name: &str,
+			topic_name: &str,
+		) -> Result<RespKafkaTopicInfo, AivenError> {
+			self.runtime
+				.block_on(self.api.get_topic_info(project, service_name, topic_name))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_topic_list`]
+		pub fn get_topic_list(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<RespKafkaTopicList, AivenError> {
+			self.runtime
+				.block_on(self.api.get_topic_list(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_schema_registry_subject_version`]
+		pub fn get_schema_registry_subject_version(
+			&self,
+			project: &str,
+			service_name: &str,
+			subject_name: &str,
+			version_id: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.get_schema_registry_subject_version(
+					project,
+					service_name,
+					subject_name,
+					version_id,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_schema_registry_subject_versions`]
+		pub fn get_schema_registry_subject_versions(
+			&self,
+			project: &str,
+			service_name: &str,
+			subject_name: &str,
+		) -> Result<ResKafkaSchemaRegistryVersions, AivenError> {
+			self.runtime
+				.block_on(self.api.get_schema_registry_subject_versions(
+					project,
+					service_name,
+					subject_name,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_schema_registry_subject_version_details`]
+		pub fn get_schema_registry_subject_version_details(
+			&self,
+			project: &str,
+			service_name: &str,
+			subject_name: &str,
+		) -> Result<Vec<SchemaRegistrySubjectVersion>, AivenError> {
+			self.runtime
+				.block_on(self.api.get_schema_registry_subject_version_details(
+					project,
+					service_name,
+					subject_name,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_kafka_connect_status`]
+		pub fn get_kafka_connect_status(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+		) -> Result<ResKafkaConnectConnectorStatus, AivenError> {
+			self.runtime.block_on(self.api.get_kafka_connect_status(
+				project,
+				service_name,
+				connector_name,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_kafka_connector_tasks`]
+		pub fn get_kafka_connector_tasks(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+		) -> Result<Vec<ConnectTask>, AivenError> {
+			self.runtime.block_on(self.api.get_kafka_connector_tasks(
+				project,
+				service_name,
+				connector_name,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_kafka_connector_topics`]
+		pub fn get_kafka_connector_topics(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+		) -> Result<ConnectorTopics, AivenError> {
+			self.runtime.block_on(self.api.get_kafka_connector_topics(
+				project,
+				service_name,
+				connector_name,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::reset_connector_topics`]
+		pub fn reset_connector_topics(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+		) -> Result<Completion<()>, AivenError> {
+			self.runtime.block_on(self.api.reset_connector_topics(
+				project,
+				service_name,
+				connector_name,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_kafka_connect_connectors`]
+		pub fn get_kafka_connect_connectors(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResKafkaConnectConnectorList, AivenError> {
+			self.runtime
+				.block_on(self.api.get_kafka_connect_connectors(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_config_schema_registry`]
+		pub fn get_config_schema_registry(
+			&self,
+			project: &str,
+			service_name: &str,
+			subject_name: &str,
+		) -> Result<ResKafkaSchemaRegistryConfig, AivenError> {
+			self.runtime.block_on(self.api.get_config_schema_registry(
+				project,
+				service_name,
+				subject_name,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_config_schema_registry_global`]
+		pub fn get_config_schema_registry_global(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResKafkaSchemaRegistryConfig, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.get_config_schema_registry_global(project, service_name),
+			)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_schema_in_schema_registry`]
+		pub fn get_schema_in_schema_registry(
+			&self,
+			project: &str,
+			service_name: &str,
+			schema_id: &str,
+		) -> Result<SchemaRegistrySchema, AivenError> {
+			self.runtime
+				.block_on(
+					self.api
+						.get_schema_in_schema_registry(project, service_name, schema_id),
+				)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::get_schema_in_schema_registry_by_version`]
+		pub fn get_schema_in_schema_registry_by_version(
+			&self,
+			project: &str,
+			service_name: &str,
+			subject_name: &str,
+			version_id: &str,
+		) -> Result<ResKafkaSchemaVersion, AivenError> {
+			self.runtime
+				.block_on(self.api.get_schema_in_schema_registry_by_version(
+					project,
+					service_name,
+					subject_name,
+					version_id,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::list_acl_entries`]
+		pub fn list_acl_entries(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResKafkaACLEntries, AivenError> {
+			self.runtime
+				.block_on(self.api.list_acl_entries(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::list_schema_registry_acl_entries`]
+		pub fn list_schema_registry_acl_entries(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ResSchemaRegistryAclEntries, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.list_schema_registry_acl_entries(project, service_name),
+			)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::add_schema_registry_acl_entry`]
+		pub fn add_schema_registry_acl_entry(
+			&self,
+			project: &str,
+			service_name: &str,
+			entry: &SchemaRegistryAclEntry,
+		) -> Result<ResSchemaRegistryAclEntries, AivenError> {
+			self.runtime
+				.block_on(
+					self.api
+						.add_schema_registry_acl_entry(project, service_name, entry),
+				)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::delete_schema_registry_acl_entry`]
+		pub fn delete_schema_registry_acl_entry(
+			&self,
+			project: &str,
+			service_name: &str,
+			schema_registry_acl_id: &str,
+		) -> Result<ResSchemaRegistryAclEntries, AivenError> {
+			self.runtime
+				.block_on(self.api.delete_schema_registry_acl_entry(
+					project,
+					service_name,
+					schema_registry_acl_id,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::rename_acl_user`]
+		pub fn rename_acl_user(
+			&self,
+			project: &str,
+			service_name: &str,
+			old_username: &str,
+			new_username: &str,
+		) -> Result<AclRenameReport, AivenError> {
+			self.runtime.block_on(self.api.rename_acl_user(
+				project,
+				service_name,
+				old_username,
+				new_username,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::list_topic_messages`]
+		pub fn list_topic_messages<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			topic: &str,
+			json_body: &T,
+		) -> Result<ResKafkaMessages, AivenError> {
+			self.runtime.block_on(self.api.list_topic_messages(
+				project,
+				service_name,
+				topic,
+				json_body,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::list_kafka_connectors`]
+		pub fn list_kafka_connectors(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<RespKafkaConnectorsList, AivenError> {
+			self.runtime
+				.block_on(self.api.list_kafka_connectors(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::list_schema_registry_subjects`]
+		pub fn list_schema_registry_subjects(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<RespKafkaSchemaRegistrySubjects, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.list_schema_registry_subjects(project, service_name),
+			)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::pause_kafka_connector`]
+		pub fn pause_kafka_connector(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.pause_kafka_connector(
+				project,
+				service_name,
+				connector_name,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::produce_message`]
+		pub fn produce_message<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			topic: &str,
+			json_body: &T,
+		) -> Result<ResKafkaProduceMessage, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.produce_message(project, service_name, topic, json_body),
+			)
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::register_schema`]
+		pub fn register_schema<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			subject_name: &str,
+			json_body: &T,
+		) -> Result<ResKafkaRegisterSchema, AivenError> {
+			self.runtime.block_on(self.api.register_schema(
+				project,
+				service_name,
+				subject_name,
+				json_body,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::restart_kafka_connect_connector_task`]
+		pub fn restart_kafka_connect_connector_task(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+			task_id: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.restart_kafka_connect_connector_task(
+					project,
+					service_name,
+					connector_name,
+					task_id,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::restart_kafka_connect_connector`]
+		pub fn restart_kafka_connect_connector(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.restart_kafka_connect_connector(
+					project,
+					service_name,
+					connector_name,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::resume_kafka_connect_connector`]
+		pub fn resume_kafka_connect_connector(
+			&self,
+			project: &str,
+			service_name: &str,
+			connector_name: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.resume_kafka_connect_connector(
+					project,
+					service_name,
+					connector_name,
+				))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::update_topic`]
+		pub fn update_topic<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			topic_name: &str,
+			json_body: &T,
+		) -> Result<Completion<()>, AivenError> {
+			self.runtime.block_on(self.api.update_topic(
+				project,
+				service_name,
+				topic_name,
+				json_body,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::update_topic_with`]
+		pub fn update_topic_with(
+			&self,
+			project: &str,
+			service_name: &str,
+			topic_name: &str,
+			request: &KafkaTopicUpdateRequest,
+		) -> Result<Completion<()>, AivenError> {
+			self.runtime.block_on(self.api.update_topic_with(
+				project,
+				service_name,
+				topic_name,
+				request,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::set_replication`]
+		pub fn set_replication(
+			&self,
+			project: &str,
+			service_name: &str,
+			topic_name: &str,
+			factor: u32,
+		) -> Result<Completion<()>, AivenError> {
+			self.runtime.block_on(self.api.set_replication(
+				project,
+				service_name,
+				topic_name,
+				factor,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaApi::list_all_acl_entries`]
+		pub fn list_all_acl_entries(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<Vec<KafkaACL>, AivenError> {
+			self.runtime
+				.block_on(self.api.list_all_acl_entries(project, service_name))
+		}
+	}
+}
+
+mod mirrormaker {
+	use super::super::{Blocking, ServiceKafkaMirrorMaker};
+	use crate::errors::AivenError;
+	use crate::service::types_mirrormaker::*;
+	use serde::Serialize;
+
+	impl Blocking<ServiceKafkaMirrorMaker> {
+		/// Blocking version of [`ServiceKafkaMirrorMaker::create_replication_flow`]
+		pub fn create_replication_flow<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.create_replication_flow(
+				project,
+				service_name,
+				json_body,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaMirrorMaker::get_replication_flows`]
+		pub fn get_replication_flows(
+			&self,
+			project: &str,
+			service_name: &str,
+		) -> Result<ReplicationFlows, AivenError> {
+			self.runtime
+				.block_on(self.api.get_replication_flows(project, service_name))
+		}
+
+		/// Blocking version of [`ServiceKafkaMirrorMaker::delete_replication_flow`]
+		pub fn delete_replication_flow(
+			&self,
+			project: &str,
+			service_name: &str,
+			source_cluster: &str,
+			target_cluster: &str,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.delete_replication_flow(
+				project,
+				service_name,
+				source_cluster,
+				target_cluster,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaMirrorMaker::get_replication_flow`]
+		pub fn get_replication_flow(
+			&self,
+			project: &str,
+			service_name: &str,
+			source_cluster: &str,
+			target_cluster: &str,
+		) -> Result<ReplicationFlowResponse, AivenError> {
+			self.runtime.block_on(self.api.get_replication_flow(
+				project,
+				service_name,
+				source_cluster,
+				target_cluster,
+			))
+		}
+
+		/// Blocking version of [`ServiceKafkaMirrorMaker::update_replication_flow`]
+		pub fn update_replication_flow<T: ?Sized + Serialize>(
+			&self,
+			project: &str,
+			service_name: &str,
+			source_cluster: &str,
+			target_cluster: &str,
+			json_body: &T,
+		) -> Result<ReplicationFlowResponse, AivenError> {
+			self.runtime.block_on(self.api.update_replication_flow(
+				project,
+				service_name,
+				source_cluster,
+				target_cluster,
+				json_body,
+			))
+		}
+	}
+}
+
+mod mysql {
+	use super::super::{Blocking, ServiceMysqlApi};
+	use crate::{
+		errors::AivenError,
+		service::{types_mysql::*, types_service::QueryStatisticsRequest},
+	};
+	use serde::Serialize;
+
+	impl Blocking<ServiceMysqlApi> {
+		/// Blocking version of [`ServiceMysqlApi::fetch_query_stats`]
+		pub fn fetch_query_stats<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &T,
+		) -> Result<ResMySqlQueriesStats, AivenError> {
+			self.runtime
+				.block_on(self.api.fetch_query_stats(project, service_name, json_body))
+		}
+
+		/// Blocking version of [`ServiceMysqlApi::fetch_query_statistics`]
+		pub fn fetch_query_statistics(
+			&self,
+			project: &str,
+			service_name: &str,
+			request: &QueryStatisticsRequest,
+		) -> Result<Vec<MySqlQueryStat>, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.fetch_query_statistics(project, service_name, request),
+			)
+		}
+	}
+}
+
+mod postgres {
+	use super::super::{Blocking, ServicePostgresApi};
+	use crate::{
+		errors::AivenError,
+		service::{types_postgres::*, types_service::QueryStatisticsRequest},
+	};
+	use serde::Serialize;
+	use std::collections::HashMap;
+
+	impl Blocking<ServicePostgresApi> {
+		/// Blocking version of [`ServicePostgresApi::create_pool`]
+		pub fn create_pool<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			body: &T,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.create_pool(project, service_name, body))
+		}
+
+		/// Blocking version of [`ServicePostgresApi::delete_pool`]
+		pub fn delete_pool(
+			&self,
+			project: &str,
+			service_name: &str,
+			pool_name: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_pool(project, service_name, pool_name))
+		}
+
+		/// Blocking version of [`ServicePostgresApi::fetch_query_stats`]
+		pub fn fetch_query_stats(
+			&self,
+			project: &str,
+			service_name: &str,
+			json_body: &HashMap<&str, String>,
+		) -> Result<ResPostgresQueriesStats, AivenError> {
+			self.runtime
+				.block_on(self.api.fetch_query_stats(project, service_name, json_body))
+		}
+
+		/// Blocking version of [`ServicePostgresApi::fetch_query_statistics`]
+		pub fn fetch_query_statistics(
+			&self,
+			project: &str,
+			service_name: &str,
+			request: &QueryStatisticsRequest,
+		) -> Result<Vec<PgQueryStat>, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.fetch_query_statistics(project, service_name, request),
+			)
+		}
+
+		/// Blocking version of [`ServicePostgresApi::top_queries_by_total_time`]
+		pub fn top_queries_by_total_time(
+			&self,
+			project: &str,
+			service_name: &str,
+			n: usize,
+		) -> Result<Vec<PgQueryStat>, AivenError> {
+			self.runtime
+				.block_on(self.api.top_queries_by_total_time(project, service_name, n))
+		}
+
+		/// Blocking version of [`ServicePostgresApi::top_queries_by_calls`]
+		pub fn top_queries_by_calls(
+			&self,
+			project: &str,
+			service_name: &str,
+			n: usize,
+		) -> Result<Vec<PgQueryStat>, AivenError> {
+			self.runtime
+				.block_on(self.api.top_queries_by_calls(project, service_name, n))
+		}
+
+		/// Blocking version of [`ServicePostgresApi::update_pool`]
+		pub fn update_pool<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			service_name: &str,
+			pool_name: &str,
+			json_body: &T,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(
+				self.api
+					.update_pool(project, service_name, pool_name, json_body),
+			)
+		}
+	}
+}
+
+mod schema_registry {
+	use super::super::{Blocking, SchemaRegistryApi};
+	use crate::{errors::AivenError, service::types_kafka::*};
+	use serde::Serialize;
+	use serde_json::Value;
+
+	impl Blocking<SchemaRegistryApi> {
+		/// Blocking version of [`SchemaRegistryApi::request`]
+		pub fn request<T: Serialize + ?Sized>(
+			&self,
+			method: reqwest::Method,
+			path: &str,
+			json_body: Option<&T>,
+		) -> Result<Value, AivenError> {
+			self.runtime
+				.block_on(self.api.request(method, path, json_body))
+		}
+
+		/// Blocking version of [`SchemaRegistryApi::subjects`]
+		pub fn subjects(&self) -> Result<Vec<String>, AivenError> {
+			self.runtime.block_on(self.api.subjects())
+		}
+
+		/// Blocking version of [`SchemaRegistryApi::versions`]
+		pub fn versions(&self, subject: &str) -> Result<Vec<i32>, AivenError> {
+			self.runtime.block_on(self.api.versions(subject))
+		}
+
+		/// Blocking version of [`SchemaRegistryApi::subject_version`]
+		pub fn subject_version(
+			&self,
+			subject: &str,
+			version: &str,
+		) -> Result<SchemaRegistrySubjectVersion, AivenError> {
+			self.runtime
+				.block_on(self.api.subject_version(subject, version))
+		}
+
+		/// Blocking version of [`SchemaRegistryApi::schema_by_id`]
+		pub fn schema_by_id(&self, id: i32) -> Result<SchemaRegistrySchema, AivenError> {
+			self.runtime.block_on(self.api.schema_by_id(id))
+		}
+
+		/// Blocking version of [`SchemaRegistryApi::register`]
+		pub fn register<T: Serialize + ?Sized>(
+			&self,
+			subject: &str,
+			json_body: &T,
+		) -> Result<ResKafkaRegisterSchema, AivenError> {
+			self.runtime.block_on(self.api.register(subject, json_body))
+		}
+	}
+}
+
+mod static_ip {
+	use super::super::{Blocking, ProjectStaticIpApi};
+	use crate::{errors::AivenError, static_ip::types};
+
+	impl Blocking<ProjectStaticIpApi> {
+		/// Blocking version of [`ProjectStaticIpApi::create`]
+		pub fn create(
+			&self,
+			project: &str,
+			request: &types::StaticIpCreateRequest,
+		) -> Result<types::StaticIp, AivenError> {
+			self.runtime.block_on(self.api.create(project, request))
+		}
+
+		/// Blocking version of [`ProjectStaticIpApi::list`]
+		pub fn list(&self, project: &str) -> Result<types::ResStaticIps, AivenError> {
+			self.runtime.block_on(self.api.list(project))
+		}
+
+		/// Blocking version of [`ProjectStaticIpApi::associate`]
+		pub fn associate(
+			&self,
+			project: &str,
+			static_ip_address_id: &str,
+			service_name: &str,
+		) -> Result<types::StaticIp, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.associate(project, static_ip_address_id, service_name),
+			)
+		}
+
+		/// Blocking version of [`ProjectStaticIpApi::dissociate`]
+		pub fn dissociate(
+			&self,
+			project: &str,
+			static_ip_address_id: &str,
+		) -> Result<types::StaticIp, AivenError> {
+			self.runtime
+				.block_on(self.api.dissociate(project, static_ip_address_id))
+		}
+
+		/// Blocking version of [`ProjectStaticIpApi::delete`]
+		pub fn delete(&self, project: &str, static_ip_address_id: &str) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete(project, static_ip_address_id))
+		}
+	}
+}
+
+mod ticket {
+	use super::super::{Blocking, TicketApi};
+	use crate::errors::AivenError;
+	use crate::ticket::types::*;
+	use serde::Serialize;
+
+	impl Blocking<TicketApi> {
+		/// Blocking version of [`TicketApi::create`]
+		pub fn create<T: Serialize + ?Sized>(
+			&self,
+			project: &str,
+			json_body: &T,
+		) -> Result<Ticket, AivenError> {
+			self.runtime.block_on(self.api.create(project, json_body))
+		}
+
+		/// Blocking version of [`TicketApi::open`]
+		pub fn open(
+			&self,
+			project: &str,
+			request: &TicketCreateRequest,
+		) -> Result<Ticket, AivenError> {
+			self.runtime.block_on(self.api.open(project, request))
+		}
+
+		/// Blocking version of [`TicketApi::list`]
+		pub fn list(&self, project: &str) -> Result<ResTickets, AivenError> {
+			self.runtime.block_on(self.api.list(project))
+		}
+
+		/// Blocking version of [`TicketApi::list_by_state`]
+		pub fn list_by_state(
+			&self,
+			project: &str,
+			state: TicketState,
+		) -> Result<Vec<TicketSummary>, AivenError> {
+			self.runtime
+				.block_on(self.api.list_by_state(project, state))
+		}
+
+		/// Blocking version of [`TicketApi::get`]
+		#[deprecated(note = "use `list`")]
+		#[allow(deprecated)]
+		pub fn get(&self, project: &str) -> Result<ResTickets, AivenError> {
+			self.runtime.block_on(self.api.get(project))
+		}
+
+		/// Blocking version of [`TicketApi::invite_user`]
+		pub fn invite_user(
+			&self,
+			project: &str,
+			ticket_id: &str,
+			user_email: &str,
+		) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.invite_user(project, ticket_id, user_email))
+		}
+	}
+}
+
+mod user {
+	use super::super::{Blocking, UserApi};
+	use crate::errors::AivenError;
+	use crate::user::types::*;
+	use serde::Serialize;
+
+	use std::collections::HashMap;
+
+	impl Blocking<UserApi> {
+		/// Blocking version of [`UserApi::authenticate`]
+		pub fn authenticate<T: Serialize + ?Sized>(
+			&self,
+			json_body: &T,
+		) -> Result<UserAuth, AivenError> {
+			self.runtime.block_on(self.api.authenticate(json_body))
+		}
+
+		/// Blocking version of [`UserApi::password_change`]
+		pub fn password_change<T: Serialize + ?Sized>(
+			&self,
+			json_body: &T,
+		) -> Result<ResUserPasswordChange, AivenError> {
+			self.runtime.block_on(self.api.password_change(json_body))
+		}
+
+		/// Blocking version of [`UserApi::complete_otp_config`]
+		pub fn complete_otp_config<T: Serialize + ?Sized>(
+			&self,
+			json_body: &T,
+		) -> Result<ResCompleteOTPConfig, AivenError> {
+			self.runtime
+				.block_on(self.api.complete_otp_config(json_body))
+		}
+
+		/// Blocking version of [`UserApi::configure_2fa`]
+		pub fn configure_2fa<T: Serialize + ?Sized>(
+			&self,
+			json_body: &T,
+		) -> Result<ResConfigure2fa, AivenError> {
+			self.runtime.block_on(self.api.configure_2fa(json_body))
+		}
+
+		/// Blocking version of [`UserApi::confirm_email_address`]
+		pub fn confirm_email_address(
+			&self,
+			verification_code: &str,
+		) -> Result<ResConfirmUseremailAddress, AivenError> {
+			self.runtime
+				.block_on(self.api.confirm_email_address(verification_code))
+		}
+
+		/// Blocking version of [`UserApi::confirm_password_reset`]
+		pub fn confirm_password_reset(
+			&self,
+			new_password: &str,
+			verification_code: &str,
+		) -> Result<(), AivenError> {
+			self.runtime.block_on(
+				self.api
+					.confirm_password_reset(new_password, verification_code),
+			)
+		}
+
+		/// Blocking version of [`UserApi::create`]
+		pub fn create(&self, user_config: &UserCreateConfig) -> Result<ResUserCreate, AivenError> {
+			self.runtime.block_on(self.api.create(user_config))
+		}
+
+		/// Blocking version of [`UserApi::create_access_token`]
+		pub fn create_access_token<T: Serialize + ?Sized>(
+			&self,
+			json_body: &T,
+		) -> Result<AccessToken, AivenError> {
+			self.runtime
+				.block_on(self.api.create_access_token(json_body))
+		}
+
+		/// Blocking version of [`UserApi::delete_auth_method`]
+		pub fn delete_auth_method(&self, auth_method: &str) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.delete_auth_method(auth_method))
+		}
+
+		/// Blocking version of [`UserApi::expire_auth_tokens`]
+		pub fn expire_auth_tokens(&self) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.expire_auth_tokens())
+		}
+
+		/// Blocking version of [`UserApi::info`]
+		pub fn info(&self) -> Result<UserInfo, AivenError> {
+			self.runtime.block_on(self.api.info())
+		}
+
+		/// Blocking version of [`UserApi::auth_login_options`]
+		pub fn auth_login_options(
+			&self,
+			json_body: &HashMap<&str, String>,
+		) -> Result<Vec<UserAuthLoginOptions>, AivenError> {
+			self.runtime
+				.block_on(self.api.auth_login_options(json_body))
+		}
+
+		/// Blocking version of [`UserApi::list_access_tokens`]
+		pub fn list_access_tokens(&self) -> Result<AccessTokens, AivenError> {
+			self.runtime.block_on(self.api.list_access_tokens())
+		}
+
+		/// Blocking version of [`UserApi::list_linked_auth_methods`]
+		pub fn list_linked_auth_methods(&self) -> Result<AuthenticationMethods, AivenError> {
+			self.runtime.block_on(self.api.list_linked_auth_methods())
+		}
+
+		/// Blocking version of [`UserApi::logout`]
+		pub fn logout(&self) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.logout())
+		}
+
+		/// Blocking version of [`UserApi::password_reset`]
+		pub fn password_reset(&self, email: &str) -> Result<(), AivenError> {
+			self.runtime.block_on(self.api.password_reset(email))
+		}
+
+		/// Blocking version of [`UserApi::revoke_access_token`]
+		pub fn revoke_access_token(&self, token_prefix: &str) -> Result<(), AivenError> {
+			self.runtime
+				.block_on(self.api.revoke_access_token(token_prefix))
+		}
+
+		/// Blocking version of [`UserApi::update_access_token`]
+		pub fn update_access_token(
+			&self,
+			token_prefix: &str,
+			description: &str,
+		) -> Result<AccessToken, AivenError> {
+			self.runtime
+				.block_on(self.api.update_access_token(token_prefix, description))
+		}
+
+		/// Blocking version of [`UserApi::check_password_strength_existing_user`]
+		pub fn check_password_strength_existing_user(
+			&self,
+			new_password: &str,
+			old_password: &str,
+		) -> Result<ResCheckPasswordStrength, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.check_password_strength_existing_user(new_password, old_password),
+			)
+		}
+
+		/// Blocking version of [`UserApi::check_password_strength_new_user`]
+		pub fn check_password_strength_new_user(
+			&self,
+			email: &str,
+			password: &str,
+			real_name: &str,
+		) -> Result<ResCheckPasswordStrength, AivenError> {
+			self.runtime.block_on(
+				self.api
+					.check_password_strength_new_user(email, password, real_name),
+			)
+		}
+
+		/// Blocking version of [`UserApi::accept_all_invites_for_account`]
+		pub fn accept_all_invites_for_account(
+			&self,
+			account_id: &str,
+			team_id: &str,
+		) -> Result<ResAccountInvites, AivenError> {
+			self.runtime
+				.block_on(self.api.accept_all_invites_for_account(account_id, team_id))
+		}
+
+		/// Blocking version of [`UserApi::list_pending_account_invites`]
+		pub fn list_pending_account_invites(&self) -> Result<ResAccountInvites, AivenError> {
+			self.runtime
+				.block_on(self.api.list_pending_account_invites())
+		}
+
+		/// Blocking version of [`UserApi::reject_invite_to_team`]
+		pub fn reject_invite_to_team(&self) -> Result<ResAccountInvites, AivenError> {
+			self.runtime.block_on(self.api.reject_invite_to_team())
+		}
+	}
+}
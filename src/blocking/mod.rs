@@ -0,0 +1,303 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Call the Aiven API from synchronous code.
+//!
+//! Enabled with the `blocking` feature. A blocking [`AivenClient`] owns an
+//! async [`crate::AivenClient`] and a single threaded tokio runtime. Its
+//! accessors mirror the ones of the async client and return a
+//! [`Blocking`] handle of the same API struct, which has a synchronous
+//! version of every method of that struct. Streams, e.g. of
+//! `ServiceApi::tail_logs`, become a [`BlockingIter`].
+//!
+//! ```rust,no_run
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client =
+//!         aiven_rs::blocking::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token")?;
+//!     let services = client.service().list_services("my-project")?;
+//!     for service in &services.services {
+//!         println!("{} {}", service.service_name, service.state);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The Kafka `TopicReader` and `TopicProducer` keep state between calls
+//! and have no blocking version; build them with `as_async` and drive them
+//! with [`AivenClient::block_on`] or [`AivenClient::iter`].
+//!
+//! Like `reqwest::blocking`, a blocking client must not be used from within
+//! an async runtime, blocking a runtime thread panics.
+
+use crate::{
+	account::AccountApi,
+	billing::ProjectBillingApi,
+	billing_group::BillingGroupApi,
+	client::RawApi,
+	cloud::CloudApi,
+	errors::AivenError,
+	key_mgmt::ProjectKeyManagementApi,
+	organization::{OrganizationApi, OrganizationUserGroupApi},
+	payment::PaymentApi,
+	project::ProjectApi,
+	service::{
+		SchemaRegistryApi, ServiceApi, ServiceElastiSearchApi, ServiceFlinkApi, ServiceGrafanaApi,
+		ServiceInfluxApi, ServiceIntegrationsApi, ServiceKafkaApi, ServiceKafkaMirrorMaker,
+		ServiceMysqlApi, ServicePostgresApi,
+	},
+	static_ip::ProjectStaticIpApi,
+	ticket::TicketApi,
+	user::UserApi,
+};
+use futures_util::{Stream, StreamExt};
+use std::{future::Future, pin::Pin, sync::Arc};
+use tokio::runtime::{Builder, Runtime};
+
+mod methods;
+
+// blocking!(self, service) wraps self.inner.service() in a `Blocking`
+macro_rules! blocking {
+	($sel:ident, $accessor:ident) => {{
+		Blocking {
+			api: $sel.inner.$accessor(),
+			runtime: $sel.runtime.clone(),
+		}
+	}};
+}
+
+/// Synchronous version of [`crate::AivenClient`], see the
+/// [module docs](self)
+pub struct AivenClient {
+	inner: crate::AivenClient,
+	runtime: Arc<Runtime>,
+}
+
+impl AivenClient {
+	/// Wrap an async client, e.g. one configured with
+	/// `aiven_rs::AivenClient::builder`
+	pub fn new(client: crate::AivenClient) -> Result<AivenClient, AivenError> {
+		let runtime = Builder::new_current_thread().enable_all().build()?;
+		Ok(AivenClient {
+			inner: client,
+			runtime: Arc::new(runtime),
+		})
+	}
+
+	/// Same as `aiven_rs::AivenClient::from_token`
+	pub fn from_token<T, U, V>(base_url: T, version: U, token: V) -> Result<AivenClient, AivenError>
+	where
+		T: Into<String>,
+		U: Into<String>,
+		V: Into<String>,
+	{
+		AivenClient::new(crate::AivenClient::from_token(base_url, version, token))
+	}
+
+	/// The wrapped async client
+	pub fn as_async(&self) -> &crate::AivenClient {
+		&self.inner
+	}
+
+	/// Run any future to completion, e.g. of a helper taking the async client
+	pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+		self.runtime.block_on(future)
+	}
+
+	/// Iterate over any stream, e.g. of a `TopicReader`
+	pub fn iter<S: Stream>(&self, stream: S) -> BlockingIter<S> {
+		BlockingIter::new(stream, self.runtime.clone())
+	}
+
+	pub fn cloud(&self) -> Blocking<CloudApi> {
+		blocking!(self, cloud)
+	}
+
+	pub fn user(&self) -> Blocking<UserApi> {
+		blocking!(self, user)
+	}
+
+	pub fn project(&self) -> Blocking<ProjectApi> {
+		blocking!(self, project)
+	}
+
+	pub fn service(&self) -> Blocking<ServiceApi> {
+		blocking!(self, service)
+	}
+
+	pub fn service_integrations(&self) -> Blocking<ServiceIntegrationsApi> {
+		blocking!(self, service_integrations)
+	}
+
+	pub fn payment(&self) -> Blocking<PaymentApi> {
+		blocking!(self, payment)
+	}
+
+	pub fn project_billing(&self) -> Blocking<ProjectBillingApi> {
+		blocking!(self, project_billing)
+	}
+
+	pub fn project_key_management(&self) -> Blocking<ProjectKeyManagementApi> {
+		blocking!(self, project_key_management)
+	}
+
+	pub fn kms(&self) -> Blocking<ProjectKeyManagementApi> {
+		blocking!(self, kms)
+	}
+
+	pub fn static_ip(&self) -> Blocking<ProjectStaticIpApi> {
+		blocking!(self, static_ip)
+	}
+
+	pub fn service_elasticsearch(&self) -> Blocking<ServiceElastiSearchApi> {
+		blocking!(self, service_elasticsearch)
+	}
+
+	pub fn service_kafka(&self) -> Blocking<ServiceKafkaApi> {
+		blocking!(self, service_kafka)
+	}
+
+	pub fn service_kafka_mirrormaker(&self) -> Blocking<ServiceKafkaMirrorMaker> {
+		blocking!(self, service_kafka_mirrormaker)
+	}
+
+	pub fn service_flink(&self) -> Blocking<ServiceFlinkApi> {
+		blocking!(self, service_flink)
+	}
+
+	pub fn service_grafana(&self) -> Blocking<ServiceGrafanaApi> {
+		blocking!(self, service_grafana)
+	}
+
+	pub fn service_influxdb(&self) -> Blocking<ServiceInfluxApi> {
+		blocking!(self, service_influxdb)
+	}
+
+	pub fn service_mysql(&self) -> Blocking<ServiceMysqlApi> {
+		blocking!(self, service_mysql)
+	}
+
+	pub fn service_postgres(&self) -> Blocking<ServicePostgresApi> {
+		blocking!(self, service_postgres)
+	}
+
+	pub fn ticket(&self) -> Blocking<TicketApi> {
+		blocking!(self, ticket)
+	}
+
+	pub fn account(&self) -> Blocking<AccountApi> {
+		blocking!(self, account)
+	}
+
+	pub fn organization(&self) -> Blocking<OrganizationApi> {
+		blocking!(self, organization)
+	}
+
+	pub fn organization_user_groups(&self) -> Blocking<OrganizationUserGroupApi> {
+		blocking!(self, organization_user_groups)
+	}
+
+	pub fn billing_group(&self) -> Blocking<BillingGroupApi> {
+		blocking!(self, billing_group)
+	}
+
+	pub fn raw(&self) -> Blocking<RawApi> {
+		blocking!(self, raw)
+	}
+}
+
+/// An API struct of the async client together with the runtime of the
+/// blocking client it came from
+pub struct Blocking<A> {
+	api: A,
+	runtime: Arc<Runtime>,
+}
+
+impl<A> Blocking<A> {
+	/// Call a method of the API struct and wait for its result, e.g. with a
+	/// helper taking the async API struct
+	pub fn call<'a, F, Fut>(&'a self, f: F) -> Fut::Output
+	where
+		F: FnOnce(&'a A) -> Fut,
+		Fut: Future + 'a,
+	{
+		self.runtime.block_on(f(&self.api))
+	}
+
+	/// The wrapped async API struct, e.g. to build a `TopicReader`
+	pub fn as_async(&self) -> &A {
+		&self.api
+	}
+}
+
+/// Items of a stream of the async client, each one waited for on the
+/// runtime of the blocking client
+pub struct BlockingIter<S> {
+	stream: Pin<Box<S>>,
+	runtime: Arc<Runtime>,
+}
+
+impl<S: Stream> BlockingIter<S> {
+	fn new(stream: S, runtime: Arc<Runtime>) -> Self {
+		BlockingIter {
+			stream: Box::pin(stream),
+			runtime,
+		}
+	}
+}
+
+impl<S: Stream> Iterator for BlockingIter<S> {
+	type Item = S::Item;
+
+	fn next(&mut self) -> Option<S::Item> {
+		let stream = &mut self.stream;
+		self.runtime.block_on(stream.next())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+
+	#[test]
+	fn test_blocking_client_calls_api() {
+		let test_data = testutil::get_test_data("tests/testdata/cloud/list.json");
+		let _m = testutil::create_mock_server("/clouds", &test_data, "GET");
+
+		let client = AivenClient::from_token(mockito::server_url(), "", "abc").unwrap();
+		match client.cloud().list_all() {
+			Ok(response) => assert!(!response.clouds.is_empty()),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[test]
+	fn test_blocking_client_call_runs_closure() {
+		let test_data = testutil::get_test_data("tests/testdata/cloud/list.json");
+		let _m = testutil::create_mock_server("/clouds", &test_data, "GET");
+
+		let client = AivenClient::from_token(mockito::server_url(), "", "abc").unwrap();
+		match client.cloud().call(|api| api.list_all()) {
+			Ok(response) => assert!(!response.clouds.is_empty()),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+}
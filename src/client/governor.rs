@@ -0,0 +1,158 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Client side limits on the request rate.
+//!
+//! The Aiven API throttles clients which send too many requests, bulk work
+//! such as creating hundreds of topics runs into it quickly. A
+//! [`RateLimit`] given to
+//! [`AivenClientBuilder::rate_limit`](crate::AivenClientBuilder::rate_limit)
+//! caps the number of requests in flight and the number of requests started
+//! per second. The limits are shared by all the APIs of a client, requests
+//! over a limit wait until they may be sent.
+//!
+//! ```rust,no_run
+//! use aiven_rs::governor::RateLimit;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = aiven_rs::AivenClient::builder("https://api.aiven.io", "v1")
+//!         .token("aiven-token")
+//!         .rate_limit(RateLimit::default().max_in_flight(8).requests_per_second(10.0))
+//!         .build()?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::{
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// Lowest rate of `RateLimit::requests_per_second`, one request a day
+const MIN_REQUESTS_PER_SECOND: f64 = 1.0 / 86400.0;
+
+/// Limits on the requests of a client, unlimited by default
+#[derive(Debug, Clone, Default)]
+pub struct RateLimit {
+	max_in_flight: Option<usize>,
+	requests_per_second: Option<f64>,
+}
+
+impl RateLimit {
+	/// Send at most `requests` requests at the same time
+	pub fn max_in_flight(mut self, requests: usize) -> Self {
+		self.max_in_flight = Some(requests.max(1));
+		self
+	}
+
+	/// Start at most `requests` requests per second, spread evenly over
+	/// the second. Retries count as requests. Rates below one request a day
+	/// are raised to it, a rate of 0 or below removes the limit.
+	pub fn requests_per_second(mut self, requests: f64) -> Self {
+		self.requests_per_second = Some(requests)
+			.filter(|r| r.is_finite() && *r > 0.0)
+			.map(|r| r.max(MIN_REQUESTS_PER_SECOND));
+		self
+	}
+}
+
+/// Enforces a [`RateLimit`], shared by the clones of a `HTTPClient`
+#[derive(Debug)]
+pub(crate) struct Governor {
+	in_flight: Option<Semaphore>,
+	interval: Option<Duration>,
+	next_slot: Mutex<Option<Instant>>,
+}
+
+impl Governor {
+	pub(crate) fn new(limit: &RateLimit) -> Self {
+		Governor {
+			in_flight: limit.max_in_flight.map(Semaphore::new),
+			interval: limit
+				.requests_per_second
+				.map(|r| Duration::from_secs_f64(1.0 / r)),
+			next_slot: Mutex::new(None),
+		}
+	}
+
+	/// Wait until a request may be sent. The returned permit counts as in
+	/// flight until it is dropped.
+	pub(crate) async fn acquire(&self) -> Option<SemaphorePermit<'_>> {
+		let permit = match &self.in_flight {
+			// The semaphore is never closed
+			Some(semaphore) => semaphore.acquire().await.ok(),
+			None => None,
+		};
+		if let Some(wait) = self.reserve_slot(Instant::now()) {
+			tokio::time::sleep(wait).await;
+		}
+		permit
+	}
+
+	/// Reserve the next free start time at or after `now` and return how
+	/// long to wait for it
+	fn reserve_slot(&self, now: Instant) -> Option<Duration> {
+		let interval = self.interval?;
+		let mut next_slot = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+		let slot = next_slot.map_or(now, |next| next.max(now));
+		*next_slot = Some(slot + interval);
+		Some(slot - now).filter(|wait| !wait.is_zero())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_reserve_slot() {
+		let governor = Governor::new(&RateLimit::default().requests_per_second(4.0));
+		let now = Instant::now();
+		assert!(governor.reserve_slot(now).is_none());
+		assert!(governor.reserve_slot(now) == Some(Duration::from_millis(250)));
+		assert!(governor.reserve_slot(now) == Some(Duration::from_millis(500)));
+		// An idle client doesn't build up a burst
+		let later = now + Duration::from_secs(10);
+		assert!(governor.reserve_slot(later).is_none());
+		assert!(governor.reserve_slot(later) == Some(Duration::from_millis(250)));
+
+		let daily = Governor::new(&RateLimit::default().requests_per_second(f64::MIN_POSITIVE));
+		assert!(daily.reserve_slot(now).is_none());
+		assert!(daily.reserve_slot(now) == Some(Duration::from_secs(86400)));
+
+		let unlimited = Governor::new(&RateLimit::default().requests_per_second(0.0));
+		assert!(unlimited.reserve_slot(now).is_none());
+		assert!(unlimited.reserve_slot(now).is_none());
+	}
+
+	#[tokio::test]
+	async fn test_max_in_flight() {
+		let governor = Governor::new(&RateLimit::default().max_in_flight(2));
+		let first = governor.acquire().await;
+		let _second = governor.acquire().await;
+		assert!(governor.in_flight.as_ref().unwrap().available_permits() == 0);
+		drop(first);
+		let _third = governor.acquire().await;
+		assert!(governor.in_flight.as_ref().unwrap().available_permits() == 0);
+	}
+}
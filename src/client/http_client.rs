@@ -20,87 +20,173 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::errors::AivenError;
+#[cfg(feature = "tracing")]
+use crate::client::instrument::{self, PathTracing};
+use crate::{
+	client::{
+		governor::{Governor, RateLimit},
+		middleware::{AuthProvider, Middleware, RequestParts, ResponseParts},
+		retry::RetryPolicy,
+		Conditional, HTTPResponse,
+	},
+	errors::AivenError,
+};
 // use log::debug;
-use percent_encoding::{percent_encode, NON_ALPHANUMERIC};
-use serde::{Deserialize, Serialize};
-use tracing::debug;
+use percent_encoding::{percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{fmt, sync::Arc, time::Instant};
+use tracing::{debug, error};
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct HTTPClient {
 	client: reqwest::Client,
 	base_url: reqwest::Url,
 	version: String,
+	middlewares: Vec<Arc<dyn Middleware>>,
+	retry: RetryPolicy,
+	/// Added to every request, for clients built without default headers
+	headers: reqwest::header::HeaderMap,
+	auth: Option<Arc<dyn AuthProvider>>,
+	governor: Option<Arc<Governor>>,
+	#[cfg(feature = "tracing")]
+	path_tracing: PathTracing,
 }
 
-#[derive(Deserialize, Serialize, Debug, Default)]
+impl fmt::Debug for HTTPClient {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("HTTPClient")
+			.field("client", &self.client)
+			.field("base_url", &self.base_url)
+			.field("version", &self.version)
+			.field("middlewares", &self.middlewares.len())
+			.field("retry", &self.retry)
+			.field("auth_provider", &self.auth.is_some())
+			.field("governor", &self.governor)
+			.finish()
+	}
+}
+
+/// A single entry of the `errors` of an error response
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct APIError {
+	/// Link to the documentation of the error
 	pub more_info: Option<String>,
 	pub status: Option<i32>,
 	pub message: Option<String>,
 }
-#[derive(Deserialize, Serialize, Debug, Default)]
-pub struct APIResponse {
-	pub errors: Option<Vec<APIError>>,
-	pub message: Option<String>,
+
+/// The json body of an error response of the Aiven API
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct AivenApiErrorBody {
+	#[serde(default)]
+	pub errors: Vec<APIError>,
+	/// Summary of all errors
+	#[serde(default)]
+	pub message: String,
 }
 
-/// Percent encode an incoming parameter
+/// Everything except the RFC 3986 unreserved characters. Reserved characters
+/// such as `/`, `+` or `@` would be valid in a path segment too, but proxies
+/// and servers don't agree on how to treat them.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+	.remove(b'-')
+	.remove(b'.')
+	.remove(b'_')
+	.remove(b'~');
+
+/// Percent encode an incoming parameter so it forms a single path segment.
+///
+/// `.` and `..` can't be expressed as a segment, url parsing resolves them
+/// even when percent encoded. Aiven names never consist of dots only.
 pub(crate) fn encode_param(param: &str) -> String {
-	percent_encode(param.as_bytes(), NON_ALPHANUMERIC).to_string()
+	percent_encode(param.as_bytes(), PATH_SEGMENT).to_string()
+}
+
+/// Number of bytes `value` takes once serialized to json, computed without
+/// buffering the serialized output.
+pub(crate) fn json_size<T: Serialize + ?Sized>(value: &T) -> Result<usize, AivenError> {
+	struct Counter(usize);
+
+	impl std::io::Write for Counter {
+		fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+			self.0 += buf.len();
+			Ok(buf.len())
+		}
+
+		fn flush(&mut self) -> std::io::Result<()> {
+			Ok(())
+		}
+	}
+
+	let mut counter = Counter(0);
+	serde_json::to_writer(&mut counter, value)?;
+	Ok(counter.0)
+}
+
+/// Turn a non-2xx response into an `AivenError::APIResponseError` which
+/// carries the method, path and status of the failed request. A `409
+/// Conflict` becomes `AivenError::AlreadyExists` instead, and a body which
+/// isn't Aiven json, e.g. a proxy error page, `AivenError::RawBody`.
+pub(crate) async fn check_response(
+	method: &reqwest::Method,
+	query_url: &str,
+	response: reqwest::Response,
+) -> Result<HTTPResponse, AivenError> {
+	let status_code = response.status().as_u16();
+	debug!("Received http status code: {}", status_code);
+
+	if (200..300).contains(&status_code) {
+		return Ok(HTTPResponse::new(response));
+	}
+	error!("status_code = {}", status_code);
+	error!("url queried = {}", query_url);
+	let body = response.text().await?;
+	let path = format!("/{}", query_url.trim_start_matches('/'));
+	let api_response: AivenApiErrorBody = match serde_json::from_str(&body) {
+		Ok(api_response) => api_response,
+		Err(_) if status_code == reqwest::StatusCode::CONFLICT.as_u16() => AivenApiErrorBody {
+			message: body,
+			..Default::default()
+		},
+		Err(_) => {
+			return Err(AivenError::RawBody {
+				method: method.to_string(),
+				path,
+				status_code,
+				body,
+			})
+		}
+	};
+	if status_code == reqwest::StatusCode::CONFLICT.as_u16() {
+		return Err(AivenError::AlreadyExists {
+			method: method.to_string(),
+			path,
+			body: api_response,
+		});
+	}
+	Err(AivenError::APIResponseError {
+		method: method.to_string(),
+		path,
+		status_code,
+		body: api_response,
+	})
 }
 
 /// Make a http request by providing a json-body
+#[doc(hidden)]
 #[macro_export]
 macro_rules! make_json_request {
 	($sel:ident, $method:path, $url:expr, $body:ident) => {{
-		use crate::{client::APIResponse, errors::AivenError};
-		use reqwest;
-		use tracing::error;
-
-		let response: reqwest::Response = $sel
-			.http_client
-			.inner($method, $url)?
-			.json($body)
-			.send()
-			.await?;
-		let status_code = &response.status().as_u16();
-
-		if !(*status_code >= 200 && *status_code < 300) {
-			error!("status_code = {}", status_code);
-			error!("url queried = {}", $url);
-			let api_response: APIResponse = response.json().await?;
-			return Err(AivenError::APIResponseError {
-				errors: api_response.errors.unwrap(),
-				message: api_response.message.unwrap(),
-			});
-		}
-		let ret: Result<reqwest::Response, AivenError> = Ok(response);
-		ret
+		$sel.http_client.execute($method, $url, Some($body)).await
 	}};
 }
 
 /// Make a http request without json body.
+#[doc(hidden)]
 #[macro_export]
 macro_rules! make_request {
 	($sel:ident, $method:path, $url:expr) => {{
-		use reqwest;
-		use tracing::debug;
-		let response: reqwest::Response = $sel.http_client.inner($method, $url)?.send().await?;
-		use crate::client::APIResponse;
-
-		let status_code = &response.status().as_u16();
-		debug!("Received http status code: {}", status_code);
-
-		if !(*status_code >= 200 && *status_code < 300) {
-			let api_response: APIResponse = response.json().await?;
-			return Err(AivenError::APIResponseError {
-				errors: api_response.errors.unwrap(),
-				message: api_response.message.unwrap(),
-			});
-		}
-		let ret: Result<reqwest::Response, AivenError> = Ok(response);
-		ret
+		$sel.http_client.execute::<()>($method, $url, None).await
 	}};
 }
 
@@ -112,14 +198,217 @@ impl HTTPClient {
 	{
 		let parsed_url =
 			reqwest::Url::parse(&base_url.into()).expect("Failed to parse the base_url");
+		HTTPClient::from_url(parsed_url, client, version)
+	}
 
+	/// Like `new`, with a `base_url` the caller already parsed
+	pub(crate) fn from_url<T: Into<String>>(
+		base_url: reqwest::Url,
+		client: reqwest::Client,
+		version: T,
+	) -> HTTPClient {
 		let ver = format!("{}/", version.into().replace("/", ""));
 		debug!("API Version is {}", &ver);
 		HTTPClient {
-			base_url: parsed_url,
+			base_url,
 			client,
 			version: ver,
+			middlewares: Vec::new(),
+			retry: RetryPolicy::default(),
+			headers: reqwest::header::HeaderMap::new(),
+			auth: None,
+			governor: None,
+			#[cfg(feature = "tracing")]
+			path_tracing: PathTracing::default(),
+		}
+	}
+
+	pub(crate) fn set_rate_limit(&mut self, limit: &RateLimit) {
+		self.governor = Some(Arc::new(Governor::new(limit)));
+	}
+
+	pub(crate) fn set_auth_provider(&mut self, auth: Arc<dyn AuthProvider>) {
+		self.auth = Some(auth);
+	}
+
+	pub(crate) fn set_headers(&mut self, headers: reqwest::header::HeaderMap) {
+		self.headers = headers;
+	}
+
+	pub(crate) fn add_middleware(&mut self, middleware: Arc<dyn Middleware>) {
+		self.middlewares.push(middleware);
+	}
+
+	pub(crate) fn set_retry_policy(&mut self, policy: RetryPolicy) {
+		self.retry = policy;
+	}
+
+	#[cfg(feature = "tracing")]
+	pub(crate) fn set_path_tracing(&mut self, path_tracing: PathTracing) {
+		self.path_tracing = path_tracing;
+	}
+
+	/// Send a request, with an optional json body, through the registered
+	/// middlewares and check its response status
+	pub(crate) async fn execute<T: Serialize + ?Sized>(
+		&self,
+		method: reqwest::Method,
+		query_url: &str,
+		body: Option<&T>,
+	) -> Result<HTTPResponse, AivenError> {
+		let mut builder = self.inner(method.clone(), query_url)?;
+		if let Some(body) = body {
+			builder = builder.json(body);
+		}
+		let response = self.send(builder, false).await?;
+		check_response(&method, query_url, response).await
+	}
+
+	/// Like `execute`, but the retry policy treats the request as idempotent
+	/// whatever its method, e.g. for a `POST` which only reads
+	pub(crate) async fn execute_idempotent<T: Serialize + ?Sized>(
+		&self,
+		method: reqwest::Method,
+		query_url: &str,
+		body: Option<&T>,
+	) -> Result<HTTPResponse, AivenError> {
+		let mut builder = self.inner(method.clone(), query_url)?;
+		if let Some(body) = body {
+			builder = builder.json(body);
+		}
+		let response = self.send(builder, true).await?;
+		check_response(&method, query_url, response).await
+	}
+
+	/// GET `query_url` with `If-None-Match: etag`, returning
+	/// `Conditional::NotModified` when the server answers `304`
+	pub(crate) async fn get_conditional<T: DeserializeOwned>(
+		&self,
+		query_url: &str,
+		etag: Option<&str>,
+	) -> Result<Conditional<T>, AivenError> {
+		let mut builder = self.inner(reqwest::Method::GET, query_url)?;
+		if let Some(etag) = etag {
+			builder = builder.header(reqwest::header::IF_NONE_MATCH, etag);
+		}
+		let response = self.send(builder, false).await?;
+		if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+			return Ok(Conditional::NotModified);
+		}
+		let response = check_response(&reqwest::Method::GET, query_url, response).await?;
+		let etag = response.etag();
+		Ok(Conditional::Modified {
+			value: response.json().await?,
+			etag,
+		})
+	}
+
+	/// Send a request, in an `aiven.request` span with the `tracing` feature
+	async fn send(
+		&self,
+		builder: reqwest::RequestBuilder,
+		idempotent: bool,
+	) -> Result<reqwest::Response, AivenError> {
+		let request = builder.build()?;
+		#[cfg(feature = "tracing")]
+		{
+			use tracing::Instrument;
+			let span =
+				instrument::request_span(request.method(), request.url().path(), self.path_tracing);
+			let started = Instant::now();
+			let mut attempts = 0;
+			let result = self
+				.send_with_retries(request, idempotent, &mut attempts)
+				.instrument(span.clone())
+				.await;
+			instrument::record_outcome(&span, &result, attempts, started.elapsed());
+			result
 		}
+		#[cfg(not(feature = "tracing"))]
+		self.send_with_retries(request, idempotent, &mut 0).await
+	}
+
+	/// Send a request, retrying transient failures as the retry policy
+	/// allows. `idempotent` requests are retried like a `GET`. `attempts`
+	/// counts the requests sent.
+	async fn send_with_retries(
+		&self,
+		mut request: reqwest::Request,
+		idempotent: bool,
+		attempts: &mut usize,
+	) -> Result<reqwest::Response, AivenError> {
+		let mut attempt = 1;
+		loop {
+			*attempts = attempt;
+			let method = request.method().clone();
+			let retried_as = if idempotent {
+				reqwest::Method::GET
+			} else {
+				method.clone()
+			};
+			// Requests with a streaming body can't be cloned and are sent once
+			let next = request.try_clone();
+			let response = self.send_once(request).await?;
+			match next {
+				Some(next)
+					if self
+						.retry
+						.should_retry(attempt, &retried_as, response.status()) =>
+				{
+					let wait = self.retry.delay(attempt, response.headers());
+					debug!(
+						"Retrying {} {} after {:?}, status {}",
+						method,
+						next.url(),
+						wait,
+						response.status()
+					);
+					tokio::time::sleep(wait).await;
+					request = next;
+					attempt += 1;
+				}
+				_ => return Ok(response),
+			}
+		}
+	}
+
+	/// Send a request once through the registered middlewares
+	async fn send_once(
+		&self,
+		mut request: reqwest::Request,
+	) -> Result<reqwest::Response, AivenError> {
+		if !self.middlewares.is_empty() || self.auth.is_some() {
+			let mut parts = RequestParts::from_request(&request);
+			for middleware in &self.middlewares {
+				middleware.on_request(&mut parts);
+			}
+			if let Some(auth) = &self.auth {
+				auth.authorize(&mut parts)?;
+			}
+			parts.apply(&mut request);
+		}
+
+		let (req_method, req_url) = (request.method().clone(), request.url().clone());
+		let _permit = match &self.governor {
+			Some(governor) => governor.acquire().await,
+			None => None,
+		};
+		let started = Instant::now();
+		let response = self.client.execute(request).await?;
+
+		if !self.middlewares.is_empty() {
+			let parts = ResponseParts {
+				method: req_method,
+				url: req_url,
+				status: response.status(),
+				headers: response.headers().clone(),
+				elapsed: started.elapsed(),
+			};
+			for middleware in &self.middlewares {
+				middleware.on_response(&parts);
+			}
+		}
+		Ok(response)
 	}
 
 	pub(crate) fn inner(
@@ -132,14 +421,137 @@ impl HTTPClient {
 		debug!("URL is {:?}", &url);
 
 		// dbg!(&url);
-		let request_with_url_and_header: Result<reqwest::RequestBuilder, AivenError> = match method
-		{
-			reqwest::Method::GET => Ok(self.client.get(url)),
-			reqwest::Method::PUT => Ok(self.client.put(url)),
-			reqwest::Method::POST => Ok(self.client.post(url)),
-			reqwest::Method::DELETE => Ok(self.client.delete(url)),
+		let request_with_url = match method {
+			reqwest::Method::GET => self.client.get(url),
+			reqwest::Method::PUT => self.client.put(url),
+			reqwest::Method::POST => self.client.post(url),
+			reqwest::Method::DELETE => self.client.delete(url),
+			reqwest::Method::PATCH => self.client.patch(url),
 			_ => return Err(AivenError::UnsupportedMethod),
 		};
-		request_with_url_and_header
+		if self.headers.is_empty() {
+			Ok(request_with_url)
+		} else {
+			Ok(request_with_url.headers(self.headers.clone()))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+	use percent_encoding::percent_decode_str;
+	use proptest::prelude::*;
+
+	#[tokio::test]
+	async fn test_structured_error_body() {
+		let client = testutil::prepare_test_client();
+		let _m = mockito::mock("GET", "/project/missingproject/service/mypg")
+			.with_status(404)
+			.with_header("content-type", "application/json")
+			.with_body(
+				r#"{"errors": [{"message": "Service not found", "status": 404,
+				"more_info": "https://api.aiven.io/doc/"}], "message": "Service not found"}"#,
+			)
+			.create();
+		match client
+			.service()
+			.get_service_info("missingproject", "mypg")
+			.await
+		{
+			Err(e) => {
+				assert!(e.is_not_found());
+				assert!(!e.is_conflict());
+				assert!(e.status_code() == Some(404));
+				let body = e.api_error_body().unwrap();
+				assert!(body.message == "Service not found");
+				assert!(body.errors[0].status == Some(404));
+				assert!(body.errors[0].more_info.as_deref() == Some("https://api.aiven.io/doc/"));
+			}
+			Ok(_) => assert!(false, "expected an error"),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_html_error_body() {
+		let client = testutil::prepare_test_client();
+		let _m = mockito::mock("POST", "/project/htmlproject/service")
+			.with_status(502)
+			.with_header("content-type", "text/html")
+			.with_body("<html><head><title>502 Bad Gateway</title></head></html>")
+			.create();
+		let body = serde_json::json!({"service_name": "mypg"});
+		match client.service().create_service("htmlproject", &body).await {
+			Err(AivenError::RawBody {
+				status_code, body, ..
+			}) => {
+				assert!(status_code == 502);
+				assert!(body.contains("Bad Gateway"));
+			}
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_plaintext_and_empty_error_bodies() {
+		let client = testutil::prepare_test_client();
+		let _m = mockito::mock("GET", "/project/plaintextproject/service/mypg")
+			.with_status(403)
+			.with_header("content-type", "text/plain")
+			.with_body("Forbidden")
+			.create();
+		match client
+			.service()
+			.get_service_info("plaintextproject", "mypg")
+			.await
+		{
+			Err(e @ AivenError::RawBody { .. }) => {
+				assert!(
+					e.to_string()
+						== "GET /project/plaintextproject/service/mypg failed: 403 Forbidden"
+				)
+			}
+			other => assert!(false, "{:?}", other),
+		}
+
+		let _m = mockito::mock("DELETE", "/project/emptybodyproject/service/mypg")
+			.with_status(400)
+			.create();
+		match client.service().terminate("emptybodyproject", "mypg").await {
+			Err(AivenError::RawBody {
+				status_code, body, ..
+			}) => assert!(status_code == 400 && body.is_empty()),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[test]
+	fn test_encode_param_examples() {
+		assert!(encode_param("my-project_1.x~") == "my-project_1.x~");
+		assert!(encode_param("jane.doe+ops@example.com") == "jane.doe%2Bops%40example.com");
+		assert!(encode_param("a/b c") == "a%2Fb%20c");
+		assert!(encode_param("jürgen@example.de") == "j%C3%BCrgen%40example.de");
+	}
+
+	proptest! {
+		#[test]
+		fn test_encode_param_roundtrip(param in "\\PC*") {
+			let encoded = encode_param(&param);
+			prop_assert!(encoded
+				.bytes()
+				.all(|b| b.is_ascii_alphanumeric() || b"-._~%".contains(&b)));
+			prop_assert!(percent_decode_str(&encoded).decode_utf8().unwrap() == param.as_str());
+		}
+
+		#[test]
+		fn test_encode_param_single_segment(param in "\\PC+") {
+			prop_assume!(param != "." && param != "..");
+			let base = reqwest::Url::parse("https://api.aiven.io/v1/").unwrap();
+			let url = base.join(&format!("project/{}/events", encode_param(&param))).unwrap();
+			let segments: Vec<&str> = url.path_segments().unwrap().collect();
+			prop_assert!(segments.len() == 4);
+			prop_assert!(percent_decode_str(segments[2]).decode_utf8().unwrap() == param.as_str());
+		}
 	}
 }
@@ -0,0 +1,306 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! Tracing spans around the HTTP calls of the client.
+//!
+//! Enabled with the `tracing` feature. Every API call runs in an
+//! `aiven.request` span at `INFO` level, including its retries, with the
+//! fields
+//!
+//! * `http.method` - e.g. `GET`
+//! * `http.path` - the request path, see [`PathTracing`]
+//! * `http.status_code` - status of the final response
+//! * `attempts` - number of requests sent, more than 1 after retries
+//! * `elapsed_ms` - time until the final response, including retries
+//! * `error` - set instead of `http.status_code` when no response arrived
+//!
+//! ```rust,no_run
+//! use aiven_rs::instrument::PathTracing;
+//!
+//! let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token")
+//!     .with_path_tracing(PathTracing::Raw);
+//! ```
+
+use crate::errors::AivenError;
+use reqwest::Method;
+use std::time::Duration;
+use tracing::{field::Empty, Span};
+
+/// Replaces the redacted path segments
+const REDACTED: &str = "{}";
+
+/// The fixed segments of the API paths, kept by `PathTracing::Redacted`.
+/// Segments of new endpoints missing here are redacted.
+const PATH_SEGMENTS: &[&str] = &[
+	"2fa",
+	"accept",
+	"access_token",
+	"account",
+	"acl",
+	"activity",
+	"alerts",
+	"application",
+	"association",
+	"authentication",
+	"authentication_methods",
+	"available-connectors",
+	"backups",
+	"billing-group",
+	"ca",
+	"cancel",
+	"card",
+	"clouds",
+	"config",
+	"connection_pool",
+	"connectors",
+	"credentials",
+	"credits",
+	"db",
+	"deployment",
+	"elasticsearch",
+	"enable-writes",
+	"events",
+	"expire_tokens",
+	"flink",
+	"ids",
+	"index",
+	"integration",
+	"integration_endpoint",
+	"integration_endpoint_types",
+	"integration_types",
+	"invitation",
+	"invite",
+	"invites",
+	"invoice",
+	"job",
+	"kafka",
+	"keypairs",
+	"kms",
+	"lines",
+	"login_options",
+	"logout",
+	"logs",
+	"maintenance",
+	"me",
+	"member",
+	"members",
+	"messages",
+	"metrics",
+	"migration",
+	"mirrormaker",
+	"mysql",
+	"organization",
+	"organizations",
+	"otp",
+	"password",
+	"password_reset",
+	"password_reset_request",
+	"password_strength",
+	"pause",
+	"peering-connections",
+	"permissions",
+	"pg",
+	"produce",
+	"project",
+	"project-assign",
+	"projects",
+	"projects-assign",
+	"query",
+	"reject",
+	"replication-flows",
+	"reset",
+	"rest",
+	"restart",
+	"resume",
+	"schema",
+	"schema-registry",
+	"schemas",
+	"search",
+	"service",
+	"service_types",
+	"service_versions",
+	"start",
+	"static-ips",
+	"stats",
+	"status",
+	"stop",
+	"stripe_key",
+	"subjects",
+	"task",
+	"team",
+	"teams",
+	"tickets",
+	"topic",
+	"topics",
+	"user",
+	"user-groups",
+	"user-peer-network-cidrs",
+	"userauth",
+	"users",
+	"v1",
+	"verify_email",
+	"versions",
+	"vpcs",
+];
+
+/// How the request path is recorded in the `http.path` field
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PathTracing {
+	/// Project, service, user and other names are replaced by `{}`, e.g.
+	/// `/v1/project/{}/service/{}`. The default.
+	#[default]
+	Redacted,
+	/// The path as sent
+	Raw,
+}
+
+impl PathTracing {
+	/// `path` as recorded in the span
+	pub fn apply(&self, path: &str) -> String {
+		match self {
+			PathTracing::Raw => path.to_owned(),
+			PathTracing::Redacted => path
+				.split('/')
+				.map(|segment| {
+					if segment.is_empty() || PATH_SEGMENTS.contains(&segment) {
+						segment
+					} else {
+						REDACTED
+					}
+				})
+				.collect::<Vec<_>>()
+				.join("/"),
+		}
+	}
+}
+
+/// Span of one API call, the outcome is recorded by `record_outcome`
+pub(crate) fn request_span(method: &Method, path: &str, tracing: PathTracing) -> Span {
+	tracing::info_span!(
+		"aiven.request",
+		http.method = %method,
+		http.path = %tracing.apply(path),
+		http.status_code = Empty,
+		attempts = Empty,
+		elapsed_ms = Empty,
+		error = Empty,
+	)
+}
+
+pub(crate) fn record_outcome(
+	span: &Span,
+	result: &Result<reqwest::Response, AivenError>,
+	attempts: usize,
+	elapsed: Duration,
+) {
+	span.record("attempts", attempts as u64);
+	span.record("elapsed_ms", elapsed.as_millis() as u64);
+	match result {
+		Ok(response) => {
+			span.record("http.status_code", response.status().as_u16());
+		}
+		Err(e) => {
+			span.record("error", tracing::field::display(e));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+	use std::sync::{Arc, Mutex};
+	use tracing::{
+		field::{Field, Visit},
+		span::{Attributes, Id, Record},
+		Subscriber,
+	};
+	use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+	/// Collects the fields of all spans
+	#[derive(Clone, Default)]
+	struct SpanFields(Arc<Mutex<Vec<(String, String)>>>);
+
+	impl Visit for SpanFields {
+		fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+			self.0
+				.lock()
+				.unwrap()
+				.push((field.name().to_owned(), format!("{:?}", value)));
+		}
+	}
+
+	impl<S: Subscriber> Layer<S> for SpanFields {
+		fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+			attrs.record(&mut self.clone());
+		}
+
+		fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+			values.record(&mut self.clone());
+		}
+	}
+
+	impl SpanFields {
+		fn get(&self, name: &str) -> Option<String> {
+			let fields = self.0.lock().unwrap();
+			fields
+				.iter()
+				.find(|(field, _)| field == name)
+				.map(|(_, value)| value.clone())
+		}
+	}
+
+	#[tokio::test]
+	async fn test_request_span() {
+		let client = testutil::prepare_test_client();
+		let test_data = testutil::get_test_data("tests/testdata/cloud/list_by_project.json");
+		let _m = testutil::create_mock_server("/project/tracedproject/clouds", &test_data, "GET");
+
+		let fields = SpanFields::default();
+		let _guard =
+			tracing::subscriber::set_default(tracing_subscriber::registry().with(fields.clone()));
+		client
+			.cloud()
+			.list_by_project("tracedproject")
+			.await
+			.unwrap();
+
+		assert!(fields.get("http.method").as_deref() == Some("GET"));
+		assert!(fields.get("http.path").as_deref() == Some("/project/{}/clouds"));
+		assert!(fields.get("http.status_code").as_deref() == Some("200"));
+		assert!(fields.get("attempts").as_deref() == Some("1"));
+		assert!(fields.get("elapsed_ms").is_some());
+	}
+
+	#[test]
+	fn test_path_tracing() {
+		let path = "/v1/project/my-project/service/my-kafka/kafka/rest/topics/orders/produce";
+		assert!(
+			PathTracing::Redacted.apply(path)
+				== "/v1/project/{}/service/{}/kafka/rest/topics/{}/produce"
+		);
+		assert!(PathTracing::Raw.apply(path) == path);
+		assert!(
+			PathTracing::Redacted.apply("/v1/me/authentication_methods")
+				== "/v1/me/authentication_methods"
+		);
+	}
+}
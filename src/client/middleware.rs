@@ -0,0 +1,235 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Hooks to observe or modify every request made by an `AivenClient`.
+//!
+//! ```rust,no_run
+//! use aiven_rs::middleware::{Middleware, RequestParts, ResponseParts};
+//!
+//! struct AuditLog;
+//!
+//! impl Middleware for AuditLog {
+//!     fn on_request(&self, request: &mut RequestParts) {
+//!         request
+//!             .headers
+//!             .insert("x-request-source", "inventory".parse().unwrap());
+//!     }
+//!
+//!     fn on_response(&self, response: &ResponseParts) {
+//!         println!("{} {} -> {}", response.method, response.url, response.status);
+//!     }
+//! }
+//!
+//! let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token")
+//!     .with_middleware(AuditLog);
+//! ```
+//!
+//! Credentials which a proxy in front of the API requires, e.g. a signature
+//! over the request, are added by an [`AuthProvider`]. It runs after all
+//! middlewares, once per attempt, and can fail the request:
+//!
+//! ```rust,no_run
+//! use aiven_rs::{errors::AivenError, middleware::{AuthProvider, RequestParts}};
+//!
+//! struct GatewaySigner {
+//!     key_id: String,
+//! }
+//!
+//! impl AuthProvider for GatewaySigner {
+//!     fn authorize(&self, request: &mut RequestParts) -> Result<(), AivenError> {
+//!         let signature = format!("{}:{}", request.method, request.url.path()); // sign properly
+//!         request.headers.insert("x-gateway-key", self.key_id.parse().unwrap());
+//!         request.headers.insert("x-gateway-signature", signature.parse().unwrap());
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token")
+//!     .with_auth_provider(GatewaySigner { key_id: "ops".into() });
+//! ```
+
+use crate::errors::AivenError;
+use reqwest::{header::HeaderMap, Method, StatusCode, Url};
+use std::time::Duration;
+
+/// A hook called around every HTTP request sent by the client.
+///
+/// Middlewares run in the order they were registered.
+pub trait Middleware: Send + Sync {
+	/// Called before the request is sent. Changes to the method, url and
+	/// headers are applied to the outgoing request.
+	fn on_request(&self, _request: &mut RequestParts) {}
+
+	/// Called once the response headers have been received
+	fn on_response(&self, _response: &ResponseParts) {}
+}
+
+/// Adds credentials to every outgoing request, e.g. headers signed for a
+/// gateway in front of the API.
+///
+/// Called after all middlewares and again for every retry, so signatures
+/// with timestamps stay fresh. An error fails the request without sending
+/// it.
+pub trait AuthProvider: Send + Sync {
+	fn authorize(&self, request: &mut RequestParts) -> Result<(), AivenError>;
+}
+
+/// The parts of an outgoing request which a middleware can inspect or modify
+#[derive(Debug)]
+pub struct RequestParts {
+	pub method: Method,
+	pub url: Url,
+	pub headers: HeaderMap,
+	body: Option<Vec<u8>>,
+}
+
+impl RequestParts {
+	pub(crate) fn from_request(request: &reqwest::Request) -> Self {
+		RequestParts {
+			method: request.method().clone(),
+			url: request.url().clone(),
+			headers: request.headers().clone(),
+			body: request
+				.body()
+				.and_then(|b| b.as_bytes())
+				.map(|b| b.to_vec()),
+		}
+	}
+
+	pub(crate) fn apply(self, request: &mut reqwest::Request) {
+		*request.method_mut() = self.method;
+		*request.url_mut() = self.url;
+		*request.headers_mut() = self.headers;
+	}
+
+	/// The serialized json body of the request, if any
+	pub fn body(&self) -> Option<&[u8]> {
+		self.body.as_deref()
+	}
+}
+
+/// The parts of a received response passed to middlewares
+#[derive(Debug)]
+pub struct ResponseParts {
+	pub method: Method,
+	pub url: Url,
+	pub status: StatusCode,
+	pub headers: HeaderMap,
+	/// Time between sending the request and receiving the response headers
+	pub elapsed: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{testutil, AivenClient};
+	use std::sync::{Arc, Mutex};
+
+	struct Recorder {
+		seen: Arc<Mutex<Vec<(String, u16)>>>,
+	}
+
+	impl Middleware for Recorder {
+		fn on_request(&self, request: &mut RequestParts) {
+			request.headers.insert("x-audit-id", "42".parse().unwrap());
+		}
+
+		fn on_response(&self, response: &ResponseParts) {
+			self.seen
+				.lock()
+				.unwrap()
+				.push((response.url.path().to_string(), response.status.as_u16()));
+		}
+	}
+
+	#[tokio::test]
+	async fn test_middleware_hooks_called() {
+		let seen = Arc::new(Mutex::new(Vec::new()));
+		let client = AivenClient::from_token(mockito::server_url(), "", "abc")
+			.with_middleware(Recorder { seen: seen.clone() });
+		let test_data = testutil::get_test_data("tests/testdata/cloud/list_by_project.json");
+		let _m = mockito::mock("GET", "/project/middlewareproject/clouds")
+			.match_header("x-audit-id", "42")
+			.with_status(200)
+			.with_header("content-type", "application/json")
+			.with_body(&test_data)
+			.create();
+
+		match client.cloud().list_by_project("middlewareproject").await {
+			Ok(response) => assert!(!response.clouds.is_empty()),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		assert!(
+			*seen.lock().unwrap() == vec![("/project/middlewareproject/clouds".to_string(), 200)]
+		);
+	}
+
+	struct Signer;
+
+	impl AuthProvider for Signer {
+		fn authorize(&self, request: &mut RequestParts) -> Result<(), AivenError> {
+			let signature = format!("{} {}", request.method, request.url.path());
+			if request.url.path().contains("forbidden") {
+				return Err(AivenError::InvalidInput {
+					field: "url".into(),
+					reason: "no key for this path".into(),
+				});
+			}
+			request
+				.headers
+				.insert("x-signature", signature.parse().unwrap());
+			Ok(())
+		}
+	}
+
+	#[tokio::test]
+	async fn test_auth_provider_signs_requests() {
+		let client =
+			AivenClient::from_token(mockito::server_url(), "", "abc").with_auth_provider(Signer);
+		let test_data = testutil::get_test_data("tests/testdata/cloud/list_by_project.json");
+		let m = mockito::mock("GET", "/project/signedproject/clouds")
+			.match_header("x-signature", "GET /project/signedproject/clouds")
+			.with_status(200)
+			.with_body(&test_data)
+			.create();
+
+		match client.cloud().list_by_project("signedproject").await {
+			Ok(_) => m.assert(),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_auth_provider_error_stops_request() {
+		let client =
+			AivenClient::from_token(mockito::server_url(), "", "abc").with_auth_provider(Signer);
+		let m = mockito::mock("GET", "/project/forbiddenproject/clouds")
+			.expect(0)
+			.create();
+
+		match client.cloud().list_by_project("forbiddenproject").await {
+			Err(AivenError::InvalidInput { field, .. }) => assert!(field == "url"),
+			other => assert!(false, "{:?}", other),
+		}
+		m.assert();
+	}
+}
@@ -1,6 +1,15 @@
 mod aiven_client;
+pub mod governor;
 mod http_client;
+#[cfg(feature = "tracing")]
+pub mod instrument;
+pub mod middleware;
+mod raw;
+mod response;
+pub mod retry;
 
-pub use aiven_client::AivenClient;
-pub(crate) use http_client::encode_param;
-pub use http_client::{APIError, APIResponse, HTTPClient};
+pub use aiven_client::{AivenClient, AivenClientBuilder};
+pub(crate) use http_client::{encode_param, json_size};
+pub use http_client::{APIError, AivenApiErrorBody, HTTPClient};
+pub use raw::{RawApi, RawResponse};
+pub use response::{Completion, Conditional, HTTPResponse, OperationRef};
@@ -0,0 +1,324 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+	client::{HTTPClient, HTTPResponse},
+	errors::AivenError,
+};
+use bytes::Bytes;
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Requests against arbitrary API paths, for endpoints this crate doesn't
+/// wrap yet.
+///
+/// Requests go through the same authentication, middlewares and retry
+/// policy as the typed APIs. Paths are relative to the API version, e.g.
+/// `project/my-project/vpcs`, and are used as given, so names in them have
+/// to be percent encoded by the caller. Non-2xx responses become errors
+/// just like for the typed APIs.
+pub struct RawApi {
+	http_client: HTTPClient,
+}
+
+impl RawApi {
+	pub(crate) fn new(client: HTTPClient) -> RawApi {
+		RawApi {
+			http_client: client,
+		}
+	}
+
+	/// GET `path`
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client.raw().get("project/my-project/vpcs").await?;
+	/// let vpcs: serde_json::Value = response.json()?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn get(&self, path: &str) -> Result<RawResponse, AivenError> {
+		self.request::<()>(Method::GET, path, None).await
+	}
+
+	/// POST `body` as json to `path`
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let body = json!({"cloud_name": "aws-eu-west-1", "network_cidr": "10.0.0.0/24"});
+	/// let response = client.raw().post("project/my-project/vpcs", &body).await?;
+	/// println!("{}", response.status());
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn post<T: Serialize + ?Sized>(
+		&self,
+		path: &str,
+		body: &T,
+	) -> Result<RawResponse, AivenError> {
+		self.request(Method::POST, path, Some(body)).await
+	}
+
+	/// PUT `body` as json to `path`
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let body = json!({"tech_emails": [{"email": "ops@example.com"}]});
+	/// client.raw().put("project/my-project", &body).await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn put<T: Serialize + ?Sized>(
+		&self,
+		path: &str,
+		body: &T,
+	) -> Result<RawResponse, AivenError> {
+		self.request(Method::PUT, path, Some(body)).await
+	}
+
+	/// DELETE `path`
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client.raw().delete("project/my-project/vpcs/1234").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn delete(&self, path: &str) -> Result<RawResponse, AivenError> {
+		self.request::<()>(Method::DELETE, path, None).await
+	}
+
+	/// DELETE `path` with `body` as json, for endpoints which take the
+	/// items to delete in the request body
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let body = json!({"ids": ["acl1", "acl2"]});
+	/// client.raw().delete_json("project/my-project/service/my-kafka/acl", &body).await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn delete_json<T: Serialize + ?Sized>(
+		&self,
+		path: &str,
+		body: &T,
+	) -> Result<RawResponse, AivenError> {
+		self.request(Method::DELETE, path, Some(body)).await
+	}
+
+	/// Send `method` to `path` with an optional json body. Only GET, POST,
+	/// PUT, PATCH and DELETE are supported.
+	pub async fn request<T: Serialize + ?Sized>(
+		&self,
+		method: Method,
+		path: &str,
+		body: Option<&T>,
+	) -> Result<RawResponse, AivenError> {
+		let response = self.http_client.execute(method, path, body).await?;
+		RawResponse::read(response).await
+	}
+}
+
+/// A successful (2xx) response with its body read into memory
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+	status: StatusCode,
+	headers: HeaderMap,
+	body: Bytes,
+}
+
+impl RawResponse {
+	async fn read(response: HTTPResponse) -> Result<Self, AivenError> {
+		let status = response.status();
+		let headers = response.headers().clone();
+		let body = response.bytes().await?;
+		Ok(RawResponse {
+			status,
+			headers,
+			body,
+		})
+	}
+
+	pub fn status(&self) -> StatusCode {
+		self.status
+	}
+
+	pub fn headers(&self) -> &HeaderMap {
+		&self.headers
+	}
+
+	pub fn bytes(&self) -> &Bytes {
+		&self.body
+	}
+
+	/// The body as text, invalid UTF-8 is replaced
+	pub fn text(&self) -> String {
+		String::from_utf8_lossy(&self.body).into_owned()
+	}
+
+	/// Deserialize the json body
+	pub fn json<T: DeserializeOwned>(&self) -> Result<T, AivenError> {
+		crate::client::response::decode_json(&self.body)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{errors::AivenError, retry::RetryPolicy, testutil, AivenClient};
+	use serde_json::json;
+	use std::time::Duration;
+
+	#[tokio::test]
+	async fn test_raw_get() {
+		let client = testutil::prepare_test_client();
+		let _m = mockito::mock("GET", "/project/rawproject/vpcs")
+			.with_status(200)
+			.with_header("x-request-id", "abc123")
+			.with_body(r#"{"vpcs": [{"project_vpc_id": "1234"}]}"#)
+			.create();
+
+		match client.raw().get("project/rawproject/vpcs").await {
+			Ok(response) => {
+				assert!(response.status() == 200);
+				assert!(response.headers()["x-request-id"] == "abc123");
+				let body: serde_json::Value = response.json().unwrap();
+				assert!(body["vpcs"][0]["project_vpc_id"] == "1234");
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_raw_post_sends_json() {
+		let client = testutil::prepare_test_client();
+		let body = json!({"cloud_name": "aws-eu-west-1", "network_cidr": "10.0.0.0/24"});
+		let m = mockito::mock("POST", "/project/rawpostproject/vpcs")
+			.match_body(mockito::Matcher::Json(body.clone()))
+			.with_status(201)
+			.with_body("{}")
+			.create();
+
+		match client
+			.raw()
+			.post("/project/rawpostproject/vpcs", &body)
+			.await
+		{
+			Ok(response) => assert!(response.status() == 201),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		m.assert();
+	}
+
+	#[tokio::test]
+	async fn test_raw_error_status() {
+		let client = testutil::prepare_test_client();
+		let _m = mockito::mock("DELETE", "/project/rawdeleteproject/vpcs/1")
+			.with_status(404)
+			.with_body(r#"{"message": "VPC not found"}"#)
+			.create();
+
+		match client.raw().delete("project/rawdeleteproject/vpcs/1").await {
+			Err(AivenError::APIResponseError { status_code, .. }) => assert!(status_code == 404),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_raw_delete_sends_json() {
+		let client = testutil::prepare_test_client();
+		let body = json!({"ids": ["acl1", "acl2"]});
+		let m = mockito::mock("DELETE", "/project/rawdeletebodyproject/service/kafka1/acl")
+			.match_header("content-type", "application/json")
+			.match_body(mockito::Matcher::Json(body.clone()))
+			.with_status(200)
+			.with_body(r#"{"message": "deleted"}"#)
+			.create();
+
+		match client
+			.raw()
+			.delete_json("project/rawdeletebodyproject/service/kafka1/acl", &body)
+			.await
+		{
+			Ok(response) => assert!(response.status() == 200),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		m.assert();
+	}
+
+	#[tokio::test]
+	async fn test_raw_delete_body_is_retried() {
+		let client = AivenClient::from_token(mockito::server_url(), "", "abc").with_retry_policy(
+			RetryPolicy::default()
+				.max_attempts(2)
+				.backoff(Duration::from_millis(1), Duration::from_millis(5)),
+		);
+		let body = json!({"ids": ["acl1"]});
+		// Only matches when the retried request still carries the body
+		let m = mockito::mock(
+			"DELETE",
+			"/project/rawdeleteretryproject/service/kafka1/acl",
+		)
+		.match_body(mockito::Matcher::Json(body.clone()))
+		.with_status(503)
+		.with_body(r#"{"message": "unavailable"}"#)
+		.expect(2)
+		.create();
+
+		match client
+			.raw()
+			.delete_json("project/rawdeleteretryproject/service/kafka1/acl", &body)
+			.await
+		{
+			Err(AivenError::APIResponseError { status_code, .. }) => assert!(status_code == 503),
+			other => assert!(false, "{:?}", other),
+		}
+		m.assert();
+	}
+}
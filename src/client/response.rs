@@ -0,0 +1,183 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{client::http_client::AivenApiErrorBody, errors::AivenError};
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+
+/// A successful (2xx) response of an API call
+#[derive(Debug)]
+pub struct HTTPResponse {
+	inner: reqwest::Response,
+}
+
+impl HTTPResponse {
+	pub(crate) fn new(inner: reqwest::Response) -> Self {
+		HTTPResponse { inner }
+	}
+
+	pub fn status(&self) -> reqwest::StatusCode {
+		self.inner.status()
+	}
+
+	pub fn headers(&self) -> &reqwest::header::HeaderMap {
+		self.inner.headers()
+	}
+
+	/// Value of a header, if the server sent it and it is valid text
+	pub(crate) fn header(&self, name: reqwest::header::HeaderName) -> Option<String> {
+		self.inner
+			.headers()
+			.get(name)
+			.and_then(|value| value.to_str().ok())
+			.map(String::from)
+	}
+
+	/// Value of the `ETag` header, if the server sent one
+	pub(crate) fn etag(&self) -> Option<String> {
+		self.header(reqwest::header::ETAG)
+	}
+
+	/// `Completion::Accepted` for a `202 Accepted` response, otherwise
+	/// `Completion::Done` with the value of `done`
+	pub(crate) async fn completion<T>(self, done: T) -> Result<Completion<T>, AivenError> {
+		if self.status() != reqwest::StatusCode::ACCEPTED {
+			return Ok(Completion::Done(done));
+		}
+		let location = self.header(reqwest::header::LOCATION);
+		let body = self.inner.bytes().await?;
+		// The body of a 202 is informational, don't fail on an empty one
+		let message = serde_json::from_slice::<AivenApiErrorBody>(&body)
+			.ok()
+			.map(|response| response.message)
+			.filter(|message| !message.is_empty());
+		Ok(Completion::Accepted(OperationRef { location, message }))
+	}
+
+	pub(crate) async fn bytes(self) -> Result<Bytes, AivenError> {
+		Ok(self.inner.bytes().await?)
+	}
+
+	/// Deserialize the json body of the response
+	pub(crate) async fn json<T: DeserializeOwned>(self) -> Result<T, AivenError> {
+		let body = self.inner.bytes().await?;
+		decode_json(&body)
+	}
+}
+
+/// Result of a conditional GET, see `ServiceApi::get_service_info_if_modified`
+#[derive(Debug)]
+pub enum Conditional<T> {
+	/// The resource changed, or no etag was given
+	Modified {
+		value: T,
+		/// Pass this on the next request, `None` if the endpoint doesn't
+		/// send ETags
+		etag: Option<String>,
+	},
+	/// The resource still matches the given etag
+	NotModified,
+}
+
+impl<T> Conditional<T> {
+	pub fn is_modified(&self) -> bool {
+		matches!(self, Conditional::Modified { .. })
+	}
+}
+
+/// Result of a call which the API may complete later, answering `202
+/// Accepted` instead, see `ServiceApi::start_maintenance_updates`
+#[derive(Debug)]
+pub enum Completion<T> {
+	/// The operation completed before the response was sent
+	Done(T),
+	/// The operation was accepted and continues in the background
+	Accepted(OperationRef),
+}
+
+impl<T> Completion<T> {
+	pub fn is_accepted(&self) -> bool {
+		matches!(self, Completion::Accepted(_))
+	}
+}
+
+/// Where to follow up on an accepted operation
+#[derive(Debug, Default, Clone)]
+pub struct OperationRef {
+	/// Value of the `Location` header, if the server sent one
+	pub location: Option<String>,
+	/// `message` of the response body, if any
+	pub message: Option<String>,
+}
+
+/// Deserialize a json body.
+///
+/// With the `serde_path_to_error` feature a failure reports the path of the
+/// offending field, e.g. `service.node_states[0].progress_updates`.
+pub(crate) fn decode_json<T: DeserializeOwned>(body: &[u8]) -> Result<T, AivenError> {
+	#[cfg(feature = "serde_path_to_error")]
+	{
+		let de = &mut serde_json::Deserializer::from_slice(body);
+		serde_path_to_error::deserialize(de).map_err(|e| AivenError::DeserializeError {
+			path: e.path().to_string(),
+			source: e.into_inner(),
+		})
+	}
+	#[cfg(not(feature = "serde_path_to_error"))]
+	{
+		Ok(serde_json::from_slice(body)?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde::Deserialize;
+
+	#[derive(Deserialize, Debug)]
+	#[allow(dead_code)]
+	struct Outer {
+		inner: Vec<Inner>,
+	}
+	#[derive(Deserialize, Debug)]
+	#[allow(dead_code)]
+	struct Inner {
+		count: i32,
+	}
+
+	#[test]
+	fn test_decode_json() {
+		let res: Result<Outer, AivenError> = decode_json(br#"{"inner": [{"count": 1}]}"#);
+		assert!(res.is_ok());
+	}
+
+	#[cfg(feature = "serde_path_to_error")]
+	#[test]
+	fn test_decode_json_reports_path() {
+		let res: Result<Outer, AivenError> =
+			decode_json(br#"{"inner": [{"count": 1}, {"count": "two"}]}"#);
+		match res {
+			Err(AivenError::DeserializeError { path, .. }) => assert!(path == "inner[1].count"),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+}
@@ -0,0 +1,166 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Retrying of requests which failed with a transient error.
+//!
+//! Every [`AivenClient`](crate::AivenClient) retries `429 Too Many Requests`
+//! and, for the idempotent `GET`, `PUT` and `DELETE` requests, `5xx`
+//! responses. The wait between attempts grows exponentially and honors the
+//! `Retry-After` header. Use
+//! [`with_retry_policy`](crate::AivenClient::with_retry_policy) to tune or
+//! disable it.
+//!
+//! ```rust,no_run
+//! use aiven_rs::retry::RetryPolicy;
+//! use std::time::Duration;
+//!
+//! let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token")
+//!     .with_retry_policy(
+//!         RetryPolicy::default()
+//!             .max_attempts(5)
+//!             .backoff(Duration::from_secs(1), Duration::from_secs(60)),
+//!     );
+//! ```
+
+use crate::poll::jitter;
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use std::time::Duration;
+
+/// When and how often a failed request is sent again
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+	max_attempts: usize,
+	base: Duration,
+	max_delay: Duration,
+	jitter: f64,
+	honor_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		RetryPolicy {
+			max_attempts: 3,
+			base: Duration::from_millis(500),
+			max_delay: Duration::from_secs(30),
+			jitter: 0.2,
+			honor_retry_after: true,
+		}
+	}
+}
+
+impl RetryPolicy {
+	/// Send every request exactly once
+	pub fn disabled() -> Self {
+		RetryPolicy::default().max_attempts(1)
+	}
+
+	/// Send a request at most `attempts` times, defaults to 3
+	pub fn max_attempts(mut self, attempts: usize) -> Self {
+		self.max_attempts = attempts.max(1);
+		self
+	}
+
+	/// Wait `base` before the first retry and double it for every further
+	/// one, up to `max_delay`. Defaults to 500ms and 30s.
+	pub fn backoff(mut self, base: Duration, max_delay: Duration) -> Self {
+		self.base = base;
+		self.max_delay = max_delay;
+		self
+	}
+
+	/// Randomly shorten or lengthen each wait by up to `fraction` of it.
+	/// Defaults to 0.2.
+	pub fn jitter(mut self, fraction: f64) -> Self {
+		self.jitter = fraction.clamp(0.0, 1.0);
+		self
+	}
+
+	/// Wait as long as the `Retry-After` header asks for, capped by the
+	/// maximum delay. Enabled by default.
+	pub fn honor_retry_after(mut self, honor: bool) -> Self {
+		self.honor_retry_after = honor;
+		self
+	}
+
+	/// Whether a response to attempt `attempt` (starting at 1) is retried
+	pub(crate) fn should_retry(&self, attempt: usize, method: &Method, status: StatusCode) -> bool {
+		if attempt >= self.max_attempts {
+			return false;
+		}
+		let idempotent = matches!(*method, Method::GET | Method::PUT | Method::DELETE);
+		status == StatusCode::TOO_MANY_REQUESTS || (idempotent && status.is_server_error())
+	}
+
+	/// The wait before retrying after attempt `attempt` (starting at 1)
+	pub(crate) fn delay(&self, attempt: usize, headers: &HeaderMap) -> Duration {
+		let retry_after = headers
+			.get(reqwest::header::RETRY_AFTER)
+			.and_then(|v| v.to_str().ok())
+			.and_then(|v| v.trim().parse::<u64>().ok())
+			.filter(|_| self.honor_retry_after);
+		let wait = match retry_after {
+			Some(seconds) => Duration::from_secs(seconds),
+			None => jitter(
+				self.base
+					.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1) as u32)),
+				self.jitter,
+			),
+		};
+		wait.min(self.max_delay)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use reqwest::header::HeaderValue;
+
+	#[test]
+	fn test_should_retry() {
+		let policy = RetryPolicy::default();
+		assert!(policy.should_retry(1, &Method::GET, StatusCode::SERVICE_UNAVAILABLE));
+		assert!(policy.should_retry(2, &Method::POST, StatusCode::TOO_MANY_REQUESTS));
+		assert!(!policy.should_retry(1, &Method::POST, StatusCode::SERVICE_UNAVAILABLE));
+		assert!(!policy.should_retry(1, &Method::GET, StatusCode::NOT_FOUND));
+		assert!(!policy.should_retry(3, &Method::GET, StatusCode::SERVICE_UNAVAILABLE));
+		assert!(!RetryPolicy::disabled().should_retry(1, &Method::GET, StatusCode::BAD_GATEWAY));
+	}
+
+	#[test]
+	fn test_delay() {
+		let policy = RetryPolicy::default()
+			.jitter(0.0)
+			.backoff(Duration::from_millis(100), Duration::from_secs(1));
+		let headers = HeaderMap::new();
+		assert!(policy.delay(1, &headers) == Duration::from_millis(100));
+		assert!(policy.delay(3, &headers) == Duration::from_millis(400));
+		assert!(policy.delay(10, &headers) == Duration::from_secs(1));
+
+		let mut headers = HeaderMap::new();
+		headers.insert("retry-after", HeaderValue::from_static("2"));
+		assert!(policy.delay(1, &headers) == Duration::from_secs(1));
+		assert!(
+			policy.clone().honor_retry_after(false).delay(1, &headers)
+				== Duration::from_millis(100)
+		);
+	}
+}
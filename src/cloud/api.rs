@@ -20,7 +20,7 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::{client::HTTPClient, errors::AivenError, make_request};
+use crate::{client::HTTPClient, errors::AivenError, make_request, service::ServiceApi};
 
 use crate::cloud::types;
 pub struct CloudApi {
@@ -35,6 +35,8 @@ impl CloudApi {
 	}
 	/// List available cloud platforms by project name
 	///
+	/// https://api.aiven.io/doc/#operation/ListProjectClouds
+	///
 	/// # Examples
 	/// Basic usage:
 	///
@@ -54,11 +56,13 @@ impl CloudApi {
 	pub async fn list_by_project(&self, project: &str) -> Result<types::ResClouds, AivenError> {
 		let url = &format!("project/{}/clouds", project);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List all available cloud platforms
 	///
+	/// https://api.aiven.io/doc/#operation/ListClouds
+	///
 	/// # Examples
 	/// Basic usage:
 	///
@@ -78,7 +82,48 @@ impl CloudApi {
 	pub async fn list_all(&self) -> Result<types::ResClouds, AivenError> {
 		let url = "clouds";
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// Build a matrix of which clouds can run which service type on which
+	/// plan.
+	///
+	/// With a project the clouds and service types available to that project
+	/// are used, otherwise the public ones.
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// # #[tokio::main]
+	/// # async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	///
+	/// let matrix = client
+	///         .cloud()
+	///         .capability_matrix(Some("my-project")).await?;
+	/// for cloud in matrix.clouds_for("pg", "startup-4") {
+	///     println!("{}", cloud.cloud_name);
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn capability_matrix(
+		&self,
+		project: Option<&str>,
+	) -> Result<types::CapabilityMatrix, AivenError> {
+		let service_api = ServiceApi::new(self.http_client.clone());
+		let (clouds, service_types) = match project {
+			Some(project) => (
+				self.list_by_project(project).await?,
+				service_api.list_service_types(project).await?,
+			),
+			None => (
+				self.list_all().await?,
+				service_api.list_public_service_types().await?,
+			),
+		};
+		Ok(types::CapabilityMatrix::build(clouds, &service_types))
 	}
 }
 
@@ -95,9 +140,9 @@ mod tests {
 
 		match client.cloud().list_all().await {
 			Ok(response) => {
-				assert!(response.clouds.len() > 0, format!("{:?}", response));
+				assert!(!response.clouds.is_empty(), "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -110,9 +155,44 @@ mod tests {
 
 		match client.cloud().list_by_project("my-project").await {
 			Ok(response) => {
-				assert!(response.clouds.len() > 0, format!("{:?}", response));
+				assert!(!response.clouds.is_empty(), "{:?}", response);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_cloud_capability_matrix() {
+		let client = testutil::prepare_test_client();
+		let clouds = testutil::get_test_data("tests/testdata/cloud/capability_clouds.json");
+		let service_types =
+			testutil::get_test_data("tests/testdata/cloud/capability_service_types.json");
+		let _m = testutil::create_mock_server("/project/capabilityproject/clouds", &clouds, "GET");
+		let _n = testutil::create_mock_server(
+			"/project/capabilityproject/service_types",
+			&service_types,
+			"GET",
+		);
+
+		match client
+			.cloud()
+			.capability_matrix(Some("capabilityproject"))
+			.await
+		{
+			Ok(matrix) => {
+				let clouds: Vec<&str> = matrix
+					.clouds_for("pg", "startup-4")
+					.iter()
+					.map(|c| c.cloud_name.as_str())
+					.collect();
+				assert!(clouds == vec!["aws-eu-central-1", "google-europe-west1"]);
+				assert!(matrix.clouds_for("pg", "hobbyist").len() == 1);
+				assert!(!matrix.supports("azure-eastus", "pg", "startup-4"));
+				assert!(
+					matrix.plans_for("pg", "aws-eu-central-1") == vec!["hobbyist", "startup-4"]
+				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 }
@@ -20,7 +20,65 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::service::types_service::ResServiceTypes;
 use serde::{Deserialize, Serialize};
+use std::{
+	fmt::{Display, Formatter},
+	str::FromStr,
+};
+
+/// Mean earth radius used for the great-circle distance between clouds
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloudProvider {
+	Aws,
+	Azure,
+	DigitalOcean,
+	Google,
+	UpCloud,
+}
+
+impl CloudProvider {
+	/// Prefix of the cloud names hosted by this provider
+	pub fn prefix(&self) -> &'static str {
+		match self {
+			CloudProvider::Aws => "aws-",
+			CloudProvider::Azure => "azure-",
+			CloudProvider::DigitalOcean => "do-",
+			CloudProvider::Google => "google-",
+			CloudProvider::UpCloud => "upcloud-",
+		}
+	}
+}
+
+impl Display for CloudProvider {
+	fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+		match self {
+			CloudProvider::Aws => write!(f, "aws"),
+			CloudProvider::Azure => write!(f, "azure"),
+			CloudProvider::DigitalOcean => write!(f, "do"),
+			CloudProvider::Google => write!(f, "gcp"),
+			CloudProvider::UpCloud => write!(f, "upcloud"),
+		}
+	}
+}
+
+impl FromStr for CloudProvider {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_lowercase().as_str() {
+			"aws" => Ok(CloudProvider::Aws),
+			"azure" => Ok(CloudProvider::Azure),
+			"do" | "digitalocean" => Ok(CloudProvider::DigitalOcean),
+			"gcp" | "google" => Ok(CloudProvider::Google),
+			"upcloud" => Ok(CloudProvider::UpCloud),
+			other => Err(format!("unknown cloud provider `{}`", other)),
+		}
+	}
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResClouds {
 	pub clouds: Vec<Cloud>,
@@ -31,6 +89,217 @@ pub struct Cloud {
 	pub cloud_description: String,
 	pub cloud_name: String,
 	pub geo_region: String,
-	pub geo_latitude: f32,
-	pub geo_longitude: f32,
+	pub geo_latitude: f64,
+	pub geo_longitude: f64,
+}
+
+/// Coordinates of a cloud region in degrees
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct GeoLocation {
+	pub latitude: f64,
+	pub longitude: f64,
+}
+
+impl GeoLocation {
+	pub fn new(latitude: f64, longitude: f64) -> Self {
+		GeoLocation {
+			latitude,
+			longitude,
+		}
+	}
+
+	/// Great-circle distance in kilometers to `other`
+	pub fn distance_km(&self, other: &GeoLocation) -> f64 {
+		let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+		let dlat = lat2 - lat1;
+		let dlon = (other.longitude - self.longitude).to_radians();
+		let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+		2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+	}
+}
+
+impl Cloud {
+	pub fn provider(&self) -> Option<CloudProvider> {
+		[
+			CloudProvider::Aws,
+			CloudProvider::Azure,
+			CloudProvider::DigitalOcean,
+			CloudProvider::Google,
+			CloudProvider::UpCloud,
+		]
+		.iter()
+		.copied()
+		.find(|p| self.cloud_name.starts_with(p.prefix()))
+	}
+
+	/// Cloud name without the provider prefix, e.g. `eu-central-1` for
+	/// `aws-eu-central-1`
+	pub fn region(&self) -> &str {
+		match self.provider() {
+			Some(p) => &self.cloud_name[p.prefix().len()..],
+			None => &self.cloud_name,
+		}
+	}
+
+	pub fn location(&self) -> GeoLocation {
+		GeoLocation::new(self.geo_latitude, self.geo_longitude)
+	}
+
+	/// Great-circle distance in kilometers from the given coordinates
+	pub fn distance_km(&self, latitude: f64, longitude: f64) -> f64 {
+		self.location()
+			.distance_km(&GeoLocation::new(latitude, longitude))
+	}
+}
+
+impl ResClouds {
+	/// Keep only the clouds hosted by `provider`
+	pub fn filter_provider(mut self, provider: CloudProvider) -> Self {
+		self.clouds.retain(|c| c.provider() == Some(provider));
+		self
+	}
+
+	/// Keep only the clouds whose region (see [`Cloud::region`]) starts with
+	/// `prefix`
+	pub fn filter_region_prefix(mut self, prefix: &str) -> Self {
+		self.clouds.retain(|c| c.region().starts_with(prefix));
+		self
+	}
+
+	/// Sort the clouds by distance to the given coordinates, nearest first
+	pub fn sort_by_distance(mut self, latitude: f64, longitude: f64) -> Self {
+		self.clouds.sort_by(|a, b| {
+			a.distance_km(latitude, longitude)
+				.total_cmp(&b.distance_km(latitude, longitude))
+		});
+		self
+	}
+}
+
+/// A single cloud on which a service type can run with a given plan
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct CloudCapability {
+	pub cloud_name: String,
+	pub service_type: String,
+	pub service_plan: String,
+	pub price_usd: String,
+}
+
+/// Which clouds can run which service type on which plan
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct CapabilityMatrix {
+	pub clouds: Vec<Cloud>,
+	pub capabilities: Vec<CloudCapability>,
+}
+
+impl CapabilityMatrix {
+	/// Build the matrix from a list of clouds and the plan/region data of the
+	/// service types. Regions which are not part of `clouds` are skipped.
+	pub fn build(clouds: ResClouds, service_types: &ResServiceTypes) -> CapabilityMatrix {
+		let mut capabilities = Vec::new();
+		for (service_type, description) in &service_types.service_types {
+			for plan in &description.service_plans {
+				for (cloud_name, region) in &plan.regions {
+					if !clouds.clouds.iter().any(|c| &c.cloud_name == cloud_name) {
+						continue;
+					}
+					capabilities.push(CloudCapability {
+						cloud_name: cloud_name.clone(),
+						service_type: service_type.clone(),
+						service_plan: plan.service_plan.clone(),
+						price_usd: region.price_usd.clone(),
+					});
+				}
+			}
+		}
+		capabilities.sort_by(|a, b| {
+			(&a.service_type, &a.service_plan, &a.cloud_name).cmp(&(
+				&b.service_type,
+				&b.service_plan,
+				&b.cloud_name,
+			))
+		});
+		CapabilityMatrix {
+			clouds: clouds.clouds,
+			capabilities,
+		}
+	}
+
+	/// Clouds which can run `service_type` on `service_plan`
+	pub fn clouds_for(&self, service_type: &str, service_plan: &str) -> Vec<&Cloud> {
+		self.clouds
+			.iter()
+			.filter(|c| self.supports(&c.cloud_name, service_type, service_plan))
+			.collect()
+	}
+
+	/// Plans available for `service_type` on the given cloud
+	pub fn plans_for(&self, service_type: &str, cloud_name: &str) -> Vec<&str> {
+		self.capabilities
+			.iter()
+			.filter(|c| c.service_type == service_type && c.cloud_name == cloud_name)
+			.map(|c| c.service_plan.as_str())
+			.collect()
+	}
+
+	pub fn supports(&self, cloud_name: &str, service_type: &str, service_plan: &str) -> bool {
+		self.capabilities.iter().any(|c| {
+			c.cloud_name == cloud_name
+				&& c.service_type == service_type
+				&& c.service_plan == service_plan
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+
+	fn clouds() -> ResClouds {
+		let data = testutil::get_test_data("tests/testdata/cloud/capability_clouds.json");
+		serde_json::from_str(&data).unwrap()
+	}
+
+	#[test]
+	fn test_cloud_provider_and_region() {
+		let clouds = clouds();
+		assert!(clouds.clouds[0].provider() == Some(CloudProvider::Aws));
+		assert!(clouds.clouds[0].region() == "eu-central-1");
+		assert!(clouds.clouds[1].provider() == Some(CloudProvider::Google));
+		assert!("gcp".parse::<CloudProvider>() == Ok(CloudProvider::Google));
+		assert!("ibm".parse::<CloudProvider>().is_err());
+	}
+
+	#[test]
+	fn test_cloud_filters() {
+		let azure = clouds().filter_provider(CloudProvider::Azure);
+		assert!(azure.clouds.len() == 1);
+		assert!(azure.clouds[0].cloud_name == "azure-eastus");
+
+		let europe = clouds().filter_region_prefix("europe");
+		assert!(europe.clouds.len() == 1);
+		assert!(europe.clouds[0].cloud_name == "google-europe-west1");
+	}
+
+	#[test]
+	fn test_cloud_sort_by_distance() {
+		// Brussels
+		let sorted = clouds().sort_by_distance(50.85, 4.35);
+		let names: Vec<&str> = sorted
+			.clouds
+			.iter()
+			.map(|c| c.cloud_name.as_str())
+			.collect();
+		assert!(names == vec!["google-europe-west1", "aws-eu-central-1", "azure-eastus"]);
+	}
+
+	#[test]
+	fn test_geo_location_distance() {
+		let helsinki = GeoLocation::new(60.17, 24.94);
+		let berlin = GeoLocation::new(52.52, 13.40);
+		let distance = helsinki.distance_km(&berlin);
+		assert!((distance - 1100.0).abs() < 10.0, "{}", distance);
+		assert!(berlin.distance_km(&berlin) == 0.0);
+	}
 }
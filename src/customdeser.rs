@@ -51,6 +51,30 @@ where
 	T::from_str(&s).map_err(de::Error::custom)
 }
 
+/// Deserialize a String from either a string or a number
+pub(crate) fn string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	match serde_json::Value::deserialize(deserializer)? {
+		serde_json::Value::String(s) => Ok(s),
+		serde_json::Value::Number(n) => Ok(n.to_string()),
+		other => Err(de::Error::invalid_type(
+			Unexpected::Other(&other.to_string()),
+			&"a string or a number",
+		)),
+	}
+}
+
+/// Deserialize `null` as the default value of the type
+pub(crate) fn null_as_default<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+	T: Default + Deserialize<'de>,
+	D: Deserializer<'de>,
+{
+	Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -81,6 +105,18 @@ mod tests {
 		assert!(response.val_i64 == 1);
 	}
 	#[test]
+	fn test_null_as_default() {
+		#[derive(Deserialize)]
+		struct TestStructNull {
+			#[serde(deserialize_with = "null_as_default")]
+			val: i64,
+		}
+		let response: TestStructNull = serde_json::from_str("{\"val\": null}").unwrap();
+		assert!(response.val == 0);
+		let response: TestStructNull = serde_json::from_str("{\"val\": 2}").unwrap();
+		assert!(response.val == 2);
+	}
+	#[test]
 	fn test_bool_from_string() {
 		let response: TestStructBool =
 			serde_json::from_str("{\"val_true\":\"true\",\"val_false\":\"false\" }").unwrap();
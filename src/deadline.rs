@@ -0,0 +1,199 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Overall deadlines for long running helpers.
+//!
+//! The waiters polling with [`PollOpts`](crate::poll::PollOpts), e.g.
+//! `poll::until`, `ServiceApi::change_plan_and_wait` or
+//! `ServiceKafkaApi::create_kafka_connector_and_wait`, and the
+//! `ServiceApi::tail_logs` stream accept a [`Deadline`] through
+//! `PollOpts::deadline`. Bulk helpers such as
+//! `ServiceKafkaApi::delete_subjects_matching`,
+//! `BillingGroupApi::list_all_invoices`, `ProjectApi::apply_member_sync` and
+//! `rotation::CredentialRotation` take one directly.
+//!
+//! Once it passes they stop and return [`AivenError::DeadlineExceeded`]
+//! together with the number of steps which did complete, e.g. deleted
+//! subjects or yielded log entries, instead of being cancelled blindly by an
+//! outer `tokio::time::timeout`. Helpers which change resources let a
+//! started step finish and only skip the remaining ones.
+
+use crate::errors::AivenError;
+use std::{
+	future::Future,
+	time::{Duration, Instant},
+};
+
+/// A point in time after which a helper gives up, or none at all
+#[derive(Debug, Clone, Copy)]
+pub struct Deadline {
+	started: Instant,
+	expires_at: Option<Instant>,
+}
+
+impl Default for Deadline {
+	fn default() -> Self {
+		Deadline::none()
+	}
+}
+
+impl Deadline {
+	/// A deadline which never expires
+	pub fn none() -> Self {
+		Deadline {
+			started: Instant::now(),
+			expires_at: None,
+		}
+	}
+
+	/// A deadline which expires `timeout` from now
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust
+	/// use aiven_rs::deadline::Deadline;
+	/// use std::time::Duration;
+	///
+	/// let deadline = Deadline::after(Duration::from_secs(300));
+	/// assert!(!deadline.is_expired());
+	/// ```
+	pub fn after(timeout: Duration) -> Self {
+		let started = Instant::now();
+		Deadline {
+			started,
+			expires_at: Some(started + timeout),
+		}
+	}
+
+	/// A deadline which expires at the given instant
+	pub fn at(instant: Instant) -> Self {
+		Deadline {
+			started: Instant::now(),
+			expires_at: Some(instant),
+		}
+	}
+
+	/// Time elapsed since the deadline was created
+	pub fn elapsed(&self) -> Duration {
+		self.started.elapsed()
+	}
+
+	/// Time left before the deadline expires, `None` if it never does
+	pub fn remaining(&self) -> Option<Duration> {
+		self.expires_at
+			.map(|at| at.saturating_duration_since(Instant::now()))
+	}
+
+	/// Whether the deadline has passed, never for `Deadline::none`
+	pub fn is_expired(&self) -> bool {
+		matches!(self.remaining(), Some(d) if d == Duration::from_secs(0))
+	}
+
+	/// Error describing this deadline being exceeded after `completed` steps
+	pub fn exceeded(&self, completed: usize) -> AivenError {
+		AivenError::DeadlineExceeded {
+			elapsed: self.elapsed(),
+			completed,
+		}
+	}
+
+	/// Return an error if the deadline has already expired
+	pub fn check(&self, completed: usize) -> Result<(), AivenError> {
+		if self.is_expired() {
+			return Err(self.exceeded(completed));
+		}
+		Ok(())
+	}
+
+	/// Run a single step, aborting it once the deadline expires.
+	///
+	/// `completed` is the number of steps finished so far and is reported
+	/// in the error if this step does not finish in time.
+	pub async fn run<F, T>(&self, completed: usize, fut: F) -> Result<T, AivenError>
+	where
+		F: Future<Output = Result<T, AivenError>>,
+	{
+		match self.remaining() {
+			None => fut.await,
+			Some(remaining) => match tokio::time::timeout(remaining, fut).await {
+				Ok(res) => res,
+				Err(_) => Err(self.exceeded(completed)),
+			},
+		}
+	}
+
+	/// Sleep for `duration`, or until the deadline expires if that is sooner.
+	pub async fn sleep(&self, duration: Duration, completed: usize) -> Result<(), AivenError> {
+		let wait = match self.remaining() {
+			Some(remaining) if remaining < duration => {
+				tokio::time::sleep(remaining).await;
+				return Err(self.exceeded(completed));
+			}
+			_ => duration,
+		};
+		tokio::time::sleep(wait).await;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_deadline_none_never_expires() {
+		let deadline = Deadline::none();
+		assert!(deadline.remaining().is_none());
+		assert!(deadline.check(0).is_ok());
+	}
+
+	#[test]
+	fn test_deadline_check_reports_progress() {
+		let deadline = Deadline::after(Duration::from_secs(0));
+		match deadline.check(3) {
+			Err(AivenError::DeadlineExceeded { completed, .. }) => assert!(completed == 3),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_deadline_run_times_out() {
+		let deadline = Deadline::after(Duration::from_millis(10));
+		let res = deadline
+			.run(1, async {
+				tokio::time::sleep(Duration::from_secs(5)).await;
+				Ok(())
+			})
+			.await;
+		match res {
+			Err(AivenError::DeadlineExceeded { completed, .. }) => assert!(completed == 1),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_deadline_sleep_stops_early() {
+		let deadline = Deadline::after(Duration::from_millis(10));
+		assert!(deadline.sleep(Duration::from_secs(5), 2).await.is_err());
+	}
+}
@@ -0,0 +1,185 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Registry of Aiven endpoints which are deprecated upstream.
+//!
+//! Methods calling such an endpoint log a `tracing` warning naming the
+//! replacement, once per method and process, so applications can migrate
+//! before the endpoint is removed.
+
+use std::{collections::BTreeSet, sync::Mutex};
+use tracing::warn;
+
+struct Deprecation {
+	api: &'static str,
+	method: &'static str,
+	replacement: &'static str,
+}
+
+const TEAMS: &str = "`OrganizationUserGroupApi`";
+const ELASTICSEARCH: &str = "Aiven for OpenSearch, Elasticsearch services reached end of life";
+
+macro_rules! deprecations {
+	($(($api:literal, $method:literal, $replacement:expr),)*) => {
+		&[$(Deprecation {
+			api: $api,
+			method: $method,
+			replacement: $replacement,
+		},)*]
+	};
+}
+
+static DEPRECATIONS: &[Deprecation] = deprecations![
+	(
+		"AccountApi",
+		"associate_team_to_project",
+		"`OrganizationUserGroupApi::assign_user_group_to_project`"
+	),
+	("AccountApi", "cancel_pending_invite", TEAMS),
+	("AccountApi", "confirm_team_invite", TEAMS),
+	(
+		"AccountApi",
+		"create_team",
+		"`OrganizationUserGroupApi::create_user_group`"
+	),
+	(
+		"AccountApi",
+		"delete_team",
+		"`OrganizationUserGroupApi::delete_user_group`"
+	),
+	(
+		"AccountApi",
+		"dissociate_team_from_project",
+		"`OrganizationUserGroupApi::revoke_user_group_from_project`"
+	),
+	(
+		"AccountApi",
+		"get_team_details",
+		"`OrganizationUserGroupApi::get_user_group`"
+	),
+	(
+		"AccountApi",
+		"invite_member",
+		"`OrganizationApi::invite_user`"
+	),
+	("AccountApi", "list_pending_invites", TEAMS),
+	("AccountApi", "list_projects_by_team", TEAMS),
+	(
+		"AccountApi",
+		"list_team_members",
+		"`OrganizationUserGroupApi::list_user_group_members`"
+	),
+	(
+		"AccountApi",
+		"list_teams",
+		"`OrganizationUserGroupApi::list_user_groups`"
+	),
+	(
+		"AccountApi",
+		"list_teams_for_project",
+		"`OrganizationUserGroupApi::list_project_permissions`"
+	),
+	("AccountApi", "list_teams_for_user", TEAMS),
+	(
+		"AccountApi",
+		"remove_member",
+		"`OrganizationUserGroupApi::remove_user_group_members`"
+	),
+	(
+		"AccountApi",
+		"update_team_details",
+		"`OrganizationUserGroupApi::update_user_group`"
+	),
+	(
+		"AccountApi",
+		"update_team_project_association",
+		"`OrganizationUserGroupApi::assign_user_group_to_project`"
+	),
+	("ServiceElastiSearchApi", "delete_index", ELASTICSEARCH),
+	("ServiceElastiSearchApi", "list_indexes", ELASTICSEARCH),
+	(
+		"ServiceElastiSearchApi",
+		"set_acl_configuration",
+		ELASTICSEARCH
+	),
+	(
+		"ServiceElastiSearchApi",
+		"show_acl_configuration",
+		ELASTICSEARCH
+	),
+	(
+		"ServiceElastiSearchApi",
+		"update_acl_configuration",
+		ELASTICSEARCH
+	),
+];
+
+static WARNED: Mutex<BTreeSet<(&str, &str)>> = Mutex::new(BTreeSet::new());
+
+/// Warn about a call of `api::method` if it is deprecated and wasn't
+/// warned about before. Returns whether a warning was logged.
+pub(crate) fn warn_deprecated(api: &'static str, method: &'static str) -> bool {
+	let deprecation = match DEPRECATIONS
+		.iter()
+		.find(|d| d.api == api && d.method == method)
+	{
+		Some(deprecation) => deprecation,
+		None => return false,
+	};
+	let first = WARNED
+		.lock()
+		.unwrap_or_else(|e| e.into_inner())
+		.insert((api, method));
+	if first {
+		warn!(
+			"{}::{} calls a deprecated Aiven endpoint, use {} instead",
+			api, method, deprecation.replacement
+		);
+	}
+	first
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::operations;
+
+	#[test]
+	fn test_deprecations_are_known_operations() {
+		for d in DEPRECATIONS {
+			assert!(
+				operations::operation(d.api, d.method).is_some(),
+				"{}::{}",
+				d.api,
+				d.method
+			);
+		}
+	}
+
+	#[test]
+	fn test_warn_deprecated_once() {
+		// Other tests may have called the method already
+		warn_deprecated("ServiceElastiSearchApi", "delete_index");
+		assert!(!warn_deprecated("ServiceElastiSearchApi", "delete_index"));
+		assert!(!warn_deprecated("ServiceApi", "get_service_info"));
+	}
+}
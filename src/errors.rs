@@ -20,8 +20,11 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::client::APIError;
-use std::io;
+use crate::{
+	client::AivenApiErrorBody, project::types::MembershipChange,
+	service::types_service::ServiceUserCredentials,
+};
+use std::{io, time::Duration};
 use thiserror::Error;
 use url::ParseError;
 
@@ -31,7 +34,7 @@ use url::ParseError;
 pub enum AivenError {
 	// #[error("Failed to get a db-connection from database pool")]
 	// PoolConnError(#[from] PoolError),
-	#[error("HTTP Request error")]
+	#[error("HTTP request error")]
 	ReqwestError(#[from] reqwest::Error),
 
 	#[error("Failed during URL parsing")]
@@ -45,14 +48,194 @@ pub enum AivenError {
 	UnsupportedMethod,
 
 	#[error("Failed during http request with status_code `{status_code:?}` and text `{text:?}`")]
-	ReqwestErrorWithStatus { status_code: String, text: String },
+	ReqwestErrorWithStatus { status_code: u16, text: String },
 
 	#[error("Failed during Serde operation")]
 	SerdeError(#[from] serde_json::Error),
 
-	#[error("Failed during parsing APIResponse: {message:?} and errors {errors:?}")]
+	#[error("{method} {path} failed: {status_code} {}", .body.message)]
 	APIResponseError {
-		errors: Vec<APIError>,
-		message: String,
+		method: String,
+		path: String,
+		status_code: u16,
+		body: AivenApiErrorBody,
 	},
+
+	/// The error response had a body which is not the json of the Aiven
+	/// API, e.g. an HTML page of a proxy or load balancer
+	#[error("{method} {path} failed: {status_code} {}", excerpt(.body))]
+	RawBody {
+		method: String,
+		path: String,
+		status_code: u16,
+		body: String,
+	},
+
+	#[error("{method} {path} failed, already exists: {}", .body.message)]
+	AlreadyExists {
+		method: String,
+		path: String,
+		body: AivenApiErrorBody,
+	},
+
+	#[error("{resource} not found")]
+	NotFound { resource: String },
+
+	#[error("Invalid `{field}`: {reason}")]
+	InvalidInput { field: String, reason: String },
+
+	#[error("Failed to deserialize response at `{path}`")]
+	DeserializeError {
+		path: String,
+		#[source]
+		source: serde_json::Error,
+	},
+
+	#[error("Deadline exceeded after {elapsed:?} with {completed} step(s) completed")]
+	DeadlineExceeded { elapsed: Duration, completed: usize },
+
+	#[error("Kafka connector `{connector}` failed: {trace}")]
+	ConnectorFailed { connector: String, trace: String },
+
+	#[error("Condition not met after {attempts} attempt(s)")]
+	MaxAttemptsReached { attempts: usize },
+
+	/// A listing didn't match the entries the API reports elsewhere, so it
+	/// may be truncated
+	#[error("Listing of {resource} is incomplete: {listed} listed, {expected} expected")]
+	IncompleteListing {
+		resource: String,
+		listed: usize,
+		expected: usize,
+	},
+
+	#[error("Request body of {size} bytes exceeds the limit of {limit} bytes")]
+	RequestBodyTooLarge { size: usize, limit: usize },
+
+	#[error("Rotating the credentials of `{username}` failed after {} user(s) were rotated", .rotated.len())]
+	RotationFailed {
+		username: String,
+		rotated: Vec<String>,
+		/// The new credentials of `username` when its password was already
+		/// reset, e.g. because storing or verifying them failed
+		credentials: Option<Box<ServiceUserCredentials>>,
+		#[source]
+		source: Box<AivenError>,
+	},
+
+	#[error("Syncing the members of `{project}` failed at {failed:?} after {} change(s) were applied", .applied.len())]
+	MemberSyncFailed {
+		project: String,
+		applied: Vec<MembershipChange>,
+		failed: MembershipChange,
+		#[source]
+		source: Box<AivenError>,
+	},
+
+	#[error("Service `{service_name}` is {state} and won't reach RUNNING")]
+	ServiceNotRunning { service_name: String, state: String },
+
+	#[error("Plan `{plan}` offers {disk_space_mb} MB of disk but {used_mb} MB are in use")]
+	PlanTooSmall {
+		plan: String,
+		disk_space_mb: i64,
+		used_mb: i64,
+	},
+}
+
+impl AivenError {
+	/// HTTP status of a failed request, `None` for errors which didn't come
+	/// from a response
+	pub fn status_code(&self) -> Option<u16> {
+		match self {
+			AivenError::APIResponseError { status_code, .. }
+			| AivenError::RawBody { status_code, .. } => Some(*status_code),
+			AivenError::AlreadyExists { .. } => Some(409),
+			AivenError::ReqwestErrorWithStatus { status_code, .. } => Some(*status_code),
+			AivenError::ReqwestError(e) => e.status().map(|status| status.as_u16()),
+			_ => None,
+		}
+	}
+
+	/// The API answered `404 Not Found`, or a looked up resource is missing
+	pub fn is_not_found(&self) -> bool {
+		matches!(self, AivenError::NotFound { .. }) || self.status_code() == Some(404)
+	}
+
+	/// The API answered `409 Conflict`, usually because the resource exists
+	pub fn is_conflict(&self) -> bool {
+		self.status_code() == Some(409)
+	}
+
+	/// A failure which may go away on its own: `429 Too Many Requests`, a
+	/// `5xx` or a request which didn't get a response, e.g. a timeout
+	pub fn is_transient(&self) -> bool {
+		match self.status_code() {
+			Some(status) => status == 429 || status >= 500,
+			None => {
+				matches!(self, AivenError::ReqwestError(e) if e.is_timeout() || e.is_connect() || e.is_request())
+			}
+		}
+	}
+
+	/// The parsed json body of an error response of the API
+	pub fn api_error_body(&self) -> Option<&AivenApiErrorBody> {
+		match self {
+			AivenError::APIResponseError { body, .. } => Some(body),
+			_ => None,
+		}
+	}
+}
+
+/// The first line of `body`, shortened so an HTML page doesn't flood logs
+fn excerpt(body: &str) -> String {
+	const MAX_CHARS: usize = 120;
+	let line = body.trim().lines().next().unwrap_or_default();
+	if line.chars().count() > MAX_CHARS {
+		format!("{}...", line.chars().take(MAX_CHARS).collect::<String>())
+	} else {
+		line.to_string()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::error::Error;
+
+	#[test]
+	fn test_api_response_error_display() {
+		let err = AivenError::APIResponseError {
+			method: "PUT".into(),
+			path: "/project/x/service/y".into(),
+			status_code: 403,
+			body: AivenApiErrorBody {
+				errors: vec![],
+				message: "Permission denied".into(),
+			},
+		};
+		assert!(err.to_string() == "PUT /project/x/service/y failed: 403 Permission denied");
+		assert!(err.source().is_none());
+	}
+
+	#[test]
+	fn test_raw_body_display() {
+		let err = AivenError::RawBody {
+			method: "GET".into(),
+			path: "/project/x".into(),
+			status_code: 502,
+			body: format!("<html>{}</html>\n<body>Bad Gateway</body>", "x".repeat(200)),
+		};
+		let display = err.to_string();
+		assert!(display.starts_with("GET /project/x failed: 502 <html>xxx"));
+		assert!(display.ends_with("..."));
+		assert!(!display.contains("Bad Gateway"));
+	}
+
+	#[test]
+	fn test_wrapped_error_source() {
+		let serde_err = serde_json::from_str::<u32>("x").unwrap_err();
+		let err = AivenError::from(serde_err);
+		assert!(err.source().is_some());
+	}
 }
@@ -0,0 +1,321 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Deterministic values of the response types, for unit tests of code
+//! built on this crate.
+//!
+//! Enabled with the `test-util` feature, usually as a dev-dependency. Every
+//! builder returns the same realistic value on each call so the results can
+//! be asserted on, individual fields can then be changed as needed.
+//!
+//! ```rust
+//! use aiven_rs::service::types_kafka::Topic;
+//! use aiven_rs::service::types_service::Service;
+//!
+//! let mut service = Service::test_with_name("events", "kafka");
+//! service.state = "REBUILDING".into();
+//! let topic = Topic::test_with_name("orders");
+//! assert!(topic.partitions == 3);
+//! ```
+use crate::{
+	project::types::{CardInfo, Email, Project},
+	service::{
+		types_kafka::{KafkaConsumerGroup, KafkaPartition, Topic as KafkaTopic, TopicInfo},
+		types_service::{
+			Backup, Component, Maintenance, NodeState, Service, ServiceUser, Topic, User,
+		},
+	},
+};
+use std::collections::HashMap;
+
+const TEST_PROJECT: &str = "test-project";
+const TEST_CLOUD: &str = "google-europe-west1";
+const TEST_TIME: &str = "2020-01-01T00:00:00Z";
+
+fn host(service_name: &str) -> String {
+	format!("{}-{}.aivencloud.com", service_name, TEST_PROJECT)
+}
+
+fn default_port(service_type: &str) -> i32 {
+	match service_type {
+		"kafka" => 24949,
+		"mysql" => 24947,
+		"redis" => 24948,
+		_ => 24946,
+	}
+}
+
+impl Service {
+	/// A running PostgreSQL service called `test-pg`
+	pub fn test_default() -> Self {
+		Service::test_with_name("test-pg", "pg")
+	}
+
+	/// A running single node service of the given name and type, on a
+	/// `startup-4` plan with an `avnadmin` user
+	pub fn test_with_name(service_name: &str, service_type: &str) -> Self {
+		let host = host(service_name);
+		let port = default_port(service_type);
+		let service_uri = format!(
+			"{}://avnadmin:test-password@{}:{}",
+			service_type, host, port
+		);
+		let mut service_uri_params = HashMap::new();
+		service_uri_params.insert("host".to_owned(), host.clone().into());
+		service_uri_params.insert("port".to_owned(), port.to_string().into());
+		service_uri_params.insert("user".to_owned(), "avnadmin".into());
+		service_uri_params.insert("password".to_owned(), "test-password".into());
+		Service {
+			acl: None,
+			backups: vec![Backup {
+				backup_name: format!("{}-backup", service_name),
+				backup_time: TEST_TIME.to_owned(),
+				data_size: 1024 * 1024,
+			}],
+			cloud_description: "Europe, Belgium - Google Cloud: Belgium".to_owned(),
+			cloud_name: TEST_CLOUD.to_owned(),
+			components: vec![Component {
+				component: service_type.to_owned(),
+				host,
+				kafka_authentication_method: None,
+				port,
+				route: Some("dynamic".to_owned()),
+				ssl: Some(true),
+				usage: "primary".to_owned(),
+			}],
+			connection_info: HashMap::new(),
+			connection_pools: None,
+			create_time: TEST_TIME.to_owned(),
+			disk_space_mb: 81920,
+			databases: None,
+			features: HashMap::new(),
+			group_list: vec!["default".to_owned()],
+			maintenance: Maintenance {
+				dow: "sunday".to_owned(),
+				time: "04:00:00".to_owned(),
+				updates: Vec::new(),
+			},
+			metadata: HashMap::new(),
+			node_count: 1,
+			node_cpu_count: 1,
+			node_memory_mb: 4096,
+			node_states: vec![NodeState {
+				name: format!("{}-1", service_name),
+				progress_updates: Vec::new(),
+				state: "running".to_owned(),
+			}],
+			plan: "startup-4".to_owned(),
+			project_vpc_id: None,
+			service_integrations: Vec::new(),
+			service_name: service_name.to_owned(),
+			service_type: service_type.to_owned(),
+			service_type_description: String::new(),
+			service_uri,
+			service_uri_params,
+			state: "RUNNING".to_owned(),
+			termination_protection: false,
+			topics: None,
+			update_time: TEST_TIME.to_owned(),
+			user_config: HashMap::new(),
+			users: vec![User::test_with_name("avnadmin")],
+			notifications: Vec::new(),
+		}
+	}
+}
+
+impl User {
+	/// A primary user with a password and no client certificate
+	pub fn test_with_name(username: &str) -> Self {
+		User {
+			access_cert: String::new(),
+			access_key: String::new(),
+			authentication: None,
+			password: "test-password".to_owned(),
+			user_type: if username == "avnadmin" {
+				"primary".to_owned()
+			} else {
+				"normal".to_owned()
+			},
+			username: username.to_owned(),
+			access_control: None,
+		}
+	}
+}
+
+impl ServiceUser {
+	/// A normal service user with a password and no access control
+	pub fn test_with_name(username: &str) -> Self {
+		ServiceUser {
+			access_cert: String::new(),
+			access_key: String::new(),
+			authentication: "caching_sha2_password".to_owned(),
+			password: "test-password".to_owned(),
+			account_type: "normal".to_owned(),
+			username: username.to_owned(),
+			access_control: None,
+		}
+	}
+}
+
+impl KafkaTopic {
+	/// An active topic with 3 partitions, replication 2 and the default
+	/// retention of one week
+	pub fn test_with_name(topic_name: &str) -> Self {
+		KafkaTopic {
+			cleanup_policy: "delete".to_owned(),
+			min_insync_replicas: 1,
+			partitions: 3,
+			replication: 2,
+			retention_bytes: -1,
+			retention_hours: 168,
+			state: "ACTIVE".to_owned(),
+			topic_name: topic_name.to_owned(),
+		}
+	}
+}
+
+impl Topic {
+	/// Same values as the Kafka [`Topic`](crate::service::types_kafka::Topic)
+	pub fn test_with_name(topic_name: &str) -> Self {
+		Topic {
+			cleanup_policy: "delete".to_owned(),
+			min_insync_replicas: 1,
+			partitions: 3,
+			replication: 2,
+			retention_bytes: -1,
+			retention_hours: 168,
+			state: "ACTIVE".to_owned(),
+			topic_name: topic_name.to_owned(),
+		}
+	}
+}
+
+impl TopicInfo {
+	/// Details of the topic from [`Topic::test_with_name`], every partition
+	/// holds 100 messages which the `test-consumer` group has read
+	pub fn test_with_name(topic_name: &str) -> Self {
+		let topic = KafkaTopic::test_with_name(topic_name);
+		let partitions = (0..topic.partitions)
+			.map(|partition| KafkaPartition {
+				consumer_groups: vec![KafkaConsumerGroup {
+					group_name: "test-consumer".to_owned(),
+					offset: 100,
+				}],
+				earliest_offset: 0,
+				isr: topic.replication,
+				latest_offset: 100,
+				partition,
+				size: 4096,
+			})
+			.collect();
+		TopicInfo {
+			cleanup_policy: topic.cleanup_policy,
+			min_insync_replicas: topic.min_insync_replicas,
+			partitions,
+			replication: topic.replication,
+			retention_bytes: topic.retention_bytes,
+			retention_hours: topic.retention_hours,
+			state: topic.state,
+			topic_name: topic.topic_name,
+		}
+	}
+}
+
+impl Project {
+	/// A project called `test-project`
+	pub fn test_default() -> Self {
+		Project::test_with_name(TEST_PROJECT)
+	}
+
+	/// A card paid project in `google-europe-west1` without credits
+	pub fn test_with_name(project_name: &str) -> Self {
+		let email = || Email {
+			email: "admin@example.com".to_owned(),
+		};
+		Project {
+			account_id: None,
+			account_name: None,
+			available_credits: "0.00".to_owned(),
+			billing_address: "Test Street 1, 00100 Helsinki".to_owned(),
+			billing_currency: "EUR".to_owned(),
+			billing_emails: vec![email()],
+			billing_extra_text: None,
+			billing_group_id: None,
+			billing_group_name: None,
+			card_info: CardInfo {
+				brand: "Visa".to_owned(),
+				card_id: Some("test-card".to_owned()),
+				country: Some("Finland".to_owned()),
+				country_code: "FI".to_owned(),
+				exp_month: Some(12),
+				exp_year: Some(2030),
+				last4: Some("4242".to_owned()),
+				name: Some("Test User".to_owned()),
+				user_email: email().email,
+			},
+			country: "Finland".to_owned(),
+			country_code: "FI".to_owned(),
+			default_cloud: TEST_CLOUD.to_owned(),
+			estimated_balance: "0.00".to_owned(),
+			features: None,
+			payment_method: "card".to_owned(),
+			project_name: project_name.to_owned(),
+			tech_emails: vec![email()],
+			tenant_id: "aiven".to_owned(),
+			trial_expiration_time: None,
+			vat_id: String::new(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::service::types_kafka::RespKafkaTopicInfo;
+	use crate::service::types_service::ResService;
+
+	#[test]
+	fn test_builders_are_deterministic() {
+		let first = serde_json::to_value(Service::test_default()).unwrap();
+		let second = serde_json::to_value(Service::test_default()).unwrap();
+		assert!(first == second);
+	}
+
+	#[test]
+	fn test_builders_round_trip() {
+		let service = Service::test_with_name("events", "kafka");
+		let json = serde_json::json!({ "service": service });
+		let parsed: ResService = serde_json::from_value(json).unwrap();
+		assert!(parsed.service.service_name == "events");
+		assert!(parsed.service.components[0].port == 24949);
+		assert!(parsed.service.users[0].user_type == "primary");
+
+		let json = serde_json::json!({ "topic": TopicInfo::test_with_name("orders") });
+		let parsed: RespKafkaTopicInfo = serde_json::from_value(json).unwrap();
+		assert!(parsed.topic.partitions.len() == 3);
+		assert!(parsed.topic.partitions[2].partition == 2);
+
+		let json = serde_json::to_value(Project::test_default()).unwrap();
+		let parsed: Project = serde_json::from_value(json).unwrap();
+		assert!(parsed.project_name == "test-project");
+	}
+}
@@ -0,0 +1,725 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Forward project event log entries to a webhook.
+//!
+//! Enabled with the `forwarders` feature. An [`EventForwarder`] polls the
+//! event log of a project and POSTs every new event as json to a webhook,
+//! retrying failed deliveries. The time of the last delivered event is kept
+//! in a [`CheckpointStore`] so a restarted forwarder continues where it
+//! stopped.
+//!
+//! ```rust,no_run
+//! use aiven_rs::forwarders::{EventForwarder, FileCheckpointStore};
+//! use std::time::Duration;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+//!     let forwarder = EventForwarder::new(
+//!         &client,
+//!         "myproject",
+//!         "https://hooks.example.com/aiven",
+//!         FileCheckpointStore::new("/var/lib/aiven-forwarder/myproject"),
+//!     )
+//!     .poll_interval(Duration::from_secs(30));
+//!     forwarder.run().await;
+//! }
+//! ```
+//!
+//! The account event log can be consumed as a stream with an
+//! [`AccountEventsWatcher`], which keeps the id of the last handled event in
+//! the same kind of store:
+//!
+//! ```rust,no_run
+//! use aiven_rs::forwarders::AccountEventsWatcher;
+//! use futures_util::StreamExt;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+//!     let watcher =
+//!         AccountEventsWatcher::with_file(&client, "a22ba494e096", "/var/lib/aiven-audit/mark");
+//!     let mut events = Box::pin(watcher.stream());
+//!     while let Some(event) = events.next().await {
+//!         match event {
+//!             Ok(event) => println!("{} {}", event.actor, event.action_description),
+//!             Err(e) => eprintln!("polling account events failed: {}", e),
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::{
+	account::types::Event as AccountEvent, errors::AivenError, project::types::Event,
+	timestamp::Timestamp, AccountApi, AivenClient, ProjectApi,
+};
+use futures_util::stream::{self, Stream};
+use std::{
+	collections::{btree_map::Entry, BTreeMap, HashMap, VecDeque},
+	fs, io,
+	path::PathBuf,
+	sync::Mutex,
+	time::Duration,
+};
+use tracing::{debug, error};
+
+/// Events `AccountEventsWatcher` requests per page of the event log
+const ACCOUNT_EVENTS_PAGE_SIZE: usize = 100;
+
+/// Persists the position of the last forwarded event per project, or of
+/// the last handled event per account
+pub trait CheckpointStore: Send + Sync {
+	/// The saved checkpoint, `None` if nothing was forwarded yet
+	fn load(&self, project: &str) -> Result<Option<String>, AivenError>;
+
+	/// Record `checkpoint` as the position of the last forwarded event
+	fn save(&self, project: &str, checkpoint: &str) -> Result<(), AivenError>;
+}
+
+/// Keeps checkpoints in memory, they are lost on restart
+#[derive(Debug, Default)]
+pub struct MemoryCheckpointStore {
+	checkpoints: Mutex<HashMap<String, String>>,
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+	fn load(&self, project: &str) -> Result<Option<String>, AivenError> {
+		Ok(self.checkpoints.lock().unwrap().get(project).cloned())
+	}
+
+	fn save(&self, project: &str, checkpoint: &str) -> Result<(), AivenError> {
+		self.checkpoints
+			.lock()
+			.unwrap()
+			.insert(project.to_string(), checkpoint.to_string());
+		Ok(())
+	}
+}
+
+/// Keeps checkpoints in a file, one `project<TAB>checkpoint` line per
+/// project, so several forwarders can share it
+#[derive(Debug)]
+pub struct FileCheckpointStore {
+	path: PathBuf,
+	// Serializes the read-modify-write of `save` within the process
+	lock: Mutex<()>,
+}
+
+impl FileCheckpointStore {
+	pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+		FileCheckpointStore {
+			path: path.into(),
+			lock: Mutex::new(()),
+		}
+	}
+
+	/// Checkpoints by project. A line without a project, as written by
+	/// earlier versions, is the checkpoint of every project without a line.
+	fn read(&self) -> Result<(HashMap<String, String>, Option<String>), AivenError> {
+		let content = match fs::read_to_string(&self.path) {
+			Ok(content) => content,
+			Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+			Err(e) => return Err(e.into()),
+		};
+		let mut checkpoints = HashMap::new();
+		let mut legacy = None;
+		for line in content
+			.lines()
+			.map(str::trim)
+			.filter(|line| !line.is_empty())
+		{
+			match line.split_once('\t') {
+				Some((project, checkpoint)) => {
+					checkpoints.insert(project.to_string(), checkpoint.to_string());
+				}
+				None => legacy = Some(line.to_string()),
+			}
+		}
+		Ok((checkpoints, legacy))
+	}
+}
+
+impl CheckpointStore for FileCheckpointStore {
+	fn load(&self, project: &str) -> Result<Option<String>, AivenError> {
+		let (mut checkpoints, legacy) = self.read()?;
+		Ok(checkpoints.remove(project).or(legacy))
+	}
+
+	fn save(&self, project: &str, checkpoint: &str) -> Result<(), AivenError> {
+		let _guard = self.lock.lock().unwrap();
+		let (mut checkpoints, _) = self.read()?;
+		checkpoints.insert(project.to_string(), checkpoint.to_string());
+		let mut lines: Vec<_> = checkpoints
+			.iter()
+			.map(|(project, checkpoint)| format!("{}\t{}\n", project, checkpoint))
+			.collect();
+		lines.sort();
+		// Write to a temporary file first so a crash never leaves a
+		// truncated checkpoint behind
+		let tmp = self.path.with_extension("tmp");
+		fs::write(&tmp, lines.concat())?;
+		fs::rename(&tmp, &self.path)?;
+		Ok(())
+	}
+}
+
+/// Position in a project event log: the time of the last forwarded event
+/// and how many events with exactly that time were forwarded, saved as
+/// `time#count`
+#[derive(Debug, Clone, PartialEq)]
+struct EventCheckpoint {
+	time: Timestamp,
+	forwarded_at_time: usize,
+}
+
+impl EventCheckpoint {
+	fn parse(checkpoint: &str) -> Result<EventCheckpoint, AivenError> {
+		// Checkpoints of earlier versions are the time alone, everything at
+		// that time was forwarded
+		let (time, count) = match checkpoint.rsplit_once('#') {
+			Some((time, count)) => (time, count.parse().ok()),
+			None => (checkpoint, Some(usize::MAX)),
+		};
+		match (Timestamp::parse(time), count) {
+			(Some(time), Some(forwarded_at_time)) => Ok(EventCheckpoint {
+				time,
+				forwarded_at_time,
+			}),
+			_ => Err(AivenError::InvalidInput {
+				field: "checkpoint".to_string(),
+				reason: format!("`{}` is not a `time#count` event checkpoint", checkpoint),
+			}),
+		}
+	}
+}
+
+/// Polls the event log of a project and POSTs new events to a webhook
+pub struct EventForwarder<S: CheckpointStore> {
+	project_api: ProjectApi,
+	project: String,
+	webhook_url: String,
+	webhook_client: reqwest::Client,
+	store: S,
+	poll_interval: Duration,
+	max_retries: u32,
+	retry_backoff: Duration,
+}
+
+impl<S: CheckpointStore> EventForwarder<S> {
+	pub fn new(client: &AivenClient, project: &str, webhook_url: &str, store: S) -> Self {
+		EventForwarder {
+			project_api: client.project(),
+			project: project.to_string(),
+			webhook_url: webhook_url.to_string(),
+			webhook_client: reqwest::Client::new(),
+			store,
+			poll_interval: Duration::from_secs(60),
+			max_retries: 3,
+			retry_backoff: Duration::from_secs(1),
+		}
+	}
+
+	/// How long to wait between two polls of the event log, defaults to 60s
+	pub fn poll_interval(mut self, interval: Duration) -> Self {
+		self.poll_interval = interval;
+		self
+	}
+
+	/// How often a failed delivery is retried before giving up, defaults to 3.
+	/// The wait between attempts doubles starting from `backoff`.
+	pub fn retries(mut self, max_retries: u32, backoff: Duration) -> Self {
+		self.max_retries = max_retries;
+		self.retry_backoff = backoff;
+		self
+	}
+
+	/// Use a custom http client for the webhook, e.g. to add auth headers
+	pub fn webhook_client(mut self, client: reqwest::Client) -> Self {
+		self.webhook_client = client;
+		self
+	}
+
+	/// Forward all events after the checkpoint, oldest first, and return
+	/// how many were delivered.
+	///
+	/// Events with the same time are ordered by their type, service, actor
+	/// and description, and the checkpoint counts how many of them were
+	/// delivered. Stops at the first event which could not be delivered, so
+	/// it is sent again on the next call. Events with an unreadable time are
+	/// skipped.
+	pub async fn forward_once(&self) -> Result<usize, AivenError> {
+		let checkpoint = match self.store.load(&self.project)? {
+			Some(checkpoint) => Some(EventCheckpoint::parse(&checkpoint)?),
+			None => None,
+		};
+		let mut events: Vec<(Timestamp, Event)> = self
+			.project_api
+			.get_event_log_entries(&self.project)
+			.await?
+			.events
+			.into_iter()
+			.filter_map(|event| match Timestamp::parse(&event.time) {
+				Some(time) => Some((time, event)),
+				None => {
+					error!("Skipping event with unreadable time {:?}", event.time);
+					None
+				}
+			})
+			.collect();
+		events.sort_by(|(a_time, a), (b_time, b)| {
+			(
+				a_time,
+				&a.event_type,
+				&a.service_name,
+				&a.actor,
+				&a.event_desc,
+			)
+				.cmp(&(
+					b_time,
+					&b.event_type,
+					&b.service_name,
+					&b.actor,
+					&b.event_desc,
+				))
+		});
+
+		let mut delivered = 0;
+		let mut position = 0;
+		for (idx, (time, event)) in events.iter().enumerate() {
+			// Position of the event among the events with the same time
+			position = match idx.checked_sub(1).map(|prev| &events[prev].0) {
+				Some(prev) if prev == time => position + 1,
+				_ => 0,
+			};
+			let forwarded = checkpoint.as_ref().is_some_and(|checkpoint| {
+				*time < checkpoint.time
+					|| (*time == checkpoint.time && position < checkpoint.forwarded_at_time)
+			});
+			if forwarded {
+				continue;
+			}
+			if let Err(e) = self.deliver(event).await {
+				error!("Failed to forward event from {}: {}", event.time, e);
+				return if delivered == 0 {
+					Err(e)
+				} else {
+					Ok(delivered)
+				};
+			}
+			self.store
+				.save(&self.project, &format!("{}#{}", event.time, position + 1))?;
+			delivered += 1;
+		}
+		Ok(delivered)
+	}
+
+	/// Forward events forever, polling every `poll_interval`. Errors are
+	/// logged and retried on the next poll.
+	pub async fn run(&self) {
+		loop {
+			match self.forward_once().await {
+				Ok(count) => debug!("Forwarded {} event(s) of {}", count, self.project),
+				Err(e) => error!("Forwarding events of {} failed: {}", self.project, e),
+			}
+			tokio::time::sleep(self.poll_interval).await;
+		}
+	}
+
+	async fn deliver(&self, event: &Event) -> Result<(), AivenError> {
+		let mut backoff = self.retry_backoff;
+		let mut attempt = 0;
+		loop {
+			let result = match self
+				.webhook_client
+				.post(&self.webhook_url)
+				.json(event)
+				.send()
+				.await
+			{
+				Ok(response) if response.status().is_success() => return Ok(()),
+				Ok(response) => Err(AivenError::ReqwestErrorWithStatus {
+					status_code: response.status().as_u16(),
+					text: response.text().await.unwrap_or_default(),
+				}),
+				Err(e) => Err(e.into()),
+			};
+			if attempt >= self.max_retries {
+				return result;
+			}
+			attempt += 1;
+			tokio::time::sleep(backoff).await;
+			backoff *= 2;
+		}
+	}
+}
+
+/// Polls the event log of an account and streams the events which were not
+/// handled yet
+///
+/// The `log_entry_id` of the last handled event is the checkpoint, saved
+/// under the account id. An event counts as handled once the next one is
+/// requested from the stream, so after a restart the event which was being
+/// processed is delivered again.
+pub struct AccountEventsWatcher<S: CheckpointStore> {
+	account_api: AccountApi,
+	account_id: String,
+	store: S,
+	poll_interval: Duration,
+}
+
+impl AccountEventsWatcher<FileCheckpointStore> {
+	/// Watch `account_id`, keeping the checkpoint in the file at `path`
+	pub fn with_file<P: Into<PathBuf>>(client: &AivenClient, account_id: &str, path: P) -> Self {
+		AccountEventsWatcher::new(client, account_id, FileCheckpointStore::new(path))
+	}
+}
+
+impl<S: CheckpointStore> AccountEventsWatcher<S> {
+	pub fn new(client: &AivenClient, account_id: &str, store: S) -> Self {
+		AccountEventsWatcher {
+			account_api: client.account(),
+			account_id: account_id.to_string(),
+			store,
+			poll_interval: Duration::from_secs(60),
+		}
+	}
+
+	/// How long to wait for new events before polling again, defaults to 60s
+	pub fn poll_interval(mut self, interval: Duration) -> Self {
+		self.poll_interval = interval;
+		self
+	}
+
+	/// The events newer than the checkpoint, oldest first. Pages through
+	/// the event log until the checkpoint. Doesn't move the checkpoint, see
+	/// [`commit`](Self::commit).
+	pub async fn poll_once(&self) -> Result<Vec<AccountEvent>, AivenError> {
+		let checkpoint = self
+			.store
+			.load(&self.account_id)?
+			.and_then(|id| id.parse::<i64>().ok());
+		let mut events: BTreeMap<i64, AccountEvent> = BTreeMap::new();
+		let mut offset = 0;
+		loop {
+			let page = self
+				.account_api
+				.list_events_page(&self.account_id, ACCOUNT_EVENTS_PAGE_SIZE, offset)
+				.await?
+				.events;
+			let full_page = page.len() >= ACCOUNT_EVENTS_PAGE_SIZE;
+			let mut reached_checkpoint = false;
+			let mut new_events = 0;
+			for event in page {
+				if checkpoint.is_some_and(|id| event.log_entry_id <= id) {
+					reached_checkpoint = true;
+				} else if let Entry::Vacant(slot) = events.entry(event.log_entry_id) {
+					new_events += 1;
+					slot.insert(event);
+				}
+			}
+			// Older pages only hold handled events once the checkpoint was
+			// reached, and a page without new events means the paging is over
+			if !full_page || reached_checkpoint || new_events == 0 {
+				break;
+			}
+			offset += ACCOUNT_EVENTS_PAGE_SIZE;
+		}
+		Ok(events.into_values().collect())
+	}
+
+	/// Mark `event` and everything before it as handled
+	pub fn commit(&self, event: &AccountEvent) -> Result<(), AivenError> {
+		self.save_checkpoint(event.log_entry_id)
+	}
+
+	fn save_checkpoint(&self, log_entry_id: i64) -> Result<(), AivenError> {
+		self.store.save(&self.account_id, &log_entry_id.to_string())
+	}
+
+	/// Endless stream of new events, oldest first.
+	///
+	/// Failed polls and failed checkpoint saves are yielded as errors and
+	/// retried after the poll interval.
+	pub fn stream(&self) -> impl Stream<Item = Result<AccountEvent, AivenError>> + '_ {
+		// Events still to yield and the id of the last yielded one
+		let state: (VecDeque<AccountEvent>, Option<i64>) = (VecDeque::new(), None);
+		stream::unfold(state, move |(mut pending, handled)| async move {
+			if let Some(id) = handled {
+				if let Err(e) = self.save_checkpoint(id) {
+					tokio::time::sleep(self.poll_interval).await;
+					return Some((Err(e), (pending, handled)));
+				}
+			}
+			while pending.is_empty() {
+				match self.poll_once().await {
+					Ok(events) if events.is_empty() => tokio::time::sleep(self.poll_interval).await,
+					Ok(events) => pending.extend(events),
+					Err(e) => {
+						tokio::time::sleep(self.poll_interval).await;
+						return Some((Err(e), (pending, None)));
+					}
+				}
+			}
+			let event = pending.pop_front()?;
+			let id = event.log_entry_id;
+			Some((Ok(event), (pending, Some(id))))
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+	use futures_util::StreamExt;
+
+	#[tokio::test]
+	async fn test_forward_once_checkpoints() {
+		let client = testutil::prepare_test_client();
+		let test_data = testutil::get_test_data("tests/testdata/project/forward_events.json");
+		let _m = testutil::create_mock_server("/project/forwardproject/events", &test_data, "GET");
+		let hook = mockito::mock("POST", "/hooks/forwardproject")
+			.with_status(200)
+			.expect(2)
+			.create();
+
+		let store = MemoryCheckpointStore::default();
+		store
+			.save("forwardproject", "2021-03-01T08:00:00.000000Z")
+			.unwrap();
+		let forwarder = EventForwarder::new(
+			client,
+			"forwardproject",
+			&format!("{}/hooks/forwardproject", mockito::server_url()),
+			store,
+		);
+		match forwarder.forward_once().await {
+			Ok(count) => assert!(count == 2),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		assert!(
+			forwarder.store.load("forwardproject").unwrap().as_deref()
+				== Some("2021-03-01T10:30:00.000000Z#1")
+		);
+		match forwarder.forward_once().await {
+			Ok(count) => assert!(count == 0),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		hook.assert();
+	}
+
+	#[tokio::test]
+	async fn test_forward_once_resumes_within_same_time() {
+		let client = testutil::prepare_test_client();
+		let test_data =
+			testutil::get_test_data("tests/testdata/project/forward_events_same_time.json");
+		let _m = testutil::create_mock_server("/project/sametimeproject/events", &test_data, "GET");
+		let hook_url = "/hooks/sametimeproject";
+		let delivered = mockito::mock("POST", hook_url)
+			.match_body(mockito::Matcher::PartialJson(
+				serde_json::json!({"event_type": "project_create"}),
+			))
+			.with_status(200)
+			.expect(1)
+			.create();
+		let created = mockito::mock("POST", hook_url)
+			.match_body(mockito::Matcher::PartialJson(
+				serde_json::json!({"event_type": "service_create"}),
+			))
+			.with_status(200)
+			.expect(1)
+			.create();
+		let failing = mockito::mock("POST", hook_url)
+			.match_body(mockito::Matcher::PartialJson(
+				serde_json::json!({"event_type": "service_poweron"}),
+			))
+			.with_status(500)
+			.create();
+
+		let forwarder = EventForwarder::new(
+			client,
+			"sametimeproject",
+			&format!("{}{}", mockito::server_url(), hook_url),
+			MemoryCheckpointStore::default(),
+		)
+		.retries(0, Duration::from_millis(1));
+		match forwarder.forward_once().await {
+			Ok(count) => assert!(count == 2),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		assert!(
+			forwarder.store.load("sametimeproject").unwrap().as_deref()
+				== Some("2021-03-01T10:30:00.000000Z#1")
+		);
+		// The event after the checkpoint at the same time is sent again
+		assert!(forwarder.forward_once().await.is_err());
+		drop(failing);
+		let _recovered = mockito::mock("POST", hook_url)
+			.match_body(mockito::Matcher::PartialJson(
+				serde_json::json!({"event_type": "service_poweron"}),
+			))
+			.with_status(200)
+			.create();
+		match forwarder.forward_once().await {
+			Ok(count) => assert!(count == 1),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		assert!(
+			forwarder.store.load("sametimeproject").unwrap().as_deref()
+				== Some("2021-03-01T10:30:00Z#2")
+		);
+		delivered.assert();
+		created.assert();
+	}
+
+	#[test]
+	fn test_file_checkpoint_store_per_project() {
+		let path = std::env::temp_dir().join(format!("aiven-checkpoints-{}", std::process::id()));
+		fs::write(&path, "2021-03-01T08:00:00Z\n").unwrap();
+		let store = FileCheckpointStore::new(&path);
+		assert!(store.load("first").unwrap().as_deref() == Some("2021-03-01T08:00:00Z"));
+		store.save("first", "2021-03-01T09:00:00Z#1").unwrap();
+		store.save("second", "2021-03-01T10:00:00Z#2").unwrap();
+		assert!(store.load("first").unwrap().as_deref() == Some("2021-03-01T09:00:00Z#1"));
+		assert!(store.load("second").unwrap().as_deref() == Some("2021-03-01T10:00:00Z#2"));
+		assert!(store.load("third").unwrap().is_none());
+		fs::remove_file(&path).unwrap();
+	}
+
+	#[tokio::test]
+	async fn test_account_events_stream() {
+		let client = testutil::prepare_test_client();
+		let test_data = testutil::get_test_data("tests/testdata/account/watch_events.json");
+		let _m = testutil::create_mock_server(
+			"/account/watchaccount/events?limit=100&offset=0",
+			&test_data,
+			"GET",
+		);
+
+		let store = MemoryCheckpointStore::default();
+		store.save("watchaccount", "1").unwrap();
+		let watcher = AccountEventsWatcher::new(client, "watchaccount", store)
+			.poll_interval(Duration::from_millis(1));
+
+		let events: Vec<_> = watcher.stream().take(2).collect().await;
+		let ids: Vec<i64> = events
+			.into_iter()
+			.map(|event| event.unwrap().log_entry_id)
+			.collect();
+		assert!(ids == vec![2, 3]);
+		// The last event taken was not acknowledged by requesting another one
+		assert!(watcher.store.load("watchaccount").unwrap().as_deref() == Some("2"));
+	}
+
+	fn account_events(ids: std::ops::RangeInclusive<i64>) -> String {
+		let events: Vec<_> = ids
+			.rev()
+			.map(|id| AccountEvent {
+				account_id: "pagedaccount".to_owned(),
+				log_entry_id: id,
+				..Default::default()
+			})
+			.collect();
+		serde_json::json!({ "events": events }).to_string()
+	}
+
+	#[tokio::test]
+	async fn test_account_events_poll_once_pages() {
+		let client = testutil::prepare_test_client();
+		let path = "/account/pagedaccount/events";
+		let first = account_events(131..=230);
+		let _first =
+			testutil::create_mock_server(&format!("{}?limit=100&offset=0", path), &first, "GET");
+		let second = account_events(31..=130);
+		let _second =
+			testutil::create_mock_server(&format!("{}?limit=100&offset=100", path), &second, "GET");
+
+		let store = MemoryCheckpointStore::default();
+		store.save("pagedaccount", "80").unwrap();
+		let watcher = AccountEventsWatcher::new(client, "pagedaccount", store);
+		match watcher.poll_once().await {
+			Ok(events) => {
+				let ids: Vec<i64> = events.iter().map(|event| event.log_entry_id).collect();
+				assert!(ids == (81..=230).collect::<Vec<_>>());
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	struct FailingStore;
+
+	impl CheckpointStore for FailingStore {
+		fn load(&self, _account: &str) -> Result<Option<String>, AivenError> {
+			Ok(None)
+		}
+
+		fn save(&self, account: &str, _checkpoint: &str) -> Result<(), AivenError> {
+			Err(AivenError::InvalidInput {
+				field: account.to_owned(),
+				reason: "read-only".to_owned(),
+			})
+		}
+	}
+
+	#[tokio::test]
+	async fn test_account_events_stream_backs_off_after_save_errors() {
+		let client = testutil::prepare_test_client();
+		let test_data = account_events(1..=1);
+		let _m = testutil::create_mock_server(
+			"/account/readonlyaccount/events?limit=100&offset=0",
+			&test_data,
+			"GET",
+		);
+		let watcher = AccountEventsWatcher::new(client, "readonlyaccount", FailingStore)
+			.poll_interval(Duration::from_millis(20));
+
+		let started = std::time::Instant::now();
+		let results: Vec<_> = watcher.stream().take(3).collect().await;
+		assert!(results[0].is_ok());
+		assert!(results[1].is_err() && results[2].is_err());
+		assert!(started.elapsed() >= Duration::from_millis(40));
+	}
+
+	#[tokio::test]
+	async fn test_deliver_gives_up_after_retries() {
+		let client = testutil::prepare_test_client();
+		let hook = mockito::mock("POST", "/hooks/failing")
+			.with_status(503)
+			.expect(3)
+			.create();
+		let forwarder = EventForwarder::new(
+			client,
+			"failingproject",
+			&format!("{}/hooks/failing", mockito::server_url()),
+			MemoryCheckpointStore::default(),
+		)
+		.retries(2, Duration::from_millis(1));
+		match forwarder.deliver(&Event::default()).await {
+			Err(AivenError::ReqwestErrorWithStatus { status_code, .. }) => {
+				assert!(status_code == 503)
+			}
+			other => assert!(false, "{:?}", other),
+		}
+		hook.assert();
+	}
+}
@@ -43,23 +43,57 @@
 //!  Ok(())
 //! }
 //! ```
+#![cfg_attr(test, allow(clippy::assertions_on_constants))]
+
 pub mod billing;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod client;
 mod customdeser;
+pub mod deadline;
+mod deprecation;
+pub mod diagnostics;
+#[cfg(feature = "test-util")]
+pub mod fixtures;
+#[cfg(feature = "forwarders")]
+pub mod forwarders;
+mod operations;
+pub mod poll;
+pub mod rotation;
+#[cfg(feature = "examples")]
+pub mod scenarios;
+mod secret;
+pub mod snippets;
+mod timestamp;
 
 pub mod account;
 pub mod billing_group;
 pub mod cloud;
 pub mod key_mgmt;
+pub mod organization;
 pub mod payment;
 pub mod project;
 pub mod service;
-mod testutil;
+pub mod static_ip;
 pub mod ticket;
+pub mod types;
 pub mod user;
 
 pub mod errors;
-pub use client::{APIError, AivenClient};
+#[cfg(feature = "tracing")]
+pub use client::instrument;
+pub use client::{
+	governor, middleware, retry, APIError, AivenApiErrorBody, AivenClient, AivenClientBuilder,
+	Completion, Conditional, OperationRef, RawApi, RawResponse,
+};
+pub use operations::{operation, operations, Operation};
+pub use secret::Secret;
+
+/// HTTP types from `reqwest` which appear in the public API, re-exported so
+/// users don't need to depend on a matching `reqwest` version.
+pub mod http {
+	pub use reqwest::{header, Client, ClientBuilder, Error, Method, StatusCode, Url};
+}
 
 #[doc(hidden)]
 pub use billing::ProjectBillingApi;
@@ -80,6 +114,9 @@ pub use service::{
 	ServiceApi, ServiceElastiSearchApi, ServiceKafkaApi, ServiceMysqlApi, ServicePostgresApi,
 };
 
+#[doc(hidden)]
+pub use static_ip::ProjectStaticIpApi;
+
 #[doc(hidden)]
 pub use user::UserApi;
 
@@ -91,3 +128,6 @@ pub use account::AccountApi;
 
 #[doc(hidden)]
 pub use billing_group::BillingGroupApi;
+
+#[cfg(test)]
+mod testutil;
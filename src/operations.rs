@@ -0,0 +1,1030 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Aiven OpenAPI operation ids of the API methods.
+//!
+//! Every API method calling a single Aiven operation is listed here with
+//! that operation id, so tooling can map the methods an application calls
+//! to Aiven permissions or audit entries. Helpers combining several
+//! operations, e.g. `ProjectApi::apply_member_sync`, aren't listed.
+//!
+//! ```rust
+//! let op = aiven_rs::operation("ServiceApi", "get_service_info").unwrap();
+//! assert!(op.operation_id == "ServiceGet");
+//!
+//! for op in aiven_rs::operations().iter().filter(|op| op.api == "TicketApi") {
+//!     println!("{}::{} -> {}", op.api, op.method, op.operation_id);
+//! }
+//! ```
+
+/// An API method together with the Aiven operation it calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operation {
+	/// Name of the API struct, e.g. `ServiceApi`
+	pub api: &'static str,
+	/// Name of the method on `api`
+	pub method: &'static str,
+	/// Operation id in the Aiven OpenAPI specification
+	pub operation_id: &'static str,
+}
+
+macro_rules! operations {
+	($(($api:literal, $method:literal, $operation_id:literal),)*) => {
+		&[$(Operation {
+			api: $api,
+			method: $method,
+			operation_id: $operation_id,
+		},)*]
+	};
+}
+
+static OPERATIONS: &[Operation] = operations![
+	(
+		"AccountApi",
+		"associate_team_to_project",
+		"AccountTeamProjectAssociate"
+	),
+	(
+		"AccountApi",
+		"cancel_pending_invite",
+		"AccountTeamMemberCancelInvite"
+	),
+	(
+		"AccountApi",
+		"confirm_team_invite",
+		"AccountTeamMemberVerifyInvite"
+	),
+	("AccountApi", "create_new", "AccountCreate"),
+	(
+		"AccountApi",
+		"create_new_auth_method",
+		"AccountAuthenticationMethodCreate"
+	),
+	("AccountApi", "create_team", "AccountTeamCreate"),
+	("AccountApi", "delete_account", "AccountDelete"),
+	(
+		"AccountApi",
+		"delete_auth_method",
+		"AccountAuthenticationMethodDelete"
+	),
+	("AccountApi", "delete_team", "AccountTeamDelete"),
+	(
+		"AccountApi",
+		"disable_auth_method",
+		"AccountAuthenticationMethodUpdate"
+	),
+	(
+		"AccountApi",
+		"dissociate_team_from_project",
+		"AccountTeamProjectDisassociate"
+	),
+	(
+		"AccountApi",
+		"enable_auth_method",
+		"AccountAuthenticationMethodUpdate"
+	),
+	(
+		"AccountApi",
+		"get_auth_method_details",
+		"AccountAuthenticationMethodGet"
+	),
+	("AccountApi", "get_details", "AccountGet"),
+	("AccountApi", "get_team_details", "AccountTeamGet"),
+	("AccountApi", "invite_member", "AccountTeamMembersInvite"),
+	("AccountApi", "list_accessible_accounts", "AccountList"),
+	(
+		"AccountApi",
+		"list_auth_methods",
+		"AccountAuthenticationMethodsList"
+	),
+	("AccountApi", "list_events", "AccountEventList"),
+	(
+		"AccountApi",
+		"list_pending_invites",
+		"AccountTeamInvitesList"
+	),
+	("AccountApi", "list_projects", "AccountProjectsList"),
+	(
+		"AccountApi",
+		"list_projects_by_team",
+		"AccountTeamProjectList"
+	),
+	(
+		"AccountApi",
+		"list_projects_by_user",
+		"AccountUserProjectsList"
+	),
+	("AccountApi", "list_team_members", "AccountTeamMembersList"),
+	("AccountApi", "list_teams", "AccountTeamList"),
+	(
+		"AccountApi",
+		"list_teams_for_project",
+		"AccountProjectsTeamsList"
+	),
+	("AccountApi", "list_teams_for_user", "AccountUserTeamsList"),
+	("AccountApi", "remove_member", "AccountTeamMemberDelete"),
+	("AccountApi", "search_users", "AccountUsersSearch"),
+	("AccountApi", "update_account", "AccountUpdate"),
+	(
+		"AccountApi",
+		"update_auth_method",
+		"AccountAuthenticationMethodUpdate"
+	),
+	(
+		"AccountApi",
+		"update_auth_method_provisioning",
+		"AccountAuthenticationMethodUpdate"
+	),
+	("AccountApi", "update_team_details", "AccountTeamUpdate"),
+	(
+		"AccountApi",
+		"update_team_project_association",
+		"AccountTeamProjectAssociationUpdate"
+	),
+	(
+		"BillingGroupApi",
+		"assign_project",
+		"BillingGroupProjectAssign"
+	),
+	(
+		"BillingGroupApi",
+		"assign_projects",
+		"BillingGroupProjectsAssign"
+	),
+	(
+		"BillingGroupApi",
+		"claim_credit_code",
+		"BillingGroupCreditsClaim"
+	),
+	(
+		"BillingGroupApi",
+		"cost_breakdown",
+		"BillingGroupInvoiceLinesList"
+	),
+	("BillingGroupApi", "create", "BillingGroupCreate"),
+	("BillingGroupApi", "delete", "BillingGroupDelete"),
+	("BillingGroupApi", "details", "BillingGroupGet"),
+	(
+		"BillingGroupApi",
+		"download_invoice",
+		"BillingGroupInvoiceDownload"
+	),
+	(
+		"BillingGroupApi",
+		"get_associated_projects",
+		"BillingGroupProjectList"
+	),
+	("BillingGroupApi", "get_invoice", "BillingGroupInvoiceGet"),
+	(
+		"BillingGroupApi",
+		"get_invoice_lines",
+		"BillingGroupInvoiceLinesList"
+	),
+	("BillingGroupApi", "get_invoices", "BillingGroupInvoiceList"),
+	("BillingGroupApi", "list", "BillingGroupList"),
+	(
+		"BillingGroupApi",
+		"list_all_invoices",
+		"BillingGroupInvoiceList"
+	),
+	(
+		"BillingGroupApi",
+		"list_billing_group_credits",
+		"BillingGroupCreditsList"
+	),
+	("BillingGroupApi", "list_events", "BillingGroupEventList"),
+	("BillingGroupApi", "update", "BillingGroupUpdate"),
+	("CloudApi", "list_all", "ListClouds"),
+	("CloudApi", "list_by_project", "ListProjectClouds"),
+	("OrganizationApi", "get_organization", "OrganizationGet"),
+	("OrganizationApi", "invite_user", "OrganizationUserInvite"),
+	("OrganizationApi", "list_organization_units", "AccountList"),
+	(
+		"OrganizationApi",
+		"list_organizations",
+		"UserOrganizationsList"
+	),
+	("OrganizationApi", "list_users", "OrganizationUserList"),
+	("OrganizationApi", "remove_user", "OrganizationUserDelete"),
+	(
+		"OrganizationApi",
+		"update_organization",
+		"OrganizationUpdate"
+	),
+	(
+		"OrganizationUserGroupApi",
+		"add_user_group_members",
+		"UserGroupMembersUpdate"
+	),
+	(
+		"OrganizationUserGroupApi",
+		"create_user_group",
+		"UserGroupCreate"
+	),
+	(
+		"OrganizationUserGroupApi",
+		"delete_user_group",
+		"UserGroupDelete"
+	),
+	("OrganizationUserGroupApi", "get_user_group", "UserGroupGet"),
+	(
+		"OrganizationUserGroupApi",
+		"list_project_permissions",
+		"PermissionsGet"
+	),
+	(
+		"OrganizationUserGroupApi",
+		"list_user_group_members",
+		"UserGroupMemberList"
+	),
+	(
+		"OrganizationUserGroupApi",
+		"list_user_groups",
+		"UserGroupsList"
+	),
+	(
+		"OrganizationUserGroupApi",
+		"remove_user_group_members",
+		"UserGroupMembersUpdate"
+	),
+	(
+		"OrganizationUserGroupApi",
+		"set_project_permissions",
+		"PermissionsSet"
+	),
+	(
+		"OrganizationUserGroupApi",
+		"update_user_group",
+		"UserGroupUpdate"
+	),
+	("PaymentApi", "add_credit_card", "CreditCardAdd"),
+	("PaymentApi", "delete_credit_card", "CreditCardDelete"),
+	("PaymentApi", "list_credit_cards", "CreditCardsList"),
+	("PaymentApi", "update_credit_card", "CreditCardUpdate"),
+	("ProjectApi", "collect_service_notifications", "ServiceList"),
+	(
+		"ProjectApi",
+		"confirm_project_invite",
+		"ProjectInviteAccept"
+	),
+	("ProjectApi", "create", "ProjectCreate"),
+	(
+		"ProjectApi",
+		"delete_peering_connection",
+		"VpcPeeringConnectionDelete"
+	),
+	(
+		"ProjectApi",
+		"delete_peering_connection_with_region",
+		"VpcPeeringConnectionWithRegionDelete"
+	),
+	("ProjectApi", "delete_project", "ProjectDelete"),
+	(
+		"ProjectApi",
+		"delete_project_invitation",
+		"ProjectInviteDelete"
+	),
+	("ProjectApi", "delete_project_vpc", "VpcDelete"),
+	("ProjectApi", "get_event_log_entries", "ProjectGetEventLogs"),
+	("ProjectApi", "get_project_details", "ProjectGet"),
+	("ProjectApi", "get_vpc_info", "VpcGet"),
+	("ProjectApi", "list_active_alerts", "ProjectAlertsList"),
+	("ProjectApi", "list_projects", "ProjectList"),
+	("ProjectApi", "list_users_for_project", "ProjectUserList"),
+	("ProjectApi", "list_vpcs", "VpcList"),
+	("ProjectApi", "remove_user", "ProjectUserRemove"),
+	(
+		"ProjectApi",
+		"request_peering_connection",
+		"VpcPeeringConnectionRequest"
+	),
+	("ProjectApi", "request_vpc_for_project", "VpcCreate"),
+	("ProjectApi", "send_membership_invitation", "ProjectInvite"),
+	("ProjectApi", "update_project", "ProjectUpdate"),
+	("ProjectApi", "update_user", "ProjectUserUpdate"),
+	(
+		"ProjectApi",
+		"update_userdefined_network_cidrs",
+		"VpcPeeringConnectionUpdate"
+	),
+	(
+		"ProjectBillingApi",
+		"claim_credit_code",
+		"ProjectCreditsClaim"
+	),
+	("ProjectBillingApi", "download_invoice", "ProjectInvoiceGet"),
+	(
+		"ProjectBillingApi",
+		"download_pdf_invoice",
+		"ProjectInvoiceGet"
+	),
+	(
+		"ProjectBillingApi",
+		"list_invoice_lines",
+		"BillingGroupInvoiceLinesList"
+	),
+	(
+		"ProjectBillingApi",
+		"list_project_credits",
+		"ProjectCreditsList"
+	),
+	(
+		"ProjectBillingApi",
+		"list_project_invoices",
+		"ProjectInvoiceList"
+	),
+	(
+		"ProjectKeyManagementApi",
+		"get_service_ca",
+		"ServiceKmsGetCA"
+	),
+	(
+		"ProjectKeyManagementApi",
+		"get_service_keypair",
+		"ServiceKmsGetKeypair"
+	),
+	(
+		"ProjectKeyManagementApi",
+		"retrieve_ca_cert",
+		"ProjectKmsGetCA"
+	),
+	(
+		"ProjectStaticIpApi",
+		"associate",
+		"ProjectStaticIPAssociate"
+	),
+	("ProjectStaticIpApi", "create", "ProjectStaticIPCreate"),
+	("ProjectStaticIpApi", "delete", "ProjectStaticIPDelete"),
+	(
+		"ProjectStaticIpApi",
+		"dissociate",
+		"ProjectStaticIPDissociate"
+	),
+	("ProjectStaticIpApi", "list", "StaticIPList"),
+	(
+		"SchemaRegistryApi",
+		"register",
+		"ServiceSchemaRegistrySubjectVersionPost"
+	),
+	(
+		"SchemaRegistryApi",
+		"schema_by_id",
+		"ServiceSchemaRegistrySchemaGet"
+	),
+	(
+		"SchemaRegistryApi",
+		"subject_version",
+		"ServiceSchemaRegistrySubjectVersionGet"
+	),
+	(
+		"SchemaRegistryApi",
+		"subjects",
+		"ServiceSchemaRegistrySubjects"
+	),
+	(
+		"SchemaRegistryApi",
+		"versions",
+		"ServiceSchemaRegistrySubjectVersionsGet"
+	),
+	("ServiceApi", "add_ip_filter_entries", "ServiceUpdate"),
+	("ServiceApi", "cancel_migration", "ServiceUpdate"),
+	("ServiceApi", "cancel_query", "ServiceCancelQuery"),
+	("ServiceApi", "check_migration", "ServiceTaskCreate"),
+	(
+		"ServiceApi",
+		"create_logical_database",
+		"ServiceDatabaseCreate"
+	),
+	("ServiceApi", "create_new_task", "ServiceTaskCreate"),
+	("ServiceApi", "create_service", "ServiceCreate"),
+	("ServiceApi", "create_user", "ServiceUserCreate"),
+	("ServiceApi", "delete_logical_db", "ServiceDatabaseDelete"),
+	("ServiceApi", "delete_user", "ServiceUserDelete"),
+	("ServiceApi", "enable_writes", "ServiceEnableWrites"),
+	(
+		"ServiceApi",
+		"estimate_days_until_disk_full",
+		"ServiceMetricsFetch"
+	),
+	(
+		"ServiceApi",
+		"fetch_current_mysql_queries",
+		"ServiceQueryActivity"
+	),
+	(
+		"ServiceApi",
+		"fetch_current_postgres_queries",
+		"ServiceQueryActivity"
+	),
+	(
+		"ServiceApi",
+		"fetch_current_queries",
+		"ServiceQueryActivity"
+	),
+	("ServiceApi", "fetch_service_metrics", "ServiceMetricsFetch"),
+	("ServiceApi", "fork_service_from_backup", "ServiceCreate"),
+	("ServiceApi", "get_ip_filter", "ServiceGet"),
+	("ServiceApi", "get_log_entries", "ProjectGetServiceLogs"),
+	(
+		"ServiceApi",
+		"get_migration_status",
+		"ServiceGetMigrationStatus"
+	),
+	("ServiceApi", "get_service_ca", "ServiceKmsGetCA"),
+	("ServiceApi", "get_service_info", "ServiceGet"),
+	("ServiceApi", "get_service_info_as", "ServiceGet"),
+	("ServiceApi", "get_service_info_if_modified", "ServiceGet"),
+	("ServiceApi", "get_service_keypair", "ServiceKmsGetKeypair"),
+	("ServiceApi", "get_task_result", "ServiceTaskGet"),
+	("ServiceApi", "get_user_details", "ServiceUserGet"),
+	("ServiceApi", "list_active_alerts", "ServiceAlertsList"),
+	("ServiceApi", "list_backups", "ServiceBackupsGet"),
+	(
+		"ServiceApi",
+		"list_public_service_types",
+		"ListPublicServiceTypes"
+	),
+	(
+		"ServiceApi",
+		"list_service_databases",
+		"ServiceDatabaseList"
+	),
+	("ServiceApi", "list_service_summaries", "ServiceList"),
+	(
+		"ServiceApi",
+		"list_service_types",
+		"ListProjectServiceTypes"
+	),
+	("ServiceApi", "list_service_versions", "ListServiceVersions"),
+	("ServiceApi", "list_services", "ServiceList"),
+	("ServiceApi", "list_services_as", "ServiceList"),
+	("ServiceApi", "list_services_if_modified", "ServiceList"),
+	(
+		"ServiceApi",
+		"modify_service_user_credential",
+		"ServiceUserCredentialsModify"
+	),
+	("ServiceApi", "remove_ip_filter_entries", "ServiceUpdate"),
+	(
+		"ServiceApi",
+		"reset_query_stats",
+		"ServiceQueryStatisticsReset"
+	),
+	(
+		"ServiceApi",
+		"reset_service_user_credential",
+		"ServiceUserCredentialsReset"
+	),
+	(
+		"ServiceApi",
+		"reset_service_user_password",
+		"ServiceUserCredentialsReset"
+	),
+	("ServiceApi", "set_ip_filter", "ServiceUpdate"),
+	(
+		"ServiceApi",
+		"start_maintenance_updates",
+		"ServiceMaintenanceStart"
+	),
+	("ServiceApi", "start_migration", "ServiceUpdate"),
+	("ServiceApi", "tail_logs", "ProjectGetServiceLogs"),
+	("ServiceApi", "terminate", "ServiceDelete"),
+	("ServiceApi", "update_configuration", "ServiceUpdate"),
+	("ServiceApi", "update_disk_space", "ServiceUpdate"),
+	("ServiceApi", "wait_for_service_running", "ServiceGet"),
+	(
+		"ServiceElastiSearchApi",
+		"delete_index",
+		"ServiceEsIndexDelete"
+	),
+	(
+		"ServiceElastiSearchApi",
+		"list_indexes",
+		"ServiceEsIndexList"
+	),
+	(
+		"ServiceElastiSearchApi",
+		"set_acl_configuration",
+		"ServiceEsAclSet"
+	),
+	(
+		"ServiceElastiSearchApi",
+		"show_acl_configuration",
+		"ServiceEsAclGet"
+	),
+	(
+		"ServiceElastiSearchApi",
+		"update_acl_configuration",
+		"ServiceEsAclUpdate"
+	),
+	(
+		"ServiceFlinkApi",
+		"create_application",
+		"ServiceFlinkCreateApplication"
+	),
+	(
+		"ServiceFlinkApi",
+		"create_deployment",
+		"ServiceFlinkCreateApplicationDeployment"
+	),
+	(
+		"ServiceFlinkApi",
+		"delete_application",
+		"ServiceFlinkDeleteApplication"
+	),
+	(
+		"ServiceFlinkApi",
+		"get_application",
+		"ServiceFlinkGetApplication"
+	),
+	(
+		"ServiceFlinkApi",
+		"list_applications",
+		"ServiceFlinkListApplications"
+	),
+	("ServiceFlinkApi", "list_jobs", "ServiceFlinkJobsList"),
+	(
+		"ServiceFlinkApi",
+		"stop_deployment",
+		"ServiceFlinkStopApplicationDeployment"
+	),
+	("ServiceGrafanaApi", "get_user_config", "ServiceGet"),
+	("ServiceGrafanaApi", "update_user_config", "ServiceUpdate"),
+	(
+		"ServiceInfluxApi",
+		"create_database",
+		"ServiceDatabaseCreate"
+	),
+	(
+		"ServiceInfluxApi",
+		"delete_database",
+		"ServiceDatabaseDelete"
+	),
+	("ServiceInfluxApi", "get_user_config", "ServiceGet"),
+	("ServiceInfluxApi", "list_databases", "ServiceDatabaseList"),
+	("ServiceInfluxApi", "update_user_config", "ServiceUpdate"),
+	(
+		"ServiceIntegrationsApi",
+		"create_datadog_endpoint",
+		"ServiceIntegrationEndpointCreate"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"create_datadog_integration",
+		"ServiceIntegrationCreate"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"create_endpoint",
+		"ServiceIntegrationEndpointCreate"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"create_integration",
+		"ServiceIntegrationCreate"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"create_integration_endpoint",
+		"ServiceIntegrationEndpointCreate"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"delete_integration",
+		"ServiceIntegrationDelete"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"delete_integration_endpoint",
+		"ServiceIntegrationEndpointDelete"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"enable_autoscaler",
+		"ServiceIntegrationCreate"
+	),
+	("ServiceIntegrationsApi", "get", "ServiceIntegrationGet"),
+	(
+		"ServiceIntegrationsApi",
+		"get_integration_endpoint",
+		"ServiceIntegrationEndpointGet"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"list_available_integration_types",
+		"ServiceIntegrationTypes"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"list_endpoints_by_project",
+		"ServiceIntegrationEndpointList"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"list_integration_endpoint_types",
+		"ServiceIntegrationEndpointTypes"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"list_integration_types_between",
+		"ServiceIntegrationTypes"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"list_integrations_for_service",
+		"ServiceIntegrationList"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"update_endpoint",
+		"ServiceIntegrationEndpointUpdate"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"update_integration",
+		"ServiceIntegrationUpdate"
+	),
+	(
+		"ServiceIntegrationsApi",
+		"update_integration_endpoint",
+		"ServiceIntegrationEndpointUpdate"
+	),
+	(
+		"ServiceKafkaApi",
+		"add_kafka_acl_entry",
+		"ServiceKafkaAclAdd"
+	),
+	(
+		"ServiceKafkaApi",
+		"add_schema_registry_acl_entry",
+		"ServiceSchemaRegistryAclAdd"
+	),
+	(
+		"ServiceKafkaApi",
+		"check_compatibility_schema_registry",
+		"ServiceSchemaRegistryCompatibility"
+	),
+	(
+		"ServiceKafkaApi",
+		"create_kafka_connector",
+		"ServiceKafkaConnectCreateConnector"
+	),
+	(
+		"ServiceKafkaApi",
+		"create_kafka_topic",
+		"ServiceKafkaTopicCreate"
+	),
+	(
+		"ServiceKafkaApi",
+		"create_kafka_topic_with",
+		"ServiceKafkaTopicCreate"
+	),
+	(
+		"ServiceKafkaApi",
+		"delete_acl_entry",
+		"ServiceKafkaAclDelete"
+	),
+	(
+		"ServiceKafkaApi",
+		"delete_kafka_connector",
+		"ServiceKafkaConnectDeleteConnector"
+	),
+	(
+		"ServiceKafkaApi",
+		"delete_schema_registry_acl_entry",
+		"ServiceSchemaRegistryAclDelete"
+	),
+	(
+		"ServiceKafkaApi",
+		"delete_schema_registry_subject",
+		"ServiceSchemaRegistrySubjectDelete"
+	),
+	(
+		"ServiceKafkaApi",
+		"delete_schema_registry_subject_version",
+		"ServiceSchemaRegistrySubjectVersionDelete"
+	),
+	("ServiceKafkaApi", "delete_topic", "ServiceKafkaTopicDelete"),
+	(
+		"ServiceKafkaApi",
+		"edit_kafka_connector",
+		"ServiceKafkaConnectEditConnector"
+	),
+	(
+		"ServiceKafkaApi",
+		"edit_schema_registry_config",
+		"ServiceSchemaRegistrySubjectConfigPut"
+	),
+	(
+		"ServiceKafkaApi",
+		"edit_schema_registry_config_global",
+		"ServiceSchemaRegistryGlobalConfigPut"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_config_schema_registry",
+		"ServiceSchemaRegistrySubjectConfigGet"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_config_schema_registry_global",
+		"ServiceSchemaRegistryGlobalConfigGet"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_kafka_connect_configuration_schema",
+		"ServiceKafkaConnectGetConnectorConfiguration"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_kafka_connect_connectors",
+		"ServiceKafkaConnectGetAvailableConnectors"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_kafka_connect_status",
+		"ServiceKafkaConnectGetConnectorStatus"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_kafka_connector_tasks",
+		"ServiceKafkaConnectGetConnectorStatus"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_kafka_connector_topics",
+		"ServiceKafkaConnectGetConnectorTopics"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_schema_in_schema_registry",
+		"ServiceSchemaRegistrySchemaGet"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_schema_in_schema_registry_by_version",
+		"ServiceSchemaRegistrySubjectVersionGet"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_schema_registry_subject_version",
+		"ServiceSchemaRegistrySubjectVersionGet"
+	),
+	(
+		"ServiceKafkaApi",
+		"get_schema_registry_subject_versions",
+		"ServiceSchemaRegistrySubjectVersionsGet"
+	),
+	("ServiceKafkaApi", "get_topic_info", "ServiceKafkaTopicGet"),
+	("ServiceKafkaApi", "get_topic_list", "ServiceKafkaTopicList"),
+	("ServiceKafkaApi", "list_acl_entries", "ServiceKafkaAclList"),
+	(
+		"ServiceKafkaApi",
+		"list_kafka_connectors",
+		"ServiceKafkaConnectList"
+	),
+	(
+		"ServiceKafkaApi",
+		"list_schema_registry_acl_entries",
+		"ServiceSchemaRegistryAclList"
+	),
+	(
+		"ServiceKafkaApi",
+		"list_schema_registry_subjects",
+		"ServiceSchemaRegistrySubjects"
+	),
+	(
+		"ServiceKafkaApi",
+		"list_topic_messages",
+		"ServiceKafkaTopicMessageList"
+	),
+	(
+		"ServiceKafkaApi",
+		"pause_kafka_connector",
+		"ServiceKafkaConnectPauseConnector"
+	),
+	(
+		"ServiceKafkaApi",
+		"produce_message",
+		"ServiceKafkaTopicMessageProduce"
+	),
+	(
+		"ServiceKafkaApi",
+		"register_schema",
+		"ServiceSchemaRegistrySubjectVersionPost"
+	),
+	(
+		"ServiceKafkaApi",
+		"reset_connector_topics",
+		"ServiceKafkaConnectResetConnectorTopics"
+	),
+	(
+		"ServiceKafkaApi",
+		"restart_kafka_connect_connector",
+		"ServiceKafkaConnectRestartConnector"
+	),
+	(
+		"ServiceKafkaApi",
+		"restart_kafka_connect_connector_task",
+		"ServiceKafkaConnectRestartConnectorTask"
+	),
+	(
+		"ServiceKafkaApi",
+		"resume_kafka_connect_connector",
+		"ServiceKafkaConnectResumeConnector"
+	),
+	(
+		"ServiceKafkaApi",
+		"set_replication",
+		"ServiceKafkaTopicUpdate"
+	),
+	("ServiceKafkaApi", "update_topic", "ServiceKafkaTopicUpdate"),
+	(
+		"ServiceKafkaApi",
+		"update_topic_with",
+		"ServiceKafkaTopicUpdate"
+	),
+	(
+		"ServiceKafkaMirrorMaker",
+		"create_replication_flow",
+		"ServiceKafkaMirrorMakerCreateReplicationFlow"
+	),
+	(
+		"ServiceKafkaMirrorMaker",
+		"delete_replication_flow",
+		"ServiceKafkaMirrorMakerDeleteReplicationFlow"
+	),
+	(
+		"ServiceKafkaMirrorMaker",
+		"get_replication_flow",
+		"ServiceKafkaMirrorMakerGetReplicationFlow"
+	),
+	(
+		"ServiceKafkaMirrorMaker",
+		"get_replication_flows",
+		"ServiceKafkaMirrorMakerGetReplicationFlows"
+	),
+	(
+		"ServiceKafkaMirrorMaker",
+		"update_replication_flow",
+		"ServiceKafkaMirrorMakerGetReplicationFlow"
+	),
+	(
+		"ServiceMysqlApi",
+		"fetch_query_statistics",
+		"MySQLServiceQueryStatistics"
+	),
+	(
+		"ServiceMysqlApi",
+		"fetch_query_stats",
+		"MySQLServiceQueryStatistics"
+	),
+	(
+		"ServicePostgresApi",
+		"create_pool",
+		"ServicePGBouncerCreate"
+	),
+	(
+		"ServicePostgresApi",
+		"delete_pool",
+		"ServicePGBouncerDelete"
+	),
+	(
+		"ServicePostgresApi",
+		"fetch_query_statistics",
+		"PGServiceQueryStatistics"
+	),
+	(
+		"ServicePostgresApi",
+		"fetch_query_stats",
+		"PGServiceQueryStatistics"
+	),
+	(
+		"ServicePostgresApi",
+		"top_queries_by_calls",
+		"PGServiceQueryStatistics"
+	),
+	(
+		"ServicePostgresApi",
+		"top_queries_by_total_time",
+		"PGServiceQueryStatistics"
+	),
+	(
+		"ServicePostgresApi",
+		"update_pool",
+		"ServicePGBouncerUpdate"
+	),
+	("TicketApi", "create", "ProjectTicketCreate"),
+	("TicketApi", "get", "ProjectTicketList"),
+	("TicketApi", "invite_user", "ProjectTicketInvite"),
+	("TicketApi", "list", "ProjectTicketList"),
+	("TicketApi", "list_by_state", "ProjectTicketList"),
+	("TicketApi", "open", "ProjectTicketCreate"),
+	(
+		"UserApi",
+		"accept_all_invites_for_account",
+		"UserAccountInvitesAccept"
+	),
+	("UserApi", "auth_login_options", "UserAuthLoginOptions"),
+	("UserApi", "authenticate", "UserAuth"),
+	(
+		"UserApi",
+		"check_password_strength_existing_user",
+		"CheckPasswordStrengthExistingUser"
+	),
+	(
+		"UserApi",
+		"check_password_strength_new_user",
+		"CheckPasswordStrengthNewUser"
+	),
+	(
+		"UserApi",
+		"complete_otp_config",
+		"TwoFactorAuthConfigureOTP"
+	),
+	("UserApi", "configure_2fa", "TwoFactorAuthConfigure"),
+	("UserApi", "confirm_email_address", "UserVerifyEmail"),
+	("UserApi", "confirm_password_reset", "UserPasswordReset"),
+	("UserApi", "create", "UserCreate"),
+	("UserApi", "create_access_token", "AccessTokenCreate"),
+	(
+		"UserApi",
+		"delete_auth_method",
+		"UserAuthenticationMethodDelete"
+	),
+	("UserApi", "expire_auth_tokens", "UserExpireTokens"),
+	("UserApi", "info", "UserInfo"),
+	("UserApi", "list_access_tokens", "AccessTokenList"),
+	(
+		"UserApi",
+		"list_linked_auth_methods",
+		"UserAuthenticationMethodsList"
+	),
+	(
+		"UserApi",
+		"list_pending_account_invites",
+		"UserAccountInvitesList"
+	),
+	("UserApi", "logout", "UserLogout"),
+	("UserApi", "password_change", "UserPasswordChange"),
+	("UserApi", "password_reset", "UserPasswordResetRequest"),
+	(
+		"UserApi",
+		"reject_invite_to_team",
+		"UserAccountInvitesReject"
+	),
+	("UserApi", "revoke_access_token", "AccessTokenRevoke"),
+	("UserApi", "update_access_token", "AccessTokenUpdate"),
+];
+
+/// All known operations, sorted by api and method
+pub fn operations() -> &'static [Operation] {
+	OPERATIONS
+}
+
+/// The operation called by `api::method`
+pub fn operation(api: &str, method: &str) -> Option<&'static Operation> {
+	OPERATIONS
+		.iter()
+		.find(|op| op.api == api && op.method == method)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_operations_sorted_and_unique() {
+		assert!(OPERATIONS
+			.windows(2)
+			.all(|w| (w[0].api, w[0].method) < (w[1].api, w[1].method)));
+	}
+
+	#[test]
+	fn test_operation_lookup() {
+		assert!(
+			operation("ProjectStaticIpApi", "create").map(|op| op.operation_id)
+				== Some("ProjectStaticIPCreate")
+		);
+		assert!(operation("ServiceApi", "does_not_exist").is_none());
+	}
+}
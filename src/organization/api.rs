@@ -0,0 +1,342 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+	account::types::{Account, Accounts},
+	client::{encode_param, HTTPClient},
+	errors::AivenError,
+	make_json_request, make_request,
+	organization::types,
+};
+use serde_json::json;
+
+pub struct OrganizationApi {
+	http_client: HTTPClient,
+}
+
+impl OrganizationApi {
+	pub(crate) fn new(client: HTTPClient) -> Self {
+		Self {
+			http_client: client,
+		}
+	}
+
+	/// List the organizations the user belongs to
+	///
+	/// https://api.aiven.io/doc/#operation/UserOrganizationsList
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client.organization().list_organizations().await?;
+	/// for organization in response.organizations {
+	///     println!("{} {}", organization.organization_id, organization.organization_name);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_organizations(&self) -> Result<types::Organizations, AivenError> {
+		let response = make_request!(self, reqwest::Method::GET, "organizations")?;
+		response.json().await
+	}
+
+	/// Get the details of an organization
+	///
+	/// https://api.aiven.io/doc/#operation/OrganizationGet
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let organization = client
+	///         .organization()
+	///         .get_organization("org1a2b3c4d5e6").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn get_organization(
+		&self,
+		organization_id: &str,
+	) -> Result<types::Organization, AivenError> {
+		let url = &format!(
+			"organization/{organization_id}",
+			organization_id = encode_param(organization_id)
+		);
+		let response = make_request!(self, reqwest::Method::GET, url)?;
+		response.json().await
+	}
+
+	/// Update the name or tier of an organization
+	///
+	/// https://api.aiven.io/doc/#operation/OrganizationUpdate
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::organization::types::OrganizationUpdate;
+	///
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let update = OrganizationUpdate::default().name("Acme Platform");
+	/// let organization = client
+	///         .organization()
+	///         .update_organization("org1a2b3c4d5e6", &update).await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn update_organization(
+		&self,
+		organization_id: &str,
+		update: &types::OrganizationUpdate,
+	) -> Result<types::Organization, AivenError> {
+		let url = &format!(
+			"organization/{organization_id}",
+			organization_id = encode_param(organization_id)
+		);
+		let response = make_json_request!(self, reqwest::Method::PATCH, url, update)?;
+		response.json().await
+	}
+
+	/// List the units of an organization. Units are accounts whose parent is
+	/// the account of the organization, only the ones visible to the user
+	/// are returned.
+	///
+	/// https://api.aiven.io/doc/#operation/AccountList
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let units = client
+	///         .organization()
+	///         .list_organization_units("org1a2b3c4d5e6").await?;
+	/// for unit in units {
+	///     println!("{} {}", unit.account_id, unit.account_name);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_organization_units(
+		&self,
+		organization_id: &str,
+	) -> Result<Vec<Account>, AivenError> {
+		let organization = self.get_organization(organization_id).await?;
+		let response = make_request!(self, reqwest::Method::GET, "account")?;
+		let accounts = response.json::<Accounts>().await?.accounts;
+		Ok(accounts
+			.into_iter()
+			.filter(|account| {
+				account.parent_account_id.as_deref() == Some(organization.account_id.as_str())
+			})
+			.collect())
+	}
+
+	/// List the users of an organization
+	///
+	/// https://api.aiven.io/doc/#operation/OrganizationUserList
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .organization()
+	///         .list_users("org1a2b3c4d5e6").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_users(
+		&self,
+		organization_id: &str,
+	) -> Result<types::OrganizationUsers, AivenError> {
+		let url = &format!(
+			"organization/{organization_id}/user",
+			organization_id = encode_param(organization_id)
+		);
+		let response = make_request!(self, reqwest::Method::GET, url)?;
+		response.json().await
+	}
+
+	/// Invite a user to an organization by email
+	///
+	/// https://api.aiven.io/doc/#operation/OrganizationUserInvite
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .organization()
+	///         .invite_user("org1a2b3c4d5e6", "jane@example.com").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn invite_user(&self, organization_id: &str, email: &str) -> Result<(), AivenError> {
+		let url = &format!(
+			"organization/{organization_id}/invitation",
+			organization_id = encode_param(organization_id)
+		);
+		let body = &json!({ "user_email": email });
+		let _response = make_json_request!(self, reqwest::Method::POST, url, body)?;
+		Ok(())
+	}
+
+	/// Remove a user from an organization
+	///
+	/// https://api.aiven.io/doc/#operation/OrganizationUserDelete
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .organization()
+	///         .remove_user("org1a2b3c4d5e6", "u2f3e4d5c6b7").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn remove_user(
+		&self,
+		organization_id: &str,
+		user_id: &str,
+	) -> Result<(), AivenError> {
+		let url = &format!(
+			"organization/{organization_id}/user/{user_id}",
+			organization_id = encode_param(organization_id),
+			user_id = encode_param(user_id),
+		);
+		let _response = make_request!(self, reqwest::Method::DELETE, url)?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{organization::types::OrganizationUpdate, testutil, AivenClient};
+	use serde_json::json;
+
+	#[tokio::test]
+	async fn test_organization_get_and_update() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/organization/orgupdate1";
+		let test_data =
+			testutil::get_test_data("tests/testdata/organization/get_organization.json");
+		let _get = testutil::create_mock_server(query_url, &test_data, "GET");
+		let update = mockito::mock("PATCH", query_url)
+			.match_body(mockito::Matcher::Json(json!({"name": "Acme Platform"})))
+			.with_status(200)
+			.with_body(&test_data)
+			.create();
+
+		match client.organization().get_organization("orgupdate1").await {
+			Ok(organization) => {
+				assert!(organization.organization_name == "Acme");
+				assert!(organization.account_id == "a2c4e6f8b0d1");
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		let body = OrganizationUpdate::default().name("Acme Platform");
+		match client
+			.organization()
+			.update_organization("orgupdate1", &body)
+			.await
+		{
+			Ok(_) => update.assert(),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_organization_list_units() {
+		// A separate version keeps the `/account` mock apart from other tests
+		let client = AivenClient::from_token(mockito::server_url(), "orgunits", "abc");
+		let test_data =
+			testutil::get_test_data("tests/testdata/organization/get_organization.json");
+		let _get =
+			testutil::create_mock_server("/orgunits/organization/orgunits1", &test_data, "GET");
+		let accounts = testutil::get_test_data("tests/testdata/organization/accounts.json");
+		let _accounts = testutil::create_mock_server("/orgunits/account", &accounts, "GET");
+
+		match client
+			.organization()
+			.list_organization_units("orgunits1")
+			.await
+		{
+			Ok(units) => {
+				let names: Vec<&str> = units.iter().map(|u| u.account_name.as_str()).collect();
+				assert!(names == vec!["Acme Data", "Acme Web"]);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_organization_users() {
+		let client = testutil::prepare_test_client();
+		let users = testutil::get_test_data("tests/testdata/organization/users.json");
+		let _list = testutil::create_mock_server("/organization/orgusers1/user", &users, "GET");
+		let invite = mockito::mock("POST", "/organization/orgusers1/invitation")
+			.match_body(mockito::Matcher::Json(
+				json!({"user_email": "new@example.com"}),
+			))
+			.with_status(200)
+			.with_body("{}")
+			.create();
+
+		let organization = client.organization();
+		match organization.list_users("orgusers1").await {
+			Ok(response) => {
+				assert!(response.users.len() == 1);
+				assert!(response.users[0].is_super_admin);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		match organization
+			.invite_user("orgusers1", "new@example.com")
+			.await
+		{
+			Ok(_) => invite.assert(),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+}
@@ -0,0 +1,612 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+	client::{encode_param, HTTPClient},
+	errors::AivenError,
+	make_json_request, make_request,
+	organization::types,
+};
+use serde_json::json;
+
+pub struct OrganizationUserGroupApi {
+	http_client: HTTPClient,
+}
+
+impl OrganizationUserGroupApi {
+	pub(crate) fn new(client: HTTPClient) -> Self {
+		Self {
+			http_client: client,
+		}
+	}
+
+	/// List the user groups of an organization
+	///
+	/// https://api.aiven.io/doc/#operation/UserGroupsList
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .organization_user_groups()
+	///         .list_user_groups("org1a2b3c4d5e6").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_user_groups(
+		&self,
+		organization_id: &str,
+	) -> Result<types::UserGroups, AivenError> {
+		let url = &format!(
+			"organization/{organization_id}/user-groups",
+			organization_id = encode_param(organization_id)
+		);
+		let response = make_request!(self, reqwest::Method::GET, url)?;
+		response.json().await
+	}
+
+	/// Create a user group in an organization
+	///
+	/// https://api.aiven.io/doc/#operation/UserGroupCreate
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let group = client
+	///         .organization_user_groups()
+	///         .create_user_group("org1a2b3c4d5e6", "platform", "Platform team").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_user_group(
+		&self,
+		organization_id: &str,
+		name: &str,
+		description: &str,
+	) -> Result<types::UserGroup, AivenError> {
+		let url = &format!(
+			"organization/{organization_id}/user-groups",
+			organization_id = encode_param(organization_id)
+		);
+		let body = &json!({ "user_group_name": name, "description": description });
+		let response = make_json_request!(self, reqwest::Method::POST, url, body)?;
+		response.json().await
+	}
+
+	/// Get a user group of an organization
+	///
+	/// https://api.aiven.io/doc/#operation/UserGroupGet
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let group = client
+	///         .organization_user_groups()
+	///         .get_user_group("org1a2b3c4d5e6", "ug22ba494e096").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn get_user_group(
+		&self,
+		organization_id: &str,
+		user_group_id: &str,
+	) -> Result<types::UserGroup, AivenError> {
+		let url = &format!(
+			"organization/{organization_id}/user-groups/{user_group_id}",
+			organization_id = encode_param(organization_id),
+			user_group_id = encode_param(user_group_id),
+		);
+		let response = make_request!(self, reqwest::Method::GET, url)?;
+		response.json().await
+	}
+
+	/// Rename a user group or change its description
+	///
+	/// https://api.aiven.io/doc/#operation/UserGroupUpdate
+	///
+	/// # Arguments
+	///
+	/// * `update` - only the fields set on it are changed
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// use aiven_rs::organization::types::UserGroupUpdate;
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let update = UserGroupUpdate::default().description("Platform engineering");
+	/// let group = client
+	///         .organization_user_groups()
+	///         .update_user_group("org1a2b3c4d5e6", "ug22ba494e096", &update).await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn update_user_group(
+		&self,
+		organization_id: &str,
+		user_group_id: &str,
+		update: &types::UserGroupUpdate,
+	) -> Result<types::UserGroup, AivenError> {
+		let url = &format!(
+			"organization/{organization_id}/user-groups/{user_group_id}",
+			organization_id = encode_param(organization_id),
+			user_group_id = encode_param(user_group_id),
+		);
+		let response = make_json_request!(self, reqwest::Method::PATCH, url, update)?;
+		response.json().await
+	}
+
+	/// Delete a user group of an organization
+	///
+	/// https://api.aiven.io/doc/#operation/UserGroupDelete
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .organization_user_groups()
+	///         .delete_user_group("org1a2b3c4d5e6", "ug22ba494e096").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn delete_user_group(
+		&self,
+		organization_id: &str,
+		user_group_id: &str,
+	) -> Result<(), AivenError> {
+		let url = &format!(
+			"organization/{organization_id}/user-groups/{user_group_id}",
+			organization_id = encode_param(organization_id),
+			user_group_id = encode_param(user_group_id),
+		);
+		let _response = make_request!(self, reqwest::Method::DELETE, url)?;
+		Ok(())
+	}
+
+	/// List the members of a user group
+	///
+	/// https://api.aiven.io/doc/#operation/UserGroupMemberList
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .organization_user_groups()
+	///         .list_user_group_members("org1a2b3c4d5e6", "ug22ba494e096").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_user_group_members(
+		&self,
+		organization_id: &str,
+		user_group_id: &str,
+	) -> Result<types::UserGroupMembers, AivenError> {
+		let url = &format!(
+			"organization/{organization_id}/user-groups/{user_group_id}/members",
+			organization_id = encode_param(organization_id),
+			user_group_id = encode_param(user_group_id),
+		);
+		let response = make_request!(self, reqwest::Method::GET, url)?;
+		response.json().await
+	}
+
+	/// Add users to a user group
+	///
+	/// https://api.aiven.io/doc/#operation/UserGroupMembersUpdate
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .organization_user_groups()
+	///         .add_user_group_members("org1a2b3c4d5e6", "ug22ba494e096", &["u2f3e4d5c6b7"])
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn add_user_group_members(
+		&self,
+		organization_id: &str,
+		user_group_id: &str,
+		user_ids: &[&str],
+	) -> Result<(), AivenError> {
+		self.update_user_group_members(organization_id, user_group_id, "add_members", user_ids)
+			.await
+	}
+
+	/// Remove users from a user group
+	///
+	/// https://api.aiven.io/doc/#operation/UserGroupMembersUpdate
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .organization_user_groups()
+	///         .remove_user_group_members("org1a2b3c4d5e6", "ug22ba494e096", &["u2f3e4d5c6b7"])
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn remove_user_group_members(
+		&self,
+		organization_id: &str,
+		user_group_id: &str,
+		user_ids: &[&str],
+	) -> Result<(), AivenError> {
+		self.update_user_group_members(organization_id, user_group_id, "remove_members", user_ids)
+			.await
+	}
+
+	async fn update_user_group_members(
+		&self,
+		organization_id: &str,
+		user_group_id: &str,
+		operation: &str,
+		user_ids: &[&str],
+	) -> Result<(), AivenError> {
+		let url = &format!(
+			"organization/{organization_id}/user-groups/{user_group_id}/members",
+			organization_id = encode_param(organization_id),
+			user_group_id = encode_param(user_group_id),
+		);
+		let body = &json!({ "operation": operation, "member_ids": user_ids });
+		let _response = make_json_request!(self, reqwest::Method::PATCH, url, body)?;
+		Ok(())
+	}
+
+	/// List the permissions users and user groups have on a project
+	///
+	/// https://api.aiven.io/doc/#operation/PermissionsGet
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .organization_user_groups()
+	///         .list_project_permissions("org1a2b3c4d5e6", "my-project").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_project_permissions(
+		&self,
+		organization_id: &str,
+		project: &str,
+	) -> Result<types::ResourcePermissions, AivenError> {
+		let url = &project_permissions_url(organization_id, project);
+		let response = make_request!(self, reqwest::Method::GET, url)?;
+		response.json().await
+	}
+
+	/// Replace all permissions on a project
+	///
+	/// Principals missing from `permissions` lose their access to the project.
+	///
+	/// https://api.aiven.io/doc/#operation/PermissionsSet
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// use aiven_rs::organization::types::{PermissionEntry, PermissionType};
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let permissions = vec![
+	///     PermissionEntry::user_group("ug22ba494e096", vec![PermissionType::Developer]),
+	/// ];
+	/// client
+	///         .organization_user_groups()
+	///         .set_project_permissions("org1a2b3c4d5e6", "my-project", &permissions).await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn set_project_permissions(
+		&self,
+		organization_id: &str,
+		project: &str,
+		permissions: &[types::PermissionEntry],
+	) -> Result<(), AivenError> {
+		let url = &project_permissions_url(organization_id, project);
+		let body = &json!({ "permissions": permissions });
+		let _response = make_json_request!(self, reqwest::Method::PUT, url, body)?;
+		Ok(())
+	}
+
+	/// Grant a user group permissions on a project
+	///
+	/// Replaces the permissions the group had on the project before and keeps
+	/// those of every other principal. The current permissions are read first,
+	/// so concurrent changes to the same project may be lost.
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// use aiven_rs::organization::types::PermissionType;
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .organization_user_groups()
+	///         .assign_user_group_to_project(
+	///             "org1a2b3c4d5e6",
+	///             "ug22ba494e096",
+	///             "my-project",
+	///             &[PermissionType::Developer],
+	///         )
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn assign_user_group_to_project(
+		&self,
+		organization_id: &str,
+		user_group_id: &str,
+		project: &str,
+		permissions: &[types::PermissionType],
+	) -> Result<(), AivenError> {
+		let entry = types::PermissionEntry::user_group(user_group_id, permissions.to_vec());
+		self.replace_user_group_permissions(organization_id, user_group_id, project, Some(entry))
+			.await
+	}
+
+	/// Remove all permissions of a user group on a project
+	///
+	/// The current permissions are read first, so concurrent changes to the
+	/// same project may be lost.
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .organization_user_groups()
+	///         .revoke_user_group_from_project("org1a2b3c4d5e6", "ug22ba494e096", "my-project")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn revoke_user_group_from_project(
+		&self,
+		organization_id: &str,
+		user_group_id: &str,
+		project: &str,
+	) -> Result<(), AivenError> {
+		self.replace_user_group_permissions(organization_id, user_group_id, project, None)
+			.await
+	}
+
+	async fn replace_user_group_permissions(
+		&self,
+		organization_id: &str,
+		user_group_id: &str,
+		project: &str,
+		entry: Option<types::PermissionEntry>,
+	) -> Result<(), AivenError> {
+		let current = self
+			.list_project_permissions(organization_id, project)
+			.await?;
+		let mut permissions: Vec<types::PermissionEntry> = current
+			.permissions
+			.into_iter()
+			.filter(|p| {
+				!(p.principal_type == types::PrincipalType::UserGroup
+					&& p.principal_id == user_group_id)
+			})
+			.collect();
+		permissions.extend(entry);
+		self.set_project_permissions(organization_id, project, &permissions)
+			.await
+	}
+}
+
+fn project_permissions_url(organization_id: &str, project: &str) -> String {
+	format!(
+		"organization/{organization_id}/permissions/project/{project}",
+		organization_id = encode_param(organization_id),
+		project = encode_param(project),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		organization::types::{PermissionEntry, PermissionType, UserGroupUpdate},
+		testutil,
+	};
+	use serde_json::json;
+
+	#[tokio::test]
+	async fn test_organization_user_groups() {
+		let client = testutil::prepare_test_client();
+		let base = "/organization/orggroups1/user-groups";
+		let groups = testutil::get_test_data("tests/testdata/organization/user_groups.json");
+		let _list = testutil::create_mock_server(base, &groups, "GET");
+		let members =
+			testutil::get_test_data("tests/testdata/organization/user_group_members.json");
+		let members_url = format!("{}/ug22ba494e096/members", base);
+		let _members = testutil::create_mock_server(&members_url, &members, "GET");
+		let add = mockito::mock("PATCH", members_url.as_str())
+			.match_body(mockito::Matcher::Json(
+				json!({"operation": "add_members", "member_ids": ["u2f3e4d5c6b7"]}),
+			))
+			.with_status(200)
+			.with_body("{}")
+			.create();
+
+		let user_groups = client.organization_user_groups();
+		match user_groups.list_user_groups("orggroups1").await {
+			Ok(response) => {
+				assert!(response.user_groups.len() == 2);
+				assert!(response.user_groups[1].managed_by_scim);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		match user_groups
+			.list_user_group_members("orggroups1", "ug22ba494e096")
+			.await
+		{
+			Ok(response) => {
+				assert!(response.members[0].user_info.user_email == "jane@example.com")
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		match user_groups
+			.add_user_group_members("orggroups1", "ug22ba494e096", &["u2f3e4d5c6b7"])
+			.await
+		{
+			Ok(_) => add.assert(),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_organization_user_group_get_and_update() {
+		let client = testutil::prepare_test_client();
+		let url = "/organization/orggroups2/user-groups/ug22ba494e096";
+		let group = json!({
+			"user_group_id": "ug22ba494e096",
+			"user_group_name": "platform",
+			"description": "Platform engineering",
+			"create_time": "2021-03-01T10:00:00Z",
+			"update_time": "2021-03-05T10:00:00Z"
+		});
+		let _get = testutil::create_mock_server(url, &group.to_string(), "GET");
+		let update = mockito::mock("PATCH", url)
+			.match_body(mockito::Matcher::Json(
+				json!({"description": "Platform engineering"}),
+			))
+			.with_status(200)
+			.with_body(group.to_string())
+			.create();
+
+		let user_groups = client.organization_user_groups();
+		match user_groups
+			.get_user_group("orggroups2", "ug22ba494e096")
+			.await
+		{
+			Ok(group) => assert!(group.user_group_name == "platform"),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		let changes = UserGroupUpdate::default().description("Platform engineering");
+		match user_groups
+			.update_user_group("orggroups2", "ug22ba494e096", &changes)
+			.await
+		{
+			Ok(group) => {
+				update.assert();
+				assert!(group.description == "Platform engineering");
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_organization_project_permissions() {
+		let client = testutil::prepare_test_client();
+		let url = "/organization/orgperms1/permissions/project/my-project";
+		let data = testutil::get_test_data("tests/testdata/organization/project_permissions.json");
+		let _get = testutil::create_mock_server(url, &data, "GET");
+		let set = mockito::mock("PUT", url)
+			.match_body(mockito::Matcher::Json(json!({"permissions": [
+				{"principal_type": "user", "principal_id": "u1a2b3c4d5e6", "permissions": ["admin"]},
+				{"principal_type": "user_group", "principal_id": "ug22ba494e096", "permissions": ["developer"]},
+			]})))
+			.with_status(200)
+			.with_body("{}")
+			.create();
+
+		let user_groups = client.organization_user_groups();
+		match user_groups
+			.list_project_permissions("orgperms1", "my-project")
+			.await
+		{
+			Ok(response) => assert!(
+				response.permissions[1].permissions
+					== vec![
+						PermissionType::ReadOnly,
+						PermissionType::Other("project:integrations:write".to_owned())
+					]
+			),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		match user_groups
+			.assign_user_group_to_project(
+				"orgperms1",
+				"ug22ba494e096",
+				"my-project",
+				&[PermissionType::Developer],
+			)
+			.await
+		{
+			Ok(_) => set.assert(),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[test]
+	fn test_permission_entry_serialization() {
+		let entry = PermissionEntry::user_group("ug1", vec![PermissionType::ReadOnly]);
+		assert!(
+			serde_json::to_value(&entry).unwrap()
+				== json!({"principal_type": "user_group", "principal_id": "ug1", "permissions": ["read_only"]})
+		);
+	}
+}
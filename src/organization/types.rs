@@ -0,0 +1,237 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct Organization {
+	pub organization_id: String,
+	pub organization_name: String,
+	/// The account behind the organization, parent of its units
+	pub account_id: String,
+	#[serde(default)]
+	pub tier: String,
+	pub create_time: String,
+	pub update_time: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct Organizations {
+	pub organizations: Vec<Organization>,
+}
+
+/// Changes to an organization, only the set fields are sent
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct OrganizationUpdate {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tier: Option<String>,
+}
+
+impl OrganizationUpdate {
+	pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+		self.name = Some(name.into());
+		self
+	}
+
+	/// E.g. `business` or `personal`
+	pub fn tier<S: Into<String>>(mut self, tier: S) -> Self {
+		self.tier = Some(tier.into());
+		self
+	}
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct OrganizationUserInfo {
+	pub user_email: String,
+	pub real_name: String,
+	pub state: String,
+	pub create_time: String,
+	pub city: Option<String>,
+	pub country: Option<String>,
+	pub job_title: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct OrganizationUser {
+	pub user_id: String,
+	#[serde(default)]
+	pub is_super_admin: bool,
+	pub join_time: String,
+	#[serde(default)]
+	pub last_activity_time: Option<String>,
+	pub user_info: OrganizationUserInfo,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct OrganizationUsers {
+	pub users: Vec<OrganizationUser>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct UserGroup {
+	pub user_group_id: String,
+	pub user_group_name: String,
+	#[serde(default)]
+	pub description: String,
+	/// Members are managed by the identity provider
+	#[serde(default)]
+	pub managed_by_scim: bool,
+	#[serde(default)]
+	pub member_count: i64,
+	pub create_time: String,
+	pub update_time: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct UserGroups {
+	pub user_groups: Vec<UserGroup>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct UserGroupMember {
+	pub user_id: String,
+	#[serde(default)]
+	pub last_activity_time: Option<String>,
+	pub user_info: OrganizationUserInfo,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct UserGroupMembers {
+	pub members: Vec<UserGroupMember>,
+}
+
+/// Changes to a user group, only the set fields are sent
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct UserGroupUpdate {
+	#[serde(rename = "user_group_name", skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub description: Option<String>,
+}
+
+impl UserGroupUpdate {
+	pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+		self.name = Some(name.into());
+		self
+	}
+
+	pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+		self.description = Some(description.into());
+		self
+	}
+}
+
+/// Permission granted to a principal on a resource
+///
+/// Permissions this crate does not know about are kept verbatim in
+/// `Other`, so reading and writing back a permission list never drops them.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum PermissionType {
+	Admin,
+	Developer,
+	Operator,
+	ReadOnly,
+	Other(String),
+}
+
+impl PermissionType {
+	pub fn as_str(&self) -> &str {
+		match self {
+			PermissionType::Admin => "admin",
+			PermissionType::Developer => "developer",
+			PermissionType::Operator => "operator",
+			PermissionType::ReadOnly => "read_only",
+			PermissionType::Other(other) => other,
+		}
+	}
+}
+
+impl From<String> for PermissionType {
+	fn from(permission: String) -> Self {
+		match permission.as_str() {
+			"admin" => PermissionType::Admin,
+			"developer" => PermissionType::Developer,
+			"operator" => PermissionType::Operator,
+			"read_only" => PermissionType::ReadOnly,
+			_ => PermissionType::Other(permission),
+		}
+	}
+}
+
+impl From<PermissionType> for String {
+	fn from(permission: PermissionType) -> Self {
+		permission.as_str().to_owned()
+	}
+}
+
+/// Kind of principal a permission is granted to
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PrincipalType {
+	User,
+	UserGroup,
+}
+
+/// Permissions of a single principal on a resource
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct PermissionEntry {
+	pub principal_type: PrincipalType,
+	pub principal_id: String,
+	pub permissions: Vec<PermissionType>,
+	#[serde(default, skip_serializing)]
+	pub create_time: Option<String>,
+	#[serde(default, skip_serializing)]
+	pub update_time: Option<String>,
+}
+
+impl PermissionEntry {
+	pub fn user<S: Into<String>>(user_id: S, permissions: Vec<PermissionType>) -> Self {
+		Self::new(PrincipalType::User, user_id.into(), permissions)
+	}
+
+	pub fn user_group<S: Into<String>>(user_group_id: S, permissions: Vec<PermissionType>) -> Self {
+		Self::new(PrincipalType::UserGroup, user_group_id.into(), permissions)
+	}
+
+	fn new(
+		principal_type: PrincipalType,
+		principal_id: String,
+		permissions: Vec<PermissionType>,
+	) -> Self {
+		Self {
+			principal_type,
+			principal_id,
+			permissions,
+			create_time: None,
+			update_time: None,
+		}
+	}
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ResourcePermissions {
+	pub permissions: Vec<PermissionEntry>,
+}
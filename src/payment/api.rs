@@ -80,7 +80,7 @@ impl PaymentApi {
 		// TODO (ansrivas): Documentation of adding card
 		let url = "card";
 		let response = make_json_request!(self, reqwest::Method::POST, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete user's credit card
@@ -130,7 +130,7 @@ impl PaymentApi {
 	pub async fn list_credit_cards(&self) -> Result<types::ResCards, AivenError> {
 		let url = "card";
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Update user's credit card
@@ -165,7 +165,7 @@ impl PaymentApi {
 	) -> Result<types::ResCard, AivenError> {
 		let url = format!("card/{card_id}", card_id = encode_param(card_id));
 		let response = make_json_request!(self, reqwest::Method::PUT, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 }
 
@@ -184,10 +184,10 @@ mod tests {
 
 		match client.payment().list_credit_cards().await {
 			Ok(response) => {
-				let isok = response.cards.len() > 0;
-				assert!(isok, format!("{:?}", response));
+				let isok = !response.cards.is_empty();
+				assert!(isok, "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 	#[should_panic]
@@ -197,9 +197,9 @@ mod tests {
 
 		match client.payment().get_stripe_key().await {
 			Ok(response) => {
-				assert!(response.len() > 0, format!("{:?}", response));
+				assert!(!response.is_empty(), "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -214,9 +214,9 @@ mod tests {
 		json_body.insert("stripe_token", "some-token");
 		match client.payment().add_credit_card(&json_body).await {
 			Ok(response) => {
-				assert!(response.card.last4 == "1234", format!("{:?}", response));
+				assert!(response.card.last4 == "1234", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -235,9 +235,9 @@ mod tests {
 		match client.payment().update_credit_card("cardid", &json).await {
 			Ok(response) => {
 				let isok = response.card.brand == "Visa";
-				assert!(isok, format!("{:?}", response));
+				assert!(isok, "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -250,7 +250,7 @@ mod tests {
 
 		match client.payment().delete_credit_card("cardid").await {
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 }
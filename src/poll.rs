@@ -0,0 +1,240 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Poll an endpoint until a condition holds.
+//!
+//! [`until`] calls a closure repeatedly, sleeping a jittered and optionally
+//! growing interval between attempts, until it returns `Some(value)`, an
+//! error, the attempt limit is reached or the [`Deadline`] expires.
+//!
+//! ```rust,no_run
+//! use aiven_rs::poll::{self, PollOpts};
+//! use std::time::Duration;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+//!     let opts = PollOpts::default()
+//!         .interval(Duration::from_secs(10))
+//!         .max_attempts(60);
+//!     let service = poll::until(
+//!         || async {
+//!             let response = client.service().get_service_info("myproject", "mypg").await?;
+//!             Ok(Some(response.service).filter(|s| s.state == "RUNNING"))
+//!         },
+//!         &opts,
+//!     )
+//!     .await?;
+//!     println!("{} is running", service.service_name);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{deadline::Deadline, errors::AivenError};
+use std::{
+	collections::hash_map::RandomState,
+	future::Future,
+	hash::{BuildHasher, Hasher},
+	time::Duration,
+};
+
+/// Controls the pace and the limits of [`until`]
+#[derive(Debug, Clone)]
+pub struct PollOpts {
+	interval: Duration,
+	max_interval: Duration,
+	backoff: f64,
+	jitter: f64,
+	pub(crate) max_attempts: Option<usize>,
+	pub(crate) deadline: Deadline,
+}
+
+impl Default for PollOpts {
+	fn default() -> Self {
+		PollOpts {
+			interval: Duration::from_secs(5),
+			max_interval: Duration::from_secs(60),
+			backoff: 1.0,
+			jitter: 0.1,
+			max_attempts: None,
+			deadline: Deadline::none(),
+		}
+	}
+}
+
+impl PollOpts {
+	/// Wait between the first two attempts, defaults to 5s
+	pub fn interval(mut self, interval: Duration) -> Self {
+		self.interval = interval;
+		self
+	}
+
+	/// Multiply the interval by `factor` after every attempt, up to
+	/// `max_interval`. Defaults to 1, a fixed interval.
+	pub fn backoff(mut self, factor: f64, max_interval: Duration) -> Self {
+		self.backoff = factor.max(1.0);
+		self.max_interval = max_interval;
+		self
+	}
+
+	/// Randomly shorten or lengthen each wait by up to `fraction` of it, so
+	/// many pollers don't hit the API in lockstep. Defaults to 0.1.
+	pub fn jitter(mut self, fraction: f64) -> Self {
+		self.jitter = fraction.clamp(0.0, 1.0);
+		self
+	}
+
+	/// Give up with `AivenError::MaxAttemptsReached` after `attempts` calls
+	pub fn max_attempts(mut self, attempts: usize) -> Self {
+		self.max_attempts = Some(attempts);
+		self
+	}
+
+	/// Give up with `AivenError::DeadlineExceeded` once `deadline` expires
+	pub fn deadline(mut self, deadline: Deadline) -> Self {
+		self.deadline = deadline;
+		self
+	}
+
+	/// The wait before attempt `attempt + 1`, without jitter
+	pub(crate) fn base_wait(&self, attempt: usize) -> Duration {
+		if self.backoff <= 1.0 {
+			return self.interval;
+		}
+		// Capped in seconds, `Duration::mul_f64` panics once the factor
+		// overflows a `Duration` on long waits
+		let exponent = attempt.saturating_sub(1).min(i32::MAX as usize) as i32;
+		let secs = (self.interval.as_secs_f64() * self.backoff.powi(exponent))
+			.min(self.max_interval.as_secs_f64());
+		Duration::try_from_secs_f64(secs).unwrap_or(self.max_interval)
+	}
+
+	pub(crate) fn jittered(&self, wait: Duration) -> Duration {
+		jitter(wait, self.jitter)
+	}
+}
+
+/// Randomly shorten or lengthen `wait` by up to `fraction` of it
+pub(crate) fn jitter(wait: Duration, fraction: f64) -> Duration {
+	if fraction == 0.0 {
+		return wait;
+	}
+	// A fresh RandomState is randomly keyed, good enough for spreading
+	// requests without pulling in a random number generator
+	let random = RandomState::new().build_hasher().finish();
+	let unit = (random as f64 / u64::MAX as f64) * 2.0 - 1.0;
+	wait.mul_f64(1.0 + unit * fraction)
+}
+
+/// Call `f` until it returns `Ok(Some(value))` and return that value.
+///
+/// `Ok(None)` means "not yet" and schedules another attempt. Errors returned
+/// by `f` are passed through immediately; wrap them in `Ok(None)` to retry
+/// instead.
+pub async fn until<F, Fut, T>(mut f: F, opts: &PollOpts) -> Result<T, AivenError>
+where
+	F: FnMut() -> Fut,
+	Fut: Future<Output = Result<Option<T>, AivenError>>,
+{
+	let mut attempt = 0;
+	loop {
+		if let Some(value) = opts.deadline.run(attempt, f()).await? {
+			return Ok(value);
+		}
+		attempt += 1;
+		if opts.max_attempts.is_some_and(|max| attempt >= max) {
+			return Err(AivenError::MaxAttemptsReached { attempts: attempt });
+		}
+		let wait = opts.jittered(opts.base_wait(attempt));
+		opts.deadline.sleep(wait, attempt).await?;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::cell::Cell;
+
+	#[tokio::test]
+	async fn test_until_returns_value() {
+		let calls = Cell::new(0);
+		let opts = PollOpts::default().interval(Duration::from_millis(1));
+		let res = until(
+			|| {
+				calls.set(calls.get() + 1);
+				let done = calls.get() == 3;
+				async move { Ok(Some("RUNNING").filter(|_| done)) }
+			},
+			&opts,
+		)
+		.await;
+		assert!(res.unwrap() == "RUNNING");
+		assert!(calls.get() == 3);
+	}
+
+	#[tokio::test]
+	async fn test_until_max_attempts() {
+		let opts = PollOpts::default()
+			.interval(Duration::from_millis(1))
+			.max_attempts(2);
+		match until(|| async { Ok(None::<()>) }, &opts).await {
+			Err(AivenError::MaxAttemptsReached { attempts }) => assert!(attempts == 2),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_until_deadline() {
+		let opts = PollOpts::default()
+			.interval(Duration::from_secs(5))
+			.deadline(Deadline::after(Duration::from_millis(10)));
+		match until(|| async { Ok(None::<()>) }, &opts).await {
+			Err(AivenError::DeadlineExceeded { completed, .. }) => assert!(completed == 1),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[test]
+	fn test_poll_opts_wait() {
+		let opts = PollOpts::default()
+			.interval(Duration::from_secs(1))
+			.backoff(2.0, Duration::from_secs(5))
+			.jitter(0.5);
+		assert!(opts.base_wait(1) == Duration::from_secs(1));
+		assert!(opts.base_wait(3) == Duration::from_secs(4));
+		assert!(opts.base_wait(10) == Duration::from_secs(5));
+		for _ in 0..20 {
+			let wait = opts.jittered(Duration::from_secs(2));
+			assert!(wait >= Duration::from_secs(1) && wait <= Duration::from_secs(3));
+		}
+	}
+
+	#[test]
+	fn test_poll_opts_wait_long_backoff() {
+		let opts = PollOpts::default()
+			.interval(Duration::from_secs(5))
+			.backoff(2.0, Duration::from_secs(60));
+		assert!(opts.base_wait(63) == Duration::from_secs(60));
+		assert!(opts.base_wait(10_000) == Duration::from_secs(60));
+		assert!(opts.jittered(opts.base_wait(10_000)) <= Duration::from_secs(66));
+	}
+}
@@ -22,12 +22,14 @@
 
 use crate::{
 	client::{encode_param, HTTPClient},
+	deadline::Deadline,
 	errors::AivenError,
 	make_json_request, make_request,
 	project::types,
+	service::types_service::ResServices,
 };
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 pub struct ProjectApi {
 	http_client: HTTPClient,
@@ -68,7 +70,7 @@ impl ProjectApi {
 			verification_code = verification_code
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Create a project
@@ -108,7 +110,7 @@ impl ProjectApi {
 	) -> Result<types::ResProject, AivenError> {
 		let url = "project";
 		let response = make_json_request!(self, reqwest::Method::POST, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete a peering connection for a project VPC
@@ -146,11 +148,13 @@ impl ProjectApi {
 			peer_vpc = encode_param(peer_vpc),
 		);
 		let response = make_request!(self, reqwest::Method::DELETE, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete a peering connection for a project VPC based on a region
 	///
+	/// https://api.aiven.io/doc/#operation/VpcPeeringConnectionWithRegionDelete
+	///
 	/// # Arguments
 	///
 	/// * `Arg1` -
@@ -193,11 +197,13 @@ impl ProjectApi {
 			peer_region = encode_param(peer_region),
 		);
 		let response = make_request!(self, reqwest::Method::DELETE, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete a project VPC
 	///
+	/// https://api.aiven.io/doc/#operation/VpcDelete
+	///
 	/// # Arguments
 	///
 	/// * `Arg1` -
@@ -229,11 +235,13 @@ impl ProjectApi {
 			project_vpc_id = encode_param(project_vpc_id),
 		);
 		let response = make_request!(self, reqwest::Method::DELETE, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete an invitation to a project
 	///
+	/// https://api.aiven.io/doc/#operation/ProjectInviteDelete
+	///
 	/// # Arguments
 	///
 	/// * `project` - Project name
@@ -270,6 +278,8 @@ impl ProjectApi {
 
 	/// Delete project
 	///
+	/// https://api.aiven.io/doc/#operation/ProjectDelete
+	///
 	/// # Arguments
 	///
 	/// * `project` - Project name
@@ -298,6 +308,8 @@ impl ProjectApi {
 
 	/// Get VPC information
 	///
+	/// https://api.aiven.io/doc/#operation/VpcGet
+	///
 	/// # Arguments
 	///
 	/// * `project` - Project name
@@ -330,7 +342,7 @@ impl ProjectApi {
 			project_vpc_id = encode_param(project_vpc_id),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Get project details
@@ -363,7 +375,7 @@ impl ProjectApi {
 	) -> Result<types::ResProject, AivenError> {
 		let url = format!("project/{project}", project = encode_param(project),);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Get project event log entries
@@ -396,11 +408,13 @@ impl ProjectApi {
 	) -> Result<types::ResEvents, AivenError> {
 		let url = format!("project/{project}/events", project = encode_param(project),);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List VPCs for a project
 	///
+	/// https://api.aiven.io/doc/#operation/VpcList
+	///
 	/// # Arguments
 	///
 	/// * `project` - Project name
@@ -424,7 +438,7 @@ impl ProjectApi {
 	pub async fn list_vpcs(&self, project: &str) -> Result<types::ProjectVPCs, AivenError> {
 		let url = format!("project/{project}/vpcs", project = encode_param(project),);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List active alerts for all services in a project
@@ -457,7 +471,62 @@ impl ProjectApi {
 	) -> Result<types::ActiveAlerts, AivenError> {
 		let url = format!("project/{project}/alerts", project = encode_param(project),);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// Collect the notifications of all the services of a project, warnings
+	/// first and then by service name
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceList
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let notifications = client
+	///         .project()
+	///         .collect_service_notifications("project")
+	///         .await?;
+	/// for n in notifications.iter().filter(|n| n.notification.is_warning()) {
+	///     println!("{}: {}", n.service_name, n.notification.message);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn collect_service_notifications(
+		&self,
+		project: &str,
+	) -> Result<Vec<types::ProjectServiceNotification>, AivenError> {
+		let url = format!("project/{project}/service", project = encode_param(project));
+		let response = make_request!(self, reqwest::Method::GET, &url)?;
+		let services = response.json::<ResServices>().await?.services;
+		let mut notifications: Vec<_> = services
+			.into_iter()
+			.flat_map(|service| {
+				let (service_name, service_type) = (service.service_name, service.service_type);
+				service.notifications.into_iter().map(move |notification| {
+					types::ProjectServiceNotification {
+						service_name: service_name.clone(),
+						service_type: service_type.clone(),
+						notification,
+					}
+				})
+			})
+			.collect();
+		notifications.sort_by(|a, b| {
+			b.notification
+				.is_warning()
+				.cmp(&a.notification.is_warning())
+				.then_with(|| a.service_name.cmp(&b.service_name))
+		});
+		Ok(notifications)
 	}
 
 	/// List projects
@@ -487,7 +556,7 @@ impl ProjectApi {
 	pub async fn list_projects(&self) -> Result<types::ProjectList, AivenError> {
 		let url = "project";
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List users with access to the project. May contain same user multiple
@@ -521,7 +590,7 @@ impl ProjectApi {
 	) -> Result<types::ProjectUserList, AivenError> {
 		let url = format!("project/{project}/users", project = encode_param(project));
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Remove user from the project.
@@ -560,6 +629,8 @@ impl ProjectApi {
 	}
 	/// Request a VPC in a cloud for the project
 	///
+	/// https://api.aiven.io/doc/#operation/VpcCreate
+	///
 	/// # Arguments
 	///
 	/// * `project` - Project name
@@ -607,7 +678,7 @@ impl ProjectApi {
 		let url = format!("project/{project}/vpcs", project = encode_param(project),);
 
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Request a peering connection for a project VPC
@@ -659,7 +730,7 @@ impl ProjectApi {
 		);
 
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Send project membership invitation
@@ -747,6 +818,138 @@ impl ProjectApi {
 		Ok(())
 	}
 
+	/// Plan the invitations, updates and removals which make the members of a
+	/// project match `desired`, e.g. the members of an identity provider
+	/// group. Nothing is changed, so the plan doubles as a dry run.
+	///
+	/// Members who belong to the project through an account team are left
+	/// alone and listed in `MembershipPlan::team_members`.
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `desired` - Emails and member types of all the members the project
+	///   should have
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::{deadline::Deadline, project::types::MemberType};
+	/// use std::time::Duration;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let desired = vec![
+	///     ("jane@example.com", MemberType::Admin),
+	///     ("john@example.com", MemberType::ReadOnly),
+	/// ];
+	/// let project = client.project();
+	/// let plan = project.plan_member_sync("project", desired).await?;
+	/// for change in &plan.changes {
+	///     println!("{:?}", change);
+	/// }
+	/// project
+	///     .apply_member_sync("project", &plan, Deadline::after(Duration::from_secs(120)))
+	///     .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn plan_member_sync<S, I>(
+		&self,
+		project: &str,
+		desired: I,
+	) -> Result<types::MembershipPlan, AivenError>
+	where
+		I: IntoIterator<Item = (S, types::MemberType)>,
+		S: Into<String>,
+	{
+		let desired: BTreeMap<String, types::MemberType> = desired
+			.into_iter()
+			.map(|(email, member_type)| (email.into(), member_type))
+			.collect();
+		let current = self.list_users_for_project(project).await?;
+		Ok(types::MembershipPlan::between(&current, &desired))
+	}
+
+	/// Apply a plan of `plan_member_sync`.
+	///
+	/// Plans which leave a project with admins without any are refused with
+	/// `AivenError::InvalidInput`, checked against the current members.
+	/// Invitations are cancelled first, so an invitation with the wrong
+	/// member type can be sent again, then users are invited, updated and
+	/// finally removed. The first failing change stops the sync with
+	/// `AivenError::MemberSyncFailed` listing the changes applied before it;
+	/// planning again picks up where it stopped. No change is started once
+	/// `deadline` has expired, the sync then fails with
+	/// `AivenError::DeadlineExceeded` counting the applied changes.
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `plan` - Changes to apply
+	/// * `deadline` - Stop applying changes after this, or `Deadline::none()`
+	pub async fn apply_member_sync(
+		&self,
+		project: &str,
+		plan: &types::MembershipPlan,
+		deadline: Deadline,
+	) -> Result<(), AivenError> {
+		deadline.check(0)?;
+		let current = deadline
+			.run(0, self.list_users_for_project(project))
+			.await?;
+		let admin = types::MemberType::Admin.to_string();
+		if plan.remaining_admins(&current) == 0
+			&& current.users.iter().any(|user| user.member_type == admin)
+		{
+			return Err(AivenError::InvalidInput {
+				field: "plan".to_owned(),
+				reason: format!("it removes or downgrades the last admin of `{}`", project),
+			});
+		}
+		let mut changes: Vec<&types::MembershipChange> = plan.changes.iter().collect();
+		changes.sort_by_key(|change| match change {
+			types::MembershipChange::CancelInvite { .. } => 0,
+			types::MembershipChange::Invite { .. } => 1,
+			types::MembershipChange::Update { .. } => 2,
+			types::MembershipChange::Remove { .. } => 3,
+		});
+		let mut applied = Vec::new();
+		for change in changes {
+			deadline.check(applied.len())?;
+			let result = match change {
+				types::MembershipChange::CancelInvite { user_email } => {
+					self.delete_project_invitation(project, user_email).await
+				}
+				types::MembershipChange::Invite {
+					user_email,
+					member_type,
+				} => {
+					let member_type = member_type.to_string();
+					self.send_membership_invitation(project, user_email, Some(&member_type))
+						.await
+				}
+				types::MembershipChange::Update { user_email, to, .. } => {
+					self.update_user(project, user_email, *to).await
+				}
+				types::MembershipChange::Remove { user_email } => {
+					self.remove_user(project, user_email).await
+				}
+			};
+			if let Err(e) = result {
+				return Err(AivenError::MemberSyncFailed {
+					project: project.to_owned(),
+					applied,
+					failed: change.clone(),
+					source: Box::new(e),
+				});
+			}
+			applied.push(change.clone());
+		}
+		Ok(())
+	}
+
 	/// Update project
 	///
 	/// https://api.aiven.io/doc/#operation/ProjectUpdate
@@ -781,7 +984,7 @@ impl ProjectApi {
 	) -> Result<types::ResProject, AivenError> {
 		let url = format!("project/{project}", project = encode_param(project),);
 		let response = make_json_request!(self, reqwest::Method::PUT, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Update user-defined peer network CIDRs for a project VPC
@@ -832,7 +1035,7 @@ impl ProjectApi {
 			project_vpc_id = encode_param(project_vpc_id),
 		);
 		let response = make_json_request!(self, reqwest::Method::PUT, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 }
 
@@ -842,6 +1045,7 @@ mod tests {
 	use super::*;
 	use crate::testutil;
 	use serde_json::json;
+	use std::time::Duration;
 
 	#[tokio::test]
 	async fn test_project_confirm_project_invite() {
@@ -858,11 +1062,12 @@ mod tests {
 		{
 			Ok(response) => {
 				assert!(
-					response.invite_details.user_email != "",
-					format!("{:?}", response)
+					!response.invite_details.user_email.is_empty(),
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -899,10 +1104,11 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.project.account_id == Some("a22ba494e096".into()),
-					format!("{:?}", response)
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 	#[tokio::test]
@@ -922,11 +1128,12 @@ mod tests {
 		{
 			Ok(response) => {
 				assert!(
-					response.peer_cloud_account == "123456789012".to_string(),
-					format!("{:?}", response)
+					response.peer_cloud_account == "123456789012",
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -954,11 +1161,12 @@ mod tests {
 		{
 			Ok(response) => {
 				assert!(
-					response.peer_cloud_account == "123456789012".to_string(),
-					format!("{:?}", response)
+					response.peer_cloud_account == "123456789012",
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -976,9 +1184,9 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.peering_connections.unwrap().len() > 0,);
+				assert!(!response.peering_connections.unwrap().is_empty(),);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -998,7 +1206,7 @@ mod tests {
 			Ok(_) => {
 				assert!(true);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1014,7 +1222,7 @@ mod tests {
 			Ok(_) => {
 				assert!(true);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1033,10 +1241,10 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.cloud_name == "aws-eu-central-1".to_string());
-				assert!(response.peering_connections.unwrap().len() > 0);
+				assert!(response.cloud_name == "aws-eu-central-1");
+				assert!(!response.peering_connections.unwrap().is_empty());
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1051,10 +1259,34 @@ mod tests {
 
 		match client.project().get_project_details("myproject").await {
 			Ok(response) => {
-				assert!(response.project.billing_currency == "USD".to_string());
-				assert!(response.project.tenant_id == "aiven".to_string());
+				assert!(response.project.billing_currency == "USD");
+				assert!(response.project.tenant_id == "aiven");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_project_list_projects() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project";
+		let test_data = testutil::get_test_data("tests/testdata/project/list_projects.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		match client.project().list_projects().await {
+			Ok(response) => {
+				assert!(response.projects.len() == 2);
+				let project = &response.projects[0];
+				assert!(project.account_name.as_deref() == Some("ACME"));
+				assert!(project.billing_group_name.as_deref() == Some("ACME main"));
+				assert!(project.estimated_balance_amount() == Some(42.30));
+
+				let groups = response.by_billing_group();
+				assert!(groups[&None][0].project_name == "sandbox");
+				assert!(groups[&Some("588a8e63-fda7-4ff7-9bff-577debfee604")].len() == 1);
+				assert!(response.by_account().len() == 2);
+			}
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1070,10 +1302,10 @@ mod tests {
 
 		match client.project().get_event_log_entries("myproject").await {
 			Ok(response) => {
-				assert!(response.events.len() > 0);
-				assert!(response.events[0].actor == "user@example.com".to_string());
+				assert!(!response.events.is_empty());
+				assert!(response.events[0].actor == "user@example.com");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1088,10 +1320,10 @@ mod tests {
 
 		match client.project().list_vpcs("myproject").await {
 			Ok(response) => {
-				assert!(response.vpcs.len() > 0);
-				assert!(response.vpcs[0].cloud_name == "aws-eu-central-1".to_string());
+				assert!(!response.vpcs.is_empty());
+				assert!(response.vpcs[0].cloud_name == "aws-eu-central-1");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1106,10 +1338,10 @@ mod tests {
 
 		match client.project().list_active_alerts("myproject").await {
 			Ok(response) => {
-				assert!(response.alerts.len() > 0);
-				assert!(response.alerts[0].event == "disk_usage".to_string());
+				assert!(!response.alerts.is_empty());
+				assert!(response.alerts[0].event == "disk_usage");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1125,11 +1357,11 @@ mod tests {
 
 		match client.project().list_users_for_project("myproject").await {
 			Ok(response) => {
-				assert!(response.invitations.len() > 0);
-				assert!(response.users.len() > 0);
-				assert!(response.users[0].auth[0] == "2fa-otp".to_string());
+				assert!(!response.invitations.is_empty());
+				assert!(!response.users.is_empty());
+				assert!(response.users[0].auth[0] == "2fa-otp");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1148,7 +1380,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1184,10 +1416,10 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.cloud_name == "aws-eu-central-1".to_string());
-				assert!(response.state == "ACTIVE".to_string())
+				assert!(response.cloud_name == "aws-eu-central-1");
+				assert!(response.state == "ACTIVE")
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1217,10 +1449,10 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.peer_cloud_account == "123456789012".to_string());
-				assert!(response.state == "ACTIVE".to_string())
+				assert!(response.peer_cloud_account == "123456789012");
+				assert!(response.state == "ACTIVE")
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1238,7 +1470,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1256,7 +1488,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1294,7 +1526,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.project.card_info.brand == "Visa"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1329,7 +1561,220 @@ mod tests {
 				assert!(response.cloud_name == "aws-eu-central-1");
 				assert!(response.state == "ACTIVE");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_project_collect_service_notifications() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/notificationsproject/service";
+		let test_data =
+			testutil::get_test_data("tests/testdata/project/service_notifications.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		match client
+			.project()
+			.collect_service_notifications("notificationsproject")
+			.await
+		{
+			Ok(notifications) => {
+				let order: Vec<(&str, &str)> = notifications
+					.iter()
+					.map(|n| {
+						(
+							n.service_name.as_str(),
+							n.notification.notification_type.as_str(),
+						)
+					})
+					.collect();
+				assert!(
+					order
+						== vec![
+							("billing-pg", "service_powered_off_removal"),
+							("orders-pg", "service_end_of_life"),
+							("orders-pg", "service_maintenance_update"),
+						]
+				);
+				assert!(notifications[1].service_type == "pg");
+				assert!(notifications[1].notification.metadata["upgrade_to_version"] == "14");
+				assert!(!notifications[2].notification.is_warning());
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_project_member_sync() {
+		let client = testutil::prepare_test_client();
+		let test_data = testutil::get_test_data("tests/testdata/project/member_sync_users.json");
+		let _users = testutil::create_mock_server("/project/syncproject/users", &test_data, "GET");
+		let invite = mockito::mock("POST", "/project/syncproject/invite")
+			.match_body(mockito::Matcher::Json(
+				json!({"user_email": "new@example.com", "member_type": "operator"}),
+			))
+			.with_status(200)
+			.with_body("{}")
+			.create();
+		let update = mockito::mock("PUT", "/project/syncproject/user/bob%40example.com")
+			.match_body(mockito::Matcher::Json(json!({"member_type": "read_only"})))
+			.with_status(200)
+			.with_body("{}")
+			.create();
+		let cancel = mockito::mock("DELETE", "/project/syncproject/invite/old%40example.com")
+			.with_status(200)
+			.with_body("{}")
+			.create();
+		let remove = mockito::mock("DELETE", "/project/syncproject/user/eve%40example.com")
+			.with_status(200)
+			.with_body("{}")
+			.create();
+
+		let desired = vec![
+			("jane@example.com", types::MemberType::Admin),
+			("bob@example.com", types::MemberType::ReadOnly),
+			("amy@example.com", types::MemberType::Developer),
+			("new@example.com", types::MemberType::Operator),
+		];
+		let project = client.project();
+		let plan = project
+			.plan_member_sync("syncproject", desired)
+			.await
+			.unwrap();
+		assert!(
+			plan.changes
+				== vec![
+					types::MembershipChange::Update {
+						user_email: "bob@example.com".into(),
+						from: "developer".into(),
+						to: types::MemberType::ReadOnly,
+					},
+					types::MembershipChange::Remove {
+						user_email: "eve@example.com".into()
+					},
+					types::MembershipChange::CancelInvite {
+						user_email: "old@example.com".into()
+					},
+					types::MembershipChange::Invite {
+						user_email: "new@example.com".into(),
+						member_type: types::MemberType::Operator,
+					},
+				]
+		);
+		assert!(plan.team_members == vec!["tom@example.com".to_owned()]);
+
+		match project
+			.apply_member_sync("syncproject", &plan, Deadline::none())
+			.await
+		{
+			Ok(_) => {
+				invite.assert();
+				update.assert();
+				cancel.assert();
+				remove.assert();
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_project_member_sync_reinvite_and_last_admin() {
+		let client = testutil::prepare_test_client();
+		let test_data = testutil::get_test_data("tests/testdata/project/member_sync_users.json");
+		let _users = testutil::create_mock_server("/project/syncproject2/users", &test_data, "GET");
+		let cancel = mockito::mock("DELETE", "/project/syncproject2/invite/amy%40example.com")
+			.with_status(200)
+			.with_body("{}")
+			.create();
+		let _invite = mockito::mock("POST", "/project/syncproject2/invite")
+			.with_status(400)
+			.with_body(r#"{"message": "Invalid member type", "errors": []}"#)
+			.create();
+
+		let project = client.project();
+		let desired = vec![
+			("jane@example.com", types::MemberType::Admin),
+			("bob@example.com", types::MemberType::Developer),
+			("eve@example.com", types::MemberType::Developer),
+			("amy@example.com", types::MemberType::ReadOnly),
+			("old@example.com", types::MemberType::ReadOnly),
+		];
+		let plan = project
+			.plan_member_sync("syncproject2", desired)
+			.await
+			.unwrap();
+		let cancel_amy = types::MembershipChange::CancelInvite {
+			user_email: "amy@example.com".into(),
+		};
+		let invite_amy = types::MembershipChange::Invite {
+			user_email: "amy@example.com".into(),
+			member_type: types::MemberType::ReadOnly,
+		};
+		assert!(plan.changes.len() == 2);
+		assert!(plan.changes.contains(&cancel_amy));
+		assert!(plan.changes.contains(&invite_amy));
+
+		match project
+			.apply_member_sync("syncproject2", &plan, Deadline::none())
+			.await
+		{
+			Err(AivenError::MemberSyncFailed {
+				applied, failed, ..
+			}) => {
+				cancel.assert();
+				assert!(applied == vec![cancel_amy]);
+				assert!(failed == invite_amy);
+			}
+			other => assert!(false, "{:?}", other),
+		}
+
+		let desired = vec![
+			("bob@example.com", types::MemberType::Admin),
+			("eve@example.com", types::MemberType::Developer),
+		];
+		let plan = project
+			.plan_member_sync("syncproject2", desired)
+			.await
+			.unwrap();
+		assert!(plan.remaining_admins(&serde_json::from_str(&test_data).unwrap()) == 1);
+		let desired = vec![("bob@example.com", types::MemberType::Developer)];
+		let plan = project
+			.plan_member_sync("syncproject2", desired)
+			.await
+			.unwrap();
+		match project
+			.apply_member_sync("syncproject2", &plan, Deadline::none())
+			.await
+		{
+			Err(AivenError::InvalidInput { field, .. }) => assert!(field == "plan"),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+	#[tokio::test]
+	async fn test_project_member_sync_deadline() {
+		let client = testutil::prepare_test_client();
+		let users = mockito::mock("GET", "/project/syncdeadlineproject/users")
+			.expect(0)
+			.create();
+		let plan = types::MembershipPlan {
+			changes: vec![types::MembershipChange::Remove {
+				user_email: "eve@example.com".into(),
+			}],
+			..Default::default()
+		};
+
+		match client
+			.project()
+			.apply_member_sync(
+				"syncdeadlineproject",
+				&plan,
+				Deadline::after(Duration::from_secs(0)),
+			)
+			.await
+		{
+			Err(AivenError::DeadlineExceeded { completed, .. }) => assert!(completed == 0),
+			other => assert!(false, "{:?}", other),
 		}
+		users.assert();
 	}
 }
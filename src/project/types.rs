@@ -20,12 +20,15 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::{errors::AivenError, service::types_service::ServiceNotification};
 use serde::{Deserialize, Serialize};
 use std::{
-	collections::HashMap,
+	collections::{BTreeMap, BTreeSet, HashMap},
 	fmt::{Display, Formatter},
+	str::FromStr,
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MemberType {
 	Admin,
 	Developer,
@@ -43,6 +46,23 @@ impl Display for MemberType {
 	}
 }
 
+impl FromStr for MemberType {
+	type Err = AivenError;
+
+	fn from_str(member_type: &str) -> Result<Self, Self::Err> {
+		match member_type {
+			"admin" => Ok(MemberType::Admin),
+			"developer" => Ok(MemberType::Developer),
+			"operator" => Ok(MemberType::Operator),
+			"read_only" => Ok(MemberType::ReadOnly),
+			_ => Err(AivenError::InvalidInput {
+				field: "member_type".to_owned(),
+				reason: format!("unknown member type `{}`", member_type),
+			}),
+		}
+	}
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResProjectInvite {
 	pub invite_details: InviteDetails,
@@ -78,12 +98,18 @@ pub struct ResProject {
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct Project {
 	pub account_id: Option<String>,
+	#[serde(default)]
+	pub account_name: Option<String>,
 	pub available_credits: String,
 	pub billing_address: String,
 	pub billing_currency: String,
 
 	pub billing_emails: Vec<Email>,
 	pub billing_extra_text: Option<String>,
+	#[serde(default)]
+	pub billing_group_id: Option<String>,
+	#[serde(default)]
+	pub billing_group_name: Option<String>,
 
 	pub card_info: CardInfo,
 	pub country: String,
@@ -94,11 +120,19 @@ pub struct Project {
 	pub payment_method: String,
 	pub project_name: String,
 	pub tech_emails: Vec<Email>,
+	#[serde(default)]
 	pub tenant_id: String,
 	pub trial_expiration_time: Option<String>,
 	pub vat_id: String,
 }
 
+impl Project {
+	/// `estimated_balance` as a number, `None` if it is not one
+	pub fn estimated_balance_amount(&self) -> Option<f64> {
+		self.estimated_balance.parse().ok()
+	}
+}
+
 /// Project membership and type of membership
 // #[derive(Deserialize, Serialize, Debug, Default)]
 // pub struct ProjectMembership {
@@ -112,6 +146,34 @@ pub struct ProjectList {
 	pub projects: Vec<Project>,
 }
 
+impl ProjectList {
+	/// Projects grouped by billing group id, `None` for projects outside of
+	/// any billing group
+	pub fn by_billing_group(&self) -> BTreeMap<Option<&str>, Vec<&Project>> {
+		let mut groups: BTreeMap<Option<&str>, Vec<&Project>> = BTreeMap::new();
+		for project in &self.projects {
+			groups
+				.entry(project.billing_group_id.as_deref())
+				.or_default()
+				.push(project);
+		}
+		groups
+	}
+
+	/// Projects grouped by account id, `None` for projects not owned by an
+	/// account
+	pub fn by_account(&self) -> BTreeMap<Option<&str>, Vec<&Project>> {
+		let mut accounts: BTreeMap<Option<&str>, Vec<&Project>> = BTreeMap::new();
+		for project in &self.projects {
+			accounts
+				.entry(project.account_id.as_deref())
+				.or_default()
+				.push(project);
+		}
+		accounts
+	}
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct StateInfo {
 	pub message: String,
@@ -151,6 +213,15 @@ pub struct Alert {
 pub struct ActiveAlerts {
 	pub alerts: Vec<Alert>,
 }
+
+/// A notification of one of the services of a project
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ProjectServiceNotification {
+	pub service_name: String,
+	pub service_type: String,
+	#[serde(flatten)]
+	pub notification: ServiceNotification,
+}
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ProjectVPC {
 	pub cloud_name: String,
@@ -206,3 +277,128 @@ pub struct ProjectUserList {
 	pub users: Vec<User>,
 	pub invitations: Vec<Invitation>,
 }
+
+/// A single step of a `MembershipPlan`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MembershipChange {
+	/// Invite a user who is neither a member nor invited
+	Invite {
+		user_email: String,
+		member_type: MemberType,
+	},
+	/// Change the member type of an existing member
+	Update {
+		user_email: String,
+		from: String,
+		to: MemberType,
+	},
+	/// Remove a member who isn't in the desired set
+	Remove { user_email: String },
+	/// Withdraw a pending invitation which isn't in the desired set or has
+	/// another member type, in which case an `Invite` follows
+	CancelInvite { user_email: String },
+}
+
+impl MembershipChange {
+	/// The email of the member or invited user the change is about
+	pub fn user_email(&self) -> &str {
+		match self {
+			MembershipChange::Invite { user_email, .. }
+			| MembershipChange::Update { user_email, .. }
+			| MembershipChange::Remove { user_email }
+			| MembershipChange::CancelInvite { user_email } => user_email,
+		}
+	}
+}
+
+/// Changes converging the members of a project to a desired set, computed
+/// by `ProjectApi::plan_member_sync`. Printing the plan before applying it
+/// gives a dry run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MembershipPlan {
+	pub changes: Vec<MembershipChange>,
+	/// Members who belong to the project through an account team. They
+	/// can't be changed per project and are left alone.
+	pub team_members: Vec<String>,
+}
+
+impl MembershipPlan {
+	/// Plan the changes from `current` to `desired`, a map of emails to
+	/// member types. Emails are compared case-insensitively.
+	pub fn between(current: &ProjectUserList, desired: &BTreeMap<String, MemberType>) -> Self {
+		let desired: BTreeMap<String, MemberType> = desired
+			.iter()
+			.map(|(email, member_type)| (email.to_lowercase(), *member_type))
+			.collect();
+		let mut plan = MembershipPlan::default();
+		let mut seen = BTreeSet::new();
+		for user in &current.users {
+			seen.insert(user.user_email.to_lowercase());
+			if user.team_id.is_some() {
+				plan.team_members.push(user.user_email.clone());
+				continue;
+			}
+			match desired.get(&user.user_email.to_lowercase()) {
+				None => plan.changes.push(MembershipChange::Remove {
+					user_email: user.user_email.clone(),
+				}),
+				Some(to) if to.to_string() != user.member_type => {
+					plan.changes.push(MembershipChange::Update {
+						user_email: user.user_email.clone(),
+						from: user.member_type.clone(),
+						to: *to,
+					})
+				}
+				Some(_) => {}
+			}
+		}
+		for invitation in &current.invitations {
+			let email = invitation.invited_user_email.to_lowercase();
+			if seen.contains(&email) {
+				continue;
+			}
+			match desired.get(&email) {
+				Some(to) if to.to_string() == invitation.member_type => {
+					seen.insert(email);
+				}
+				_ => plan.changes.push(MembershipChange::CancelInvite {
+					user_email: invitation.invited_user_email.clone(),
+				}),
+			}
+		}
+		for (email, member_type) in &desired {
+			if !seen.contains(email) {
+				plan.changes.push(MembershipChange::Invite {
+					user_email: email.clone(),
+					member_type: *member_type,
+				});
+			}
+		}
+		plan
+	}
+
+	/// Nothing to change
+	pub fn is_empty(&self) -> bool {
+		self.changes.is_empty()
+	}
+
+	/// Number of admins of `current` left once the plan is applied,
+	/// including admins through a team. Pending invitations don't count.
+	pub fn remaining_admins(&self, current: &ProjectUserList) -> usize {
+		current
+			.users
+			.iter()
+			.filter(|user| {
+				let change = self
+					.changes
+					.iter()
+					.find(|change| change.user_email().eq_ignore_ascii_case(&user.user_email));
+				match change {
+					Some(MembershipChange::Remove { .. }) => false,
+					Some(MembershipChange::Update { to, .. }) => *to == MemberType::Admin,
+					_ => user.member_type == MemberType::Admin.to_string(),
+				}
+			})
+			.count()
+	}
+}
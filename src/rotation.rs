@@ -0,0 +1,314 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Rotate the credentials of service users one at a time.
+//!
+//! [`CredentialRotation`] codifies the usual runbook: reset the password of
+//! one user, hand the new credentials to a [`SecretStore`] so applications
+//! pick them up, optionally verify a login with them, pause, and only then
+//! move on to the next user. The first failure stops the rotation so the
+//! remaining users keep working credentials. If the password was already
+//! reset when it failed, the error carries the new credentials so they
+//! aren't lost. A [`Deadline`] bounds the whole run; a user whose rotation
+//! started is always finished so its new credentials reach the store.
+//!
+//! ```rust,no_run
+//! use aiven_rs::{
+//!     deadline::Deadline,
+//!     errors::AivenError,
+//!     rotation::{CredentialRotation, SecretStore},
+//!     service::types_service::ServiceUserCredentials,
+//! };
+//! use std::time::Duration;
+//!
+//! struct Vault;
+//!
+//! impl SecretStore for Vault {
+//!     fn store(&self, service_name: &str, user: &ServiceUserCredentials) -> Result<(), AivenError> {
+//!         println!("writing {}/{} to vault", service_name, user.username);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+//!     let rotated = CredentialRotation::new(&client, "my-project", "my-pg", Vault)
+//!         .users(&["app-reader", "app-writer"])
+//!         .pause(Duration::from_secs(30))
+//!         .deadline(Deadline::after(Duration::from_secs(600)))
+//!         .run()
+//!         .await?;
+//!     println!("rotated {:?}", rotated);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+	deadline::Deadline, errors::AivenError, service::types_service::ServiceUserCredentials,
+	AivenClient,
+};
+use std::time::Duration;
+use tracing::{debug, info};
+
+/// Receives the new credentials of every rotated user.
+///
+/// The methods are called from async code and must not block for long; hand
+/// slow work to a thread, e.g. with `tokio::task::spawn_blocking`, and wait
+/// for it there.
+pub trait SecretStore: Send + Sync {
+	/// Persist the new credentials of `user`, e.g. in a vault or a
+	/// kubernetes secret
+	fn store(&self, service_name: &str, user: &ServiceUserCredentials) -> Result<(), AivenError>;
+
+	/// Check that the new credentials work, e.g. by opening a connection to
+	/// the service. Called after `store`, the default does nothing.
+	fn verify(
+		&self,
+		_service_name: &str,
+		_user: &ServiceUserCredentials,
+	) -> Result<(), AivenError> {
+		Ok(())
+	}
+}
+
+/// Resets the credentials of a list of service users one after another
+pub struct CredentialRotation<'a, S: SecretStore> {
+	client: &'a AivenClient,
+	project: String,
+	service_name: String,
+	store: S,
+	users: Vec<String>,
+	pause: Duration,
+	deadline: Deadline,
+}
+
+impl<'a, S: SecretStore> CredentialRotation<'a, S> {
+	pub fn new(client: &'a AivenClient, project: &str, service_name: &str, store: S) -> Self {
+		CredentialRotation {
+			client,
+			project: project.to_string(),
+			service_name: service_name.to_string(),
+			store,
+			users: Vec::new(),
+			pause: Duration::from_secs(10),
+			deadline: Deadline::none(),
+		}
+	}
+
+	/// The service users to rotate, in this order
+	pub fn users<U: AsRef<str>>(mut self, users: &[U]) -> Self {
+		self.users = users.iter().map(|u| u.as_ref().to_string()).collect();
+		self
+	}
+
+	/// Wait between two users, so clients can reconnect with the new
+	/// credentials before the next user changes. Defaults to 10s.
+	pub fn pause(mut self, pause: Duration) -> Self {
+		self.pause = pause;
+		self
+	}
+
+	/// Don't start rotating another user once `deadline` expires, defaults
+	/// to `Deadline::none()`
+	pub fn deadline(mut self, deadline: Deadline) -> Self {
+		self.deadline = deadline;
+		self
+	}
+
+	/// Rotate all users and return their names.
+	///
+	/// Stops at the first user which can't be reset, stored or verified and
+	/// returns `AivenError::RotationFailed` with the users rotated so far and
+	/// the new credentials of the failed user, if its password was reset.
+	/// Once the deadline expires it stops with `AivenError::DeadlineExceeded`
+	/// counting the rotated users.
+	pub async fn run(&self) -> Result<Vec<String>, AivenError> {
+		let mut rotated = Vec::with_capacity(self.users.len());
+		for (i, username) in self.users.iter().enumerate() {
+			if i > 0 && !self.pause.is_zero() {
+				debug!("pausing {:?} before rotating {}", self.pause, username);
+				self.deadline.sleep(self.pause, rotated.len()).await?;
+			}
+			self.deadline.check(rotated.len())?;
+			if let Err((credentials, e)) = self.rotate(username).await {
+				return Err(AivenError::RotationFailed {
+					username: username.clone(),
+					rotated,
+					credentials: credentials.map(Box::new),
+					source: Box::new(e),
+				});
+			}
+			info!("rotated credentials of {}/{}", self.service_name, username);
+			rotated.push(username.clone());
+		}
+		Ok(rotated)
+	}
+
+	async fn rotate(
+		&self,
+		username: &str,
+	) -> Result<(), (Option<ServiceUserCredentials>, AivenError)> {
+		let user = self
+			.client
+			.service()
+			.reset_service_user_password(&self.project, &self.service_name, username)
+			.await
+			.map_err(|e| (None, e))?;
+		match self
+			.store
+			.store(&self.service_name, &user)
+			.and_then(|_| self.store.verify(&self.service_name, &user))
+		{
+			Ok(()) => Ok(()),
+			Err(e) => Err((Some(user), e)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+	use std::sync::Mutex;
+
+	#[derive(Default)]
+	struct Recorder {
+		stored: Mutex<Vec<(String, String)>>,
+		reject_login: bool,
+	}
+
+	impl SecretStore for &Recorder {
+		fn store(&self, _: &str, user: &ServiceUserCredentials) -> Result<(), AivenError> {
+			self.stored
+				.lock()
+				.unwrap()
+				.push((user.username.clone(), user.password.expose().to_string()));
+			Ok(())
+		}
+
+		fn verify(&self, _: &str, user: &ServiceUserCredentials) -> Result<(), AivenError> {
+			if self.reject_login {
+				Err(AivenError::InvalidInput {
+					field: user.username.clone(),
+					reason: "login rejected".to_string(),
+				})
+			} else {
+				Ok(())
+			}
+		}
+	}
+
+	#[tokio::test]
+	async fn test_rotation_stores_credentials() {
+		let client = testutil::prepare_test_client();
+		let query_url =
+			"/project/rotationproject/service/myservice/user/testuser/credentials/reset";
+		let test_data = testutil::get_test_data(
+			"tests/testdata/service/service/reset_service_user_credential.json",
+		);
+		let _m = testutil::create_mock_server(query_url, &test_data, "PUT");
+
+		let recorder = Recorder::default();
+		match CredentialRotation::new(client, "rotationproject", "myservice", &recorder)
+			.users(&["testuser"])
+			.run()
+			.await
+		{
+			Ok(rotated) => {
+				assert!(rotated == vec!["testuser".to_string()]);
+				assert!(recorder.stored.lock().unwrap()[0].0 == "testuser");
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_rotation_stops_at_failed_verify() {
+		let client = testutil::prepare_test_client();
+		let query_url =
+			"/project/rotationfailproject/service/myservice/user/testuser/credentials/reset";
+		let test_data = testutil::get_test_data(
+			"tests/testdata/service/service/reset_service_user_credential.json",
+		);
+		let _m = testutil::create_mock_server(query_url, &test_data, "PUT");
+
+		let recorder = Recorder {
+			reject_login: true,
+			..Default::default()
+		};
+		match CredentialRotation::new(client, "rotationfailproject", "myservice", &recorder)
+			.users(&["testuser", "never-reached"])
+			.pause(Duration::from_millis(0))
+			.run()
+			.await
+		{
+			Err(AivenError::RotationFailed {
+				username,
+				rotated,
+				credentials,
+				..
+			}) => {
+				assert!(username == "testuser");
+				assert!(rotated.is_empty());
+				assert!(credentials.unwrap().username == "testuser");
+				assert!(recorder.stored.lock().unwrap().len() == 1);
+			}
+			other => assert!(false, "{:?}", other),
+		}
+	}
+	#[tokio::test]
+	async fn test_rotation_stops_at_deadline() {
+		let client = testutil::prepare_test_client();
+		let base = "/project/rotationdeadlineproject/service/myservice/user";
+		let test_data = testutil::get_test_data(
+			"tests/testdata/service/service/reset_service_user_credential.json",
+		);
+		let _first = testutil::create_mock_server(
+			&format!("{}/testuser/credentials/reset", base),
+			&test_data,
+			"PUT",
+		);
+		let second = mockito::mock(
+			"PUT",
+			format!("{}/never-reached/credentials/reset", base).as_str(),
+		)
+		.expect(0)
+		.create();
+
+		let recorder = Recorder::default();
+		match CredentialRotation::new(client, "rotationdeadlineproject", "myservice", &recorder)
+			.users(&["testuser", "never-reached"])
+			.pause(Duration::from_secs(5))
+			.deadline(Deadline::after(Duration::from_millis(200)))
+			.run()
+			.await
+		{
+			Err(AivenError::DeadlineExceeded { completed, .. }) => {
+				assert!(completed == 1);
+				assert!(recorder.stored.lock().unwrap().len() == 1);
+			}
+			other => assert!(false, "{:?}", other),
+		}
+		second.assert();
+	}
+}
@@ -0,0 +1,434 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Ready made end-to-end scenarios built on the public API.
+//!
+//! Enabled with the `examples` feature. Every scenario is an ordinary async
+//! function which can be called directly, the code doubles as an example of
+//! combining the APIs of this crate. The scenarios can be re-run, steps
+//! which were already done are skipped.
+//!
+//! ```rust,no_run
+//! use aiven_rs::scenarios::{self, KafkaEnvironment};
+//! use aiven_rs::service::types_kafka::KafkaTopicCreateRequest;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+//!     let environment = KafkaEnvironment::new("events", "aws-eu-central-1", "business-4")
+//!         .topic(KafkaTopicCreateRequest::new("orders").partitions(6))
+//!         .user("orders-app", "orders", "readwrite");
+//!     let report =
+//!         scenarios::bootstrap_kafka_environment(&client, "my-project", &environment).await?;
+//!     println!("created topics {:?}", report.created_topics);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+	errors::AivenError,
+	poll::PollOpts,
+	service::{types_kafka::KafkaTopicCreateRequest, types_service::Service},
+	AivenClient,
+};
+use serde_json::json;
+
+/// A Kafka service together with its topics, users and ACLs
+#[derive(Debug)]
+pub struct KafkaEnvironment {
+	service_name: String,
+	cloud: String,
+	plan: String,
+	topics: Vec<KafkaTopicCreateRequest>,
+	/// `(username, topic pattern, permission)`
+	grants: Vec<(String, String, String)>,
+	poll_opts: PollOpts,
+}
+
+impl KafkaEnvironment {
+	pub fn new(service_name: &str, cloud: &str, plan: &str) -> Self {
+		KafkaEnvironment {
+			service_name: service_name.to_string(),
+			cloud: cloud.to_string(),
+			plan: plan.to_string(),
+			topics: Vec::new(),
+			grants: Vec::new(),
+			poll_opts: PollOpts::default().interval(std::time::Duration::from_secs(10)),
+		}
+	}
+
+	/// Create `topic` unless a topic with the same name exists
+	pub fn topic(mut self, topic: KafkaTopicCreateRequest) -> Self {
+		self.topics.push(topic);
+		self
+	}
+
+	/// Create the service user `username` and grant it `permission` (`read`,
+	/// `write`, `readwrite` or `admin`) on the topics matching `topic`
+	pub fn user(mut self, username: &str, topic: &str, permission: &str) -> Self {
+		self.grants
+			.push((username.into(), topic.into(), permission.into()));
+		self
+	}
+
+	/// How to wait for the service to be running, polls every 10s by default
+	pub fn poll_opts(mut self, opts: PollOpts) -> Self {
+		self.poll_opts = opts;
+		self
+	}
+}
+
+/// What `bootstrap_kafka_environment` changed
+#[derive(Debug)]
+pub struct KafkaEnvironmentReport {
+	/// The running service, as it was before topics and users were added
+	pub service: Service,
+	pub service_created: bool,
+	pub created_topics: Vec<String>,
+	pub created_users: Vec<String>,
+	/// `(username, topic pattern, permission)` of the added ACL entries
+	pub created_acls: Vec<(String, String, String)>,
+}
+
+/// Create a Kafka service with topics, users and ACLs, skipping everything
+/// which exists already.
+///
+/// An existing service is only reused if its type, plan and cloud match
+/// `environment`, otherwise this fails with `AivenError::InvalidInput`.
+/// Waits for the service to be running before the topics and users are
+/// created.
+pub async fn bootstrap_kafka_environment(
+	client: &AivenClient,
+	project: &str,
+	environment: &KafkaEnvironment,
+) -> Result<KafkaEnvironmentReport, AivenError> {
+	let service_api = client.service();
+	let kafka_api = client.service_kafka();
+	let service_name = environment.service_name.as_str();
+
+	let body = json!({
+		"cloud": environment.cloud,
+		"plan": environment.plan,
+		"service_name": service_name,
+		"service_type": "kafka",
+		"user_config": {"kafka_rest": true},
+	});
+	let service_created = match service_api.create_service(project, &body).await {
+		Ok(_) => true,
+		Err(AivenError::AlreadyExists { .. }) => {
+			let existing = service_api
+				.get_service_info(project, service_name)
+				.await?
+				.service;
+			check_existing_kafka(&existing, environment)?;
+			false
+		}
+		Err(e) => return Err(e),
+	};
+	let service = service_api
+		.wait_for_service_running(project, service_name, &environment.poll_opts)
+		.await?
+		.service;
+
+	let mut created_topics = Vec::new();
+	for topic in &environment.topics {
+		let exists = service
+			.topics
+			.iter()
+			.flatten()
+			.any(|existing| existing.topic_name == topic.topic_name);
+		if !exists {
+			kafka_api
+				.create_kafka_topic_with(project, service_name, topic)
+				.await?;
+			created_topics.push(topic.topic_name.clone());
+		}
+	}
+
+	let mut created_users: Vec<String> = Vec::new();
+	let mut created_acls = Vec::new();
+	for (username, topic, permission) in &environment.grants {
+		let user_exists = service.users.iter().any(|user| &user.username == username)
+			|| created_users.contains(username);
+		if !user_exists {
+			service_api
+				.create_user(project, service_name, &json!({ "username": username }))
+				.await?;
+			created_users.push(username.clone());
+		}
+		let acl_exists = service.acl.iter().flatten().any(|acl| {
+			&acl.username == username && &acl.topic == topic && &acl.permission == permission
+		});
+		if !acl_exists {
+			let body = json!({
+				"permission": permission,
+				"topic": topic,
+				"username": username,
+			});
+			kafka_api
+				.add_kafka_acl_entry(project, service_name, &body)
+				.await?;
+			created_acls.push((username.clone(), topic.clone(), permission.clone()));
+		}
+	}
+
+	Ok(KafkaEnvironmentReport {
+		service,
+		service_created,
+		created_topics,
+		created_users,
+		created_acls,
+	})
+}
+
+/// Fail unless `existing` is the Kafka service `environment` asks for
+fn check_existing_kafka(
+	existing: &Service,
+	environment: &KafkaEnvironment,
+) -> Result<(), AivenError> {
+	let mismatches: Vec<String> = [
+		("service_type", existing.service_type.as_str(), "kafka"),
+		("plan", existing.plan.as_str(), environment.plan.as_str()),
+		(
+			"cloud",
+			existing.cloud_name.as_str(),
+			environment.cloud.as_str(),
+		),
+	]
+	.iter()
+	.filter(|(_, found, wanted)| found != wanted)
+	.map(|(field, found, wanted)| format!("{} is `{}`, not `{}`", field, found, wanted))
+	.collect();
+	if mismatches.is_empty() {
+		return Ok(());
+	}
+	Err(AivenError::InvalidInput {
+		field: "service_name".into(),
+		reason: format!(
+			"service `{}` exists already but its {}",
+			environment.service_name,
+			mismatches.join(", ")
+		),
+	})
+}
+
+/// Fork the PostgreSQL service `source` into a new service `staging_name`
+/// in the same cloud and wait until it is running.
+///
+/// The fork uses the latest backup of `source` and keeps its major version.
+/// `plan` defaults to the plan of `source`. Fails with
+/// `AivenError::AlreadyExists` if `staging_name` exists, so an old staging
+/// copy is never mistaken for a fresh one.
+pub async fn clone_pg_to_staging(
+	client: &AivenClient,
+	project: &str,
+	source: &str,
+	staging_name: &str,
+	plan: Option<&str>,
+	opts: &PollOpts,
+) -> Result<Service, AivenError> {
+	let service_api = client.service();
+	let source_service = service_api.get_service_info(project, source).await?.service;
+	if source_service.service_type != "pg" {
+		return Err(AivenError::InvalidInput {
+			field: "source".into(),
+			reason: format!(
+				"`{}` is a {} service, not pg",
+				source, source_service.service_type
+			),
+		});
+	}
+
+	let mut user_config = json!({ "service_to_fork_from": source });
+	if let Some(version) = source_service.user_config.get("pg_version") {
+		user_config["pg_version"] = version.clone();
+	}
+	let body = json!({
+		"cloud": source_service.cloud_name,
+		"plan": plan.unwrap_or(&source_service.plan),
+		"service_name": staging_name,
+		"service_type": "pg",
+		"user_config": user_config,
+	});
+	service_api.create_service(project, &body).await?;
+	Ok(service_api
+		.wait_for_service_running(project, staging_name, opts)
+		.await?
+		.service)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+	use std::time::Duration;
+
+	fn fast_poll() -> PollOpts {
+		PollOpts::default()
+			.interval(Duration::from_millis(1))
+			.max_attempts(3)
+	}
+
+	#[tokio::test]
+	async fn test_bootstrap_kafka_environment_skips_existing() {
+		let client = testutil::prepare_test_client();
+		let base = "/project/scenario-kafka/service";
+		let _service = mockito::mock("POST", base)
+			.with_status(409)
+			.with_header("content-type", "application/json")
+			.with_body(r#"{"message": "Service name is already in use in this project"}"#)
+			.create();
+		let info = testutil::get_test_data("tests/testdata/scenarios/kafka_service_running.json");
+		let _info = testutil::create_mock_server(&format!("{}/events", base), &info, "GET");
+		let topic = mockito::mock("POST", format!("{}/events/topic", base).as_str())
+			.match_body(mockito::Matcher::PartialJson(
+				json!({"topic_name": "payments", "partitions": 3}),
+			))
+			.with_status(200)
+			.with_body("{}")
+			.expect(1)
+			.create();
+		let user_data = testutil::get_test_data("tests/testdata/service/service/create_user.json");
+		let user = mockito::mock("POST", format!("{}/events/user", base).as_str())
+			.match_body(mockito::Matcher::Json(json!({"username": "payments-app"})))
+			.with_status(200)
+			.with_body(&user_data)
+			.expect(1)
+			.create();
+		let acl_data =
+			testutil::get_test_data("tests/testdata/service/kafka/add_kafka_acl_entry.json");
+		let acl = mockito::mock("POST", format!("{}/events/acl", base).as_str())
+			.match_body(mockito::Matcher::Json(json!({
+				"permission": "write",
+				"topic": "payments",
+				"username": "payments-app"
+			})))
+			.with_status(200)
+			.with_body(&acl_data)
+			.expect(1)
+			.create();
+
+		let environment = KafkaEnvironment::new("events", "aws-eu-central-1", "business-4")
+			.topic(KafkaTopicCreateRequest::new("orders"))
+			.topic(KafkaTopicCreateRequest::new("payments").partitions(3))
+			.user("avnadmin", "*", "admin")
+			.user("payments-app", "payments", "write")
+			.poll_opts(fast_poll());
+		match bootstrap_kafka_environment(client, "scenario-kafka", &environment).await {
+			Ok(report) => {
+				assert!(!report.service_created);
+				assert!(report.created_topics == vec!["payments"]);
+				assert!(report.created_users == vec!["payments-app"]);
+				assert!(report.created_acls.len() == 1);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		topic.assert();
+		user.assert();
+		acl.assert();
+	}
+
+	#[tokio::test]
+	async fn test_bootstrap_kafka_environment_rejects_different_existing() {
+		let client = testutil::prepare_test_client();
+		let base = "/project/scenario-kafka-other/service";
+		let _service = mockito::mock("POST", base)
+			.with_status(409)
+			.with_header("content-type", "application/json")
+			.with_body(r#"{"message": "Service name is already in use in this project"}"#)
+			.create();
+		let info = testutil::get_test_data("tests/testdata/scenarios/kafka_service_running.json");
+		let _info = testutil::create_mock_server(&format!("{}/events", base), &info, "GET");
+
+		let environment = KafkaEnvironment::new("events", "google-europe-west1", "startup-2")
+			.poll_opts(fast_poll());
+		match bootstrap_kafka_environment(client, "scenario-kafka-other", &environment).await {
+			Err(AivenError::InvalidInput { field, reason }) => {
+				assert!(field == "service_name");
+				assert!(reason.contains("plan is `business-4`, not `startup-2`"));
+				assert!(reason.contains("cloud is `aws-eu-central-1`"));
+				assert!(!reason.contains("service_type"));
+			}
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_clone_pg_to_staging() {
+		let client = testutil::prepare_test_client();
+		let base = "/project/scenario-pg/service";
+		let source = testutil::get_test_data("tests/testdata/scenarios/pg_source.json");
+		let _source = testutil::create_mock_server(&format!("{}/orders-db", base), &source, "GET");
+		let staging = testutil::get_test_data("tests/testdata/scenarios/pg_staging_running.json");
+		let create = mockito::mock("POST", base)
+			.match_body(mockito::Matcher::Json(json!({
+				"cloud": "aws-eu-central-1",
+				"plan": "startup-4",
+				"service_name": "orders-db-staging",
+				"service_type": "pg",
+				"user_config": {"service_to_fork_from": "orders-db", "pg_version": "15"}
+			})))
+			.with_status(200)
+			.with_body(&staging)
+			.expect(1)
+			.create();
+		let _staging =
+			testutil::create_mock_server(&format!("{}/orders-db-staging", base), &staging, "GET");
+
+		match clone_pg_to_staging(
+			client,
+			"scenario-pg",
+			"orders-db",
+			"orders-db-staging",
+			Some("startup-4"),
+			&fast_poll(),
+		)
+		.await
+		{
+			Ok(service) => assert!(service.service_name == "orders-db-staging"),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		create.assert();
+	}
+
+	#[tokio::test]
+	async fn test_clone_pg_to_staging_rejects_other_types() {
+		let client = testutil::prepare_test_client();
+		let info = testutil::get_test_data("tests/testdata/scenarios/kafka_service_running.json");
+		let _info =
+			testutil::create_mock_server("/project/scenario-pg-kafka/service/events", &info, "GET");
+
+		match clone_pg_to_staging(
+			client,
+			"scenario-pg-kafka",
+			"events",
+			"events-staging",
+			None,
+			&fast_poll(),
+		)
+		.await
+		{
+			Err(AivenError::InvalidInput { field, .. }) => assert!(field == "source"),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+}
@@ -22,6 +22,7 @@
 
 use crate::{
 	client::{encode_param, HTTPClient},
+	deprecation,
 	errors::AivenError,
 	make_json_request, make_request,
 	service::types_elasticsearch::*,
@@ -61,6 +62,7 @@ impl ServiceElastiSearchApi {
 		service_name: &str,
 		index_name: &str,
 	) -> Result<(), AivenError> {
+		deprecation::warn_deprecated("ServiceElastiSearchApi", "delete_index");
 		let url = format!(
 			"project/{project}/service/{service_name}/index/{index_name}",
 			project = encode_param(project),
@@ -77,13 +79,14 @@ impl ServiceElastiSearchApi {
 		project: &str,
 		service_name: &str,
 	) -> Result<Indexes, AivenError> {
+		deprecation::warn_deprecated("ServiceElastiSearchApi", "list_indexes");
 		let url = format!(
 			"project/{project}/service/{service_name}/index",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// https://api.aiven.io/doc/#operation/ServiceEsAclSet
@@ -93,13 +96,14 @@ impl ServiceElastiSearchApi {
 		service_name: &str,
 		acl_config: &ElasticSearchACLConfig,
 	) -> Result<ElasticSearchACLConfig, AivenError> {
+		deprecation::warn_deprecated("ServiceElastiSearchApi", "set_acl_configuration");
 		let url = format!(
 			"project/{project}/service/{service_name}/elasticsearch/acl",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, acl_config)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// https://api.aiven.io/doc/#operation/ServiceEsAclGet
@@ -108,13 +112,14 @@ impl ServiceElastiSearchApi {
 		project: &str,
 		service_name: &str,
 	) -> Result<ElasticSearchACLConfig, AivenError> {
+		deprecation::warn_deprecated("ServiceElastiSearchApi", "show_acl_configuration");
 		let url = format!(
 			"project/{project}/service/{service_name}/elasticsearch/acl",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// https://api.aiven.io/doc/#operation/ServiceEsAclUpdate
@@ -124,13 +129,14 @@ impl ServiceElastiSearchApi {
 		service_name: &str,
 		acl_config: &ElasticSearchACLConfig,
 	) -> Result<ElasticSearchACLConfig, AivenError> {
+		deprecation::warn_deprecated("ServiceElastiSearchApi", "update_acl_configuration");
 		let url = format!(
 			"project/{project}/service/{service_name}/elasticsearch/acl",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
 		);
 		let response = make_json_request!(self, reqwest::Method::PUT, &url, acl_config)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 }
 
@@ -152,9 +158,9 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.indexes[0].docs == 5019, format!("{:?}", response));
+				assert!(response.indexes[0].docs == 5019, "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -180,10 +186,11 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.elasticsearch_acl_config.enabled == Some(false),
-					format!("{:?}", response)
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -203,10 +210,11 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.elasticsearch_acl_config.enabled == Some(false),
-					format!("{:?}", response)
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -232,10 +240,11 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.elasticsearch_acl_config.enabled == Some(false),
-					format!("{:?}", response)
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 }
@@ -0,0 +1,499 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+	client::{encode_param, HTTPClient},
+	errors::AivenError,
+	make_json_request, make_request,
+	service::types_flink::*,
+};
+use serde::Serialize;
+
+pub struct ServiceFlinkApi {
+	http_client: HTTPClient,
+}
+
+impl ServiceFlinkApi {
+	pub(crate) fn new(client: HTTPClient) -> Self {
+		Self {
+			http_client: client,
+		}
+	}
+
+	/// Create a Flink application
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceFlinkCreateApplication
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `json_body` - Name and optionally the first version of the application
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let body = json!({"name": "orders-enrichment"});
+	/// let response = client
+	///         .service_flink()
+	///         .create_application("my-project", "my-flink", &body)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_application<T: Serialize + ?Sized>(
+		&self,
+		project: &str,
+		service_name: &str,
+		json_body: &T,
+	) -> Result<FlinkApplication, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/flink/application",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+		);
+		make_json_request!(self, reqwest::Method::POST, &url, json_body)?
+			.json()
+			.await
+	}
+
+	/// List the Flink applications of a service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceFlinkListApplications
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .service_flink()
+	///         .list_applications("my-project", "my-flink")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_applications(
+		&self,
+		project: &str,
+		service_name: &str,
+	) -> Result<ResFlinkApplications, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/flink/application",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+		);
+		make_request!(self, reqwest::Method::GET, &url)?
+			.json()
+			.await
+	}
+
+	/// Get a Flink application with its versions and current deployment
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceFlinkGetApplication
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `application_id` - Application id
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .service_flink()
+	///         .get_application("my-project", "my-flink", "application-id")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn get_application(
+		&self,
+		project: &str,
+		service_name: &str,
+		application_id: &str,
+	) -> Result<FlinkApplication, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/flink/application/{application_id}",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+			application_id = encode_param(application_id),
+		);
+		make_request!(self, reqwest::Method::GET, &url)?
+			.json()
+			.await
+	}
+
+	/// Delete a Flink application
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceFlinkDeleteApplication
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `application_id` - Application id
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .service_flink()
+	///         .delete_application("my-project", "my-flink", "application-id")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn delete_application(
+		&self,
+		project: &str,
+		service_name: &str,
+		application_id: &str,
+	) -> Result<FlinkApplication, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/flink/application/{application_id}",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+			application_id = encode_param(application_id),
+		);
+		make_request!(self, reqwest::Method::DELETE, &url)?
+			.json()
+			.await
+	}
+
+	/// Deploy a version of a Flink application
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceFlinkCreateApplicationDeployment
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `application_id` - Application id
+	/// * `request` - Version to deploy and its settings
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_flink::FlinkDeploymentRequest;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let request = FlinkDeploymentRequest::new("version-id").parallelism(2);
+	/// let response = client
+	///         .service_flink()
+	///         .create_deployment("my-project", "my-flink", "application-id", &request)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_deployment(
+		&self,
+		project: &str,
+		service_name: &str,
+		application_id: &str,
+		request: &FlinkDeploymentRequest,
+	) -> Result<FlinkDeployment, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/flink/application/{application_id}/deployment",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+			application_id = encode_param(application_id),
+		);
+		let json_body = request;
+		make_json_request!(self, reqwest::Method::POST, &url, json_body)?
+			.json()
+			.await
+	}
+
+	/// Stop a deployment of a Flink application
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceFlinkStopApplicationDeployment
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `application_id` - Application id
+	/// * `deployment_id` - Deployment id
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .service_flink()
+	///         .stop_deployment("my-project", "my-flink", "application-id", "deployment-id")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn stop_deployment(
+		&self,
+		project: &str,
+		service_name: &str,
+		application_id: &str,
+		deployment_id: &str,
+	) -> Result<FlinkDeployment, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/flink/application/{application_id}/deployment/{deployment_id}/stop",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+			application_id = encode_param(application_id),
+			deployment_id = encode_param(deployment_id),
+		);
+		make_request!(self, reqwest::Method::POST, &url)?
+			.json()
+			.await
+	}
+
+	/// List the jobs running on a Flink service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceFlinkJobsList
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .service_flink()
+	///         .list_jobs("my-project", "my-flink")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_jobs(
+		&self,
+		project: &str,
+		service_name: &str,
+	) -> Result<ResFlinkJobs, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/flink/job",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+		);
+		make_request!(self, reqwest::Method::GET, &url)?
+			.json()
+			.await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+	use serde_json::json;
+
+	const BASE: &str = "/project/flinkproject/service/myflink/flink";
+	const APP: &str = "a1b2c3d4-0000-4000-8000-000000000001";
+
+	#[tokio::test]
+	async fn test_flink_create_application() {
+		let client = testutil::prepare_test_client();
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/flink/get_application.json");
+		let _m = mockito::mock("POST", format!("{}/application", BASE).as_str())
+			.match_body(mockito::Matcher::Json(json!({"name": "orders-enrichment"})))
+			.with_status(200)
+			.with_header("content-type", "application/json")
+			.with_body(&test_data)
+			.create();
+
+		match client
+			.service_flink()
+			.create_application(
+				"flinkproject",
+				"myflink",
+				&json!({"name": "orders-enrichment"}),
+			)
+			.await
+		{
+			Ok(app) => assert!(app.name == "orders-enrichment"),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_flink_list_applications() {
+		let client = testutil::prepare_test_client();
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/flink/list_applications.json");
+		let _m = testutil::create_mock_server(&format!("{}/application", BASE), &test_data, "GET");
+
+		match client
+			.service_flink()
+			.list_applications("flinkproject", "myflink")
+			.await
+		{
+			Ok(response) => assert!(response.applications[0].id == APP),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_flink_get_application() {
+		let client = testutil::prepare_test_client();
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/flink/get_application.json");
+		let _m = testutil::create_mock_server(
+			&format!("{}/application/{}", BASE, APP),
+			&test_data,
+			"GET",
+		);
+
+		match client
+			.service_flink()
+			.get_application("flinkproject", "myflink", APP)
+			.await
+		{
+			Ok(app) => {
+				assert!(app.application_versions[0].version == 1);
+				let deployment = app.current_deployment.unwrap_or_default();
+				assert!(deployment.status == FlinkDeploymentStatus::Running);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_flink_delete_application() {
+		let client = testutil::prepare_test_client();
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/flink/get_application.json");
+		let _m = testutil::create_mock_server(
+			&format!("{}/application/{}", BASE, APP),
+			&test_data,
+			"DELETE",
+		);
+
+		match client
+			.service_flink()
+			.delete_application("flinkproject", "myflink", APP)
+			.await
+		{
+			Ok(app) => assert!(app.id == APP),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_flink_create_deployment() {
+		let client = testutil::prepare_test_client();
+		let test_data = testutil::get_test_data("tests/testdata/service/flink/deployment.json");
+		let _m = mockito::mock(
+			"POST",
+			format!("{}/application/{}/deployment", BASE, APP).as_str(),
+		)
+		.match_body(mockito::Matcher::Json(json!({
+			"version_id": "a1b2c3d4-0000-4000-8000-000000000002",
+			"parallelism": 2
+		})))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(&test_data)
+		.create();
+
+		let request =
+			FlinkDeploymentRequest::new("a1b2c3d4-0000-4000-8000-000000000002").parallelism(2);
+		match client
+			.service_flink()
+			.create_deployment("flinkproject", "myflink", APP, &request)
+			.await
+		{
+			Ok(deployment) => assert!(deployment.parallelism == Some(2)),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_flink_stop_deployment() {
+		let client = testutil::prepare_test_client();
+		let test_data = testutil::get_test_data("tests/testdata/service/flink/deployment.json");
+		let deployment_id = "a1b2c3d4-0000-4000-8000-000000000003";
+		let _m = testutil::create_mock_server(
+			&format!(
+				"{}/application/{}/deployment/{}/stop",
+				BASE, APP, deployment_id
+			),
+			&test_data,
+			"POST",
+		);
+
+		match client
+			.service_flink()
+			.stop_deployment("flinkproject", "myflink", APP, deployment_id)
+			.await
+		{
+			Ok(deployment) => assert!(deployment.status == FlinkDeploymentStatus::Cancelling),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_flink_list_jobs() {
+		let client = testutil::prepare_test_client();
+		let test_data = testutil::get_test_data("tests/testdata/service/flink/list_jobs.json");
+		let _m = testutil::create_mock_server(&format!("{}/job", BASE), &test_data, "GET");
+
+		match client
+			.service_flink()
+			.list_jobs("flinkproject", "myflink")
+			.await
+		{
+			Ok(response) => assert!(response.jobs[0].status == "RUNNING"),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+}
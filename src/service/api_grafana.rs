@@ -0,0 +1,189 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+	client::{encode_param, HTTPClient},
+	errors::AivenError,
+	make_json_request, make_request,
+	service::{
+		types_grafana::*,
+		types_service::{ResService, ResServiceUserConfig},
+	},
+};
+use serde_json::json;
+
+pub struct ServiceGrafanaApi {
+	http_client: HTTPClient,
+}
+
+impl ServiceGrafanaApi {
+	pub(crate) fn new(client: HTTPClient) -> Self {
+		Self {
+			http_client: client,
+		}
+	}
+
+	/// Get the typed `user_config` of a Grafana service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceGet
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` -  Service name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let config = client
+	///         .service_grafana()
+	///         .get_user_config("my-project", "my-grafana")
+	///         .await?;
+	/// println!("{:?}", config.dashboards_min_refresh_interval);
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn get_user_config(
+		&self,
+		project: &str,
+		service_name: &str,
+	) -> Result<GrafanaUserConfig, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}",
+			project = encode_param(project),
+			service_name = encode_param(service_name)
+		);
+		let response: ResServiceUserConfig<GrafanaUserConfig> =
+			make_request!(self, reqwest::Method::GET, &url)?
+				.json()
+				.await?;
+		Ok(response.service.user_config)
+	}
+
+	/// Update the `user_config` of a Grafana service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceUpdate
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` -  Service name
+	/// * `user_config` - Settings to change, unset fields are kept
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_grafana::{GrafanaRole, GrafanaUserConfig};
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let config = GrafanaUserConfig {
+	///     dashboards_min_refresh_interval: Some("10s".into()),
+	///     user_auto_assign_org_role: Some(GrafanaRole::Viewer),
+	///     ..Default::default()
+	/// };
+	/// let response = client
+	///         .service_grafana()
+	///         .update_user_config("my-project", "my-grafana", &config)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn update_user_config(
+		&self,
+		project: &str,
+		service_name: &str,
+		user_config: &GrafanaUserConfig,
+	) -> Result<ResService, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}",
+			project = encode_param(project),
+			service_name = encode_param(service_name)
+		);
+		let body = json!({ "user_config": user_config });
+		let json_body = &body;
+		make_json_request!(self, reqwest::Method::PUT, &url, json_body)?
+			.json()
+			.await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+
+	#[tokio::test]
+	async fn test_grafana_get_user_config() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/grafanaproject/service/mygrafana";
+		let test_data = testutil::get_test_data("tests/testdata/service/grafana/service_info.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		match client
+			.service_grafana()
+			.get_user_config("grafanaproject", "mygrafana")
+			.await
+		{
+			Ok(config) => {
+				assert!(config.dashboards_versions_to_keep == Some(20));
+				assert!(config.user_auto_assign_org_role == Some(GrafanaRole::Editor));
+				assert!(config.extra.contains_key("smtp_server"));
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_grafana_update_user_config() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/grafanaupdateproject/service/mygrafana";
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/service/get_service_info.json");
+		let _m = mockito::mock("PUT", query_url)
+			.match_body(mockito::Matcher::Json(json!({
+				"user_config": {"dashboards_min_refresh_interval": "10s"}
+			})))
+			.with_status(200)
+			.with_header("content-type", "application/json")
+			.with_body(&test_data)
+			.create();
+
+		let config = GrafanaUserConfig {
+			dashboards_min_refresh_interval: Some("10s".into()),
+			..Default::default()
+		};
+		match client
+			.service_grafana()
+			.update_user_config("grafanaupdateproject", "mygrafana", &config)
+			.await
+		{
+			Ok(_) => assert!(true),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+}
@@ -0,0 +1,327 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+	client::{encode_param, HTTPClient},
+	errors::AivenError,
+	make_json_request, make_request,
+	service::{
+		types_influxdb::*,
+		types_service::{ResDatabaseNames, ResService, ResServiceUserConfig},
+	},
+};
+use serde_json::json;
+
+pub struct ServiceInfluxApi {
+	http_client: HTTPClient,
+}
+
+impl ServiceInfluxApi {
+	pub(crate) fn new(client: HTTPClient) -> Self {
+		Self {
+			http_client: client,
+		}
+	}
+
+	/// Get the typed `user_config` of an InfluxDB service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceGet
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` -  Service name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let config = client
+	///         .service_influxdb()
+	///         .get_user_config("my-project", "my-influx")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn get_user_config(
+		&self,
+		project: &str,
+		service_name: &str,
+	) -> Result<InfluxDbUserConfig, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}",
+			project = encode_param(project),
+			service_name = encode_param(service_name)
+		);
+		let response: ResServiceUserConfig<InfluxDbUserConfig> =
+			make_request!(self, reqwest::Method::GET, &url)?
+				.json()
+				.await?;
+		Ok(response.service.user_config)
+	}
+
+	/// Update the `user_config` of an InfluxDB service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceUpdate
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` -  Service name
+	/// * `user_config` - Settings to change, unset fields are kept
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_influxdb::{InfluxDbSettings, InfluxDbUserConfig};
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let config = InfluxDbUserConfig {
+	///     influxdb: Some(InfluxDbSettings {
+	///         query_timeout: Some(60),
+	///         ..Default::default()
+	///     }),
+	///     ..Default::default()
+	/// };
+	/// let response = client
+	///         .service_influxdb()
+	///         .update_user_config("my-project", "my-influx", &config)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn update_user_config(
+		&self,
+		project: &str,
+		service_name: &str,
+		user_config: &InfluxDbUserConfig,
+	) -> Result<ResService, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}",
+			project = encode_param(project),
+			service_name = encode_param(service_name)
+		);
+		let body = json!({ "user_config": user_config });
+		let json_body = &body;
+		make_json_request!(self, reqwest::Method::PUT, &url, json_body)?
+			.json()
+			.await
+	}
+
+	/// List the databases of an InfluxDB service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceDatabaseList
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` -  Service name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .service_influxdb()
+	///         .list_databases("my-project", "my-influx")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_databases(
+		&self,
+		project: &str,
+		service_name: &str,
+	) -> Result<ResDatabaseNames, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/db",
+			project = encode_param(project),
+			service_name = encode_param(service_name)
+		);
+		make_request!(self, reqwest::Method::GET, &url)?
+			.json()
+			.await
+	}
+
+	/// Create a database in an InfluxDB service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceDatabaseCreate
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` -  Service name
+	/// * `database` - Name of the new database
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .service_influxdb()
+	///         .create_database("my-project", "my-influx", "metrics")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_database(
+		&self,
+		project: &str,
+		service_name: &str,
+		database: &str,
+	) -> Result<(), AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/db",
+			project = encode_param(project),
+			service_name = encode_param(service_name)
+		);
+		let body = json!({ "database": database });
+		let json_body = &body;
+		let _response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
+		Ok(())
+	}
+
+	/// Delete a database of an InfluxDB service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceDatabaseDelete
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` -  Service name
+	/// * `database` - Name of the database
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .service_influxdb()
+	///         .delete_database("my-project", "my-influx", "metrics")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn delete_database(
+		&self,
+		project: &str,
+		service_name: &str,
+		database: &str,
+	) -> Result<(), AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/db/{database}",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+			database = encode_param(database)
+		);
+		let _response = make_request!(self, reqwest::Method::DELETE, &url)?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+
+	#[tokio::test]
+	async fn test_influxdb_get_user_config() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/influxproject/service/myinflux";
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/influxdb/service_info.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		match client
+			.service_influxdb()
+			.get_user_config("influxproject", "myinflux")
+			.await
+		{
+			Ok(config) => {
+				let settings = config.influxdb.unwrap_or_default();
+				assert!(settings.query_timeout == Some(30));
+				assert!(settings.max_row_limit == Some(10000));
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_influxdb_create_database() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/influxproject/service/myinflux/db";
+		let _m = mockito::mock("POST", query_url)
+			.match_body(mockito::Matcher::Json(json!({"database": "metrics"})))
+			.with_status(200)
+			.with_header("content-type", "application/json")
+			.with_body("{}")
+			.create();
+
+		match client
+			.service_influxdb()
+			.create_database("influxproject", "myinflux", "metrics")
+			.await
+		{
+			Ok(_) => assert!(true),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_influxdb_delete_database() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/influxproject/service/myinflux/db/metrics";
+		let _m = testutil::create_mock_server(query_url, "{}", "DELETE");
+
+		match client
+			.service_influxdb()
+			.delete_database("influxproject", "myinflux", "metrics")
+			.await
+		{
+			Ok(_) => assert!(true),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[test]
+	fn test_retention_policy_statement() {
+		let policy = RetentionPolicy::new("raw \"data\"", "metrics", "7d").shard_duration("1d");
+		assert!(
+			policy.to_string()
+				== r#"CREATE RETENTION POLICY "raw \"data\"" ON "metrics" DURATION 7d REPLICATION 1 SHARD DURATION 1d"#
+		);
+	}
+}
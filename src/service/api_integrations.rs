@@ -24,8 +24,12 @@ use crate::{
 	client::{encode_param, HTTPClient},
 	errors::AivenError,
 	make_json_request, make_request,
+	service::ServiceApi,
+	Secret,
 };
 use serde::Serialize;
+use serde_json::json;
+use tracing::warn;
 
 use crate::service::types_integrations::*;
 
@@ -79,7 +83,7 @@ impl ServiceIntegrationsApi {
 			project = encode_param(project),
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Create a new service integration
@@ -124,7 +128,7 @@ impl ServiceIntegrationsApi {
 			project = encode_param(project),
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete a service integration endpoint
@@ -241,7 +245,7 @@ impl ServiceIntegrationsApi {
 			integration_id = encode_param(integration_id),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List available integration endpoints for project
@@ -277,7 +281,7 @@ impl ServiceIntegrationsApi {
 			project = encode_param(project),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List available integrations for a service
@@ -316,7 +320,7 @@ impl ServiceIntegrationsApi {
 			service = encode_param(service),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List available service integration endpoint types
@@ -352,7 +356,7 @@ impl ServiceIntegrationsApi {
 			project = encode_param(project),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List available service integration types
@@ -384,11 +388,95 @@ impl ServiceIntegrationsApi {
 		project: &str,
 	) -> Result<ResIntegrationTypes, AivenError> {
 		let url = format!(
-			"project/{project}/integration_endpoint_types",
+			"project/{project}/integration_types",
 			project = encode_param(project),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// List the integration types which can connect a source service type to
+	/// a destination service type
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceIntegrationTypes
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `source_service_type` - Service type of the source, e.g. `kafka`
+	/// * `dest_service_type` - Service type of the destination, e.g. `pg`
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let choices = client
+	///         .service_integrations()
+	///         .list_integration_types_between("my-project", "kafka", "kafka_connect")
+	///         .await?;
+	/// for choice in choices {
+	///     println!("{}: {}", choice.integration_type, choice.dest_description);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_integration_types_between(
+		&self,
+		project: &str,
+		source_service_type: &str,
+		dest_service_type: &str,
+	) -> Result<Vec<IntegrationType>, AivenError> {
+		Ok(self
+			.list_available_integration_types(project)
+			.await?
+			.between(source_service_type, dest_service_type))
+	}
+
+	/// List the integration types which can connect two existing services
+	///
+	/// Looks up the service types of both services and filters the
+	/// available integration types with them.
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `source_service` - Name of the source service
+	/// * `dest_service` - Name of the destination service
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let choices = client
+	///         .service_integrations()
+	///         .list_integration_types_for_services("my-project", "my-kafka", "my-pg")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_integration_types_for_services(
+		&self,
+		project: &str,
+		source_service: &str,
+		dest_service: &str,
+	) -> Result<Vec<IntegrationType>, AivenError> {
+		let service_api = ServiceApi::new(self.http_client.clone());
+		let source = service_api
+			.get_service_info(project, source_service)
+			.await?
+			.service;
+		let dest = service_api
+			.get_service_info(project, dest_service)
+			.await?
+			.service;
+		self.list_integration_types_between(project, &source.service_type, &dest.service_type)
+			.await
 	}
 
 	/// Update a service integration
@@ -427,7 +515,7 @@ impl ServiceIntegrationsApi {
 			integration_id = encode_param(integration_id),
 		);
 		let response = make_json_request!(self, reqwest::Method::PUT, &url, user_config)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Update service integration endpoint
@@ -469,15 +557,768 @@ impl ServiceIntegrationsApi {
 			endpoint_id = encode_param(endpoint_id),
 		);
 		let response = make_json_request!(self, reqwest::Method::PUT, &url, user_config)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// Get a single integration endpoint
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceIntegrationEndpointGet
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `endpoint_id` - Endpoint id
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .service_integrations()
+	///         .get_integration_endpoint("my-project", "endpoint-id")
+	///         .await?;
+	/// let config = response.service_integration_endpoint.typed_config()?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn get_integration_endpoint(
+		&self,
+		project: &str,
+		endpoint_id: &str,
+	) -> Result<ResServiceIntegrationEndPoint, AivenError> {
+		let url = format!(
+			"project/{project}/integration_endpoint/{endpoint_id}",
+			project = encode_param(project),
+			endpoint_id = encode_param(endpoint_id),
+		);
+		make_request!(self, reqwest::Method::GET, &url)?
+			.json()
+			.await
+	}
+
+	/// Create an integration endpoint from a typed config
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceIntegrationEndpointCreate
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `endpoint_name` - Name of the new endpoint
+	/// * `config` - Type and settings of the endpoint
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_integrations::{
+	///     IntegrationEndpointConfig, RsyslogEndpointConfig, RsyslogFormat,
+	/// };
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let config = IntegrationEndpointConfig::Rsyslog(RsyslogEndpointConfig {
+	///     server: "logs.example.com".into(),
+	///     port: 6514,
+	///     tls: true,
+	///     format: RsyslogFormat::Rfc5424,
+	///     ..Default::default()
+	/// });
+	/// let response = client
+	///         .service_integrations()
+	///         .create_endpoint("my-project", "central-syslog", &config)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_endpoint(
+		&self,
+		project: &str,
+		endpoint_name: &str,
+		config: &IntegrationEndpointConfig,
+	) -> Result<ResServiceIntegrationEndPoint, AivenError> {
+		if let IntegrationEndpointConfig::Datadog(datadog) = config {
+			return self
+				.create_datadog_endpoint(project, endpoint_name, datadog)
+				.await;
+		}
+		let mut body = serde_json::to_value(config)?;
+		body["endpoint_name"] = json!(endpoint_name);
+		self.create_integration_endpoint(project, &body).await
+	}
+
+	/// Replace the `user_config` of an integration endpoint with a typed
+	/// config. The endpoint type can't be changed.
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceIntegrationEndpointUpdate
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `endpoint_id` - Endpoint id
+	/// * `config` - New settings of the endpoint
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_integrations::{IntegrationEndpointConfig, PrometheusEndpointConfig};
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let config = IntegrationEndpointConfig::Prometheus(PrometheusEndpointConfig {
+	///     basic_auth_username: Some("scraper".into()),
+	///     basic_auth_password: Some(aiven_rs::Secret::new("new-password")),
+	/// });
+	/// let response = client
+	///         .service_integrations()
+	///         .update_endpoint("my-project", "endpoint-id", &config)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn update_endpoint(
+		&self,
+		project: &str,
+		endpoint_id: &str,
+		config: &IntegrationEndpointConfig,
+	) -> Result<ResServiceIntegrationEndPoint, AivenError> {
+		let body = serde_json::to_value(config)?;
+		let body = json!({ "user_config": body["user_config"] });
+		self.update_integration_endpoint(project, endpoint_id, &body)
+			.await
+	}
+
+	/// Create a dedicated Kafka Connect service and integrate it with an
+	/// existing Kafka service.
+	///
+	/// The Kafka Connect service is created first, then the `kafka_connect`
+	/// integration from the Kafka service to it. If creating the integration
+	/// fails, the new Kafka Connect service is left in place and the error is
+	/// returned.
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `request` - Kafka service, new Kafka Connect service and its plan
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_integrations::DedicatedKafkaConnectRequest;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let request = DedicatedKafkaConnectRequest {
+	///     kafka_service: "my-kafka".into(),
+	///     connect_service: "my-connect".into(),
+	///     cloud: "google-europe-west1".into(),
+	///     plan: "startup-4".into(),
+	///     ..Default::default()
+	/// };
+	/// let response = client
+	///         .service_integrations()
+	///         .create_dedicated_kafka_connect("my-project", &request)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_dedicated_kafka_connect(
+		&self,
+		project: &str,
+		request: &DedicatedKafkaConnectRequest,
+	) -> Result<ResDedicatedKafkaConnect, AivenError> {
+		let mut service_body = json!({
+			"service_name": request.connect_service,
+			"service_type": "kafka_connect",
+			"cloud": request.cloud,
+			"plan": request.plan,
+		});
+		if let Some(vpc) = &request.project_vpc_id {
+			service_body["project_vpc_id"] = json!(vpc);
+		}
+		if let Some(user_config) = &request.user_config {
+			service_body["user_config"] = user_config.clone();
+		}
+		let service = ServiceApi::new(self.http_client.clone())
+			.create_service(project, &service_body)
+			.await?
+			.service;
+
+		let integration_body = json!({
+			"integration_type": "kafka_connect",
+			"source_service": request.kafka_service,
+			"dest_service": request.connect_service,
+		});
+		let service_integration = self
+			.create_integration(project, &integration_body)
+			.await?
+			.service_integration;
+
+		Ok(ResDedicatedKafkaConnect {
+			service,
+			service_integration,
+		})
+	}
+
+	/// Build the graph of services, integration endpoints and the service
+	/// integrations between them for a project
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let topology = client
+	///         .service_integrations()
+	///         .topology("my-project")
+	///         .await?;
+	/// println!("{}", topology.to_dot());
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn topology(&self, project: &str) -> Result<Topology, AivenError> {
+		let services = ServiceApi::new(self.http_client.clone())
+			.list_services(project)
+			.await?
+			.services;
+		let endpoints = self
+			.list_endpoints_by_project(project)
+			.await?
+			.service_integration_endpoints;
+		Ok(Topology::build(&services, &endpoints))
+	}
+
+	/// Create a Prometheus integration endpoint protected with basic-auth,
+	/// attach it to the given services and return their scrape targets.
+	///
+	/// If attaching a service fails, the endpoint is deleted again, which
+	/// removes the integrations created so far with it.
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `endpoint_name` - Name of the Prometheus endpoint to create
+	/// * `username` - Basic-auth username used by the scraper
+	/// * `password` - Basic-auth password used by the scraper
+	/// * `services` - Services exposing metrics on this endpoint
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .service_integrations()
+	///         .create_prometheus_integration("my-project", "prometheus", "scraper", "s3cret", &["my-pg"])
+	///         .await?;
+	/// for target in response.targets {
+	///     println!("{}:{}", target.host, target.port);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_prometheus_integration(
+		&self,
+		project: &str,
+		endpoint_name: &str,
+		username: &str,
+		password: &str,
+		services: &[&str],
+	) -> Result<ResPrometheusIntegration, AivenError> {
+		let endpoint_body = json!({
+			"endpoint_name": endpoint_name,
+			"endpoint_type": "prometheus",
+			"user_config": {
+				"basic_auth_username": username,
+				"basic_auth_password": password,
+			}
+		});
+		let endpoint = self
+			.create_integration_endpoint(project, &endpoint_body)
+			.await?
+			.service_integration_endpoint;
+
+		let mut integration = ResPrometheusIntegration {
+			endpoint,
+			service_integrations: Vec::with_capacity(services.len()),
+			targets: Vec::with_capacity(services.len()),
+		};
+		if let Err(e) = self
+			.attach_prometheus_services(project, username, password, services, &mut integration)
+			.await
+		{
+			let endpoint_id = &integration.endpoint.endpoint_id;
+			if let Err(cleanup) = self.delete_integration_endpoint(project, endpoint_id).await {
+				warn!(
+					"could not delete prometheus endpoint {}: {}",
+					endpoint_id, cleanup
+				);
+			}
+			return Err(e);
+		}
+		Ok(integration)
+	}
+
+	/// Integrate `services` with the endpoint of `integration` and collect
+	/// their scrape targets
+	async fn attach_prometheus_services(
+		&self,
+		project: &str,
+		username: &str,
+		password: &str,
+		services: &[&str],
+		integration: &mut ResPrometheusIntegration,
+	) -> Result<(), AivenError> {
+		let service_api = ServiceApi::new(self.http_client.clone());
+		for service_name in services {
+			let integration_body = json!({
+				"integration_type": "prometheus",
+				"source_service": service_name,
+				"dest_endpoint_id": integration.endpoint.endpoint_id,
+			});
+			integration.service_integrations.push(
+				self.create_integration(project, &integration_body)
+					.await?
+					.service_integration,
+			);
+
+			let service = service_api
+				.get_service_info(project, service_name)
+				.await?
+				.service;
+			if let Some(component) = service
+				.components
+				.iter()
+				.find(|c| c.component == "prometheus")
+			{
+				integration.targets.push(PrometheusScrapeTarget {
+					service_name: service_name.to_string(),
+					host: component.host.clone(),
+					port: component.port,
+					username: username.to_string(),
+					password: Secret::new(password),
+				});
+			}
+		}
+		Ok(())
+	}
+
+	/// Create a Datadog integration endpoint from a typed configuration.
+	///
+	/// The API key and the tags are validated before anything is sent,
+	/// returning `AivenError::InvalidInput` for a missing key or a malformed
+	/// tag.
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceIntegrationEndpointCreate
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_integrations::{DatadogEndpointConfig, DatadogSite, DatadogTag};
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let config = DatadogEndpointConfig {
+	///     datadog_api_key: Some(aiven_rs::Secret::new("dd-api-key")),
+	///     site: DatadogSite::Eu1,
+	///     datadog_tags: vec![DatadogTag::new("env:prod")?],
+	/// };
+	/// let response = client
+	///         .service_integrations()
+	///         .create_datadog_endpoint("my-project", "datadog-eu", &config)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_datadog_endpoint(
+		&self,
+		project: &str,
+		endpoint_name: &str,
+		config: &DatadogEndpointConfig,
+	) -> Result<ResServiceIntegrationEndPoint, AivenError> {
+		if config.datadog_api_key.is_none() {
+			return Err(AivenError::InvalidInput {
+				field: "datadog_api_key".to_owned(),
+				reason: "is required to create a Datadog endpoint".to_owned(),
+			});
+		}
+		config
+			.datadog_tags
+			.iter()
+			.try_for_each(DatadogTag::validate)?;
+		let body = json!({
+			"endpoint_name": endpoint_name,
+			"endpoint_type": "datadog",
+			"user_config": config,
+		});
+		self.create_integration_endpoint(project, &body).await
+	}
+
+	/// Send the metrics of a service to a Datadog endpoint, with a typed
+	/// integration configuration.
+	///
+	/// Tags are validated before anything is sent, returning
+	/// `AivenError::InvalidInput` for a malformed tag.
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceIntegrationCreate
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_integrations::{DatadogIntegrationConfig, DatadogTag};
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let config = DatadogIntegrationConfig {
+	///     datadog_tags: vec![DatadogTag::new("team:payments")?],
+	///     exclude_topics: vec!["__consumer_offsets".into()],
+	///     ..Default::default()
+	/// };
+	/// let response = client
+	///         .service_integrations()
+	///         .create_datadog_integration("my-project", "my-kafka", "endpoint-id", &config)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_datadog_integration(
+		&self,
+		project: &str,
+		service_name: &str,
+		endpoint_id: &str,
+		config: &DatadogIntegrationConfig,
+	) -> Result<ResServiceIntegration, AivenError> {
+		config.validate()?;
+		let body = json!({
+			"integration_type": "datadog",
+			"source_service": service_name,
+			"dest_endpoint_id": endpoint_id,
+			"user_config": config,
+		});
+		self.create_integration(project, &body).await
+	}
+
+	/// Integrate a service with an `autoscaler` endpoint, so its disk grows
+	/// up to the cap of the endpoint when it fills up
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceIntegrationCreate
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `service_name` - Service to autoscale
+	/// * `endpoint_id` - Id of an endpoint created from
+	///   `IntegrationEndpointConfig::Autoscaler`
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_integrations::{
+	///     AutoscalerEndpointConfig, AutoscalingRule, IntegrationEndpointConfig,
+	/// };
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let integrations = client.service_integrations();
+	/// let config = IntegrationEndpointConfig::Autoscaler(AutoscalerEndpointConfig {
+	///     autoscaling: vec![AutoscalingRule::disk(200)],
+	/// });
+	/// let endpoint = integrations
+	///         .create_endpoint("my-project", "disk-autoscaler", &config)
+	///         .await?;
+	/// integrations
+	///         .enable_autoscaler(
+	///             "my-project",
+	///             "my-pg",
+	///             &endpoint.service_integration_endpoint.endpoint_id,
+	///         )
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn enable_autoscaler(
+		&self,
+		project: &str,
+		service_name: &str,
+		endpoint_id: &str,
+	) -> Result<ResServiceIntegration, AivenError> {
+		let body = json!({
+			"integration_type": "autoscaler",
+			"source_service": service_name,
+			"dest_endpoint_id": endpoint_id,
+		});
+		self.create_integration(project, &body).await
+	}
+
+	/// The autoscaler a service is integrated with, `None` when its disk
+	/// isn't autoscaled
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `service_name` - Service name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// if let Some(autoscaler) = client
+	///         .service_integrations()
+	///         .get_autoscaler_config("my-project", "my-pg")
+	///         .await?
+	/// {
+	///     println!("disk capped at {:?} GB", autoscaler.config.disk_cap_gb());
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn get_autoscaler_config(
+		&self,
+		project: &str,
+		service_name: &str,
+	) -> Result<Option<ServiceAutoscaler>, AivenError> {
+		let integration = self
+			.list_integrations_for_service(project, service_name)
+			.await?
+			.service_integrations
+			.into_iter()
+			.find(|i| i.integration_type == "autoscaler" && i.source_service == service_name);
+		let integration = match integration {
+			Some(integration) => integration,
+			None => return Ok(None),
+		};
+		let endpoint = self
+			.get_integration_endpoint(project, &integration.dest_endpoint_id)
+			.await?
+			.service_integration_endpoint;
+		let config = match endpoint.typed_config()? {
+			IntegrationEndpointConfig::Autoscaler(config) => config,
+			_ => {
+				return Err(AivenError::InvalidInput {
+					field: "endpoint_type".to_owned(),
+					reason: format!(
+						"endpoint `{}` of the autoscaler integration is `{}`",
+						endpoint.endpoint_name, endpoint.endpoint_type
+					),
+				})
+			}
+		};
+		Ok(Some(ServiceAutoscaler {
+			service_integration_id: integration.service_integration_id,
+			endpoint_id: endpoint.endpoint_id,
+			endpoint_name: endpoint.endpoint_name,
+			config,
+		}))
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::testutil;
+	use crate::{
+		errors::AivenError,
+		service::types_integrations::{
+			AutoscalerEndpointConfig, AutoscalingRule, AutoscalingType, DatadogEndpointConfig,
+			DatadogIntegrationConfig, DatadogTag, DedicatedKafkaConnectRequest,
+			IntegrationEndpointConfig, PrometheusEndpointConfig, Topology, TopologyNode,
+		},
+		testutil,
+	};
 	use serde_json::json;
 
+	#[tokio::test]
+	async fn test_service_integrations_topology() {
+		let client = testutil::client();
+		let services =
+			testutil::get_test_data("tests/testdata/service/integrations/topology_services.json");
+		let endpoints = testutil::get_test_data(
+			"tests/testdata/service/integrations/list_service_integration_eps.json",
+		);
+		let _m = testutil::create_mock_server("/project/topoproject/service", &services, "GET");
+		let _n = testutil::create_mock_server(
+			"/project/topoproject/integration_endpoint",
+			&endpoints,
+			"GET",
+		);
+
+		match client.service_integrations().topology("topoproject").await {
+			Ok(topology) => {
+				assert!(topology.nodes.len() == 3);
+				assert!(topology.edges.len() == 2);
+				let from_pg = topology.edges_from("pg1");
+				assert!(from_pg.len() == 2);
+				assert!(topology.edges_to("kafka1")[0].integration_type == "kafka_logs");
+				let datadog = topology.edges_to("543e420d-aa63-43e8-b8e8-294a78c600e7");
+				assert!(datadog[0].source == "pg1");
+				let dot = topology.to_dot();
+				assert!(dot.starts_with("digraph topology {"));
+				assert!(dot.contains("\"pg1\" -> \"kafka1\" [label=\"kafka_logs\"];"));
+			}
+			Err(e) => assert!(false, "Error was {:?}", e),
+		}
+	}
+
+	#[test]
+	fn test_topology_to_dot_escapes() {
+		let topology = Topology {
+			nodes: vec![TopologyNode {
+				id: "pg\\1".to_owned(),
+				name: "say \"hi\"\tü".to_owned(),
+				node_type: "pg".to_owned(),
+				..Default::default()
+			}],
+			edges: Vec::new(),
+		};
+		let dot = topology.to_dot();
+		assert!(dot.contains("  \"pg\\\\1\" [label=\"say \\\"hi\\\"\tü (pg)\", shape=box];"));
+	}
+
+	#[tokio::test]
+	async fn test_service_integrations_create_prometheus_integration() {
+		let client = testutil::client();
+		let endpoint_data = testutil::get_test_data(
+			"tests/testdata/service/integrations/create_prometheus_endpoint.json",
+		);
+		let integration_data = testutil::get_test_data(
+			"tests/testdata/service/integrations/create_prometheus_integration.json",
+		);
+		let service_data = testutil::get_test_data(
+			"tests/testdata/service/integrations/prometheus_service_info.json",
+		);
+		let _m = testutil::create_mock_server(
+			"/project/promproject/integration_endpoint",
+			&endpoint_data,
+			"POST",
+		);
+		let _n = testutil::create_mock_server(
+			"/project/promproject/integration",
+			&integration_data,
+			"POST",
+		);
+		let _o =
+			testutil::create_mock_server("/project/promproject/service/mypg", &service_data, "GET");
+
+		match client
+			.service_integrations()
+			.create_prometheus_integration(
+				"promproject",
+				"prometheus",
+				"scraper",
+				"s3cret",
+				&["mypg"],
+			)
+			.await
+		{
+			Ok(resp) => {
+				assert!(resp.endpoint.endpoint_type == "prometheus");
+				assert!(resp.service_integrations.len() == 1);
+				assert!(resp.targets.len() == 1);
+				assert!(resp.targets[0].host == "my-pg-myproject.aivencloud.com");
+				assert!(resp.targets[0].port == 9273);
+				assert!(resp.targets[0].username == "scraper");
+				assert!(resp.targets[0].password.expose() == "s3cret");
+			}
+			Err(e) => assert!(false, "Error was {:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_integrations_create_prometheus_integration_cleans_up() {
+		let client = testutil::client();
+		let endpoint_data = testutil::get_test_data(
+			"tests/testdata/service/integrations/create_prometheus_endpoint.json",
+		);
+		let _m = testutil::create_mock_server(
+			"/project/promfailproject/integration_endpoint",
+			&endpoint_data,
+			"POST",
+		);
+		let _n = mockito::mock("POST", "/project/promfailproject/integration")
+			.with_status(400)
+			.with_body(r#"{"message": "Service mypg does not exist", "errors": []}"#)
+			.create();
+		let deleted = mockito::mock(
+			"DELETE",
+			"/project/promfailproject/integration_endpoint/543e420d-aa63-43e8-b8e8-294a78c600e7",
+		)
+		.with_status(200)
+		.with_body("{}")
+		.create();
+
+		match client
+			.service_integrations()
+			.create_prometheus_integration(
+				"promfailproject",
+				"prometheus",
+				"scraper",
+				"s3cret",
+				&["mypg"],
+			)
+			.await
+		{
+			Err(AivenError::APIResponseError { status_code, .. }) => {
+				assert!(status_code == 400);
+				deleted.assert();
+			}
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_integrations_create_dedicated_kafka_connect() {
+		let client = testutil::client();
+		let service_data = testutil::get_test_data(
+			"tests/testdata/service/integrations/create_kafka_connect_service.json",
+		);
+		let integration_data = testutil::get_test_data(
+			"tests/testdata/service/integrations/create_kafka_connect_integration.json",
+		);
+		let _m =
+			testutil::create_mock_server("/project/connectproject/service", &service_data, "POST");
+		let _n = testutil::create_mock_server(
+			"/project/connectproject/integration",
+			&integration_data,
+			"POST",
+		);
+
+		let request = DedicatedKafkaConnectRequest {
+			kafka_service: "my-kafka".into(),
+			connect_service: "my-connect".into(),
+			cloud: "google-europe-west1".into(),
+			plan: "startup-4".into(),
+			..Default::default()
+		};
+		match client
+			.service_integrations()
+			.create_dedicated_kafka_connect("connectproject", &request)
+			.await
+		{
+			Ok(resp) => {
+				assert!(resp.service.service_type == "kafka_connect");
+				assert!(resp.service_integration.integration_type == "kafka_connect");
+				assert!(resp.service_integration.dest_service == "my-connect");
+			}
+			Err(e) => assert!(false, "Error was {:?}", e),
+		}
+	}
+
 	#[tokio::test]
 	async fn test_service_integrations_create_integration_endpoint() {
 		let client = testutil::client();
@@ -504,6 +1345,67 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn test_service_integrations_create_datadog_integration() {
+		let client = testutil::client();
+		let query_url = "/project/datadogproject/integration";
+		let test_data = testutil::get_test_data("tests/testdata/service/integrations/create.json");
+		let _m = mockito::mock("POST", query_url)
+			.match_body(mockito::Matcher::Json(json!({
+				"integration_type": "datadog",
+				"source_service": "kafka1",
+				"dest_endpoint_id": "endpoint1",
+				"user_config": {
+					"datadog_tags": [{ "tag": "env:prod", "comment": "environment" }],
+					"exclude_topics": ["__consumer_offsets"]
+				}
+			})))
+			.with_status(200)
+			.with_header("content-type", "application/json")
+			.with_body(&test_data)
+			.create();
+
+		let client = client.service_integrations();
+		let config = DatadogIntegrationConfig {
+			datadog_tags: vec![DatadogTag::new("env:prod").unwrap().comment("environment")],
+			exclude_topics: vec!["__consumer_offsets".into()],
+			..Default::default()
+		};
+		match client
+			.create_datadog_integration("datadogproject", "kafka1", "endpoint1", &config)
+			.await
+		{
+			Ok(_) => assert!(true),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+
+		let config = DatadogIntegrationConfig {
+			datadog_tags: vec![DatadogTag {
+				tag: "env prod".into(),
+				comment: None,
+			}],
+			..Default::default()
+		};
+		match client
+			.create_datadog_integration("datadogproject", "kafka1", "endpoint1", &config)
+			.await
+		{
+			Err(AivenError::InvalidInput { field, .. }) => assert!(field == "datadog_tags"),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[test]
+	fn test_datadog_tag_validation() {
+		assert!(DatadogTag::new("env:prod").is_ok());
+		assert!(DatadogTag::new("team/payments.eu-1_a").is_ok());
+		assert!(DatadogTag::new("").is_err());
+		assert!(DatadogTag::new("1env").is_err());
+		assert!(DatadogTag::new("env:").is_err());
+		assert!(DatadogTag::new("env=prod").is_err());
+		assert!(DatadogTag::new("a".repeat(201)).is_err());
+	}
+
 	#[tokio::test]
 	async fn test_service_integrations_create_integration() {
 		let client = testutil::client();
@@ -596,7 +1498,7 @@ mod tests {
 
 		let client = client.service_integrations();
 		match client.list_endpoints_by_project("myproject").await {
-			Ok(resp) => assert!(resp.service_integration_endpoints.len() > 0),
+			Ok(resp) => assert!(!resp.service_integration_endpoints.is_empty()),
 			Err(e) => {
 				assert!(false, "Error was {:?}", e);
 			}
@@ -619,7 +1521,7 @@ mod tests {
 			.list_integrations_for_service("myproject", "myservice")
 			.await
 		{
-			Ok(resp) => assert!(resp.service_integrations.len() > 0),
+			Ok(resp) => assert!(!resp.service_integrations.is_empty()),
 			Err(e) => {
 				assert!(false, "Error was {:?}", e);
 			}
@@ -639,7 +1541,7 @@ mod tests {
 
 		let client = client.service_integrations();
 		match client.list_integration_endpoint_types("myproject").await {
-			Ok(resp) => assert!(resp.endpoint_types.len() > 0),
+			Ok(resp) => assert!(!resp.endpoint_types.is_empty()),
 			Err(e) => {
 				assert!(false, "Error was {:?}", e);
 			}
@@ -649,7 +1551,7 @@ mod tests {
 	#[tokio::test]
 	async fn test_service_integrations_list_available_integration_types() {
 		let client = testutil::client();
-		let query_url = "/project/myproject/integration_endpoint_types";
+		let query_url = "/project/myavailableproject/integration_types";
 
 		let test_data = testutil::get_test_data(
 			"tests/testdata/service/integrations/list_service_integration_types.json",
@@ -658,8 +1560,37 @@ mod tests {
 		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
 
 		let client = client.service_integrations();
-		match client.list_available_integration_types("myproject").await {
-			Ok(resp) => assert!(resp.integration_types.len() > 0),
+		match client
+			.list_available_integration_types("myavailableproject")
+			.await
+		{
+			Ok(resp) => assert!(!resp.integration_types.is_empty()),
+			Err(e) => {
+				assert!(false, "Error was {:?}", e);
+			}
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_integrations_list_integration_types_between() {
+		let client = testutil::client();
+		let query_url = "/project/mytypesproject/integration_types";
+
+		let test_data = testutil::get_test_data(
+			"tests/testdata/service/integrations/list_integration_types_pairs.json",
+		);
+
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		let client = client.service_integrations();
+		match client
+			.list_integration_types_between("mytypesproject", "kafka", "kafka_connect")
+			.await
+		{
+			Ok(types) => {
+				assert!(types.len() == 1);
+				assert!(types[0].integration_type == "kafka_connect");
+			}
 			Err(e) => {
 				assert!(false, "Error was {:?}", e);
 			}
@@ -717,4 +1648,207 @@ mod tests {
 			}
 		}
 	}
+
+	#[tokio::test]
+	async fn test_service_integrations_create_typed_endpoint() {
+		let client = testutil::client();
+		let test_data = testutil::get_test_data(
+			"tests/testdata/service/integrations/create_prometheus_endpoint.json",
+		);
+		let _m = mockito::mock("POST", "/project/typed-ep-project/integration_endpoint")
+			.match_body(mockito::Matcher::Json(json!({
+				"endpoint_name": "prometheus",
+				"endpoint_type": "prometheus",
+				"user_config": {
+					"basic_auth_username": "scraper",
+					"basic_auth_password": "s3cret"
+				}
+			})))
+			.with_status(200)
+			.with_body(&test_data)
+			.create();
+
+		let config = IntegrationEndpointConfig::Prometheus(PrometheusEndpointConfig {
+			basic_auth_username: Some("scraper".into()),
+			basic_auth_password: Some(crate::Secret::new("s3cret")),
+		});
+		match client
+			.service_integrations()
+			.create_endpoint("typed-ep-project", "prometheus", &config)
+			.await
+		{
+			Ok(resp) => match resp.service_integration_endpoint.typed_config() {
+				Ok(IntegrationEndpointConfig::Prometheus(c)) => {
+					assert!(c.basic_auth_username.as_deref() == Some("scraper"))
+				}
+				other => assert!(false, "unexpected config {:?}", other),
+			},
+			Err(e) => assert!(false, "Error was {:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_integrations_get_integration_endpoint() {
+		let client = testutil::client();
+		let test_data = testutil::get_test_data(
+			"tests/testdata/service/integrations/update_service_integration_endpoint.json",
+		);
+		let _m = testutil::create_mock_server(
+			"/project/get-ep-project/integration_endpoint/endpointid",
+			&test_data,
+			"GET",
+		);
+
+		match client
+			.service_integrations()
+			.get_integration_endpoint("get-ep-project", "endpointid")
+			.await
+		{
+			Ok(resp) => {
+				let endpoint = resp.service_integration_endpoint;
+				assert!(endpoint.endpoint_type == "datadog");
+				// the API key isn't returned with the stored config
+				match endpoint.typed_config() {
+					Ok(IntegrationEndpointConfig::Datadog(c)) => {
+						assert!(c.datadog_api_key.is_none())
+					}
+					other => assert!(false, "unexpected config {:?}", other),
+				}
+			}
+			Err(e) => assert!(false, "Error was {:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_integrations_create_datadog_endpoint_without_key() {
+		let client = testutil::client();
+		let config = DatadogEndpointConfig::default();
+		match client
+			.service_integrations()
+			.create_datadog_endpoint("dd-key-project", "datadog", &config)
+			.await
+		{
+			Err(AivenError::InvalidInput { field, .. }) => assert!(field == "datadog_api_key"),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_integrations_update_typed_endpoint() {
+		let client = testutil::client();
+		let test_data = testutil::get_test_data(
+			"tests/testdata/service/integrations/create_prometheus_endpoint.json",
+		);
+		let _m = mockito::mock(
+			"PUT",
+			"/project/typed-ep-update/integration_endpoint/endpointid",
+		)
+		.match_body(mockito::Matcher::Json(json!({
+			"user_config": {"basic_auth_username": "scraper"}
+		})))
+		.with_status(200)
+		.with_body(&test_data)
+		.create();
+
+		let config = IntegrationEndpointConfig::Prometheus(PrometheusEndpointConfig {
+			basic_auth_username: Some("scraper".into()),
+			basic_auth_password: None,
+		});
+		match client
+			.service_integrations()
+			.update_endpoint("typed-ep-update", "endpointid", &config)
+			.await
+		{
+			Ok(resp) => assert!(resp.service_integration_endpoint.endpoint_type == "prometheus"),
+			Err(e) => assert!(false, "Error was {:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_integrations_autoscaler() {
+		let client = testutil::client();
+		let integrations = testutil::get_test_data(
+			"tests/testdata/service/integrations/autoscaler_integrations.json",
+		);
+		let endpoint =
+			testutil::get_test_data("tests/testdata/service/integrations/autoscaler_endpoint.json");
+		let _list = testutil::create_mock_server(
+			"/project/autoscaleproject/service/my-pg/integration",
+			&integrations,
+			"GET",
+		);
+		let _empty = testutil::create_mock_server(
+			"/project/autoscaleproject/service/my-kafka/integration",
+			r#"{"service_integrations": []}"#,
+			"GET",
+		);
+		let _endpoint = testutil::create_mock_server(
+			"/project/autoscaleproject/integration_endpoint/ep7c1d2e3f4a5",
+			&endpoint,
+			"GET",
+		);
+		let create = mockito::mock("POST", "/project/autoscaleproject/integration")
+			.match_body(mockito::Matcher::Json(json!({
+				"integration_type": "autoscaler",
+				"source_service": "my-pg",
+				"dest_endpoint_id": "ep7c1d2e3f4a5",
+			})))
+			.with_status(200)
+			.with_body(
+				json!({
+					"service_integration": serde_json::from_str::<serde_json::Value>(&integrations)
+						.unwrap()["service_integrations"][0]
+				})
+				.to_string(),
+			)
+			.create();
+
+		let client = client.service_integrations();
+		match client
+			.get_autoscaler_config("autoscaleproject", "my-pg")
+			.await
+		{
+			Ok(Some(autoscaler)) => {
+				assert!(autoscaler.service_integration_id == "si4b5c6d7e8f9");
+				assert!(autoscaler.config.disk_cap_gb() == Some(200));
+			}
+			other => assert!(false, "{:?}", other),
+		}
+		match client
+			.get_autoscaler_config("autoscaleproject", "my-kafka")
+			.await
+		{
+			Ok(None) => {}
+			other => assert!(false, "{:?}", other),
+		}
+		match client
+			.enable_autoscaler("autoscaleproject", "my-pg", "ep7c1d2e3f4a5")
+			.await
+		{
+			Ok(_) => create.assert(),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[test]
+	fn test_autoscaler_endpoint_config() {
+		let config = IntegrationEndpointConfig::Autoscaler(AutoscalerEndpointConfig {
+			autoscaling: vec![AutoscalingRule::disk(100)],
+		});
+		assert!(config.endpoint_type() == "autoscaler");
+		assert!(
+			serde_json::to_value(&config).unwrap()
+				== json!({
+					"endpoint_type": "autoscaler",
+					"user_config": {"autoscaling": [{"type": "autoscale_disk", "cap_gb": 100}]}
+				})
+		);
+	}
+
+	#[test]
+	fn test_autoscaling_type_unknown() {
+		let rule: AutoscalingRule =
+			serde_json::from_value(json!({"type": "autoscale_cpu", "cap_gb": 4})).unwrap();
+		assert!(rule.kind == AutoscalingType::Unknown);
+	}
 }
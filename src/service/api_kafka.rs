@@ -21,13 +21,33 @@
 // SOFTWARE.
 
 use crate::{
-	client::{encode_param, HTTPClient},
+	client::{encode_param, json_size, Completion, HTTPClient},
+	deadline::Deadline,
 	errors::AivenError,
 	make_json_request, make_request,
+	poll::{self, PollOpts},
+	retry::RetryPolicy,
+	service::{
+		types_service::{Service, ACL},
+		SchemaRegistryApi, ServiceApi, TopicProducer, TopicReader,
+	},
 };
 
 use crate::service::types_kafka::*;
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use regex::Regex;
 use serde::Serialize;
+use std::collections::BTreeSet;
+use tracing::error;
+
+/// How often `list_all_acl_entries` fetches the ACL entries before giving up
+/// on a consistent listing
+const ACL_LISTING_ATTEMPTS: usize = 3;
+
+/// How many subject versions `get_schema_registry_subject_version_details`
+/// fetches at a time
+const SCHEMA_VERSION_CONCURRENCY: usize = 4;
+
 pub struct ServiceKafkaApi {
 	http_client: HTTPClient,
 }
@@ -39,6 +59,116 @@ impl ServiceKafkaApi {
 		}
 	}
 
+	pub(crate) fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+		self.http_client.set_retry_policy(policy);
+		self
+	}
+
+	/// Confluent compatible access to the schema registry of a service
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let registry = client.service_kafka().schema_registry("myproject", "myservicename");
+	/// let subjects = registry.subjects().await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub fn schema_registry(&self, project: &str, service_name: &str) -> SchemaRegistryApi {
+		SchemaRegistryApi::new(self.http_client.clone(), project, service_name)
+	}
+
+	/// Read the messages of a topic batch by batch through the REST proxy,
+	/// continuing at the offsets where the previous batch ended
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `topic` - Kafka topic name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let mut reader = client
+	///             .service_kafka()
+	///             .topic_reader("myproject", "myservicename", "mytopic")
+	///             .start_at(0, 1000);
+	/// loop {
+	///     let batch = reader.next_batch().await?;
+	///     if batch.is_empty() {
+	///         break;
+	///     }
+	///     println!("read {} messages", batch.len());
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub fn topic_reader(&self, project: &str, service_name: &str, topic: &str) -> TopicReader {
+		TopicReader::new(
+			ServiceKafkaApi::new(self.http_client.clone()),
+			project,
+			service_name,
+			topic,
+		)
+	}
+
+	/// Produce records to a topic through the REST proxy in batches,
+	/// returning the partition and offset or error of every record
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `topic` - Kafka topic name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
+	/// use std::time::Duration;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let mut producer = client
+	///             .service_kafka()
+	///             .topic_producer("myproject", "myservicename", "mytopic")
+	///             .max_records(100)
+	///             .linger(Duration::from_millis(500));
+	/// for i in 0..1000 {
+	///     for result in producer.send(&json!({"value": {"id": i}})).await? {
+	///         result?;
+	///     }
+	/// }
+	/// for result in producer.flush().await? {
+	///     result?;
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub fn topic_producer(&self, project: &str, service_name: &str, topic: &str) -> TopicProducer {
+		TopicProducer::new(
+			ServiceKafkaApi::new(self.http_client.clone()),
+			project,
+			service_name,
+			topic,
+		)
+	}
+
 	/// Add a Kafka ACL entry
 	///
 	/// https://api.aiven.io/doc/#operation/ServiceKafkaAclAdd
@@ -80,11 +210,13 @@ impl ServiceKafkaApi {
 			service_name = encode_param(service_name)
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Check compatibility of schema in Schema Registry
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistryCompatibility
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -132,11 +264,13 @@ impl ServiceKafkaApi {
 			version_id = encode_param(version_id),
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Create a Kafka Connect connector
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectCreateConnector
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -173,11 +307,92 @@ impl ServiceKafkaApi {
 			service_name = encode_param(service_name),
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// Create a Kafka Connect connector and wait until it and all of its
+	/// tasks are `RUNNING`.
+	///
+	/// Fails with `AivenError::ConnectorFailed`, carrying the task's stack
+	/// trace, as soon as the connector or one of its tasks is `FAILED`.
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `opts` - How often and how long to poll the connector status
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::poll::PollOpts;
+	/// use serde_json::json;
+	/// use std::time::Duration;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let json_body = json!({
+	///         "connector.class": "io.aiven.kafka.connect.s3.AivenKafkaConnectS3SinkConnector",
+	///         "name": "s3-sink-us-east-1"
+	/// });
+	/// let opts = PollOpts::default()
+	///     .interval(Duration::from_secs(5))
+	///     .max_attempts(24);
+	/// let status = client
+	///             .service_kafka()
+	///             .create_kafka_connector_and_wait("myproject", "myservicename", &json_body, &opts)
+	///             .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_kafka_connector_and_wait<T: Serialize + ?Sized>(
+		&self,
+		project: &str,
+		service_name: &str,
+		json_body: &T,
+		opts: &PollOpts,
+	) -> Result<ConnectorStatus, AivenError> {
+		let connector = self
+			.create_kafka_connector(project, service_name, json_body)
+			.await?
+			.connector;
+		let name = connector.name.as_str();
+		poll::until(
+			|| async move {
+				let status = self
+					.get_kafka_connect_status(project, service_name, name)
+					.await?
+					.status;
+				if let Some(task) = status.failed_tasks().next() {
+					return Err(AivenError::ConnectorFailed {
+						connector: name.to_string(),
+						trace: task.trace.clone().unwrap_or_default(),
+					});
+				}
+				if status.state == "FAILED" {
+					return Err(AivenError::ConnectorFailed {
+						connector: name.to_string(),
+						trace: String::new(),
+					});
+				}
+				let running = status.state == "RUNNING"
+					&& !status.tasks.is_empty()
+					&& status.tasks.iter().all(|t| t.state == TaskState::Running);
+				Ok(Some(status).filter(|_| running))
+			},
+			opts,
+		)
+		.await
 	}
 
 	/// Create a Kafka topic
 	///
+	/// Returns `Completion::Accepted` when the API answers `202 Accepted`
+	/// and creates the topic in the background.
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaTopicCreate
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -212,18 +427,60 @@ impl ServiceKafkaApi {
 		project: &str,
 		service_name: &str,
 		json_body: &T,
-	) -> Result<(), AivenError> {
+	) -> Result<Completion<()>, AivenError> {
 		let url = format!(
 			"project/{project}/service/{service_name}/topic",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
 		);
-		let _response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(())
+		make_json_request!(self, reqwest::Method::POST, &url, json_body)?
+			.completion(())
+			.await
+	}
+
+	/// Create a Kafka topic from a typed request
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaTopicCreate
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `request` - Topic settings
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_kafka::{CleanupPolicy, KafkaTopicCreateRequest};
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let request = KafkaTopicCreateRequest::new("mytopic")
+	///         .partitions(3)
+	///         .replication(2)
+	///         .cleanup_policy(CleanupPolicy::Compact);
+	/// client
+	///         .service_kafka()
+	///         .create_kafka_topic_with("myproject", "myservicename", &request)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create_kafka_topic_with(
+		&self,
+		project: &str,
+		service_name: &str,
+		request: &KafkaTopicCreateRequest,
+	) -> Result<Completion<()>, AivenError> {
+		self.create_kafka_topic(project, service_name, request)
+			.await
 	}
 
 	/// Delete kafka connect connector.
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectDeleteConnector
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -263,6 +520,8 @@ impl ServiceKafkaApi {
 
 	/// Delete Schema Registry subject version
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectVersionDelete
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -310,6 +569,8 @@ impl ServiceKafkaApi {
 
 	/// Delete Schema Registry subject
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectDelete
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -347,8 +608,132 @@ impl ServiceKafkaApi {
 		Ok(())
 	}
 
+	/// Delete all schema registry subjects whose name matches a pattern
+	///
+	/// The subjects are listed once and filtered with `pattern`. A
+	/// `DeleteMode::DryRun` stops there and returns the plan in `matched`,
+	/// otherwise the subjects are deleted with bounded concurrency and
+	/// failures are collected instead of aborting the remaining deletions.
+	///
+	/// Every deletion is a single request, so dropping the returned future
+	/// leaves each subject either deleted or untouched. Calling it again
+	/// resumes, as deleted subjects are no longer listed. Once `deadline`
+	/// expires no further deletions are started and the call fails with
+	/// `AivenError::DeadlineExceeded`, counting the deleted subjects.
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `pattern` - Regular expression matched against the subject names
+	/// * `mode` - Dry run or delete
+	/// * `deadline` - Stop deleting after this, or `Deadline::none()`
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::{deadline::Deadline, service::types_kafka::DeleteMode};
+	/// use std::time::Duration;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let plan = client
+	///             .service_kafka()
+	///             .delete_subjects_matching(
+	///                 "myproject",
+	///                 "myservicename",
+	///                 "^stale-.*-value$",
+	///                 DeleteMode::DryRun,
+	///                 Deadline::none(),
+	///             )
+	///             .await?;
+	/// println!("would delete {:?}", plan.matched);
+	/// let result = client
+	///             .service_kafka()
+	///             .delete_subjects_matching(
+	///                 "myproject",
+	///                 "myservicename",
+	///                 "^stale-.*-value$",
+	///                 DeleteMode::Delete { concurrency: 8 },
+	///                 Deadline::after(Duration::from_secs(300)),
+	///             )
+	///             .await?;
+	/// println!("deleted {}, failed {}", result.deleted.len(), result.failed.len());
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn delete_subjects_matching(
+		&self,
+		project: &str,
+		service_name: &str,
+		pattern: &str,
+		mode: DeleteMode,
+		deadline: Deadline,
+	) -> Result<SubjectDeletion, AivenError> {
+		let pattern = Regex::new(pattern).map_err(|e| AivenError::InvalidInput {
+			field: "pattern".to_string(),
+			reason: e.to_string(),
+		})?;
+		deadline.check(0)?;
+		let matched: Vec<String> = deadline
+			.run(0, self.list_schema_registry_subjects(project, service_name))
+			.await?
+			.subjects
+			.into_iter()
+			.filter(|subject| pattern.is_match(subject))
+			.collect();
+
+		let concurrency = match mode {
+			DeleteMode::DryRun => {
+				return Ok(SubjectDeletion {
+					matched,
+					..Default::default()
+				})
+			}
+			DeleteMode::Delete { concurrency } => concurrency.max(1),
+		};
+
+		// Started deletions run to the end, expiry only skips the rest
+		let results: Vec<_> = stream::iter(matched.iter())
+			.map(|subject| async move {
+				if deadline.is_expired() {
+					return (subject.clone(), None);
+				}
+				let result = self
+					.delete_schema_registry_subject(project, service_name, subject)
+					.await;
+				(subject.clone(), Some(result))
+			})
+			.buffer_unordered(concurrency)
+			.collect()
+			.await;
+
+		let mut deletion = SubjectDeletion {
+			matched,
+			..Default::default()
+		};
+		let mut skipped = false;
+		for (subject, result) in results {
+			match result {
+				Some(Ok(())) => deletion.deleted.push(subject),
+				Some(Err(e)) => {
+					error!("Failed to delete subject {}: {}", subject, e);
+					deletion.failed.push((subject, e));
+				}
+				None => skipped = true,
+			}
+		}
+		if skipped {
+			return Err(deadline.exceeded(deletion.deleted.len()));
+		}
+		Ok(deletion)
+	}
+
 	/// Delete a Kafka ACL entry
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaAclDelete
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -388,6 +773,10 @@ impl ServiceKafkaApi {
 
 	/// Delete a Kafka topic
 	///
+	/// `Completion::Accepted` means the topic is still being removed.
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaTopicDelete
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -414,19 +803,22 @@ impl ServiceKafkaApi {
 		project: &str,
 		service_name: &str,
 		topic_name: &str,
-	) -> Result<(), AivenError> {
+	) -> Result<Completion<()>, AivenError> {
 		let url = format!(
 			"project/{project}/service/{service_name}/topic/{topic_name}",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
 			topic_name = encode_param(topic_name),
 		);
-		let _response = make_request!(self, reqwest::Method::DELETE, &url)?;
-		Ok(())
+		make_request!(self, reqwest::Method::DELETE, &url)?
+			.completion(())
+			.await
 	}
 
 	/// Edit Kafka Connect connector.
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectEditConnector
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -466,11 +858,13 @@ impl ServiceKafkaApi {
 			connector_name = encode_param(connector_name),
 		);
 		let response = make_json_request!(self, reqwest::Method::PUT, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Edit configuration for Schema Registry subject.
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectConfigPut
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -511,11 +905,13 @@ impl ServiceKafkaApi {
 			subject_name = encode_param(subject_name),
 		);
 		let _response = make_json_request!(self, reqwest::Method::PUT, &url, json_body)?;
-		Ok(_response.json().await?)
+		_response.json().await
 	}
 
 	/// Edit global configuration for Schema Registry
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistryGlobalConfigPut
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -548,11 +944,13 @@ impl ServiceKafkaApi {
 			service_name = encode_param(service_name),
 		);
 		let _response = make_json_request!(self, reqwest::Method::PUT, &url, json_body)?;
-		Ok(_response.json().await?)
+		_response.json().await
 	}
 
 	/// Get Kafka Connect connector configuration schema
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectGetConnectorConfiguration
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -591,11 +989,13 @@ impl ServiceKafkaApi {
 			connector_name = encode_param(connector_name),
 		);
 		let _response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(_response.json().await?)
+		_response.json().await
 	}
 
 	/// Get Kafka topic info
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaTopicGet
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -630,11 +1030,13 @@ impl ServiceKafkaApi {
 			topic_name = encode_param(topic_name),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Get Kafka topic list
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaTopicList
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -666,11 +1068,13 @@ impl ServiceKafkaApi {
 			service_name = encode_param(service_name),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Get Schema Registry Subject version
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectVersionGet
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -719,6 +1123,8 @@ impl ServiceKafkaApi {
 
 	/// Get Schema Registry Subject versions
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectVersionsGet
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -754,54 +1160,74 @@ impl ServiceKafkaApi {
 			subject_name = encode_param(subject_name),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
-	/// Get a Kafka Connect Connector status
+	/// Get all versions of a Schema Registry subject with the id and type of
+	/// their schema, oldest version first.
+	///
+	/// The versions are fetched concurrently, at most four at a time.
 	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
 	/// * `service_name` - Service name
-	/// * `connector_name` - Connector name
+	/// * `subject_name` - Subject name
 	///
 	/// # Examples
 	/// Basic usage:
 	///
 	/// ```rust,no_run
-	/// use serde_json::json;
 	/// #[tokio::main]
 	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
 	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
-	/// let response = client
+	/// let versions = client
 	///             .service_kafka()
-	///             .get_kafka_connect_status("myproject", "myservicename", "myconnector")
+	///             .get_schema_registry_subject_version_details("myproject", "myservicename", "mysubject")
 	///             .await?;
+	/// for version in versions {
+	///     println!("v{} id={} {:?}", version.version, version.id, version.schema_type);
+	/// }
 	/// Ok(())
 	/// }
 	/// ```
-	pub async fn get_kafka_connect_status(
+	pub async fn get_schema_registry_subject_version_details(
 		&self,
 		project: &str,
 		service_name: &str,
-		connector_name: &str,
-	) -> Result<ResKafkaConnectConnectorStatus, AivenError> {
-		let url = format!(
-			"project/{project}/service/{service_name}/connectors/{connector_name}/status",
-			project = encode_param(project),
-			service_name = encode_param(service_name),
-			connector_name = encode_param(connector_name),
-		);
-		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		subject_name: &str,
+	) -> Result<Vec<SchemaRegistrySubjectVersion>, AivenError> {
+		let mut versions = self
+			.get_schema_registry_subject_versions(project, service_name, subject_name)
+			.await?
+			.versions;
+		versions.sort_unstable();
+		stream::iter(versions)
+			.map(|version| async move {
+				let version = self
+					.get_schema_in_schema_registry_by_version(
+						project,
+						service_name,
+						subject_name,
+						&version.to_string(),
+					)
+					.await?;
+				Ok(version.version)
+			})
+			.buffered(SCHEMA_VERSION_CONCURRENCY)
+			.try_collect()
+			.await
 	}
 
-	/// Get available Kafka Connect connectors
+	/// Get a Kafka Connect Connector status
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectGetConnectorStatus
 	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
 	/// * `service_name` - Service name
+	/// * `connector_name` - Connector name
 	///
 	/// # Examples
 	/// Basic usage:
@@ -813,142 +1239,322 @@ impl ServiceKafkaApi {
 	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
 	/// let response = client
 	///             .service_kafka()
-	///             .get_kafka_connect_connectors("myproject", "myservicename")
+	///             .get_kafka_connect_status("myproject", "myservicename", "myconnector")
 	///             .await?;
 	/// Ok(())
 	/// }
 	/// ```
-	pub async fn get_kafka_connect_connectors(
+	pub async fn get_kafka_connect_status(
 		&self,
 		project: &str,
 		service_name: &str,
-	) -> Result<ResKafkaConnectConnectorList, AivenError> {
+		connector_name: &str,
+	) -> Result<ResKafkaConnectConnectorStatus, AivenError> {
 		let url = format!(
-			"project/{project}/service/{service_name}/available-connectors",
+			"project/{project}/service/{service_name}/connectors/{connector_name}/status",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
+			connector_name = encode_param(connector_name),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
-	/// Get configuration for Schema Registry subject
+	/// Get the status of each task of a Kafka Connect connector, ordered by
+	/// task id
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectGetConnectorStatus
 	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
 	/// * `service_name` - Service name
-	/// * `subject_name` - Subject name
+	/// * `connector_name` - Connector name
 	///
 	/// # Examples
 	/// Basic usage:
 	///
 	/// ```rust,no_run
-	/// use serde_json::json;
 	/// #[tokio::main]
 	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
 	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
-	/// let response = client
+	/// let tasks = client
 	///             .service_kafka()
-	///             .get_config_schema_registry("myproject", "myservicename", "mysubject")
+	///             .get_kafka_connector_tasks("myproject", "myservicename", "myconnector")
 	///             .await?;
+	/// for task in tasks {
+	///     println!("{} {:?} {:?}", task.id, task.state, task.worker_id);
+	/// }
 	/// Ok(())
 	/// }
 	/// ```
-	pub async fn get_config_schema_registry(
+	pub async fn get_kafka_connector_tasks(
 		&self,
 		project: &str,
 		service_name: &str,
-		subject_name: &str,
-	) -> Result<ResKafkaSchemaRegistryConfig, AivenError> {
-		let url = format!(
-			"project/{project}/service/{service_name}/kafka/schema/config/{subject_name}",
-			project = encode_param(project),
-			service_name = encode_param(service_name),
-			subject_name = encode_param(subject_name),
-		);
-		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		connector_name: &str,
+	) -> Result<Vec<ConnectTask>, AivenError> {
+		let mut tasks = self
+			.get_kafka_connect_status(project, service_name, connector_name)
+			.await?
+			.status
+			.tasks;
+		tasks.sort_by_key(|task| task.id);
+		Ok(tasks)
 	}
 
-	/// Get configuration for Schema Registry subject
+	/// Get the topics a Kafka Connect connector has used
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectGetConnectorTopics
 	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
 	/// * `service_name` - Service name
-	/// * `subject_name` - Subject name
+	/// * `connector_name` - Connector name
 	///
 	/// # Examples
 	/// Basic usage:
 	///
 	/// ```rust,no_run
-	/// use serde_json::json;
 	/// #[tokio::main]
 	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
 	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
 	/// let response = client
 	///             .service_kafka()
-	///             .get_config_schema_registry_global("myproject", "myservicename")
+	///             .get_kafka_connector_topics("myproject", "myservicename", "myconnector")
 	///             .await?;
+	/// println!("{:?}", response.topics);
 	/// Ok(())
 	/// }
 	/// ```
-	pub async fn get_config_schema_registry_global(
+	pub async fn get_kafka_connector_topics(
 		&self,
 		project: &str,
 		service_name: &str,
-	) -> Result<ResKafkaSchemaRegistryConfig, AivenError> {
+		connector_name: &str,
+	) -> Result<ConnectorTopics, AivenError> {
 		let url = format!(
-			"project/{project}/service/{service_name}/kafka/schema/config",
+			"project/{project}/service/{service_name}/connectors/{connector_name}/topics",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
+			connector_name = encode_param(connector_name),
 		);
-		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		// The response is keyed by connector name, like the Connect REST API
+		let mut response: std::collections::HashMap<String, ConnectorTopics> =
+			make_request!(self, reqwest::Method::GET, &url)?
+				.json()
+				.await?;
+		Ok(response.remove(connector_name).unwrap_or_default())
 	}
 
-	/// Get schema in Schema Registry
+	/// Reset the set of topics a Kafka Connect connector has used. The
+	/// connector has to be stopped or paused first. The reset may finish in
+	/// the background, see `Completion::Accepted`.
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectResetConnectorTopics
 	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
 	/// * `service_name` - Service name
-	/// * `schema_id` - Schema Id
+	/// * `connector_name` - Connector name
 	///
 	/// # Examples
 	/// Basic usage:
 	///
 	/// ```rust,no_run
-	/// use serde_json::json;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///     .service_kafka()
+	///     .reset_connector_topics("myproject", "myservicename", "myconnector")
+	///     .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn reset_connector_topics(
+		&self,
+		project: &str,
+		service_name: &str,
+		connector_name: &str,
+	) -> Result<Completion<()>, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/connectors/{connector_name}/topics/reset",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+			connector_name = encode_param(connector_name),
+		);
+		make_request!(self, reqwest::Method::PUT, &url)?
+			.completion(())
+			.await
+	}
+
+	/// Get available Kafka Connect connectors
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectGetAvailableConnectors
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
 	/// #[tokio::main]
 	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
 	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
 	/// let response = client
 	///             .service_kafka()
-	///             .get_schema_in_schema_registry("myproject", "myservicename", "myconnector")
+	///             .get_kafka_connect_connectors("myproject", "myservicename")
 	///             .await?;
 	/// Ok(())
 	/// }
 	/// ```
+	pub async fn get_kafka_connect_connectors(
+		&self,
+		project: &str,
+		service_name: &str,
+	) -> Result<ResKafkaConnectConnectorList, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/available-connectors",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+		);
+		let response = make_request!(self, reqwest::Method::GET, &url)?;
+		response.json().await
+	}
+
+	/// Get configuration for Schema Registry subject
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectConfigGet
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `subject_name` - Subject name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///             .service_kafka()
+	///             .get_config_schema_registry("myproject", "myservicename", "mysubject")
+	///             .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn get_config_schema_registry(
+		&self,
+		project: &str,
+		service_name: &str,
+		subject_name: &str,
+	) -> Result<ResKafkaSchemaRegistryConfig, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/kafka/schema/config/{subject_name}",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+			subject_name = encode_param(subject_name),
+		);
+		let response = make_request!(self, reqwest::Method::GET, &url)?;
+		response.json().await
+	}
+
+	/// Get configuration for Schema Registry subject
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistryGlobalConfigGet
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `subject_name` - Subject name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///             .service_kafka()
+	///             .get_config_schema_registry_global("myproject", "myservicename")
+	///             .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn get_config_schema_registry_global(
+		&self,
+		project: &str,
+		service_name: &str,
+	) -> Result<ResKafkaSchemaRegistryConfig, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/kafka/schema/config",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+		);
+		let response = make_request!(self, reqwest::Method::GET, &url)?;
+		response.json().await
+	}
+
+	/// Get schema in Schema Registry
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySchemaGet
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `schema_id` - Schema Id
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///             .service_kafka()
+	///             .get_schema_in_schema_registry("myproject", "myservicename", "1")
+	///             .await?;
+	/// println!("{}", response.schema);
+	/// Ok(())
+	/// }
+	/// ```
 	pub async fn get_schema_in_schema_registry(
 		&self,
 		project: &str,
 		service_name: &str,
 		schema_id: &str,
-	) -> Result<(), AivenError> {
+	) -> Result<SchemaRegistrySchema, AivenError> {
 		let url = format!(
 			"project/{project}/service/{service_name}/kafka/schema/schemas/ids/{schema_id}",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
 			schema_id = encode_param(schema_id),
 		);
-		let _response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(())
+		let response = make_request!(self, reqwest::Method::GET, &url)?;
+		response.json().await
 	}
 
 	/// Get schema of a specific version in Schema Registry
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectVersionGet
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -969,9 +1575,10 @@ impl ServiceKafkaApi {
 	///             .get_schema_in_schema_registry_by_version(
 	///                 "myproject",
 	///                 "myservicename",
-	///                 "myconnector",
-	///                 "versionid")
+	///                 "mysubject",
+	///                 "latest")
 	///             .await?;
+	/// println!("{} {}", response.version.id, response.version.schema);
 	/// Ok(())
 	/// }
 	/// ```
@@ -981,33 +1588,36 @@ impl ServiceKafkaApi {
 		service_name: &str,
 		subject_name: &str,
 		version_id: &str,
-	) -> Result<(), AivenError> {
+	) -> Result<ResKafkaSchemaVersion, AivenError> {
 		let url = format!(
 			"project/{project}/service/{service_name}/kafka/schema/subjects/{subject_name}/\
-			 versions/{version_id}/schema",
+			 versions/{version_id}",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
 			subject_name = encode_param(subject_name),
 			version_id = encode_param(version_id),
 		);
-		let _response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(())
+		let response = make_request!(self, reqwest::Method::GET, &url)?;
+		response.json().await
 	}
 
 	/// List Kafka ACL entries
 	///
+	/// The endpoint is not paginated, all entries of the service are returned
+	/// in a single response. Use `list_all_acl_entries` when the listing must
+	/// be verified to be complete, e.g. for an audit.
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaAclList
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
 	/// * `service_name` - Service name
-	/// * `subject_name` - Subject name
-	/// * `version_id` - Version Id
 	///
 	/// # Examples
 	/// Basic usage:
 	///
 	/// ```rust,no_run
-	/// use serde_json::json;
 	/// #[tokio::main]
 	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
 	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
@@ -1029,7 +1639,242 @@ impl ServiceKafkaApi {
 			service_name = encode_param(service_name),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// List Schema Registry ACL entries
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistryAclList
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///             .service_kafka()
+	///             .list_schema_registry_acl_entries("myproject", "myservicename")
+	///             .await?;
+	/// for entry in response.acl {
+	///     println!("{} {:?} {}", entry.username, entry.permission, entry.resource);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_schema_registry_acl_entries(
+		&self,
+		project: &str,
+		service_name: &str,
+	) -> Result<ResSchemaRegistryAclEntries, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/kafka/schema-registry/acl",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+		);
+		let response = make_request!(self, reqwest::Method::GET, &url)?;
+		response.json().await
+	}
+
+	/// Add a Schema Registry ACL entry, returns all the entries of the
+	/// service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistryAclAdd
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `entry` - Entry to add, its `id` is ignored
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_kafka::{SchemaRegistryAclEntry, SchemaRegistryAclPermission};
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let entry = SchemaRegistryAclEntry::subject(
+	///     "orders-app",
+	///     "orders-*",
+	///     SchemaRegistryAclPermission::SchemaRegistryWrite,
+	/// );
+	/// let response = client
+	///             .service_kafka()
+	///             .add_schema_registry_acl_entry("myproject", "myservicename", &entry)
+	///             .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn add_schema_registry_acl_entry(
+		&self,
+		project: &str,
+		service_name: &str,
+		entry: &SchemaRegistryAclEntry,
+	) -> Result<ResSchemaRegistryAclEntries, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/kafka/schema-registry/acl",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+		);
+		let body = &SchemaRegistryAclEntry {
+			id: String::new(),
+			..entry.clone()
+		};
+		let response = make_json_request!(self, reqwest::Method::POST, &url, body)?;
+		response.json().await
+	}
+
+	/// Delete a Schema Registry ACL entry, returns the remaining entries of
+	/// the service
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistryAclDelete
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `schema_registry_acl_id` - Schema Registry ACL ID
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///             .service_kafka()
+	///             .delete_schema_registry_acl_entry("myproject", "myservicename", "acl2f1c3a5b6d7")
+	///             .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn delete_schema_registry_acl_entry(
+		&self,
+		project: &str,
+		service_name: &str,
+		schema_registry_acl_id: &str,
+	) -> Result<ResSchemaRegistryAclEntries, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/kafka/schema-registry/acl/{acl_id}",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+			acl_id = encode_param(schema_registry_acl_id),
+		);
+		let response = make_request!(self, reqwest::Method::DELETE, &url)?;
+		response.json().await
+	}
+
+	/// Move all ACL entries of a user over to a new username
+	///
+	/// Entries of `old_username` are first re-created for `new_username`,
+	/// skipping those which already exist, and only then removed. If creating
+	/// an entry fails the entries created so far are deleted again and the
+	/// error is returned. Failures while removing the old entries do not
+	/// abort, they are collected in `AclRenameReport::not_removed`.
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `old_username` - Username the entries are currently granted to
+	/// * `new_username` - Username to grant the entries to
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let report = client
+	///             .service_kafka()
+	///             .rename_acl_user("myproject", "myservicename", "billing", "billing-v2")
+	///             .await?;
+	/// for (acl, err) in &report.not_removed {
+	///     eprintln!("could not remove {}: {}", acl.id, err);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn rename_acl_user(
+		&self,
+		project: &str,
+		service_name: &str,
+		old_username: &str,
+		new_username: &str,
+	) -> Result<AclRenameReport, AivenError> {
+		let existing = self.list_acl_entries(project, service_name).await?.acl;
+		let mut known_ids: Vec<String> = existing.iter().map(|acl| acl.id.clone()).collect();
+		let mut report = AclRenameReport::default();
+
+		let old_entries: Vec<&KafkaACL> = existing
+			.iter()
+			.filter(|acl| acl.username == old_username)
+			.collect();
+		for old in &old_entries {
+			let already_granted = existing.iter().any(|acl| {
+				acl.username == new_username
+					&& acl.topic == old.topic
+					&& acl.permission == old.permission
+			});
+			if already_granted {
+				continue;
+			}
+			let json_body = &serde_json::json!({
+				"permission": old.permission,
+				"topic": old.topic,
+				"username": new_username,
+			});
+			match self
+				.add_kafka_acl_entry(project, service_name, json_body)
+				.await
+			{
+				Ok(response) => {
+					if let Some(created) = response.acl.into_iter().find(|acl| {
+						acl.username == new_username
+							&& acl.topic == old.topic
+							&& acl.permission == old.permission
+							&& !known_ids.contains(&acl.id)
+					}) {
+						known_ids.push(created.id.clone());
+						report.created.push(KafkaACL {
+							id: created.id,
+							permission: created.permission,
+							topic: created.topic,
+							username: created.username,
+						});
+					}
+				}
+				Err(e) => {
+					for created in &report.created {
+						if let Err(rollback) = self
+							.delete_acl_entry(project, service_name, &created.id)
+							.await
+						{
+							error!("Failed to roll back ACL entry {}: {}", created.id, rollback);
+						}
+					}
+					return Err(e);
+				}
+			}
+		}
+
+		for old in old_entries {
+			match self.delete_acl_entry(project, service_name, &old.id).await {
+				Ok(()) => report.removed.push(old.clone()),
+				Err(e) => report.not_removed.push((old.clone(), e.to_string())),
+			}
+		}
+		Ok(report)
 	}
 
 	/// List kafka topic messages
@@ -1071,14 +1916,25 @@ impl ServiceKafkaApi {
 		topic: &str,
 		json_body: &T,
 	) -> Result<ResKafkaMessages, AivenError> {
-		let url = format!(
-			"project/{project}/service/{service_name}/kafka/rest/topics/{topic}/messages",
-			project = encode_param(project),
-			service_name = encode_param(service_name),
-			topic = encode_param(topic),
-		);
+		let url = topic_messages_url(project, service_name, topic);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// `list_topic_messages`, retried like a `GET` since it only reads
+	pub(crate) async fn read_topic_messages<T: Serialize + ?Sized>(
+		&self,
+		project: &str,
+		service_name: &str,
+		topic: &str,
+		json_body: &T,
+	) -> Result<ResKafkaMessages, AivenError> {
+		let url = topic_messages_url(project, service_name, topic);
+		let response = self
+			.http_client
+			.execute_idempotent(reqwest::Method::POST, &url, Some(json_body))
+			.await?;
+		response.json().await
 	}
 	/// Lists Kafka connectors
 	///
@@ -1115,11 +1971,13 @@ impl ServiceKafkaApi {
 			service_name = encode_param(service_name),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Lists Schema Registry subjects
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjects
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -1151,11 +2009,13 @@ impl ServiceKafkaApi {
 			service_name = encode_param(service_name),
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Pause a Kafka Connect Connector
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectPauseConnector
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -1203,6 +2063,10 @@ impl ServiceKafkaApi {
 	/// * `service_name` - Service name
 	/// * `topic` - topic name
 	///
+	/// Bodies larger than `KAFKA_REST_MAX_BODY_BYTES` are rejected with
+	/// `AivenError::RequestBodyTooLarge` before sending; use `chunk_records`
+	/// to split large batches.
+	///
 	/// # Examples
 	/// Basic usage:
 	///
@@ -1246,12 +2110,21 @@ impl ServiceKafkaApi {
 			service_name = encode_param(service_name),
 			topic = encode_param(topic),
 		);
+		let size = json_size(json_body)?;
+		if size > KAFKA_REST_MAX_BODY_BYTES {
+			return Err(AivenError::RequestBodyTooLarge {
+				size,
+				limit: KAFKA_REST_MAX_BODY_BYTES,
+			});
+		}
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Register a new Schema in Schema Registry
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectVersionPost
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -1291,11 +2164,13 @@ impl ServiceKafkaApi {
 			subject_name = encode_param(subject_name),
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Restart a Kafka Connect Connector task
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectRestartConnectorTask
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -1343,6 +2218,8 @@ impl ServiceKafkaApi {
 
 	/// Restart a Kafka Connect Connector
 	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaConnectRestartConnector
+	///
 	/// # Arguments
 	///
 	/// * `project` -  Project name
@@ -1423,6 +2300,9 @@ impl ServiceKafkaApi {
 
 	/// Update a Kafka topic
 	///
+	/// The API may apply the change in the background, the result is then
+	/// `Completion::Accepted`.
+	///
 	/// https://api.aiven.io/doc/#operation/ServiceKafkaTopicUpdate
 	///
 	/// # Arguments
@@ -1459,23 +2339,213 @@ impl ServiceKafkaApi {
 		service_name: &str,
 		topic_name: &str,
 		json_body: &T,
-	) -> Result<(), AivenError> {
+	) -> Result<Completion<()>, AivenError> {
 		let url = format!(
 			"project/{project}/service/{service_name}/topic/{topic_name}",
 			project = encode_param(project),
 			service_name = encode_param(service_name),
 			topic_name = encode_param(topic_name),
 		);
-		let _response = make_json_request!(self, reqwest::Method::PUT, &url, json_body)?;
-		Ok(())
+		make_json_request!(self, reqwest::Method::PUT, &url, json_body)?
+			.completion(())
+			.await
+	}
+
+	/// Update a Kafka topic from a typed request
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaTopicUpdate
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `topic_name` - Topic name
+	/// * `request` - Settings to change
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_kafka::KafkaTopicUpdateRequest;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let request = KafkaTopicUpdateRequest::new().retention_hours(24);
+	/// client
+	///         .service_kafka()
+	///         .update_topic_with("myproject", "myservicename", "topic", &request)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn update_topic_with(
+		&self,
+		project: &str,
+		service_name: &str,
+		topic_name: &str,
+		request: &KafkaTopicUpdateRequest,
+	) -> Result<Completion<()>, AivenError> {
+		self.update_topic(project, service_name, topic_name, request)
+			.await
+	}
+
+	/// Change the replication factor of a topic. The factor is checked
+	/// against the number of brokers of the service first, a factor which
+	/// can't be satisfied fails with `AivenError::InvalidInput` without
+	/// touching the topic.
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceKafkaTopicUpdate
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	/// * `topic_name` - Topic name
+	/// * `factor` - New replication factor
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .service_kafka()
+	///         .set_replication("myproject", "myservicename", "topic", 3)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn set_replication(
+		&self,
+		project: &str,
+		service_name: &str,
+		topic_name: &str,
+		factor: u32,
+	) -> Result<Completion<()>, AivenError> {
+		let service = ServiceApi::new(self.http_client.clone())
+			.get_service_info(project, service_name)
+			.await?
+			.service;
+		check_replication(factor, &service)?;
+		let request = KafkaTopicUpdateRequest::new().replication(factor);
+		self.update_topic_with(project, service_name, topic_name, &request)
+			.await
+	}
+
+	/// List all Kafka ACL entries, verified to be complete
+	///
+	/// The listing is cross-checked against the ACL ids embedded in the
+	/// service info. Both are fetched again when they differ, e.g. because an
+	/// entry was added in between, and after three attempts
+	/// `AivenError::IncompleteListing` is returned instead of a possibly
+	/// truncated list.
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` - Service name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let entries = client
+	///             .service_kafka()
+	///             .list_all_acl_entries("myproject", "myservicename")
+	///             .await?;
+	/// for entry in entries {
+	///     println!("{} {} {}", entry.username, entry.permission, entry.topic);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_all_acl_entries(
+		&self,
+		project: &str,
+		service_name: &str,
+	) -> Result<Vec<KafkaACL>, AivenError> {
+		let service_api = ServiceApi::new(self.http_client.clone());
+		let (mut listed, mut expected) = (0, 0);
+		for _ in 0..ACL_LISTING_ATTEMPTS {
+			let entries = self.list_acl_entries(project, service_name).await?.acl;
+			let service = service_api
+				.get_service_info(project, service_name)
+				.await?
+				.service;
+			let service_acl = match service.acl {
+				Some(acl) => acl,
+				None => return Ok(entries),
+			};
+			if is_complete_listing(&entries, &service_acl) {
+				return Ok(entries);
+			}
+			listed = entries.len();
+			expected = service_acl.len();
+		}
+		Err(AivenError::IncompleteListing {
+			resource: format!("ACL entries of service `{}`", service_name),
+			listed,
+			expected,
+		})
 	}
 }
 
+/// The listed entries are unique and have exactly the ids of `service_acl`
+fn is_complete_listing(entries: &[KafkaACL], service_acl: &[ACL]) -> bool {
+	let listed: BTreeSet<&str> = entries.iter().map(|entry| entry.id.as_str()).collect();
+	let expected: BTreeSet<&str> = service_acl.iter().map(|entry| entry.id.as_str()).collect();
+	listed.len() == entries.len() && listed == expected
+}
+
+/// Check that `factor` replicas fit on the brokers of `service`
+fn check_replication(factor: u32, service: &Service) -> Result<(), AivenError> {
+	let brokers = service.node_count.max(0) as u32;
+	let reason = if factor == 0 {
+		"must be at least 1".to_owned()
+	} else if factor > brokers {
+		format!(
+			"{} exceeds the {} brokers of service `{}` on plan `{}`",
+			factor, brokers, service.service_name, service.plan
+		)
+	} else {
+		return Ok(());
+	};
+	Err(AivenError::InvalidInput {
+		field: "replication".into(),
+		reason,
+	})
+}
+
+fn topic_messages_url(project: &str, service_name: &str, topic: &str) -> String {
+	format!(
+		"project/{project}/service/{service_name}/kafka/rest/topics/{topic}/messages",
+		project = encode_param(project),
+		service_name = encode_param(service_name),
+		topic = encode_param(topic),
+	)
+}
+
 #[cfg(test)]
 mod tests {
 
-	use crate::testutil;
+	use crate::{
+		client::Completion,
+		deadline::Deadline,
+		errors::AivenError,
+		poll::PollOpts,
+		service::types_kafka::{
+			CleanupPolicy, DeleteMode, KafkaTopicCreateRequest, SchemaRegistryAclEntry,
+			SchemaRegistryAclPermission, TaskState, KAFKA_REST_MAX_BODY_BYTES,
+		},
+		testutil,
+	};
 	use serde_json::json;
+	use std::time::Duration;
 
 	#[tokio::test]
 	async fn test_service_kafka_add_kafka_acl_entry() {
@@ -1496,10 +2566,10 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.acl.len() > 0);
+				assert!(!response.acl.is_empty());
 				assert!(response.acl[0].id == "id-e11v3n")
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1529,7 +2599,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(!response.is_compatible),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1552,7 +2622,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.connector.name == "elastic-sink"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 	#[tokio::test]
@@ -1578,7 +2648,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1595,7 +2665,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1618,7 +2688,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1635,7 +2705,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1654,7 +2724,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 	#[tokio::test]
@@ -1671,7 +2741,30 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_delete_topic_accepted() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/acceptedproject/service/myservicename/topic/mytopic";
+		let _m = mockito::mock("DELETE", query_url)
+			.with_status(202)
+			.with_header("content-type", "application/json")
+			.with_body(r#"{"message": "Topic deletion scheduled"}"#)
+			.create();
+
+		match client
+			.service_kafka()
+			.delete_topic("acceptedproject", "myservicename", "mytopic")
+			.await
+		{
+			Ok(Completion::Accepted(operation)) => {
+				assert!(operation.location.is_none());
+				assert!(operation.message.as_deref() == Some("Topic deletion scheduled"));
+			}
+			other => assert!(false, "{:?}", other),
 		}
 	}
 
@@ -1692,7 +2785,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1712,7 +2805,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.compatibility == "FULL"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1731,7 +2824,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.compatibility == "FULL"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1751,10 +2844,10 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.configuration_schema.len() > 0);
+				assert!(!response.configuration_schema.is_empty());
 				assert!(response.configuration_schema[0].default_value == 1)
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1774,7 +2867,7 @@ mod tests {
 				assert!(response.topic.cleanup_policy == "delete");
 				assert!(response.topic.topic_name == "mytopic")
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1791,10 +2884,10 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.topics.len() > 0);
+				assert!(!response.topics.is_empty());
 				assert!(response.topics[0].topic_name == "mytopic");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1820,7 +2913,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1839,8 +2932,8 @@ mod tests {
 			.get_schema_registry_subject_versions("myproject", "myservicename", "mysubject")
 			.await
 		{
-			Ok(response) => assert!(response.versions.len() > 0),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Ok(response) => assert!(!response.versions.is_empty()),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1857,8 +2950,67 @@ mod tests {
 			.get_kafka_connect_status("myproject", "myservicename", "myconnector")
 			.await
 		{
-			Ok(response) => assert!(response.status.tasks.len() > 0),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Ok(response) => assert!(!response.status.tasks.is_empty()),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_get_kafka_connector_tasks() {
+		let client = testutil::prepare_test_client();
+		let query_url =
+			"/project/tasks-project/service/myservicename/connectors/myconnector/status";
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/kafka/connector_tasks_status.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		match client
+			.service_kafka()
+			.get_kafka_connector_tasks("tasks-project", "myservicename", "myconnector")
+			.await
+		{
+			Ok(tasks) => {
+				let ids: Vec<i32> = tasks.iter().map(|task| task.id).collect();
+				assert!(ids == vec![0, 1]);
+				assert!(tasks[1].state == TaskState::Failed);
+				assert!(tasks[0].worker_id.as_deref() == Some("10.0.0.1:8083"));
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_get_kafka_connector_topics() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/myproject/service/myservicename/connectors/myconnector/topics";
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/kafka/get_kafka_connector_topics.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		match client
+			.service_kafka()
+			.get_kafka_connector_topics("myproject", "myservicename", "myconnector")
+			.await
+		{
+			Ok(response) => assert!(response.topics == vec!["orders", "payments"]),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_reset_connector_topics() {
+		let client = testutil::prepare_test_client();
+		let query_url =
+			"/project/myproject/service/myservicename/connectors/myconnector/topics/reset";
+		let _m = testutil::create_mock_server(query_url, "", "PUT");
+
+		match client
+			.service_kafka()
+			.reset_connector_topics("myproject", "myservicename", "myconnector")
+			.await
+		{
+			Ok(_) => {}
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1877,10 +3029,10 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.plugins.len() > 0);
+				assert!(!response.plugins.is_empty());
 				assert!(response.plugins[0].author == "Debezium")
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1897,7 +3049,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.compatibility_level == "FULL"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1914,36 +3066,85 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.compatibility_level == "FULL"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
-	#[ignore]
 	#[tokio::test]
 	async fn test_service_kafka_get_schema_in_schema_registry() {
 		let client = testutil::prepare_test_client();
-		let query_url =
-			"/project/myproject/service/myservicename/kafka/schema/schemas/ids/myschemaid";
-		let test_data = "".to_string();
+		let query_url = "/project/myproject/service/myservicename/kafka/schema/schemas/ids/1";
+		let test_data = testutil::get_test_data(
+			"tests/testdata/service/kafka/get_schema_in_schema_registry.json",
+		);
 		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
 
 		match client
 			.service_kafka()
-			.get_schema_in_schema_registry("myproject", "myservicename", "myschemaid")
+			.get_schema_in_schema_registry("myproject", "myservicename", "1")
 			.await
 		{
-			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Ok(response) => {
+				assert!(response.schema.contains("\"name\":\"Order\""));
+				assert!(response.schema_type.is_none());
+			}
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
-	#[ignore]
+	#[tokio::test]
+	async fn test_service_kafka_get_schema_registry_subject_version_details() {
+		let client = testutil::prepare_test_client();
+		let base =
+			"/project/catalogproject/service/myservicename/kafka/schema/subjects/orders-value/\
+		            versions";
+		let _versions = testutil::create_mock_server(base, r#"{"versions": [2, 1]}"#, "GET");
+		let version_mocks: Vec<mockito::Mock> = [(1, 3, None), (2, 7, Some("JSON"))]
+			.iter()
+			.map(|(version, id, schema_type)| {
+				let body = json!({"version": {
+					"id": id,
+					"schema": "{}",
+					"schemaType": schema_type,
+					"subject": "orders-value",
+					"version": version,
+				}});
+				testutil::create_mock_server(
+					&format!("{}/{}", base, version),
+					&body.to_string(),
+					"GET",
+				)
+			})
+			.collect();
+
+		match client
+			.service_kafka()
+			.get_schema_registry_subject_version_details(
+				"catalogproject",
+				"myservicename",
+				"orders-value",
+			)
+			.await
+		{
+			Ok(details) => {
+				let ids: Vec<(i32, i32)> = details.iter().map(|d| (d.version, d.id)).collect();
+				assert!(ids == vec![(1, 3), (2, 7)]);
+				assert!(details[0].schema_type.is_none());
+				assert!(details[1].schema_type.as_deref() == Some("JSON"));
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		drop(version_mocks);
+	}
+
 	#[tokio::test]
 	async fn test_service_kafka_get_schema_in_schema_registry_by_version() {
 		let client = testutil::prepare_test_client();
 		let query_url = "/project/myproject/service/myservicename/kafka/schema/subjects/\
-		                 mysubjectname/versions/myversionid/schema";
-		let test_data = "".to_string();
+		                 orders-value/versions/2";
+		let test_data = testutil::get_test_data(
+			"tests/testdata/service/kafka/get_schema_in_schema_registry_by_version.json",
+		);
 		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
 
 		match client
@@ -1951,13 +3152,19 @@ mod tests {
 			.get_schema_in_schema_registry_by_version(
 				"myproject",
 				"myservicename",
-				"mysubjectname",
-				"myversionid",
+				"orders-value",
+				"2",
 			)
 			.await
 		{
-			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Ok(response) => {
+				let version = response.version;
+				assert!(version.id == 7);
+				assert!(version.subject == "orders-value");
+				assert!(version.version == 2);
+				assert!(version.schema_type.as_deref() == Some("JSON"));
+			}
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -1974,8 +3181,39 @@ mod tests {
 			.list_acl_entries("myproject", "myservicename")
 			.await
 		{
-			Ok(response) => assert!(response.acl.len() > 0),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Ok(response) => assert!(!response.acl.is_empty()),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_rename_acl_user() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/aclrenameproject/service/mykafka/acl";
+		let list_data =
+			testutil::get_test_data("tests/testdata/service/kafka/rename_acl_list.json");
+		let add_data = testutil::get_test_data("tests/testdata/service/kafka/rename_acl_add.json");
+		let _list = testutil::create_mock_server(query_url, &list_data, "GET");
+		let _add = testutil::create_mock_server(query_url, &add_data, "POST");
+		let _del_orders = testutil::create_mock_server(
+			"/project/aclrenameproject/service/mykafka/acl/acl-old-orders",
+			"",
+			"DELETE",
+		);
+
+		match client
+			.service_kafka()
+			.rename_acl_user("aclrenameproject", "mykafka", "billing", "billing-v2")
+			.await
+		{
+			Ok(report) => {
+				let created: Vec<_> = report.created.iter().map(|acl| acl.id.as_str()).collect();
+				assert!(created == vec!["acl-new-orders", "acl-new-invoices"]);
+				assert!(report.removed.len() == 1 && report.removed[0].id == "acl-old-orders");
+				assert!(!report.is_complete());
+				assert!(report.not_removed[0].0.id == "acl-old-invoices");
+			}
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -2001,10 +3239,10 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.messages.len() > 0);
+				assert!(!response.messages.is_empty());
 				assert!(response.messages[0].offset == 10);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -2022,10 +3260,10 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.connectors.len() > 0);
+				assert!(!response.connectors.is_empty());
 				assert!(response.connectors[0].name == "elastic-sink");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 	#[tokio::test]
@@ -2043,10 +3281,10 @@ mod tests {
 			.await
 		{
 			Ok(response) => {
-				assert!(response.subjects.len() > 0);
+				assert!(!response.subjects.is_empty());
 				assert!(response.subjects[0] == "topic1-values");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -2064,7 +3302,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -2099,9 +3337,84 @@ mod tests {
 		{
 			Ok(response) => {
 				assert!(response.key_schema_id == 1);
-				assert!(response.offsets.len() > 0);
+				assert!(!response.offsets.is_empty());
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_produce_message_too_large() {
+		let client = testutil::prepare_test_client();
+		let json_body = json!({
+			"format": "json",
+			"records": [{ "value": "x".repeat(KAFKA_REST_MAX_BODY_BYTES) }],
+		});
+		match client
+			.service_kafka()
+			.produce_message("myproject", "myservicename", "mytopic", &json_body)
+			.await
+		{
+			Err(AivenError::RequestBodyTooLarge { size, limit }) => {
+				assert!(size > limit && limit == KAFKA_REST_MAX_BODY_BYTES)
+			}
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_create_kafka_connector_and_wait() {
+		let client = testutil::prepare_test_client();
+		let create_data =
+			testutil::get_test_data("tests/testdata/service/kafka/create_kafka_connector.json");
+		let running =
+			testutil::get_test_data("tests/testdata/service/kafka/get_kafka_connect_status.json");
+		let failed =
+			testutil::get_test_data("tests/testdata/service/kafka/connector_status_failed.json");
+		let json_body = json!({ "name": "elastic-sink" });
+		let opts = PollOpts::default()
+			.interval(std::time::Duration::from_millis(1))
+			.max_attempts(3);
+
+		let _create = testutil::create_mock_server(
+			"/project/connectwaitproject/service/mykafka/connectors",
+			&create_data,
+			"POST",
+		);
+		let _status = testutil::create_mock_server(
+			"/project/connectwaitproject/service/mykafka/connectors/elastic-sink/status",
+			&running,
+			"GET",
+		);
+		match client
+			.service_kafka()
+			.create_kafka_connector_and_wait("connectwaitproject", "mykafka", &json_body, &opts)
+			.await
+		{
+			Ok(status) => assert!(status.state == "RUNNING"),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+
+		let _create = testutil::create_mock_server(
+			"/project/connectfailproject/service/mykafka/connectors",
+			&create_data,
+			"POST",
+		);
+		let _status = testutil::create_mock_server(
+			"/project/connectfailproject/service/mykafka/connectors/elastic-sink/status",
+			&failed,
+			"GET",
+		);
+		match client
+			.service_kafka()
+			.create_kafka_connector_and_wait("connectfailproject", "mykafka", &json_body, &opts)
+			.await
+		{
+			Err(AivenError::ConnectorFailed { connector, trace }) => {
+				assert!(connector == "elastic-sink");
+				assert!(trace.contains("does not exist"));
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			other => assert!(false, "{:?}", other),
 		}
 	}
 
@@ -2124,7 +3437,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.id == 1),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -2141,7 +3454,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -2164,7 +3477,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -2181,8 +3494,136 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_create_kafka_topic_with() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/typedtopicproject/service/myservicename/topic";
+		let _m = mockito::mock("POST", query_url)
+			.match_body(mockito::Matcher::Json(json!({
+				"topic_name": "orders",
+				"partitions": 3,
+				"replication": 2,
+				"cleanup_policy": "compact",
+				"config": {"max_message_bytes": 2097152}
+			})))
+			.with_status(200)
+			.with_header("content-type", "application/json")
+			.with_body("{}")
+			.create();
+
+		let request = KafkaTopicCreateRequest::new("orders")
+			.partitions(3)
+			.replication(2)
+			.cleanup_policy(CleanupPolicy::Compact)
+			.config("max_message_bytes", 2097152);
+		match client
+			.service_kafka()
+			.create_kafka_topic_with("typedtopicproject", "myservicename", &request)
+			.await
+		{
+			Ok(_) => assert!(true),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_delete_subjects_matching() {
+		let client = testutil::prepare_test_client();
+		let base = "/project/subjectcleanupproject/service/myservicename/kafka/schema/subjects";
+		let subjects = r#"{"subjects": ["stale-a-value", "stale-b-value", "orders-value"]}"#;
+		let _list = testutil::create_mock_server(base, subjects, "GET");
+		let _a = testutil::create_mock_server(&format!("{}/stale-a-value", base), "{}", "DELETE");
+		let _b = mockito::mock("DELETE", format!("{}/stale-b-value", base).as_str())
+			.with_status(404)
+			.with_header("content-type", "application/json")
+			.with_body(r#"{"message": "Subject not found"}"#)
+			.create();
+		let _orders = mockito::mock("DELETE", format!("{}/orders-value", base).as_str())
+			.expect(0)
+			.create();
+
+		let kafka = client.service_kafka();
+		match kafka
+			.delete_subjects_matching(
+				"subjectcleanupproject",
+				"myservicename",
+				"^stale-.*-value$",
+				DeleteMode::DryRun,
+				Deadline::none(),
+			)
+			.await
+		{
+			Ok(plan) => {
+				assert!(plan.matched == vec!["stale-a-value", "stale-b-value"]);
+				assert!(plan.deleted.is_empty());
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+
+		match kafka
+			.delete_subjects_matching(
+				"subjectcleanupproject",
+				"myservicename",
+				"^stale-.*-value$",
+				DeleteMode::Delete { concurrency: 2 },
+				Deadline::none(),
+			)
+			.await
+		{
+			Ok(result) => {
+				assert!(result.deleted == vec!["stale-a-value"]);
+				assert!(result.failed.len() == 1);
+				assert!(result.failed[0].0 == "stale-b-value");
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		_orders.assert();
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_delete_subjects_matching_invalid_pattern() {
+		let client = testutil::prepare_test_client();
+		match client
+			.service_kafka()
+			.delete_subjects_matching(
+				"myproject",
+				"myservicename",
+				"(",
+				DeleteMode::DryRun,
+				Deadline::none(),
+			)
+			.await
+		{
+			Err(AivenError::InvalidInput { field, .. }) => assert!(field == "pattern"),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_delete_subjects_matching_deadline() {
+		let client = testutil::prepare_test_client();
+		let base = "/project/subjectdeadlineproject/service/myservicename/kafka/schema/subjects";
+		let list = mockito::mock("GET", base).expect(0).create();
+
+		match client
+			.service_kafka()
+			.delete_subjects_matching(
+				"subjectdeadlineproject",
+				"myservicename",
+				"^stale-",
+				DeleteMode::Delete { concurrency: 2 },
+				Deadline::after(Duration::from_secs(0)),
+			)
+			.await
+		{
+			Err(AivenError::DeadlineExceeded { completed, .. }) => assert!(completed == 0),
+			other => assert!(false, "{:?}", other),
 		}
+		list.assert();
 	}
 
 	#[tokio::test]
@@ -2205,7 +3646,189 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_schema_registry_acl_entries() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/sraclproject/service/mykafka/kafka/schema-registry/acl";
+		let list_data = testutil::get_test_data(
+			"tests/testdata/service/kafka/list_schema_registry_acl_entries.json",
+		);
+		let _list = testutil::create_mock_server(query_url, &list_data, "GET");
+		let add = mockito::mock("POST", query_url)
+			.match_body(mockito::Matcher::Json(json!({
+				"permission": "schema_registry_read",
+				"resource": "Subject:orders-*",
+				"username": "orders-app"
+			})))
+			.with_status(200)
+			.with_body(&list_data)
+			.create();
+		let delete_data = testutil::get_test_data(
+			"tests/testdata/service/kafka/delete_schema_registry_acl_entry.json",
+		);
+		let _delete = testutil::create_mock_server(
+			&format!("{}/acl2f1c3a5b6d7", query_url),
+			&delete_data,
+			"DELETE",
+		);
+
+		let kafka = client.service_kafka();
+		match kafka
+			.list_schema_registry_acl_entries("sraclproject", "mykafka")
+			.await
+		{
+			Ok(response) => {
+				assert!(response.acl.len() == 3);
+				assert!(response.acl[0].resource == "Config:");
+				assert!(
+					response.acl[2].permission == SchemaRegistryAclPermission::SchemaRegistryRead
+				);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+
+		let entry = SchemaRegistryAclEntry::subject(
+			"orders-app",
+			"orders-*",
+			SchemaRegistryAclPermission::SchemaRegistryRead,
+		);
+		match kafka
+			.add_schema_registry_acl_entry("sraclproject", "mykafka", &entry)
+			.await
+		{
+			Ok(response) => {
+				assert!(response.acl.iter().any(|acl| acl.id == "acl2f1c3a5b6d7"));
+				add.assert();
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+
+		match kafka
+			.delete_schema_registry_acl_entry("sraclproject", "mykafka", "acl2f1c3a5b6d7")
+			.await
+		{
+			Ok(response) => assert!(response.acl.len() == 2),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_set_replication() {
+		let client = testutil::prepare_test_client();
+		let service_data =
+			testutil::get_test_data("tests/testdata/service/service/get_service_info.json");
+		let _service = testutil::create_mock_server(
+			"/project/replproject/service/mykafka",
+			&service_data,
+			"GET",
+		);
+		let update = mockito::mock("PUT", "/project/replproject/service/mykafka/topic/orders")
+			.match_body(mockito::Matcher::Json(json!({"replication": 2})))
+			.with_status(200)
+			.with_body("{}")
+			.expect(1)
+			.create();
+
+		match client
+			.service_kafka()
+			.set_replication("replproject", "mykafka", "orders", 3)
+			.await
+		{
+			Err(AivenError::InvalidInput { field, reason }) => {
+				assert!(field == "replication");
+				assert!(reason.contains("exceeds the 2 brokers"), "{}", reason);
+			}
+			other => assert!(false, "{:?}", other),
+		}
+		match client
+			.service_kafka()
+			.set_replication("replproject", "mykafka", "orders", 2)
+			.await
+		{
+			Ok(_) => update.assert(),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	fn acl_entries(count: usize) -> Vec<serde_json::Value> {
+		(0..count)
+			.map(|i| {
+				json!({
+					"id": format!("acl{:05}", i),
+					"permission": "read",
+					"topic": format!("topic-{}", i),
+					"username": "auditor"
+				})
+			})
+			.collect()
+	}
+
+	fn service_with_acl(count: usize) -> String {
+		let data = testutil::get_test_data("tests/testdata/service/service/get_service_info.json");
+		let mut service: serde_json::Value = serde_json::from_str(&data).unwrap();
+		service["service"]["acl"] = json!(acl_entries(count));
+		service.to_string()
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_list_all_acl_entries() {
+		let client = testutil::prepare_test_client();
+		let _service = testutil::create_mock_server(
+			"/project/aclauditproject/service/mykafka",
+			&service_with_acl(1500),
+			"GET",
+		);
+		let listing = json!({ "acl": acl_entries(1500) }).to_string();
+		let _acl = testutil::create_mock_server(
+			"/project/aclauditproject/service/mykafka/acl",
+			&listing,
+			"GET",
+		);
+
+		match client
+			.service_kafka()
+			.list_all_acl_entries("aclauditproject", "mykafka")
+			.await
+		{
+			Ok(entries) => {
+				assert!(entries.len() == 1500);
+				assert!(entries[1499].id == "acl01499");
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_service_kafka_list_all_acl_entries_truncated() {
+		let client = testutil::prepare_test_client();
+		let _service = testutil::create_mock_server(
+			"/project/acltruncproject/service/mykafka",
+			&service_with_acl(1500),
+			"GET",
+		);
+		let listing = json!({ "acl": acl_entries(1000) }).to_string();
+		let acl = mockito::mock("GET", "/project/acltruncproject/service/mykafka/acl")
+			.with_status(200)
+			.with_body(listing)
+			.expect(3)
+			.create();
+
+		match client
+			.service_kafka()
+			.list_all_acl_entries("acltruncproject", "mykafka")
+			.await
+		{
+			Err(AivenError::IncompleteListing {
+				listed, expected, ..
+			}) => {
+				acl.assert();
+				assert!(listed == 1000 && expected == 1500);
+			}
+			other => assert!(false, "{:?}", other),
 		}
 	}
 }
@@ -118,7 +118,7 @@ impl ServiceKafkaMirrorMaker {
 			service_name = encode_param(service_name)
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete a replication flow
@@ -200,7 +200,7 @@ impl ServiceKafkaMirrorMaker {
 			target_cluster = encode_param(target_cluster)
 		);
 		let response = make_request!(self, reqwest::Method::GET, &url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Get a replication flow
@@ -243,7 +243,7 @@ impl ServiceKafkaMirrorMaker {
 			target_cluster = encode_param(target_cluster)
 		);
 		let response = make_json_request!(self, reqwest::Method::PUT, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 }
 
@@ -282,7 +282,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -307,7 +307,7 @@ mod tests {
 			Ok(response) => {
 				assert!(response.replication_flows[0].target_cluster == "target-cluster")
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -338,7 +338,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -368,7 +368,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.replication_flow.source_cluster == "source-cluster"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -411,7 +411,7 @@ mod tests {
 			.await
 		{
 			Ok(response) => assert!(response.replication_flow.source_cluster == "source-cluster"),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 }
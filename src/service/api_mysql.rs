@@ -24,7 +24,7 @@ use crate::{
 	client::{encode_param, HTTPClient},
 	errors::AivenError,
 	make_json_request,
-	service::types_mysql::*,
+	service::{types_mysql::*, types_service::QueryStatisticsRequest},
 };
 use serde::Serialize;
 
@@ -82,13 +82,58 @@ impl ServiceMysqlApi {
 			service_name = encode_param(service_name)
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// Fetch MySQL service query statistics as typed rows
+	///
+	/// https://api.aiven.io/doc/#operation/MySQLServiceQueryStatistics
+	///
+	/// # Arguments
+	///
+	/// * `project` -  Project name
+	/// * `service_name` -  Service name
+	/// * `request` -  Paging and ordering of the rows
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_service::QueryStatisticsRequest;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::new("https://api.aiven.io", "v1");
+	///
+	/// let request = QueryStatisticsRequest::default().limit(10).order_by("sum_timer_wait:desc");
+	/// let rows = client
+	///         .service_mysql()
+	///         .fetch_query_statistics("myproject", "mymysql", &request)
+	///         .await?;
+	/// for row in rows {
+	///     println!("{:>10.1} ms {:>8} calls  {}", row.total_time(), row.calls(), row.query());
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn fetch_query_statistics(
+		&self,
+		project: &str,
+		service_name: &str,
+		request: &QueryStatisticsRequest,
+	) -> Result<Vec<MySqlQueryStat>, AivenError> {
+		let url = format!(
+			"project/{project}/service/{service_name}/mysql/query/stats",
+			project = encode_param(project),
+			service_name = encode_param(service_name)
+		);
+		let response = make_json_request!(self, reqwest::Method::POST, &url, request)?;
+		Ok(response.json::<ResMySqlQueryStatRows>().await?.queries)
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::testutil;
+	use crate::{service::types_service::QueryStatisticsRequest, testutil};
 	use std::collections::HashMap;
 	#[tokio::test]
 	async fn test_mysql_fetch_query_stats() {
@@ -109,7 +154,40 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_mysql_fetch_query_statistics() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/mysqlquerystatsproject/service/mymysql/mysql/query/stats";
+		let test_data = testutil::get_test_data("tests/testdata/service/mysql/query_stats.json");
+		let _m = mockito::mock("POST", query_url)
+			.match_body(mockito::Matcher::Json(
+				serde_json::json!({"limit": 2, "order_by": "sum_timer_wait:desc"}),
+			))
+			.with_status(200)
+			.with_body(test_data)
+			.create();
+
+		let request = QueryStatisticsRequest::default()
+			.limit(2)
+			.order_by("sum_timer_wait:desc");
+		match client
+			.service_mysql()
+			.fetch_query_statistics("mysqlquerystatsproject", "mymysql", &request)
+			.await
+		{
+			Ok(rows) => {
+				assert!(rows.len() == 2);
+				assert!(rows[0].query() == "SELECT * FROM `orders` WHERE `id` = ?");
+				assert!(rows[0].calls() == 1500.0);
+				assert!(rows[0].total_time() == 1200.0);
+				assert!(rows[0].rows() == 1500.0);
+				assert!(rows[1].query() == "DELETE FROM sessions WHERE expires_at < NOW()");
+			}
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 }
@@ -23,7 +23,7 @@ use crate::{
 	client::{encode_param, HTTPClient},
 	errors::AivenError,
 	make_json_request, make_request,
-	service::types_postgres::*,
+	service::{types_postgres::*, types_service::QueryStatisticsRequest},
 };
 use serde::Serialize;
 use std::collections::HashMap;
@@ -167,7 +167,148 @@ impl ServicePostgresApi {
 			service_name = encode_param(service_name),
 		);
 		let response = make_json_request!(self, reqwest::Method::POST, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// Fetch PostgreSQL service query statistics as typed rows
+	///
+	/// https://api.aiven.io/doc/#operation/PGServiceQueryStatistics
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `service_name` - Service name
+	/// * `request` - Paging and ordering of the rows
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::service::types_service::QueryStatisticsRequest;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::new("https://api.aiven.io", "v1");
+	///
+	/// let request = QueryStatisticsRequest::default().limit(50).order_by("mean_time:desc");
+	/// let rows = client
+	///         .service_postgres()
+	///         .fetch_query_statistics("myproject", "mypg", &request)
+	///         .await?;
+	/// for row in rows {
+	///     println!("{:>8.1} ms {:>8} rows  {}", row.mean_time, row.rows, row.query);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn fetch_query_statistics(
+		&self,
+		project: &str,
+		service_name: &str,
+		request: &QueryStatisticsRequest,
+	) -> Result<Vec<PgQueryStat>, AivenError> {
+		let url = &format!(
+			"project/{project}/service/{service_name}/pg/query/stats",
+			project = encode_param(project),
+			service_name = encode_param(service_name),
+		);
+		let response = make_json_request!(self, reqwest::Method::POST, url, request)?;
+		Ok(response.json::<ResPostgresQueryStatRows>().await?.queries)
+	}
+
+	/// Fetch the `n` queries with the highest total execution time
+	///
+	/// https://api.aiven.io/doc/#operation/PGServiceQueryStatistics
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `service_name` - Service name
+	/// * `n` - Number of queries to return
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::new("https://api.aiven.io", "v1");
+	///
+	/// let rows = client
+	///         .service_postgres()
+	///         .top_queries_by_total_time("myproject", "mypg", 10)
+	///         .await?;
+	/// for row in rows {
+	///     println!("{:>10.1} ms {:>8} calls  {}", row.total_time, row.calls, row.query);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn top_queries_by_total_time(
+		&self,
+		project: &str,
+		service_name: &str,
+		n: usize,
+	) -> Result<Vec<PgQueryStat>, AivenError> {
+		self.top_queries(project, service_name, n, "total_time", |row| row.total_time)
+			.await
+	}
+
+	/// Fetch the `n` most frequently called queries
+	///
+	/// https://api.aiven.io/doc/#operation/PGServiceQueryStatistics
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `service_name` - Service name
+	/// * `n` - Number of queries to return
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::new("https://api.aiven.io", "v1");
+	///
+	/// let rows = client
+	///         .service_postgres()
+	///         .top_queries_by_calls("myproject", "mypg", 10)
+	///         .await?;
+	/// for row in rows {
+	///     println!("{:>10.1} ms {:>8} calls  {}", row.total_time, row.calls, row.query);
+	/// }
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn top_queries_by_calls(
+		&self,
+		project: &str,
+		service_name: &str,
+		n: usize,
+	) -> Result<Vec<PgQueryStat>, AivenError> {
+		self.top_queries(project, service_name, n, "calls", |row| row.calls)
+			.await
+	}
+
+	async fn top_queries(
+		&self,
+		project: &str,
+		service_name: &str,
+		n: usize,
+		order_by: &str,
+		key: fn(&PgQueryStat) -> f64,
+	) -> Result<Vec<PgQueryStat>, AivenError> {
+		let request = QueryStatisticsRequest::default()
+			.limit(n.min(u32::MAX as usize) as u32)
+			.order_by(format!("{}:desc", order_by));
+		let mut rows = self
+			.fetch_query_statistics(project, service_name, &request)
+			.await?;
+		// The API already sorts, keep the result stable if it ever does not
+		rows.sort_by(|a, b| key(b).total_cmp(&key(a)));
+		rows.truncate(n);
+		Ok(rows)
 	}
 
 	/// Update a connection pool
@@ -247,7 +388,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -263,7 +404,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -286,7 +427,68 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_postgres_fetch_query_statistics() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/pgquerystatsproject/service/mypg/pg/query/stats";
+		let test_data = testutil::get_test_data("tests/testdata/service/postgres/top_queries.json");
+		let _m = mockito::mock("POST", query_url)
+			.match_body(mockito::Matcher::Json(
+				serde_json::json!({"limit": 3, "offset": 10, "order_by": "rows:desc"}),
+			))
+			.with_status(200)
+			.with_body(test_data)
+			.create();
+
+		let request = QueryStatisticsRequest::default()
+			.limit(3)
+			.offset(10)
+			.order_by("rows:desc");
+		match client
+			.service_postgres()
+			.fetch_query_statistics("pgquerystatsproject", "mypg", &request)
+			.await
+		{
+			Ok(rows) => {
+				assert!(rows.len() == 3);
+				assert!(rows[0].calls == 1200.0);
+				assert!(rows[0].queryid == Some(4212348791));
+				assert!(rows[1].user_name == "analytics");
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_postgres_top_queries() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/topqueriesproject/service/mypg/pg/query/stats";
+		let test_data = testutil::get_test_data("tests/testdata/service/postgres/top_queries.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "POST");
+
+		match client
+			.service_postgres()
+			.top_queries_by_total_time("topqueriesproject", "mypg", 2)
+			.await
+		{
+			Ok(rows) => {
+				assert!(rows.len() == 2);
+				assert!(rows[0].query == "SELECT count(*) FROM events");
+				assert!(rows[1].total_time == 960.0);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+		match client
+			.service_postgres()
+			.top_queries_by_calls("topqueriesproject", "mypg", 1)
+			.await
+		{
+			Ok(rows) => assert!(rows.len() == 1 && rows[0].calls == 1200.0),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -309,7 +511,7 @@ mod tests {
 			.await
 		{
 			Ok(_) => assert!(true),
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 }
@@ -0,0 +1,358 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Confluent compatible view on the Aiven schema registry endpoints.
+//!
+//! Aiven serves the schema registry below
+//! `project/{project}/service/{service}/kafka/schema` and wraps the replies
+//! in an envelope, e.g. `{"subjects": [..]}` instead of `[..]`. The
+//! [`SchemaRegistryApi`] takes the paths of the Confluent REST API, sends
+//! them through the authenticated client and unwraps the envelope, so code
+//! written against a Confluent registry gets the shapes it expects.
+//!
+//! ```rust,no_run
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+//!     let registry = client.service_kafka().schema_registry("my-project", "my-kafka");
+//!     // Same path and reply as `GET /subjects/orders-value/versions/latest`
+//!     let latest = registry
+//!         .request::<()>(reqwest::Method::GET, "/subjects/orders-value/versions/latest", None)
+//!         .await?;
+//!     println!("{}", latest["schema"]);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+	client::{encode_param, HTTPClient},
+	errors::AivenError,
+	service::types_kafka::*,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// Keys Aiven wraps the Confluent replies in
+const ENVELOPE_KEYS: [&str; 3] = ["subjects", "versions", "version"];
+
+pub struct SchemaRegistryApi {
+	http_client: HTTPClient,
+	base: String,
+}
+
+impl SchemaRegistryApi {
+	pub(crate) fn new(client: HTTPClient, project: &str, service_name: &str) -> Self {
+		Self {
+			http_client: client,
+			base: format!(
+				"project/{project}/service/{service_name}/kafka/schema",
+				project = encode_param(project),
+				service_name = encode_param(service_name),
+			),
+		}
+	}
+
+	/// Send a request using a Confluent schema registry path
+	///
+	/// The path is appended as is, so path parameters have to be percent
+	/// encoded by the caller just like for a Confluent registry.
+	///
+	/// # Arguments
+	///
+	/// * `method` - Http method
+	/// * `path` - Confluent REST path, e.g. `/subjects/orders-value/versions`
+	/// * `json_body` - Optional request body
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let body = json!({"schema": "{\"type\": \"string\"}"});
+	/// let response = client
+	///             .service_kafka()
+	///             .schema_registry("myproject", "myservicename")
+	///             .request(reqwest::Method::POST, "/subjects/mysubject/versions", Some(&body))
+	///             .await?;
+	/// println!("registered with id {}", response["id"]);
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn request<T: Serialize + ?Sized>(
+		&self,
+		method: reqwest::Method,
+		path: &str,
+		json_body: Option<&T>,
+	) -> Result<Value, AivenError> {
+		let url = format!("{}/{}", self.base, path.trim_start_matches('/'));
+		let response = self.http_client.execute(method, &url, json_body).await?;
+		let body: Value = response.json().await?;
+		Ok(unwrap_envelope(body))
+	}
+
+	/// List subjects, the same as `GET /subjects`
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjects
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let subjects = client
+	///             .service_kafka()
+	///             .schema_registry("myproject", "myservicename")
+	///             .subjects()
+	///             .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn subjects(&self) -> Result<Vec<String>, AivenError> {
+		self.get("/subjects").await
+	}
+
+	/// List the versions of a subject, the same as
+	/// `GET /subjects/{subject}/versions`
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectVersionsGet
+	///
+	/// # Arguments
+	///
+	/// * `subject` - Subject name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let versions = client
+	///             .service_kafka()
+	///             .schema_registry("myproject", "myservicename")
+	///             .versions("mysubject")
+	///             .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn versions(&self, subject: &str) -> Result<Vec<i32>, AivenError> {
+		self.get(&format!("/subjects/{}/versions", encode_param(subject)))
+			.await
+	}
+
+	/// Get a version of a subject, the same as
+	/// `GET /subjects/{subject}/versions/{version}`
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectVersionGet
+	///
+	/// # Arguments
+	///
+	/// * `subject` - Subject name
+	/// * `version` - Version number or `latest`
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let latest = client
+	///             .service_kafka()
+	///             .schema_registry("myproject", "myservicename")
+	///             .subject_version("mysubject", "latest")
+	///             .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn subject_version(
+		&self,
+		subject: &str,
+		version: &str,
+	) -> Result<SchemaRegistrySubjectVersion, AivenError> {
+		self.get(&format!(
+			"/subjects/{}/versions/{}",
+			encode_param(subject),
+			encode_param(version)
+		))
+		.await
+	}
+
+	/// Get a schema by its id, the same as `GET /schemas/ids/{id}`
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySchemaGet
+	///
+	/// # Arguments
+	///
+	/// * `id` - Schema id
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let schema = client
+	///             .service_kafka()
+	///             .schema_registry("myproject", "myservicename")
+	///             .schema_by_id(1)
+	///             .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn schema_by_id(&self, id: i32) -> Result<SchemaRegistrySchema, AivenError> {
+		self.get(&format!("/schemas/ids/{}", id)).await
+	}
+
+	/// Register a schema under a subject, the same as
+	/// `POST /subjects/{subject}/versions`
+	///
+	/// https://api.aiven.io/doc/#operation/ServiceSchemaRegistrySubjectVersionPost
+	///
+	/// # Arguments
+	///
+	/// * `subject` - Subject name
+	/// * `json_body` - Confluent registration body
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use serde_json::json;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let body = json!({"schema": "{\"type\": \"string\"}", "schemaType": "AVRO"});
+	/// let registered = client
+	///             .service_kafka()
+	///             .schema_registry("myproject", "myservicename")
+	///             .register("mysubject", &body)
+	///             .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn register<T: Serialize + ?Sized>(
+		&self,
+		subject: &str,
+		json_body: &T,
+	) -> Result<ResKafkaRegisterSchema, AivenError> {
+		let path = format!("/subjects/{}/versions", encode_param(subject));
+		let value = self
+			.request(reqwest::Method::POST, &path, Some(json_body))
+			.await?;
+		Ok(serde_json::from_value(value)?)
+	}
+
+	async fn get<R: DeserializeOwned>(&self, path: &str) -> Result<R, AivenError> {
+		let value = self.request::<()>(reqwest::Method::GET, path, None).await?;
+		Ok(serde_json::from_value(value)?)
+	}
+}
+
+/// Drop the Aiven `errors`/`message` fields and unwrap the single envelope key
+fn unwrap_envelope(body: Value) -> Value {
+	match body {
+		Value::Object(mut map) => {
+			map.remove("errors");
+			map.remove("message");
+			if map.len() == 1 {
+				if let Some(key) = ENVELOPE_KEYS.iter().find(|k| map.contains_key(**k)) {
+					return map.remove(*key).unwrap_or_default();
+				}
+			}
+			Value::Object(map)
+		}
+		other => other,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil;
+	use serde_json::json;
+
+	#[test]
+	fn test_unwrap_envelope() {
+		assert!(
+			unwrap_envelope(json!({"subjects": ["a"], "errors": [], "message": "ok"}))
+				== json!(["a"])
+		);
+		assert!(unwrap_envelope(json!({"versions": [1, 2]})) == json!([1, 2]));
+		assert!(unwrap_envelope(json!({"id": 3})) == json!({"id": 3}));
+		assert!(
+			unwrap_envelope(json!({"is_compatible": true, "message": ""}))
+				== json!({"is_compatible": true})
+		);
+	}
+
+	#[tokio::test]
+	async fn test_schema_registry_subject_version() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/myproject/service/myregistry/kafka/schema/subjects/orders-value/\
+		                 versions/latest";
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/kafka/schema_registry_latest.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		match client
+			.service_kafka()
+			.schema_registry("myproject", "myregistry")
+			.subject_version("orders-value", "latest")
+			.await
+		{
+			Ok(version) => {
+				assert!(version.subject == "orders-value");
+				assert!(version.id == 7);
+				assert!(version.version == 3);
+				assert!(version.schema_type.as_deref() == Some("AVRO"));
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_schema_registry_subjects() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/myproject/service/myregistry/kafka/schema/subjects";
+		let test_data = testutil::get_test_data(
+			"tests/testdata/service/kafka/list_schema_registry_subjects.json",
+		);
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		match client
+			.service_kafka()
+			.schema_registry("myproject", "myregistry")
+			.subjects()
+			.await
+		{
+			Ok(subjects) => assert!(subjects == vec!["topic1-values".to_string()]),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+}
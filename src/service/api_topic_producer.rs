@@ -0,0 +1,361 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+	client::json_size,
+	errors::AivenError,
+	service::{
+		types_kafka::{RecordError, RecordOffset, KAFKA_REST_MAX_BODY_BYTES},
+		ServiceKafkaApi,
+	},
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// Result of producing a single record
+pub type RecordResult = Result<RecordOffset, RecordError>;
+
+/// Produces to a Kafka topic through the Aiven REST proxy in batches.
+///
+/// The producer buffers records and sends them with one `produce_message`
+/// call per batch. A batch is sent once it holds
+/// `max_records` records, once the next record would push it over
+/// `max_bytes`, or once its oldest record waited longer than `linger`.
+/// Every sent record gets a typed result with its partition and offset or
+/// the error the REST proxy reported for it.
+///
+/// Request bodies are sent uncompressed, the REST proxy does not document
+/// accepting gzip encoded produce requests.
+///
+/// Producing is not idempotent, so failed batches are not retried. They
+/// stay buffered and are sent again by the next `send` or `flush`.
+///
+/// ```rust,no_run
+/// use serde_json::json;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+///     let mut producer = client
+///         .service_kafka()
+///         .topic_producer("my-project", "my-kafka", "orders")
+///         .max_records(200);
+///     let records: Vec<_> = (0..1000).map(|i| json!({"value": {"id": i}})).collect();
+///     for result in producer.send_all(&records).await? {
+///         match result {
+///             Ok(written) => println!("{}:{}", written.partition, written.offset),
+///             Err(e) => eprintln!("record failed: {}", e),
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct TopicProducer {
+	kafka: ServiceKafkaApi,
+	project: String,
+	service_name: String,
+	topic: String,
+	format: String,
+	key_schema_id: Option<i64>,
+	value_schema_id: Option<i64>,
+	max_records: usize,
+	max_bytes: usize,
+	linger: Option<Duration>,
+	buffer: Vec<Value>,
+	buffered_bytes: usize,
+	first_buffered: Option<Instant>,
+}
+
+impl TopicProducer {
+	pub(crate) fn new(
+		kafka: ServiceKafkaApi,
+		project: &str,
+		service_name: &str,
+		topic: &str,
+	) -> Self {
+		TopicProducer {
+			kafka,
+			project: project.to_string(),
+			service_name: service_name.to_string(),
+			topic: topic.to_string(),
+			format: "json".to_string(),
+			key_schema_id: None,
+			value_schema_id: None,
+			max_records: 500,
+			max_bytes: KAFKA_REST_MAX_BODY_BYTES - 4096,
+			linger: None,
+			buffer: Vec::new(),
+			buffered_bytes: 0,
+			first_buffered: None,
+		}
+	}
+
+	/// Embedded format of the records, e.g. `binary` or `avro`. Defaults to
+	/// `json`.
+	pub fn format(mut self, format: &str) -> Self {
+		self.format = format.to_string();
+		self
+	}
+
+	/// Schema registry id of the record keys
+	pub fn key_schema_id(mut self, id: i64) -> Self {
+		self.key_schema_id = Some(id);
+		self
+	}
+
+	/// Schema registry id of the record values
+	pub fn value_schema_id(mut self, id: i64) -> Self {
+		self.value_schema_id = Some(id);
+		self
+	}
+
+	/// Upper limit of records per request, defaults to 500. Values below 1
+	/// are raised to 1.
+	pub fn max_records(mut self, max_records: usize) -> Self {
+		self.max_records = max_records.max(1);
+		self
+	}
+
+	/// Upper limit of the serialized `records` array per request. Defaults
+	/// to `KAFKA_REST_MAX_BODY_BYTES` less 4 KiB for the other fields.
+	pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+		self.max_bytes = max_bytes;
+		self
+	}
+
+	/// Send a batch once its oldest record waited this long. There is no
+	/// background timer, the age is checked by `send`, so `flush` after the
+	/// last record.
+	pub fn linger(mut self, linger: Duration) -> Self {
+		self.linger = Some(linger);
+		self
+	}
+
+	/// Number of records waiting to be sent
+	pub fn buffered(&self) -> usize {
+		self.buffer.len()
+	}
+
+	/// Buffer `record`, sending the buffered batch first if it is full or
+	/// lingered long enough. Returns the results of the sent batch, if any.
+	///
+	/// On error `record` is not buffered and the earlier records stay
+	/// buffered.
+	pub async fn send<R: Serialize + ?Sized>(
+		&mut self,
+		record: &R,
+	) -> Result<Vec<RecordResult>, AivenError> {
+		let record = serde_json::to_value(record)?;
+		let size = json_size(&record)?;
+		// Size of the surrounding `[` and `]`
+		if size + 2 > self.max_bytes {
+			return Err(AivenError::RequestBodyTooLarge {
+				size: size + 2,
+				limit: self.max_bytes,
+			});
+		}
+		let lingered = match (self.linger, self.first_buffered) {
+			(Some(linger), Some(first)) => first.elapsed() >= linger,
+			_ => false,
+		};
+		let results = if self.buffer.len() >= self.max_records
+			|| self.buffered_bytes + size + 1 > self.max_bytes
+			|| lingered
+		{
+			self.flush().await?
+		} else {
+			Vec::new()
+		};
+		if self.buffer.is_empty() {
+			self.first_buffered = Some(Instant::now());
+			self.buffered_bytes = size + 2;
+		} else {
+			// Records after the first one are preceded by a `,`
+			self.buffered_bytes += size + 1;
+		}
+		self.buffer.push(record);
+		Ok(results)
+	}
+
+	/// Send all `records` and flush, returning one result per record in
+	/// the order of `records`
+	pub async fn send_all<R: Serialize>(
+		&mut self,
+		records: &[R],
+	) -> Result<Vec<RecordResult>, AivenError> {
+		let mut results = Vec::with_capacity(records.len());
+		for record in records {
+			results.extend(self.send(record).await?);
+		}
+		results.extend(self.flush().await?);
+		Ok(results)
+	}
+
+	/// Send the buffered records, returning one result per record
+	pub async fn flush(&mut self) -> Result<Vec<RecordResult>, AivenError> {
+		if self.buffer.is_empty() {
+			return Ok(Vec::new());
+		}
+		let mut body = json!({
+			"format": self.format,
+			"records": self.buffer,
+		});
+		if let Some(id) = self.key_schema_id {
+			body["key_schema_id"] = json!(id);
+		}
+		if let Some(id) = self.value_schema_id {
+			body["value_schema_id"] = json!(id);
+		}
+		let response = self
+			.kafka
+			.produce_message(&self.project, &self.service_name, &self.topic, &body)
+			.await?;
+		debug!("produced {} records to {}", self.buffer.len(), self.topic);
+		self.buffer.clear();
+		self.buffered_bytes = 0;
+		self.first_buffered = None;
+		Ok(response.results())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::testutil;
+	use serde_json::json;
+	use std::time::Duration;
+
+	fn produce_mock(topic: &str, records: serde_json::Value, first_offset: i64) -> mockito::Mock {
+		let count = records.as_array().map(|r| r.len()).unwrap_or(0) as i64;
+		let offsets: Vec<_> = (0..count)
+			.map(
+				|i| json!({"error": null, "error_code": null, "offset": first_offset + i, "partition": 0}),
+			)
+			.collect();
+		mockito::mock(
+			"POST",
+			format!(
+				"/project/producerproject/service/myservicename/kafka/rest/topics/{}/produce",
+				topic
+			)
+			.as_str(),
+		)
+		.match_body(mockito::Matcher::Json(
+			json!({"format": "json", "records": records}),
+		))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(
+			json!({"key_schema_id": null, "offsets": offsets, "value_schema_id": null}).to_string(),
+		)
+		.create()
+	}
+
+	#[tokio::test]
+	async fn test_topic_producer_batches_by_count() {
+		let client = testutil::prepare_test_client();
+		let first = produce_mock("counted", json!([{"value": 0}, {"value": 1}]), 10);
+		let second = produce_mock("counted", json!([{"value": 2}]), 12);
+
+		let records: Vec<_> = (0..3).map(|i| json!({ "value": i })).collect();
+		let mut producer = client
+			.service_kafka()
+			.topic_producer("producerproject", "myservicename", "counted")
+			.max_records(2);
+		let results = producer.send_all(&records).await.unwrap();
+		let offsets: Vec<_> = results.into_iter().map(|r| r.unwrap().offset).collect();
+		assert!(offsets == vec![10, 11, 12]);
+		assert!(producer.buffered() == 0);
+		first.assert();
+		second.assert();
+	}
+
+	#[tokio::test]
+	async fn test_topic_producer_batches_by_size() {
+		let client = testutil::prepare_test_client();
+		let first = produce_mock("sized", json!([{"value": 0}, {"value": 1}]), 0);
+		let second = produce_mock("sized", json!([{"value": 2}]), 2);
+
+		// Each record is `{"value":N}`, 11 bytes, two of them fill 25 bytes
+		let mut producer = client
+			.service_kafka()
+			.topic_producer("producerproject", "myservicename", "sized")
+			.max_bytes(30);
+		for i in 0..3 {
+			producer.send(&json!({ "value": i })).await.unwrap();
+		}
+		assert!(producer.buffered() == 1);
+		let results = producer.flush().await.unwrap();
+		assert!(results.len() == 1);
+		first.assert();
+		second.assert();
+
+		match producer.send(&json!({ "value": "x".repeat(30) })).await {
+			Err(crate::errors::AivenError::RequestBodyTooLarge { limit, .. }) => {
+				assert!(limit == 30)
+			}
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_topic_producer_linger() {
+		let client = testutil::prepare_test_client();
+		let first = produce_mock("lingered", json!([{"value": 0}]), 0);
+
+		let mut producer = client
+			.service_kafka()
+			.topic_producer("producerproject", "myservicename", "lingered")
+			.linger(Duration::from_millis(10));
+		assert!(producer
+			.send(&json!({ "value": 0 }))
+			.await
+			.unwrap()
+			.is_empty());
+		tokio::time::sleep(Duration::from_millis(20)).await;
+		let results = producer.send(&json!({ "value": 1 })).await.unwrap();
+		assert!(results.len() == 1);
+		assert!(producer.buffered() == 1);
+		first.assert();
+	}
+
+	#[tokio::test]
+	async fn test_topic_producer_keeps_failed_batch() {
+		let client = testutil::prepare_test_client();
+		let _failed = mockito::mock(
+			"POST",
+			"/project/producerproject/service/myservicename/kafka/rest/topics/failing/produce",
+		)
+		.with_status(500)
+		.with_body(r#"{"message": "boom"}"#)
+		.create();
+
+		let mut producer =
+			client
+				.service_kafka()
+				.topic_producer("producerproject", "myservicename", "failing");
+		producer.send(&json!({ "value": 0 })).await.unwrap();
+		assert!(producer.flush().await.is_err());
+		assert!(producer.buffered() == 1);
+	}
+}
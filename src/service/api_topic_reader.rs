@@ -0,0 +1,344 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+	errors::AivenError,
+	retry::RetryPolicy,
+	service::{types_kafka::Message, ServiceKafkaApi},
+};
+use futures_util::stream::{self, Stream};
+use serde_json::json;
+use std::{
+	collections::{BTreeMap, VecDeque},
+	time::Duration,
+};
+use tracing::debug;
+
+/// Reads a Kafka topic through the Aiven REST proxy page by page.
+///
+/// The reader keeps the next offset of every partition between calls to
+/// `list_topic_messages`, so each batch continues where the previous one
+/// stopped. A batch is only fetched once the previous one was consumed, and
+/// failed fetches are retried by the client's retry policy without moving
+/// the offsets.
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+///     let reader = client
+///         .service_kafka()
+///         .topic_reader("my-project", "my-kafka", "orders")
+///         .max_bytes(1024 * 1024);
+///     let mut messages = Box::pin(reader.stream());
+///     while let Some(message) = messages.next().await {
+///         let message = message?;
+///         println!("{}:{} {} bytes", message.partition, message.offset, message.value.len());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct TopicReader {
+	kafka: ServiceKafkaApi,
+	project: String,
+	service_name: String,
+	topic: String,
+	offsets: BTreeMap<i64, i64>,
+	started: bool,
+	max_bytes: usize,
+	timeout: Duration,
+	follow: Option<Duration>,
+}
+
+impl TopicReader {
+	pub(crate) fn new(
+		kafka: ServiceKafkaApi,
+		project: &str,
+		service_name: &str,
+		topic: &str,
+	) -> Self {
+		TopicReader {
+			kafka,
+			project: project.to_string(),
+			service_name: service_name.to_string(),
+			topic: topic.to_string(),
+			offsets: BTreeMap::new(),
+			started: false,
+			max_bytes: 4 * 1024 * 1024,
+			timeout: Duration::from_secs(3),
+			follow: None,
+		}
+	}
+
+	/// Start reading `partition` at `offset`. Partitions without a start
+	/// offset are read from their earliest offset.
+	pub fn start_at(mut self, partition: i64, offset: i64) -> Self {
+		self.offsets.insert(partition, offset);
+		self
+	}
+
+	/// Upper limit of the size of one batch, defaults to 4 MiB
+	pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+		self.max_bytes = max_bytes;
+		self
+	}
+
+	/// How long the REST proxy waits for messages, defaults to 3s
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = timeout;
+		self
+	}
+
+	/// Retries of a failed batch instead of the client's retry policy.
+	/// Reading is idempotent, so `5xx` responses are retried like for a
+	/// `GET`.
+	pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+		self.kafka = self.kafka.with_retry_policy(retry);
+		self
+	}
+
+	/// Keep polling every `interval` once the end of the topic is reached,
+	/// instead of ending the stream
+	pub fn follow(mut self, interval: Duration) -> Self {
+		self.follow = Some(interval);
+		self
+	}
+
+	/// The next offset to read per partition
+	pub fn offsets(&self) -> &BTreeMap<i64, i64> {
+		&self.offsets
+	}
+
+	/// Fetch the next batch and advance the offsets past it. An empty batch
+	/// means the end of the topic was reached.
+	pub async fn next_batch(&mut self) -> Result<Vec<Message>, AivenError> {
+		if !self.started {
+			self.start_at_earliest().await?;
+		}
+		let partitions: BTreeMap<String, _> = self
+			.offsets
+			.iter()
+			.map(|(partition, offset)| (partition.to_string(), json!({ "offset": offset })))
+			.collect();
+		let body = json!({
+			"max_bytes": self.max_bytes,
+			"partitions": partitions,
+			"timeout": self.timeout.as_millis() as u64,
+		});
+
+		let messages = self
+			.kafka
+			.read_topic_messages(&self.project, &self.service_name, &self.topic, &body)
+			.await?
+			.messages;
+		for message in &messages {
+			let next = self.offsets.entry(message.partition).or_default();
+			*next = (*next).max(message.offset + 1);
+		}
+		debug!("read {} messages of {}", messages.len(), self.topic);
+		Ok(messages)
+	}
+
+	/// All messages as a stream, ending at the end of the topic unless
+	/// `follow` was set. The stream ends after yielding an error.
+	pub fn stream(self) -> impl Stream<Item = Result<Message, AivenError>> {
+		stream::unfold(
+			(self, VecDeque::new(), false),
+			|(mut reader, mut buffered, failed)| async move {
+				if failed {
+					return None;
+				}
+				loop {
+					if let Some(message) = buffered.pop_front() {
+						return Some((Ok(message), (reader, buffered, false)));
+					}
+					match reader.next_batch().await {
+						Ok(batch) if batch.is_empty() => match reader.follow {
+							Some(interval) => tokio::time::sleep(interval).await,
+							None => return None,
+						},
+						Ok(batch) => buffered.extend(batch),
+						Err(e) => return Some((Err(e), (reader, buffered, true))),
+					}
+				}
+			},
+		)
+	}
+
+	/// Start the partitions without a `start_at` offset at their earliest
+	async fn start_at_earliest(&mut self) -> Result<(), AivenError> {
+		let info = self
+			.kafka
+			.get_topic_info(&self.project, &self.service_name, &self.topic)
+			.await?;
+		for partition in info.topic.partitions {
+			self.offsets
+				.entry(partition.partition as i64)
+				.or_insert(partition.earliest_offset as i64);
+		}
+		self.started = true;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{retry::RetryPolicy, testutil};
+	use futures_util::StreamExt;
+	use serde_json::json;
+	use std::time::Duration;
+
+	#[tokio::test]
+	async fn test_topic_reader_reads_to_end() {
+		let client = testutil::prepare_test_client();
+		let base = "/project/readerproject/service/myservicename";
+		let messages_url = format!("{}/kafka/rest/topics/orders/messages", base);
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/kafka/topic_reader_info.json");
+		let _info =
+			testutil::create_mock_server(&format!("{}/topic/orders", base), &test_data, "GET");
+		let _first = mockito::mock("POST", messages_url.as_str())
+			.match_body(mockito::Matcher::PartialJson(
+				json!({"partitions": {"0": {"offset": 5}, "1": {"offset": 0}}}),
+			))
+			.with_status(200)
+			.with_header("content-type", "application/json")
+			.with_body(
+				json!({"messages": [
+					{"key": "a", "offset": 5, "partition": 0, "topic": "orders", "value": "x"},
+					{"key": "b", "offset": 6, "partition": 0, "topic": "orders", "value": "y"}
+				]})
+				.to_string(),
+			)
+			.create();
+		let _second = mockito::mock("POST", messages_url.as_str())
+			.match_body(mockito::Matcher::PartialJson(
+				json!({"partitions": {"0": {"offset": 7}, "1": {"offset": 0}}}),
+			))
+			.with_status(200)
+			.with_header("content-type", "application/json")
+			.with_body(r#"{"messages": []}"#)
+			.create();
+
+		let reader =
+			client
+				.service_kafka()
+				.topic_reader("readerproject", "myservicename", "orders");
+		let offsets: Vec<_> = reader
+			.stream()
+			.map(|message| message.map(|m| m.offset))
+			.collect()
+			.await;
+		match offsets.as_slice() {
+			[Ok(5), Ok(6)] => assert!(true),
+			other => assert!(false, "{:?}", other),
+		}
+		_second.assert();
+	}
+
+	#[tokio::test]
+	async fn test_topic_reader_keeps_offsets_on_error() {
+		let client = testutil::prepare_test_client();
+		let base = "/project/readererrorproject/service/myservicename";
+		let messages_url = format!("{}/kafka/rest/topics/orders/messages", base);
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/kafka/topic_reader_info.json");
+		let _info =
+			testutil::create_mock_server(&format!("{}/topic/orders", base), &test_data, "GET");
+		let _m = mockito::mock("POST", messages_url.as_str())
+			.with_status(400)
+			.with_header("content-type", "application/json")
+			.with_body(r#"{"message": "Invalid offset"}"#)
+			.create();
+
+		let mut reader = client
+			.service_kafka()
+			.topic_reader("readererrorproject", "myservicename", "orders")
+			.start_at(0, 42);
+		match reader.next_batch().await {
+			Err(_) => assert!(reader.offsets().get(&0) == Some(&42)),
+			Ok(batch) => assert!(false, "{:?}", batch),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_topic_reader_start_at_reads_other_partitions() {
+		let client = testutil::prepare_test_client();
+		let base = "/project/readerstartproject/service/myservicename";
+		let messages_url = format!("{}/kafka/rest/topics/orders/messages", base);
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/kafka/topic_reader_info.json");
+		let _info =
+			testutil::create_mock_server(&format!("{}/topic/orders", base), &test_data, "GET");
+		let read = mockito::mock("POST", messages_url.as_str())
+			.match_body(mockito::Matcher::PartialJson(
+				json!({"partitions": {"0": {"offset": 6}, "1": {"offset": 0}}}),
+			))
+			.with_status(200)
+			.with_header("content-type", "application/json")
+			.with_body(r#"{"messages": []}"#)
+			.create();
+
+		let mut reader = client
+			.service_kafka()
+			.topic_reader("readerstartproject", "myservicename", "orders")
+			.start_at(0, 6);
+		match reader.next_batch().await {
+			Ok(batch) => {
+				assert!(batch.is_empty());
+				read.assert();
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_topic_reader_retries_server_errors() {
+		let client = testutil::prepare_test_client();
+		let base = "/project/readerretryproject/service/myservicename";
+		let messages_url = format!("{}/kafka/rest/topics/orders/messages", base);
+		let test_data =
+			testutil::get_test_data("tests/testdata/service/kafka/topic_reader_info.json");
+		let _info =
+			testutil::create_mock_server(&format!("{}/topic/orders", base), &test_data, "GET");
+		let failing = mockito::mock("POST", messages_url.as_str())
+			.with_status(503)
+			.with_body("unavailable")
+			.expect(2)
+			.create();
+
+		let mut reader = client
+			.service_kafka()
+			.topic_reader("readerretryproject", "myservicename", "orders")
+			.retry_policy(
+				RetryPolicy::default()
+					.max_attempts(2)
+					.backoff(Duration::from_millis(1), Duration::from_millis(1)),
+			);
+		match reader.next_batch().await {
+			Err(_) => failing.assert(),
+			Ok(batch) => assert!(false, "{:?}", batch),
+		}
+	}
+}
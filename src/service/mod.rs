@@ -1,20 +1,35 @@
 mod api;
 mod api_elasticsearch;
+mod api_flink;
+mod api_grafana;
+mod api_influxdb;
 mod api_integrations;
 mod api_kafka;
 mod api_mirrormaker;
 mod api_mysql;
 mod api_postgres;
+mod api_schema_registry;
+mod api_topic_producer;
+mod api_topic_reader;
 
 pub use api::ServiceApi;
 pub use api_elasticsearch::ServiceElastiSearchApi;
+pub use api_flink::ServiceFlinkApi;
+pub use api_grafana::ServiceGrafanaApi;
+pub use api_influxdb::ServiceInfluxApi;
 pub use api_integrations::ServiceIntegrationsApi;
 pub use api_kafka::ServiceKafkaApi;
 pub use api_mirrormaker::ServiceKafkaMirrorMaker;
 pub use api_mysql::ServiceMysqlApi;
 pub use api_postgres::ServicePostgresApi;
+pub use api_schema_registry::SchemaRegistryApi;
+pub use api_topic_producer::{RecordResult, TopicProducer};
+pub use api_topic_reader::TopicReader;
 
 pub mod types_elasticsearch;
+pub mod types_flink;
+pub mod types_grafana;
+pub mod types_influxdb;
 pub mod types_integrations;
 pub mod types_kafka;
 pub mod types_mirrormaker;
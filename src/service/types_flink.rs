@@ -0,0 +1,147 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct FlinkApplicationSummary {
+	pub id: String,
+	pub name: String,
+	pub created_at: Option<String>,
+	pub created_by: Option<String>,
+	pub updated_at: Option<String>,
+	pub updated_by: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResFlinkApplications {
+	pub applications: Vec<FlinkApplicationSummary>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct FlinkApplicationVersion {
+	pub id: String,
+	pub version: i32,
+	pub statement: Option<String>,
+	#[serde(default)]
+	pub sinks: Vec<serde_json::Value>,
+	#[serde(default)]
+	pub sources: Vec<serde_json::Value>,
+	pub created_at: Option<String>,
+	pub created_by: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct FlinkApplication {
+	pub id: String,
+	pub name: String,
+	#[serde(default)]
+	pub application_versions: Vec<FlinkApplicationVersion>,
+	pub current_deployment: Option<FlinkDeployment>,
+	pub created_at: Option<String>,
+	pub created_by: Option<String>,
+	pub updated_at: Option<String>,
+	pub updated_by: Option<String>,
+}
+
+/// State of a Flink application deployment
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FlinkDeploymentStatus {
+	Initializing,
+	Created,
+	Running,
+	Failing,
+	Failed,
+	Saving,
+	Cancelling,
+	Canceled,
+	SavingAndStop,
+	Finished,
+	DeleteRequested,
+	Deleting,
+	#[default]
+	#[serde(other)]
+	Unknown,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct FlinkDeployment {
+	pub id: String,
+	pub version_id: String,
+	pub status: FlinkDeploymentStatus,
+	pub job_id: Option<String>,
+	pub parallelism: Option<i32>,
+	pub restart_enabled: Option<bool>,
+	pub starting_savepoint: Option<String>,
+	pub error_msg: Option<String>,
+	pub created_at: Option<String>,
+	pub created_by: Option<String>,
+}
+
+/// Body of `ServiceFlinkApi::create_deployment`
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct FlinkDeploymentRequest {
+	pub version_id: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub parallelism: Option<i32>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub restart_enabled: Option<bool>,
+	/// Savepoint to restore the job state from
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub starting_savepoint: Option<String>,
+}
+
+impl FlinkDeploymentRequest {
+	pub fn new(version_id: &str) -> Self {
+		FlinkDeploymentRequest {
+			version_id: version_id.to_string(),
+			..Default::default()
+		}
+	}
+
+	pub fn parallelism(mut self, parallelism: i32) -> Self {
+		self.parallelism = Some(parallelism);
+		self
+	}
+
+	pub fn restart_enabled(mut self, enabled: bool) -> Self {
+		self.restart_enabled = Some(enabled);
+		self
+	}
+
+	pub fn starting_savepoint(mut self, savepoint: &str) -> Self {
+		self.starting_savepoint = Some(savepoint.to_string());
+		self
+	}
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct FlinkJob {
+	pub id: String,
+	pub status: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResFlinkJobs {
+	pub jobs: Vec<FlinkJob>,
+}
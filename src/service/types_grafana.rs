@@ -0,0 +1,64 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// `user_config` of a Grafana service, unset fields are left unchanged on
+/// update and unknown ones are kept in `extra`
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct GrafanaUserConfig {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub alerting_enabled: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub allow_embedding: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub auth_basic_enabled: Option<bool>,
+	/// Lower limit of the refresh interval dashboards may use, e.g. `5s`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub dashboards_min_refresh_interval: Option<String>,
+	/// How many versions of a dashboard are kept
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub dashboards_versions_to_keep: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub disable_gravatar: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub editors_can_admin: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ip_filter: Option<Vec<String>>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub metrics_enabled: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub user_auto_assign_org_role: Option<GrafanaRole>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub viewers_can_edit: Option<bool>,
+	#[serde(flatten)]
+	pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// Organization role of a Grafana user
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrafanaRole {
+	Viewer,
+	Editor,
+	Admin,
+}
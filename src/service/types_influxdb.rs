@@ -0,0 +1,137 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt};
+
+/// `user_config` of an InfluxDB service, unset fields are left unchanged on
+/// update and unknown ones are kept in `extra`
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct InfluxDbUserConfig {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub influxdb: Option<InfluxDbSettings>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ip_filter: Option<Vec<String>>,
+	#[serde(flatten)]
+	pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// The `influxdb` section of `InfluxDbUserConfig`
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct InfluxDbSettings {
+	/// Log queries running longer than this many seconds, 0 disables it
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub log_queries_after: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_connection_limit: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_row_limit: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_select_buckets: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_select_point: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub query_log_enabled: Option<bool>,
+	/// Abort queries running longer than this many seconds
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub query_timeout: Option<i64>,
+}
+
+/// An InfluxDB retention policy.
+///
+/// The Aiven API doesn't manage retention policies, they are created with
+/// InfluxQL over the service URI. `Display` renders the statement.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// use aiven_rs::service::types_influxdb::RetentionPolicy;
+/// let policy = RetentionPolicy::new("two_weeks", "metrics", "14d").default_policy(true);
+/// assert!(
+///     policy.to_string()
+///         == r#"CREATE RETENTION POLICY "two_weeks" ON "metrics" DURATION 14d REPLICATION 1 DEFAULT"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetentionPolicy {
+	pub name: String,
+	pub database: String,
+	/// InfluxQL duration, e.g. `14d` or `INF`
+	pub duration: String,
+	pub replication: u32,
+	pub shard_duration: Option<String>,
+	pub default_policy: bool,
+}
+
+impl RetentionPolicy {
+	pub fn new<N, D, T>(name: N, database: D, duration: T) -> Self
+	where
+		N: Into<String>,
+		D: Into<String>,
+		T: Into<String>,
+	{
+		RetentionPolicy {
+			name: name.into(),
+			database: database.into(),
+			duration: duration.into(),
+			replication: 1,
+			shard_duration: None,
+			default_policy: false,
+		}
+	}
+
+	pub fn shard_duration<S: Into<String>>(mut self, duration: S) -> Self {
+		self.shard_duration = Some(duration.into());
+		self
+	}
+
+	/// Make this the default policy of the database
+	pub fn default_policy(mut self, default: bool) -> Self {
+		self.default_policy = default;
+		self
+	}
+}
+
+fn quote_identifier(name: &str) -> String {
+	format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl fmt::Display for RetentionPolicy {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"CREATE RETENTION POLICY {} ON {} DURATION {} REPLICATION {}",
+			quote_identifier(&self.name),
+			quote_identifier(&self.database),
+			self.duration,
+			self.replication
+		)?;
+		if let Some(shard_duration) = &self.shard_duration {
+			write!(f, " SHARD DURATION {}", shard_duration)?;
+		}
+		if self.default_policy {
+			write!(f, " DEFAULT")?;
+		}
+		Ok(())
+	}
+}
@@ -20,7 +20,13 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::{
+	errors::AivenError,
+	service::{types_service, types_service::Service},
+	Secret,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ServiceIntegrationEndPoint {
@@ -63,10 +69,40 @@ pub struct IntegrationType {
 	pub user_config_schema: serde_json::Value,
 }
 
+impl IntegrationType {
+	/// True when this integration can use a service of the given type as
+	/// source
+	pub fn accepts_source(&self, service_type: &str) -> bool {
+		self.source_service_types.iter().any(|t| t == service_type)
+	}
+
+	/// True when this integration can use a service of the given type as
+	/// destination
+	pub fn accepts_dest(&self, service_type: &str) -> bool {
+		self.dest_service_types.iter().any(|t| t == service_type)
+			|| self.dest_service_type == service_type
+	}
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResIntegrationTypes {
 	pub integration_types: Vec<IntegrationType>,
 }
+
+impl ResIntegrationTypes {
+	/// The integration types connecting `source_service_type` to
+	/// `dest_service_type`
+	pub fn between(
+		self,
+		source_service_type: &str,
+		dest_service_type: &str,
+	) -> Vec<IntegrationType> {
+		self.integration_types
+			.into_iter()
+			.filter(|t| t.accepts_source(source_service_type) && t.accepts_dest(dest_service_type))
+			.collect()
+	}
+}
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResServiceIntegration {
 	pub service_integration: ServiceIntegration,
@@ -99,3 +135,478 @@ pub struct EndpointType {
 pub struct ResEndpointTypes {
 	pub endpoint_types: Vec<EndpointType>,
 }
+
+/// Request to create a standalone Kafka Connect service attached to an
+/// existing Kafka service
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct DedicatedKafkaConnectRequest {
+	/// Name of the existing Kafka service
+	pub kafka_service: String,
+	/// Name of the Kafka Connect service to create
+	pub connect_service: String,
+	pub cloud: String,
+	pub plan: String,
+	pub project_vpc_id: Option<String>,
+	pub user_config: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResDedicatedKafkaConnect {
+	pub service: Service,
+	pub service_integration: ServiceIntegration,
+}
+
+/// Prometheus scrape target of a service integrated with a Prometheus
+/// endpoint, protected by the endpoint's basic-auth credentials
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct PrometheusScrapeTarget {
+	pub service_name: String,
+	pub host: String,
+	pub port: i32,
+	pub username: String,
+	pub password: Secret,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResPrometheusIntegration {
+	pub endpoint: ServiceIntegrationEndPoint,
+	pub service_integrations: Vec<ServiceIntegration>,
+	pub targets: Vec<PrometheusScrapeTarget>,
+}
+
+/// Datadog site the metrics are sent to
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DatadogSite {
+	#[default]
+	#[serde(rename = "datadoghq.com")]
+	Us1,
+	#[serde(rename = "us3.datadoghq.com")]
+	Us3,
+	#[serde(rename = "us5.datadoghq.com")]
+	Us5,
+	#[serde(rename = "datadoghq.eu")]
+	Eu1,
+	#[serde(rename = "ap1.datadoghq.com")]
+	Ap1,
+	#[serde(rename = "ddog-gov.com")]
+	Gov,
+}
+
+/// A tag attached to all metrics sent to Datadog, e.g. `env:prod`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct DatadogTag {
+	pub tag: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub comment: Option<String>,
+}
+
+impl DatadogTag {
+	/// Create a tag, checking it against Datadog's tag rules: at most 200
+	/// characters, starting with a letter, made of letters, digits and
+	/// `_-:./` and not ending with a colon.
+	pub fn new<S: Into<String>>(tag: S) -> Result<Self, AivenError> {
+		let tag = DatadogTag {
+			tag: tag.into(),
+			comment: None,
+		};
+		tag.validate()?;
+		Ok(tag)
+	}
+
+	pub fn comment<S: Into<String>>(mut self, comment: S) -> Self {
+		self.comment = Some(comment.into());
+		self
+	}
+
+	pub fn validate(&self) -> Result<(), AivenError> {
+		let invalid = |reason: &str| {
+			Err(AivenError::InvalidInput {
+				field: "datadog_tags".to_string(),
+				reason: format!("`{}` {}", self.tag, reason),
+			})
+		};
+		if self.tag.is_empty() || self.tag.len() > 200 {
+			return invalid("must be between 1 and 200 characters");
+		}
+		if !self.tag.starts_with(|c: char| c.is_ascii_alphabetic()) {
+			return invalid("must start with a letter");
+		}
+		if self.tag.ends_with(':') {
+			return invalid("must not end with a colon");
+		}
+		if let Some(c) = self
+			.tag
+			.chars()
+			.find(|c| !c.is_ascii_alphanumeric() && !"_-:./".contains(*c))
+		{
+			return invalid(&format!("contains invalid character `{}`", c));
+		}
+		Ok(())
+	}
+}
+
+/// `user_config` of a Datadog integration endpoint
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct DatadogEndpointConfig {
+	/// Required when creating an endpoint. `None` in the configs read back
+	/// from the API, which doesn't return the key.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub datadog_api_key: Option<Secret>,
+	#[serde(default)]
+	pub site: DatadogSite,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub datadog_tags: Vec<DatadogTag>,
+}
+
+/// `user_config` of a Datadog integration between a service and an endpoint.
+/// Empty lists are left out so the service defaults apply.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct DatadogIntegrationConfig {
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub datadog_tags: Vec<DatadogTag>,
+	/// Kafka topics to send metrics for
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub include_topics: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub exclude_topics: Vec<String>,
+	/// Kafka consumer groups to send metrics for
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub include_consumer_groups: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub exclude_consumer_groups: Vec<String>,
+	/// Additional Kafka metrics, e.g. `kafka.log.log_size`
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub kafka_custom_metrics: Vec<String>,
+}
+
+impl DatadogIntegrationConfig {
+	pub fn validate(&self) -> Result<(), AivenError> {
+		self.datadog_tags.iter().try_for_each(DatadogTag::validate)
+	}
+}
+
+/// Security protocol used to reach an external Kafka cluster
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KafkaSecurityProtocol {
+	Plaintext,
+	#[default]
+	Ssl,
+	SaslPlaintext,
+	SaslSsl,
+}
+
+/// `user_config` of an `external_kafka` integration endpoint
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ExternalKafkaEndpointConfig {
+	/// Comma separated `host:port` list
+	pub bootstrap_servers: String,
+	pub security_protocol: KafkaSecurityProtocol,
+	/// `PLAIN`, `SCRAM-SHA-256` or `SCRAM-SHA-512`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub sasl_mechanism: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub sasl_plain_username: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub sasl_plain_password: Option<Secret>,
+	/// PEM encoded CA certificate
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ssl_ca_cert: Option<String>,
+	/// PEM encoded client certificate
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ssl_client_cert: Option<String>,
+	/// PEM encoded client key
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ssl_client_key: Option<Secret>,
+	/// `https` to verify the server hostname
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ssl_endpoint_identification_algorithm: Option<String>,
+}
+
+/// `user_config` of a `prometheus` integration endpoint, the credentials
+/// Prometheus uses to scrape the services
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PrometheusEndpointConfig {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub basic_auth_username: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub basic_auth_password: Option<Secret>,
+}
+
+/// Message format of a remote syslog server
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RsyslogFormat {
+	#[default]
+	Rfc5424,
+	Rfc3164,
+	/// Use the `logline` template
+	Custom,
+}
+
+/// `user_config` of an `rsyslog` integration endpoint
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RsyslogEndpointConfig {
+	pub server: String,
+	pub port: u16,
+	pub tls: bool,
+	pub format: RsyslogFormat,
+	/// Template of a line for `RsyslogFormat::Custom`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub logline: Option<String>,
+	/// Structured data block, e.g. a token of a log service
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub sd: Option<String>,
+	/// PEM encoded CA certificate
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ca: Option<String>,
+	/// PEM encoded client certificate
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cert: Option<String>,
+	/// PEM encoded client key
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub key: Option<Secret>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_message_size: Option<i64>,
+}
+
+/// What an autoscaler scales
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoscalingType {
+	#[default]
+	AutoscaleDisk,
+	#[serde(other)]
+	Unknown,
+}
+
+/// A rule of an `autoscaler` integration endpoint
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct AutoscalingRule {
+	#[serde(rename = "type")]
+	pub kind: AutoscalingType,
+	/// Upper limit of the additional disk space in GB
+	pub cap_gb: u32,
+}
+
+impl AutoscalingRule {
+	/// Grow the disk by at most `cap_gb` GB beyond the plan
+	pub fn disk(cap_gb: u32) -> Self {
+		Self {
+			kind: AutoscalingType::AutoscaleDisk,
+			cap_gb,
+		}
+	}
+}
+
+/// `user_config` of an `autoscaler` integration endpoint
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct AutoscalerEndpointConfig {
+	pub autoscaling: Vec<AutoscalingRule>,
+}
+
+impl AutoscalerEndpointConfig {
+	/// The disk space cap in GB, if the endpoint autoscales disks
+	pub fn disk_cap_gb(&self) -> Option<u32> {
+		self.autoscaling
+			.iter()
+			.find(|rule| rule.kind == AutoscalingType::AutoscaleDisk)
+			.map(|rule| rule.cap_gb)
+	}
+}
+
+/// The autoscaler endpoint a service is integrated with
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ServiceAutoscaler {
+	pub service_integration_id: String,
+	pub endpoint_id: String,
+	pub endpoint_name: String,
+	pub config: AutoscalerEndpointConfig,
+}
+
+/// Type and `user_config` of an integration endpoint
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(
+	tag = "endpoint_type",
+	content = "user_config",
+	rename_all = "snake_case"
+)]
+pub enum IntegrationEndpointConfig {
+	Autoscaler(AutoscalerEndpointConfig),
+	Datadog(DatadogEndpointConfig),
+	ExternalKafka(ExternalKafkaEndpointConfig),
+	Prometheus(PrometheusEndpointConfig),
+	Rsyslog(RsyslogEndpointConfig),
+}
+
+impl IntegrationEndpointConfig {
+	/// The `endpoint_type` the API uses for this config
+	pub fn endpoint_type(&self) -> &'static str {
+		match self {
+			IntegrationEndpointConfig::Autoscaler(_) => "autoscaler",
+			IntegrationEndpointConfig::Datadog(_) => "datadog",
+			IntegrationEndpointConfig::ExternalKafka(_) => "external_kafka",
+			IntegrationEndpointConfig::Prometheus(_) => "prometheus",
+			IntegrationEndpointConfig::Rsyslog(_) => "rsyslog",
+		}
+	}
+}
+
+impl ServiceIntegrationEndPoint {
+	/// The `user_config` parsed according to `endpoint_type`. Fails for
+	/// endpoint types without a typed config.
+	pub fn typed_config(&self) -> Result<IntegrationEndpointConfig, AivenError> {
+		Ok(serde_json::from_value(serde_json::json!({
+			"endpoint_type": self.endpoint_type,
+			"user_config": self.user_config,
+		}))?)
+	}
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TopologyNodeKind {
+	#[default]
+	Service,
+	Endpoint,
+}
+
+/// A service or an integration endpoint of a project. Services are
+/// identified by their name, endpoints by their id.
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct TopologyNode {
+	pub id: String,
+	pub name: String,
+	pub kind: TopologyNodeKind,
+	/// Service type or endpoint type, e.g. `pg` or `datadog`
+	pub node_type: String,
+}
+
+/// A service integration between two nodes
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct TopologyEdge {
+	pub service_integration_id: String,
+	pub integration_type: String,
+	pub source: String,
+	pub dest: String,
+	pub enabled: bool,
+}
+
+/// Graph of the services and integration endpoints of a project, connected
+/// by their service integrations
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct Topology {
+	pub nodes: Vec<TopologyNode>,
+	pub edges: Vec<TopologyEdge>,
+}
+
+impl Topology {
+	pub fn build(services: &[Service], endpoints: &[ServiceIntegrationEndPoint]) -> Topology {
+		let mut nodes: Vec<TopologyNode> = services
+			.iter()
+			.map(|s| TopologyNode {
+				id: s.service_name.clone(),
+				name: s.service_name.clone(),
+				kind: TopologyNodeKind::Service,
+				node_type: s.service_type.clone(),
+			})
+			.collect();
+		nodes.extend(endpoints.iter().map(|e| TopologyNode {
+			id: e.endpoint_id.clone(),
+			name: e.endpoint_name.clone(),
+			kind: TopologyNodeKind::Endpoint,
+			node_type: e.endpoint_type.clone(),
+		}));
+
+		// Every integration is listed on both of the services it connects
+		let mut seen = HashSet::new();
+		let mut edges = Vec::new();
+		for integration in services.iter().flat_map(|s| &s.service_integrations) {
+			if !seen.insert(integration.service_integration_id.as_str()) {
+				continue;
+			}
+			let (source, dest) = match (Self::source(integration), Self::dest(integration)) {
+				(Some(source), Some(dest)) => (source, dest),
+				_ => continue,
+			};
+			edges.push(TopologyEdge {
+				service_integration_id: integration.service_integration_id.clone(),
+				integration_type: integration.integration_type.clone(),
+				source: source.to_string(),
+				dest: dest.to_string(),
+				enabled: integration.enabled,
+			});
+		}
+		Topology { nodes, edges }
+	}
+
+	fn source(integration: &types_service::ServiceIntegration) -> Option<&str> {
+		non_empty(&integration.source_service).or(non_empty(&integration.source_endpoint_id))
+	}
+
+	fn dest(integration: &types_service::ServiceIntegration) -> Option<&str> {
+		non_empty(&integration.dest_service).or(non_empty(&integration.dest_endpoint_id))
+	}
+
+	pub fn node(&self, id: &str) -> Option<&TopologyNode> {
+		self.nodes.iter().find(|n| n.id == id)
+	}
+
+	/// Integrations going out of the node with the given id
+	pub fn edges_from(&self, id: &str) -> Vec<&TopologyEdge> {
+		self.edges.iter().filter(|e| e.source == id).collect()
+	}
+
+	/// Integrations coming into the node with the given id
+	pub fn edges_to(&self, id: &str) -> Vec<&TopologyEdge> {
+		self.edges.iter().filter(|e| e.dest == id).collect()
+	}
+
+	/// Render the graph in graphviz DOT format
+	pub fn to_dot(&self) -> String {
+		let mut dot = String::from("digraph topology {\n");
+		for node in &self.nodes {
+			let shape = match node.kind {
+				TopologyNodeKind::Service => "box",
+				TopologyNodeKind::Endpoint => "ellipse",
+			};
+			dot.push_str(&format!(
+				"  {} [label={}, shape={}];\n",
+				dot_string(&node.id),
+				dot_string(&format!("{} ({})", node.name, node.node_type)),
+				shape
+			));
+		}
+		for edge in &self.edges {
+			dot.push_str(&format!(
+				"  {} -> {} [label={}];\n",
+				dot_string(&edge.source),
+				dot_string(&edge.dest),
+				dot_string(&edge.integration_type)
+			));
+		}
+		dot.push_str("}\n");
+		dot
+	}
+}
+
+fn non_empty(value: &str) -> Option<&str> {
+	Some(value).filter(|v| !v.is_empty())
+}
+
+/// `value` as a quoted DOT string
+fn dot_string(value: &str) -> String {
+	let mut quoted = String::with_capacity(value.len() + 2);
+	quoted.push('"');
+	for c in value.chars() {
+		match c {
+			'"' => quoted.push_str("\\\""),
+			'\\' => quoted.push_str("\\\\"),
+			'\n' => quoted.push_str("\\n"),
+			'\r' => {}
+			c => quoted.push(c),
+		}
+	}
+	quoted.push('"');
+	quoted
+}
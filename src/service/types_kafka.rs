@@ -20,9 +20,80 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::customdeser;
+use crate::{client::json_size, customdeser, errors::AivenError};
 use serde::{Deserialize, Serialize};
-#[derive(Deserialize, Serialize, Debug, Default)]
+use std::collections::BTreeMap;
+
+/// Largest request body accepted by the Kafka REST produce endpoint
+pub const KAFKA_REST_MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Split `records` into consecutive batches whose json encoded `records`
+/// array fits in `max_bytes`.
+///
+/// The produce request also carries `format` and optional schemas, so pass a
+/// limit below `KAFKA_REST_MAX_BODY_BYTES` leaving room for those fields.
+/// Fails with `AivenError::RequestBodyTooLarge` if a single record alone
+/// does not fit.
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use aiven_rs::service::types_kafka::{chunk_records, KAFKA_REST_MAX_BODY_BYTES};
+/// use serde_json::json;
+/// #[tokio::main]
+/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+///
+/// let records: Vec<_> = (0..100_000).map(|i| json!({"value": {"id": i}})).collect();
+/// for batch in chunk_records(&records, KAFKA_REST_MAX_BODY_BYTES - 4096)? {
+///     client
+///         .service_kafka()
+///         .produce_message("myproject", "myservicename", "topic", &json!({
+///             "format": "json",
+///             "records": batch,
+///         }))
+///         .await?;
+/// }
+/// Ok(())
+/// }
+/// ```
+pub fn chunk_records<R: Serialize>(
+	records: &[R],
+	max_bytes: usize,
+) -> Result<Vec<&[R]>, AivenError> {
+	let mut batches = Vec::new();
+	let mut start = 0;
+	// Size of the surrounding `[` and `]`
+	let mut size = 2;
+	for (idx, record) in records.iter().enumerate() {
+		let record_size = json_size(record)?;
+		if record_size + 2 > max_bytes {
+			return Err(AivenError::RequestBodyTooLarge {
+				size: record_size + 2,
+				limit: max_bytes,
+			});
+		}
+		// Records after the first one in a batch are preceded by a `,`
+		let added = if idx == start {
+			record_size
+		} else {
+			record_size + 1
+		};
+		if size + added > max_bytes {
+			batches.push(&records[start..idx]);
+			start = idx;
+			size = 2 + record_size;
+		} else {
+			size += added;
+		}
+	}
+	if start < records.len() {
+		batches.push(&records[start..]);
+	}
+	Ok(batches)
+}
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
 pub struct AclDefinition {
 	pub id: String,
 	pub permission: String,
@@ -160,16 +231,45 @@ pub struct RespKafkaTopicInfo {
 pub struct ResKafkaSchemaRegistryVersions {
 	pub versions: Vec<i32>,
 }
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct RespKafkaTopicList {
 	pub topics: Vec<Topic>,
 }
 
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TaskState {
+	Running,
+	Failed,
+	Paused,
+	#[default]
+	Unassigned,
+	#[serde(other)]
+	Unknown,
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ConnectTask {
 	pub id: i32,
-	pub state: String,
-	pub trace: String,
+	pub state: TaskState,
+	/// Stack trace of the error which made the task fail, usually only set
+	/// for `FAILED` tasks
+	#[serde(default)]
+	pub trace: Option<String>,
+	/// `host:port` of the Connect worker running the task
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub worker_id: Option<String>,
+}
+
+impl ConnectTask {
+	/// The trace of a failed task
+	pub fn failure_trace(&self) -> Option<&str> {
+		match self.state {
+			TaskState::Failed => Some(self.trace.as_deref().unwrap_or_default()),
+			_ => None,
+		}
+	}
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
@@ -177,6 +277,21 @@ pub struct ConnectorStatus {
 	pub state: String,
 	pub tasks: Vec<ConnectTask>,
 }
+
+impl ConnectorStatus {
+	pub fn failed_tasks(&self) -> impl Iterator<Item = &ConnectTask> {
+		self.tasks
+			.iter()
+			.filter(|task| task.state == TaskState::Failed)
+	}
+}
+
+/// Topics a connector has used since its creation or the last reset
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ConnectorTopics {
+	pub topics: Vec<String>,
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResKafkaConnectConnectorStatus {
 	pub status: ConnectorStatus,
@@ -218,27 +333,83 @@ pub struct ResKafkaConnectConnectorList {
 	pub plugins: Vec<ConnectorPlugin>,
 }
 
+/// Outcome of one produced record. The REST proxy sends `null` for the
+/// error fields and schema ids it has no value for, these deserialize to
+/// their defaults.
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct Offset {
+	#[serde(default, deserialize_with = "customdeser::null_as_default")]
 	pub error: String,
+	#[serde(default, deserialize_with = "customdeser::null_as_default")]
 	pub error_code: i64,
+	#[serde(default, deserialize_with = "customdeser::null_as_default")]
 	pub offset: i64,
+	#[serde(default, deserialize_with = "customdeser::null_as_default")]
+	pub partition: i64,
+}
+
+impl Offset {
+	/// Where the record was written, or why it was not
+	pub fn result(&self) -> Result<RecordOffset, RecordError> {
+		if self.error_code == 0 && self.error.is_empty() {
+			Ok(RecordOffset {
+				partition: self.partition,
+				offset: self.offset,
+			})
+		} else {
+			Err(RecordError {
+				error_code: self.error_code,
+				message: self.error.clone(),
+			})
+		}
+	}
+}
+
+/// Partition and offset a record was written to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordOffset {
 	pub partition: i64,
+	pub offset: i64,
+}
+
+/// Error the REST proxy reported for a single record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordError {
+	pub error_code: i64,
+	pub message: String,
 }
+
+impl std::fmt::Display for RecordError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "error {}: {}", self.error_code, self.message)
+	}
+}
+
+impl std::error::Error for RecordError {}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResKafkaProduceMessage {
+	#[serde(default, deserialize_with = "customdeser::null_as_default")]
 	pub key_schema_id: i64,
 	pub offsets: Vec<Offset>,
+	#[serde(default, deserialize_with = "customdeser::null_as_default")]
 	pub value_schema_id: i64,
 }
 
+impl ResKafkaProduceMessage {
+	/// Per-record results, in the order the records were sent
+	pub fn results(&self) -> Vec<Result<RecordOffset, RecordError>> {
+		self.offsets.iter().map(Offset::result).collect()
+	}
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResKafkaSchemaRegistryConfig {
 	#[serde(rename = "compatibilityLevel")]
 	pub compatibility_level: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, Default)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
 pub struct KafkaACL {
 	pub id: String,
 	pub permission: String,
@@ -251,12 +422,381 @@ pub struct ResKafkaACLEntries {
 	pub acl: Vec<KafkaACL>,
 }
 
+/// Permission granted by a schema registry ACL entry
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaRegistryAclPermission {
+	#[default]
+	SchemaRegistryRead,
+	SchemaRegistryWrite,
+	#[serde(other)]
+	Unknown,
+}
+
+/// A schema registry ACL entry. `resource` is `Config:` for the global
+/// configuration or `Subject:<pattern>` for subjects, patterns may use `*`
+/// and `?` like `username`. The `id` is assigned by the API and left empty
+/// when adding an entry.
+///
+/// ```rust
+/// use aiven_rs::service::types_kafka::{SchemaRegistryAclEntry, SchemaRegistryAclPermission};
+///
+/// let entry = SchemaRegistryAclEntry::subject(
+///     "orders-app",
+///     "orders-*",
+///     SchemaRegistryAclPermission::SchemaRegistryRead,
+/// );
+/// assert!(entry.resource == "Subject:orders-*");
+/// ```
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct SchemaRegistryAclEntry {
+	#[serde(default, skip_serializing_if = "String::is_empty")]
+	pub id: String,
+	pub permission: SchemaRegistryAclPermission,
+	pub resource: String,
+	pub username: String,
+}
+
+impl SchemaRegistryAclEntry {
+	/// Grant `username` access to the subjects matching `subject_pattern`
+	pub fn subject<U: Into<String>>(
+		username: U,
+		subject_pattern: &str,
+		permission: SchemaRegistryAclPermission,
+	) -> Self {
+		SchemaRegistryAclEntry {
+			id: String::new(),
+			permission,
+			resource: format!("Subject:{}", subject_pattern),
+			username: username.into(),
+		}
+	}
+
+	/// Grant `username` access to the global configuration
+	pub fn config<U: Into<String>>(username: U, permission: SchemaRegistryAclPermission) -> Self {
+		SchemaRegistryAclEntry {
+			id: String::new(),
+			permission,
+			resource: "Config:".to_owned(),
+			username: username.into(),
+		}
+	}
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResSchemaRegistryAclEntries {
+	pub acl: Vec<SchemaRegistryAclEntry>,
+}
+
+/// Outcome of `ServiceKafkaApi::rename_acl_user`
+#[derive(Debug, Default)]
+pub struct AclRenameReport {
+	/// Entries created for the new username
+	pub created: Vec<KafkaACL>,
+	/// Entries of the old username which were removed
+	pub removed: Vec<KafkaACL>,
+	/// Entries of the old username which could not be removed, with the error
+	pub not_removed: Vec<(KafkaACL, String)>,
+}
+
+impl AclRenameReport {
+	/// True when every entry of the old username was moved over
+	pub fn is_complete(&self) -> bool {
+		self.not_removed.is_empty()
+	}
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct RespKafkaSchemaRegistrySubjects {
 	pub subjects: Vec<String>,
 }
 
+/// How `delete_subjects_matching` treats the matched subjects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeleteMode {
+	/// Only list the subjects which would be deleted
+	DryRun,
+	/// Delete the subjects, at most `concurrency` at a time
+	Delete { concurrency: usize },
+}
+
+/// Outcome of `delete_subjects_matching`
+#[derive(Debug, Default)]
+pub struct SubjectDeletion {
+	/// Subjects matching the pattern, the plan of a dry run
+	pub matched: Vec<String>,
+	pub deleted: Vec<String>,
+	pub failed: Vec<(String, AivenError)>,
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResKafkaRegisterSchema {
 	pub id: i32,
 }
+
+/// A subject version in the shape of the Confluent schema registry
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct SchemaRegistrySubjectVersion {
+	pub subject: String,
+	pub id: i32,
+	pub version: i32,
+	pub schema: String,
+	#[serde(rename = "schemaType", default)]
+	pub schema_type: Option<String>,
+}
+
+/// A schema in the shape of the Confluent schema registry. `schema_type` is
+/// `None` for Avro schemas.
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct SchemaRegistrySchema {
+	pub schema: String,
+	#[serde(rename = "schemaType", default)]
+	pub schema_type: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResKafkaSchemaVersion {
+	pub version: SchemaRegistrySubjectVersion,
+}
+
+/// Retention behaviour of a topic
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupPolicy {
+	Delete,
+	Compact,
+	#[serde(rename = "compact,delete")]
+	CompactDelete,
+}
+
+/// Body of `ServiceKafkaApi::create_kafka_topic`
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// use aiven_rs::service::types_kafka::{CleanupPolicy, KafkaTopicCreateRequest};
+/// let request = KafkaTopicCreateRequest::new("orders")
+///     .partitions(6)
+///     .replication(3)
+///     .retention_hours(72)
+///     .cleanup_policy(CleanupPolicy::Delete)
+///     .config("max_message_bytes", 2097152);
+/// ```
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct KafkaTopicCreateRequest {
+	pub topic_name: String,
+	#[serde(flatten)]
+	pub settings: KafkaTopicUpdateRequest,
+}
+
+impl KafkaTopicCreateRequest {
+	pub fn new<S: Into<String>>(topic_name: S) -> Self {
+		KafkaTopicCreateRequest {
+			topic_name: topic_name.into(),
+			settings: KafkaTopicUpdateRequest::default(),
+		}
+	}
+
+	pub fn partitions(mut self, partitions: u32) -> Self {
+		self.settings = self.settings.partitions(partitions);
+		self
+	}
+
+	pub fn replication(mut self, replication: u32) -> Self {
+		self.settings = self.settings.replication(replication);
+		self
+	}
+
+	pub fn min_insync_replicas(mut self, replicas: u32) -> Self {
+		self.settings = self.settings.min_insync_replicas(replicas);
+		self
+	}
+
+	pub fn retention_hours(mut self, hours: i64) -> Self {
+		self.settings = self.settings.retention_hours(hours);
+		self
+	}
+
+	pub fn retention_bytes(mut self, bytes: i64) -> Self {
+		self.settings = self.settings.retention_bytes(bytes);
+		self
+	}
+
+	pub fn cleanup_policy(mut self, policy: CleanupPolicy) -> Self {
+		self.settings = self.settings.cleanup_policy(policy);
+		self
+	}
+
+	/// Set a per-topic config entry, e.g. `max_message_bytes`
+	pub fn config<K: Into<String>, V: Into<serde_json::Value>>(mut self, key: K, value: V) -> Self {
+		self.settings = self.settings.config(key, value);
+		self
+	}
+}
+
+/// Body of `ServiceKafkaApi::update_topic`, unset fields are left unchanged
+///
+/// # Examples
+/// Basic usage:
+///
+/// ```rust
+/// use aiven_rs::service::types_kafka::KafkaTopicUpdateRequest;
+/// let request = KafkaTopicUpdateRequest::new()
+///     .partitions(12)
+///     .retention_bytes(1 << 30);
+/// ```
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct KafkaTopicUpdateRequest {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub partitions: Option<u32>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub replication: Option<u32>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub min_insync_replicas: Option<u32>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub retention_hours: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub retention_bytes: Option<i64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cleanup_policy: Option<CleanupPolicy>,
+	#[serde(skip_serializing_if = "BTreeMap::is_empty")]
+	pub config: BTreeMap<String, serde_json::Value>,
+}
+
+impl KafkaTopicUpdateRequest {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn partitions(mut self, partitions: u32) -> Self {
+		self.partitions = Some(partitions);
+		self
+	}
+
+	pub fn replication(mut self, replication: u32) -> Self {
+		self.replication = Some(replication);
+		self
+	}
+
+	pub fn min_insync_replicas(mut self, replicas: u32) -> Self {
+		self.min_insync_replicas = Some(replicas);
+		self
+	}
+
+	pub fn retention_hours(mut self, hours: i64) -> Self {
+		self.retention_hours = Some(hours);
+		self
+	}
+
+	pub fn retention_bytes(mut self, bytes: i64) -> Self {
+		self.retention_bytes = Some(bytes);
+		self
+	}
+
+	pub fn cleanup_policy(mut self, policy: CleanupPolicy) -> Self {
+		self.cleanup_policy = Some(policy);
+		self
+	}
+
+	/// Set a per-topic config entry, e.g. `max_message_bytes`
+	pub fn config<K: Into<String>, V: Into<serde_json::Value>>(mut self, key: K, value: V) -> Self {
+		self.config.insert(key.into(), value.into());
+		self
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_topic_create_request_serialization() {
+		let request = KafkaTopicCreateRequest::new("orders")
+			.partitions(6)
+			.replication(3)
+			.cleanup_policy(CleanupPolicy::CompactDelete)
+			.config("max_message_bytes", 2097152);
+		assert!(
+			serde_json::to_value(&request).unwrap()
+				== json!({
+					"topic_name": "orders",
+					"partitions": 6,
+					"replication": 3,
+					"cleanup_policy": "compact,delete",
+					"config": {"max_message_bytes": 2097152}
+				})
+		);
+		assert!(serde_json::to_value(KafkaTopicUpdateRequest::new()).unwrap() == json!({}));
+	}
+
+	#[test]
+	fn test_produce_results_with_null_errors() {
+		let response: ResKafkaProduceMessage = serde_json::from_value(json!({
+			"key_schema_id": null,
+			"offsets": [
+				{"error": null, "error_code": null, "offset": 7, "partition": 1},
+				{"error": "Leader not available", "error_code": 50003, "offset": null, "partition": null}
+			],
+			"value_schema_id": null
+		}))
+		.unwrap();
+		let results = response.results();
+		assert!(
+			results[0]
+				== Ok(RecordOffset {
+					partition: 1,
+					offset: 7
+				})
+		);
+		assert!(
+			results[1]
+				== Err(RecordError {
+					error_code: 50003,
+					message: "Leader not available".to_string()
+				})
+		);
+	}
+
+	#[test]
+	fn test_chunk_records_fits_limit() {
+		let records: Vec<_> = (0..10).map(|i| json!({ "value": i })).collect();
+		// Each record is `{"value":N}`, 11 bytes
+		let batches = chunk_records(&records, 40).unwrap();
+		assert!(batches.iter().map(|b| b.len()).collect::<Vec<_>>() == vec![3, 3, 3, 1]);
+		for batch in &batches {
+			assert!(json_size(batch).unwrap() <= 40);
+		}
+		assert!(chunk_records(&records, 1024).unwrap().len() == 1);
+		assert!(chunk_records::<u8>(&[], 10).unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_chunk_records_single_record_too_large() {
+		let records = vec![json!({ "value": "x".repeat(100) })];
+		match chunk_records(&records, 50) {
+			Err(AivenError::RequestBodyTooLarge { limit, .. }) => assert!(limit == 50),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+
+	#[test]
+	fn test_connector_status_task_states() {
+		let status: ConnectorStatus = serde_json::from_value(json!({
+			"state": "RUNNING",
+			"tasks": [
+				{ "id": 0, "state": "RUNNING" },
+				{ "id": 1, "state": "FAILED", "trace": "java.lang.OutOfMemoryError" },
+				{ "id": 2, "state": "RESTARTING" }
+			]
+		}))
+		.unwrap();
+		assert!(status.tasks[0].state == TaskState::Running);
+		assert!(status.tasks[0].failure_trace().is_none());
+		assert!(status.tasks[2].state == TaskState::Unknown);
+		let failed: Vec<_> = status.failed_tasks().collect();
+		assert!(failed.len() == 1);
+		assert!(failed[0].failure_trace() == Some("java.lang.OutOfMemoryError"));
+	}
+}
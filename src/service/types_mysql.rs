@@ -26,3 +26,71 @@ use serde::{Deserialize, Serialize};
 pub struct ResMySqlQueriesStats {
 	pub queries: Vec<serde_json::Value>,
 }
+
+/// A single row of `performance_schema.events_statements_summary_by_digest`
+/// as returned by the query statistics endpoint. Timer values are in
+/// picoseconds, as MySQL reports them.
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct MySqlQueryStat {
+	pub schema_name: Option<String>,
+	pub digest: Option<String>,
+	pub digest_text: Option<String>,
+	pub query_sample_text: Option<String>,
+	pub count_star: f64,
+	pub sum_timer_wait: f64,
+	pub min_timer_wait: f64,
+	pub avg_timer_wait: f64,
+	pub max_timer_wait: f64,
+	pub sum_lock_time: f64,
+	pub sum_errors: f64,
+	pub sum_warnings: f64,
+	pub sum_rows_affected: f64,
+	pub sum_rows_sent: f64,
+	pub sum_rows_examined: f64,
+	pub sum_created_tmp_disk_tables: f64,
+	pub sum_created_tmp_tables: f64,
+	pub sum_select_full_join: f64,
+	pub sum_select_scan: f64,
+	pub sum_sort_rows: f64,
+	pub sum_no_index_used: f64,
+	pub first_seen: Option<String>,
+	pub last_seen: Option<String>,
+}
+
+const PICOSECONDS_PER_MILLISECOND: f64 = 1_000_000_000.0;
+
+impl MySqlQueryStat {
+	/// The normalized statement, falling back to a sample of it
+	pub fn query(&self) -> &str {
+		self.digest_text
+			.as_deref()
+			.or(self.query_sample_text.as_deref())
+			.unwrap_or_default()
+	}
+
+	/// Number of times the statement ran
+	pub fn calls(&self) -> f64 {
+		self.count_star
+	}
+
+	/// Total execution time in milliseconds
+	pub fn total_time(&self) -> f64 {
+		self.sum_timer_wait / PICOSECONDS_PER_MILLISECOND
+	}
+
+	/// Mean execution time in milliseconds
+	pub fn mean_time(&self) -> f64 {
+		self.avg_timer_wait / PICOSECONDS_PER_MILLISECOND
+	}
+
+	/// Rows returned to the client
+	pub fn rows(&self) -> f64 {
+		self.sum_rows_sent
+	}
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResMySqlQueryStatRows {
+	pub queries: Vec<MySqlQueryStat>,
+}
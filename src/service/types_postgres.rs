@@ -25,3 +25,29 @@ use serde::{Deserialize, Serialize};
 pub struct ResPostgresQueriesStats {
 	pub queries: Vec<serde_json::Value>,
 }
+
+/// A single row of `pg_stat_statements` as returned by the query statistics
+/// endpoint. Times are in milliseconds.
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct PgQueryStat {
+	pub query: String,
+	pub queryid: Option<i64>,
+	pub database_name: String,
+	pub user_name: String,
+	pub calls: f64,
+	pub rows: f64,
+	pub total_time: f64,
+	pub mean_time: f64,
+	pub min_time: f64,
+	pub max_time: f64,
+	pub stddev_time: f64,
+	pub shared_blks_hit: f64,
+	pub shared_blks_read: f64,
+	pub temp_blks_written: f64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResPostgresQueryStatRows {
+	pub queries: Vec<PgQueryStat>,
+}
@@ -20,7 +20,8 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use std::collections::HashMap;
+use crate::{errors::AivenError, Secret};
+use std::{collections::HashMap, time::Duration};
 
 use serde::{Deserialize, Serialize};
 
@@ -46,13 +47,125 @@ pub struct ACL {
 	pub username: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, Default)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct Backup {
 	pub backup_name: String,
 	pub backup_time: String,
 	pub data_size: i64,
 }
 
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResBackups {
+	pub backups: Vec<Backup>,
+}
+
+/// `user_config` of a service forked from another one
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct ServiceForkConfig {
+	pub service_to_fork_from: String,
+	/// Project of the source service, when it is in another project
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub project_to_fork_from: Option<String>,
+	/// Point in time to restore to, in ISO 8601 format. Without it the
+	/// latest backup is restored.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub recovery_target_time: Option<String>,
+}
+
+/// Create a service restored from the backups of another service
+///
+/// ```rust
+/// use aiven_rs::service::types_service::ServiceForkRequest;
+/// let request = ServiceForkRequest::new("pg-restored", "pg", "business-4", "pg-prod")
+///     .cloud("google-europe-west1")
+///     .recovery_target_time("2021-04-01T10:00:00Z");
+/// ```
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct ServiceForkRequest {
+	pub service_name: String,
+	pub service_type: String,
+	pub plan: String,
+	/// Defaults to the cloud of the project
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub cloud: Option<String>,
+	pub user_config: ServiceForkConfig,
+}
+
+impl ServiceForkRequest {
+	pub fn new(
+		service_name: impl Into<String>,
+		service_type: impl Into<String>,
+		plan: impl Into<String>,
+		service_to_fork_from: impl Into<String>,
+	) -> Self {
+		Self {
+			service_name: service_name.into(),
+			service_type: service_type.into(),
+			plan: plan.into(),
+			cloud: None,
+			user_config: ServiceForkConfig {
+				service_to_fork_from: service_to_fork_from.into(),
+				..Default::default()
+			},
+		}
+	}
+
+	pub fn cloud<S: Into<String>>(mut self, cloud: S) -> Self {
+		self.cloud = Some(cloud.into());
+		self
+	}
+
+	/// Fork a service of another project
+	pub fn project_to_fork_from<S: Into<String>>(mut self, project: S) -> Self {
+		self.user_config.project_to_fork_from = Some(project.into());
+		self
+	}
+
+	/// Restore to a point in time, only supported by PostgreSQL and MySQL
+	pub fn recovery_target_time<S: Into<String>>(mut self, time: S) -> Self {
+		self.user_config.recovery_target_time = Some(time.into());
+		self
+	}
+
+	/// Restore to the time `backup` was taken
+	pub fn from_backup(self, backup: &Backup) -> Self {
+		let time = backup.backup_time.clone();
+		self.recovery_target_time(time)
+	}
+
+	pub(crate) fn validate(&self) -> Result<(), AivenError> {
+		let invalid = |field: &str, reason: String| {
+			Err(AivenError::InvalidInput {
+				field: field.to_owned(),
+				reason,
+			})
+		};
+		let config = &self.user_config;
+		if config.service_to_fork_from.is_empty() {
+			return invalid("service_to_fork_from", "must not be empty".to_owned());
+		}
+		if config.project_to_fork_from.is_none() && config.service_to_fork_from == self.service_name
+		{
+			return invalid(
+				"service_name",
+				format!("`{}` can't be forked onto itself", self.service_name),
+			);
+		}
+		if config.recovery_target_time.is_some()
+			&& !matches!(self.service_type.as_str(), "pg" | "mysql")
+		{
+			return invalid(
+				"recovery_target_time",
+				format!(
+					"point in time recovery isn't supported by `{}` services",
+					self.service_type
+				),
+			);
+		}
+		Ok(())
+	}
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct Component {
 	pub component: String,
@@ -106,23 +219,33 @@ pub struct NodeState {
 	pub state: String,
 }
 
+/// An integration of a service. The source or destination is either a
+/// service or an endpoint, the fields of the other one are empty.
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ServiceIntegration {
 	pub active: bool,
 	pub description: String,
+	#[serde(default, deserialize_with = "crate::customdeser::null_as_default")]
 	pub dest_endpoint: String,
+	#[serde(default, deserialize_with = "crate::customdeser::null_as_default")]
 	pub dest_endpoint_id: String,
 	pub dest_project: String,
+	#[serde(default, deserialize_with = "crate::customdeser::null_as_default")]
 	pub dest_service: String,
+	#[serde(default, deserialize_with = "crate::customdeser::null_as_default")]
 	pub dest_service_type: String,
 	pub enabled: bool,
 	pub integration_status: HashMap<String, serde_json::Value>,
 	pub integration_type: String,
 	pub service_integration_id: String,
+	#[serde(default, deserialize_with = "crate::customdeser::null_as_default")]
 	pub source_endpoint: String,
+	#[serde(default, deserialize_with = "crate::customdeser::null_as_default")]
 	pub source_endpoint_id: String,
 	pub source_project: String,
+	#[serde(default, deserialize_with = "crate::customdeser::null_as_default")]
 	pub source_service: String,
+	#[serde(default, deserialize_with = "crate::customdeser::null_as_default")]
 	pub source_service_type: String,
 	pub user_config: HashMap<String, serde_json::Value>,
 }
@@ -148,6 +271,8 @@ pub struct User {
 	#[serde(rename = "type")]
 	pub user_type: String,
 	pub username: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub access_control: Option<ServiceUserAccessControl>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
@@ -162,9 +287,11 @@ pub struct Service {
 	pub create_time: String,
 	pub disk_space_mb: i64,
 	pub databases: Option<Vec<String>>,
+	#[serde(default)]
 	pub features: HashMap<String, serde_json::Value>,
 	pub group_list: Vec<String>,
 	pub maintenance: Maintenance,
+	#[serde(default)]
 	pub metadata: HashMap<String, serde_json::Value>,
 	pub node_count: i32,
 	pub node_cpu_count: i32,
@@ -184,12 +311,254 @@ pub struct Service {
 	pub update_time: String,
 	pub user_config: HashMap<String, serde_json::Value>,
 	pub users: Vec<User>,
+	/// Warnings about the service, e.g. an upcoming end of life
+	#[serde(default, rename = "service_notifications", alias = "notifications")]
+	pub notifications: Vec<ServiceNotification>,
+}
+
+/// Severity of a [`ServiceNotification`]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceNotificationLevel {
+	#[default]
+	Notice,
+	Warning,
+	#[serde(other)]
+	Unknown,
+}
+
+/// A notification attached to a service, such as an end of life warning of
+/// its version or the removal of a powered off service
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ServiceNotification {
+	#[serde(default)]
+	pub level: ServiceNotificationLevel,
+	pub message: String,
+	/// Details depending on the type, e.g. `service_end_of_life_time` and
+	/// `upgrade_to_version`
+	#[serde(default)]
+	pub metadata: HashMap<String, serde_json::Value>,
+	/// E.g. `service_end_of_life` or `service_powered_off_removal`
+	#[serde(rename = "type")]
+	pub notification_type: String,
+}
+
+impl ServiceNotification {
+	pub fn is_warning(&self) -> bool {
+		self.level == ServiceNotificationLevel::Warning
+	}
 }
+
+impl Service {
+	/// Returns true if the given key in `features` is set to `true`.
+	/// Unknown keys or non-boolean values are treated as disabled.
+	pub fn feature_enabled(&self, feature: &str) -> bool {
+		self.features
+			.get(feature)
+			.and_then(serde_json::Value::as_bool)
+			.unwrap_or(false)
+	}
+
+	/// Returns true if Kafka Connect is enabled for this service
+	pub fn kafka_connect_enabled(&self) -> bool {
+		self.feature_enabled("kafka_connect")
+	}
+
+	/// Returns true if Kafka REST is enabled for this service
+	pub fn kafka_rest_enabled(&self) -> bool {
+		self.feature_enabled("kafka_rest")
+	}
+
+	/// Returns true if the schema registry is enabled for this service
+	pub fn schema_registry_enabled(&self) -> bool {
+		self.feature_enabled("schema_registry")
+	}
+
+	/// Returns the string value stored under `key` in `metadata`, if any.
+	pub fn metadata_str(&self, key: &str) -> Option<&str> {
+		self.metadata.get(key).and_then(serde_json::Value::as_str)
+	}
+
+	/// The `connection_info` of a Kafka service. Addresses missing from
+	/// `connection_info`, as for older services, are taken from
+	/// `components` and the service URI.
+	pub fn kafka_connection_info(&self) -> Result<KafkaConnectionInfo, AivenError> {
+		self.expect_service_type(&["kafka"])?;
+		let mut info: KafkaConnectionInfo = self.typed_connection_info()?;
+		if info.bootstrap_servers.is_empty() {
+			info.bootstrap_servers = match self.component_address("kafka") {
+				Some(address) => vec![address],
+				None if !self.service_uri.is_empty() => vec![self.service_uri.clone()],
+				None => Vec::new(),
+			};
+		}
+		let https = |component: &str| {
+			self.component_address(component)
+				.map(|address| Secret::new(format!("https://{}", address)))
+		};
+		info.connect_uri = info.connect_uri.or_else(|| https("kafka_connect"));
+		info.rest_uri = info.rest_uri.or_else(|| https("kafka_rest"));
+		info.schema_registry_uri = info
+			.schema_registry_uri
+			.or_else(|| https("schema_registry"));
+		Ok(info)
+	}
+
+	/// The `connection_info` of a PostgreSQL or MySQL service. For older
+	/// services without it the service URI and `service_uri_params` are
+	/// used.
+	pub fn database_connection_info(&self) -> Result<DatabaseConnectionInfo, AivenError> {
+		self.expect_service_type(&["pg", "mysql"])?;
+		let prefix = self.service_type.as_str();
+		let mut info = DatabaseConnectionInfo {
+			uris: self.connection_info_field(prefix)?,
+			params: self.connection_info_field(&format!("{}_params", prefix))?,
+			standby_uris: self.connection_info_field(&format!("{}_standby", prefix))?,
+			pgbouncer_uri: self.connection_info_field("pgbouncer")?,
+		};
+		if info.uris.is_empty() && !self.service_uri.is_empty() {
+			info.uris.push(Secret::new(self.service_uri.clone()));
+		}
+		if info.params.is_empty() && !self.service_uri_params.is_empty() {
+			let params = serde_json::Value::Object(
+				self.service_uri_params
+					.iter()
+					.map(|(k, v)| (k.clone(), v.clone()))
+					.collect(),
+			);
+			info.params.push(serde_json::from_value(params)?);
+		}
+		Ok(info)
+	}
+
+	/// `InvalidInput` unless the service is one of `service_types`
+	pub(crate) fn expect_service_type(&self, service_types: &[&str]) -> Result<(), AivenError> {
+		if service_types.contains(&self.service_type.as_str()) {
+			return Ok(());
+		}
+		Err(self.wrong_service_type(service_types))
+	}
+
+	pub(crate) fn wrong_service_type(&self, service_types: &[&str]) -> AivenError {
+		AivenError::InvalidInput {
+			field: "service_type".to_owned(),
+			reason: format!(
+				"`{}` is a `{}` service, expected {}",
+				self.service_name,
+				self.service_type,
+				service_types.join(" or ")
+			),
+		}
+	}
+
+	fn typed_connection_info<T: serde::de::DeserializeOwned>(&self) -> Result<T, AivenError> {
+		let info = serde_json::Value::Object(
+			self.connection_info
+				.iter()
+				.map(|(k, v)| (k.clone(), v.clone()))
+				.collect(),
+		);
+		Ok(serde_json::from_value(info)?)
+	}
+
+	/// `key` of `connection_info`, with `null` or a missing key as default
+	fn connection_info_field<T>(&self, key: &str) -> Result<T, AivenError>
+	where
+		T: serde::de::DeserializeOwned + Default,
+	{
+		match self.connection_info.get(key) {
+			None | Some(serde_json::Value::Null) => Ok(T::default()),
+			Some(value) => Ok(serde_json::from_value(value.clone())?),
+		}
+	}
+
+	/// `host:port` of the primary `component`
+	fn component_address(&self, component: &str) -> Option<String> {
+		let mut matching = self.components.iter().filter(|c| c.component == component);
+		matching
+			.clone()
+			.find(|c| c.usage == "primary")
+			.or_else(|| matching.next())
+			.map(|c| format!("{}:{}", c.host, c.port))
+	}
+}
+
+/// Typed `connection_info` of a Kafka service, see
+/// `Service::kafka_connection_info`
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct KafkaConnectionInfo {
+	/// `host:port` of the brokers
+	#[serde(default, rename = "kafka")]
+	pub bootstrap_servers: Vec<String>,
+	#[serde(default, rename = "kafka_access_cert")]
+	pub access_cert: Option<String>,
+	#[serde(default, rename = "kafka_access_key")]
+	pub access_key: Option<Secret>,
+	/// Only set when Kafka Connect runs on the Kafka nodes. The URIs hold
+	/// the password of the service.
+	#[serde(default, rename = "kafka_connect_uri")]
+	pub connect_uri: Option<Secret>,
+	#[serde(default, rename = "kafka_rest_uri")]
+	pub rest_uri: Option<Secret>,
+	#[serde(default)]
+	pub schema_registry_uri: Option<Secret>,
+}
+
+/// Typed `connection_info` of a PostgreSQL or MySQL service, see
+/// `Service::database_connection_info`
+#[derive(Serialize, Debug, Default, Clone, PartialEq)]
+pub struct DatabaseConnectionInfo {
+	/// URIs of the primary, holding the password of the user
+	pub uris: Vec<Secret>,
+	pub params: Vec<DatabaseConnectionParams>,
+	/// URIs of the read-only replicas
+	pub standby_uris: Vec<Secret>,
+	/// Only set for PostgreSQL services with connection pooling
+	pub pgbouncer_uri: Option<Secret>,
+}
+
+/// Connection parameters of one database node
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct DatabaseConnectionParams {
+	pub host: String,
+	#[serde(deserialize_with = "crate::customdeser::string_or_number")]
+	pub port: String,
+	pub user: String,
+	pub password: Secret,
+	#[serde(default)]
+	pub dbname: Option<String>,
+	#[serde(default)]
+	pub sslmode: Option<String>,
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResService {
 	pub service: Service,
 }
 
+/// Only the `user_config` of a service, parsed into a service type
+/// specific struct
+#[derive(Deserialize, Debug, Default)]
+#[serde(bound = "T: serde::de::DeserializeOwned + Default")]
+pub(crate) struct ResServiceUserConfig<T> {
+	pub service: ServiceUserConfig<T>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(bound = "T: serde::de::DeserializeOwned + Default")]
+pub(crate) struct ServiceUserConfig<T> {
+	#[serde(default)]
+	pub user_config: T,
+}
+
+/// The `ip_filter` of a `user_config`. Entries are either a network or an
+/// object with `network` and `description`, kept as is.
+#[derive(Deserialize, Debug, Default)]
+pub(crate) struct IpFilterConfig {
+	#[serde(default)]
+	pub ip_filter: Vec<serde_json::Value>,
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResMigrationStatus {
 	pub migration: MigrationStatus,
@@ -202,6 +571,13 @@ pub struct MigrationStatus {
 	pub status: String,
 }
 
+impl MigrationStatus {
+	/// The `status` as `MigrationState`
+	pub fn state(&self) -> MigrationState {
+		MigrationState::from(self.status.as_str())
+	}
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct MigrationDetail {
 	pub dbname: String,
@@ -210,6 +586,71 @@ pub struct MigrationDetail {
 	pub status: String,
 }
 
+impl MigrationDetail {
+	/// The `status` as `MigrationState`
+	pub fn state(&self) -> MigrationState {
+		MigrationState::from(self.status.as_str())
+	}
+}
+
+/// Progress of a migration from an external database
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+	/// Copying the initial data
+	Running,
+	/// The initial copy is done and changes are replicated continuously
+	Syncing,
+	Done,
+	Failed,
+	Unknown,
+}
+
+impl From<&str> for MigrationState {
+	fn from(status: &str) -> Self {
+		match status {
+			"running" => MigrationState::Running,
+			"syncing" => MigrationState::Syncing,
+			"done" => MigrationState::Done,
+			"failed" => MigrationState::Failed,
+			_ => MigrationState::Unknown,
+		}
+	}
+}
+
+/// How data is copied from the external database
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MigrationMethod {
+	/// One-off copy with `pg_dump` or `mysqldump`
+	Dump,
+	/// Initial copy followed by continuous replication
+	Replication,
+}
+
+/// The external database a service migrates from, the `migration` of its
+/// `user_config`
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ExternalMigration {
+	pub host: String,
+	pub port: u16,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub username: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub password: Option<Secret>,
+	/// Connect with TLS, the API defaults to `true`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ssl: Option<bool>,
+	/// Database to connect to, not the one to migrate
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub dbname: Option<String>,
+	/// Comma separated databases which are not migrated
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub ignore_dbs: Option<String>,
+	/// Without a method the API tries replication and falls back to dump
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub method: Option<MigrationMethod>,
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ServiceCancelQuery {
 	pub success: bool,
@@ -224,6 +665,92 @@ pub struct ServiceUser {
 	#[serde(rename = "type")]
 	pub account_type: String,
 	pub username: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub access_control: Option<ServiceUserAccessControl>,
+}
+
+/// Per service permissions of a service user. Each service type only sets
+/// its own fields, the others keep their defaults.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct ServiceUserAccessControl {
+	#[serde(flatten)]
+	pub redis: RedisAccessControl,
+	#[serde(flatten)]
+	pub pg: PgAccessControl,
+	#[serde(flatten)]
+	pub m3: M3AccessControl,
+}
+
+/// Redis ACL rules of a service user, e.g. `+@read` or `-@dangerous`
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct RedisAccessControl {
+	#[serde(
+		rename = "redis_acl_categories",
+		default,
+		skip_serializing_if = "Vec::is_empty"
+	)]
+	pub categories: Vec<String>,
+	#[serde(
+		rename = "redis_acl_commands",
+		default,
+		skip_serializing_if = "Vec::is_empty"
+	)]
+	pub commands: Vec<String>,
+	/// Key patterns the user may access
+	#[serde(
+		rename = "redis_acl_keys",
+		default,
+		skip_serializing_if = "Vec::is_empty"
+	)]
+	pub keys: Vec<String>,
+	/// Pub/Sub channel patterns the user may access
+	#[serde(
+		rename = "redis_acl_channels",
+		default,
+		skip_serializing_if = "Vec::is_empty"
+	)]
+	pub channels: Vec<String>,
+}
+
+impl RedisAccessControl {
+	/// Whether the category rules grant `category` (without the `@`).
+	///
+	/// Rules apply in order like in Redis, starting from nothing allowed, so
+	/// `["+@all", "-@dangerous"]` allows everything but `dangerous`.
+	pub fn category_allowed(&self, category: &str) -> bool {
+		self.categories.iter().fold(false, |allowed, rule| {
+			let (grant, name) = if let Some(name) = rule.strip_prefix("+@") {
+				(true, name)
+			} else if let Some(name) = rule.strip_prefix("-@") {
+				(false, name)
+			} else {
+				return allowed;
+			};
+			if name == "all" || name == category {
+				grant
+			} else {
+				allowed
+			}
+		})
+	}
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct PgAccessControl {
+	/// Whether the user may open replication connections
+	#[serde(
+		rename = "pg_allow_replication",
+		default,
+		skip_serializing_if = "Option::is_none"
+	)]
+	pub allow_replication: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct M3AccessControl {
+	/// M3 access group the user belongs to
+	#[serde(rename = "m3_group", default, skip_serializing_if = "Option::is_none")]
+	pub group: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
@@ -231,6 +758,28 @@ pub struct ResServiceUser {
 	pub user: ServiceUser,
 }
 
+/// A service user with freshly issued credentials
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ServiceUserCredentials {
+	pub username: String,
+	pub password: Secret,
+	pub access_cert: Option<String>,
+	pub access_key: Option<Secret>,
+	pub authentication: Option<String>,
+	#[serde(rename = "type")]
+	pub user_type: String,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub(crate) struct ServiceUsersCredentials {
+	pub users: Vec<ServiceUserCredentials>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub(crate) struct ResServiceUsersCredentials {
+	pub service: ServiceUsersCredentials,
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResResetQueryStats {
 	pub queries: Vec<serde_json::Value>,
@@ -255,12 +804,56 @@ pub struct ServicePlan {
 	pub backup_config: BackupConfig,
 	pub node_count: i64,
 	pub regions: HashMap<String, Region>,
+	#[serde(default)]
+	pub service_plan: String,
+	#[serde(default)]
+	pub service_type: String,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResServices {
 	pub services: Vec<Service>,
 }
+
+/// Lightweight view of a service, only the fields needed for an overview
+/// are parsed from the service list.
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct ServiceSummary {
+	pub service_name: String,
+	pub service_type: String,
+	pub plan: String,
+	pub state: String,
+	pub cloud_name: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResServiceSummaries {
+	pub services: Vec<ServiceSummary>,
+}
+
+/// Client-side filter for service listings. Unset fields match everything.
+#[derive(Debug, Default, Clone)]
+pub struct ServiceFilter {
+	pub service_type: Option<String>,
+	pub state: Option<String>,
+	pub cloud_name: Option<String>,
+	pub name_prefix: Option<String>,
+}
+
+impl ServiceFilter {
+	pub fn matches(&self, service: &ServiceSummary) -> bool {
+		fn eq(expected: &Option<String>, actual: &str) -> bool {
+			expected.as_deref().is_none_or(|e| e == actual)
+		}
+		eq(&self.service_type, &service.service_type)
+			&& eq(&self.state, &service.state)
+			&& eq(&self.cloud_name, &service.cloud_name)
+			&& self
+				.name_prefix
+				.as_deref()
+				.is_none_or(|p| service.service_name.starts_with(p))
+	}
+}
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ServiceDescription {
 	pub description: String,
@@ -339,6 +932,8 @@ pub struct ResServiceKeyPair {
 
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct Task {
+	#[serde(default)]
+	pub task_id: String,
 	pub create_time: String,
 	pub result: String,
 	pub success: bool,
@@ -350,7 +945,7 @@ pub struct ResTask {
 	pub task: Task,
 }
 
-#[derive(Deserialize, Serialize, Debug, Default)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq)]
 pub struct Log {
 	pub msg: String,
 	pub time: String,
@@ -363,10 +958,122 @@ pub struct ResLogs {
 	pub offset: String,
 }
 
+/// Where `ServiceApi::tail_logs` starts following the logs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogStart {
+	/// The newest `n` entries, like `tail -n`
+	Recent(usize),
+	/// The entries of the last `Duration`, like `--since 10m`
+	Since(Duration),
+	/// The oldest entry the API still returns
+	Beginning,
+	/// The entries after `ResLogs::offset` of an earlier response
+	Offset(String),
+}
+
+impl Default for LogStart {
+	fn default() -> Self {
+		LogStart::Recent(100)
+	}
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct ResQueries {
 	pub queries: Vec<Query>,
 }
+
+/// Backend state as reported by `pg_stat_activity`
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PostgresQueryState {
+	Active,
+	#[default]
+	Idle,
+	#[serde(rename = "idle in transaction")]
+	IdleInTransaction,
+	#[serde(rename = "idle in transaction (aborted)")]
+	IdleInTransactionAborted,
+	#[serde(rename = "fastpath function call")]
+	FastpathFunctionCall,
+	Disabled,
+	#[serde(other)]
+	Unknown,
+}
+
+/// A single row of the PostgreSQL query activity, fields which are only set
+/// for some backends are optional instead of defaulting to empty values
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct PostgresQuery {
+	pub pid: i32,
+	pub query: String,
+	#[serde(default)]
+	pub state: Option<PostgresQueryState>,
+	#[serde(default)]
+	pub datid: Option<i32>,
+	#[serde(default)]
+	pub datname: Option<String>,
+	#[serde(default)]
+	pub usesysid: Option<i32>,
+	#[serde(default)]
+	pub usename: Option<String>,
+	#[serde(default)]
+	pub application_name: Option<String>,
+	#[serde(default)]
+	pub backend_type: Option<String>,
+	#[serde(default)]
+	pub backend_start: Option<String>,
+	#[serde(default)]
+	pub backend_xid: Option<String>,
+	#[serde(default)]
+	pub backend_xmin: Option<String>,
+	#[serde(default)]
+	pub client_addr: Option<String>,
+	#[serde(default)]
+	pub client_hostname: Option<String>,
+	#[serde(default)]
+	pub client_port: Option<i32>,
+	#[serde(default)]
+	pub query_start: Option<String>,
+	#[serde(default)]
+	pub query_duration: Option<f64>,
+	#[serde(default)]
+	pub state_change: Option<String>,
+	#[serde(default)]
+	pub wait_event: Option<String>,
+	#[serde(default)]
+	pub wait_event_type: Option<String>,
+	#[serde(default)]
+	pub xact_start: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResPostgresQueries {
+	pub queries: Vec<PostgresQuery>,
+}
+
+/// A single row of the MySQL process list
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct MysqlQuery {
+	pub id: String,
+	#[serde(default)]
+	pub query: Option<String>,
+	#[serde(default)]
+	pub state: Option<String>,
+	#[serde(default)]
+	pub datname: Option<String>,
+	#[serde(default)]
+	pub usename: Option<String>,
+	#[serde(default)]
+	pub client_addr: Option<String>,
+	#[serde(default)]
+	pub query_duration: Option<f64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResMysqlQueries {
+	pub queries: Vec<MysqlQuery>,
+}
+
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct Query {
 	pub active_channel_subscriptions: i32,
@@ -407,3 +1114,171 @@ pub struct Query {
 	pub waiting: bool,
 	pub xact_start: String,
 }
+
+/// Paging and ordering of the query statistics of PostgreSQL and MySQL
+/// services
+///
+/// ```rust
+/// use aiven_rs::service::types_service::QueryStatisticsRequest;
+///
+/// let request = QueryStatisticsRequest::default()
+///     .limit(20)
+///     .order_by("calls:desc,total_time:asc");
+/// ```
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct QueryStatisticsRequest {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub limit: Option<u32>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub offset: Option<u32>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub order_by: Option<String>,
+}
+
+impl QueryStatisticsRequest {
+	/// Return at most `limit` queries, the API defaults to 100
+	pub fn limit(mut self, limit: u32) -> Self {
+		self.limit = Some(limit);
+		self
+	}
+
+	pub fn offset(mut self, offset: u32) -> Self {
+		self.offset = Some(offset);
+		self
+	}
+
+	/// Comma separated `column:asc` or `column:desc` pairs
+	pub fn order_by<S: Into<String>>(mut self, order_by: S) -> Self {
+		self.order_by = Some(order_by.into());
+		self
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	fn service(service_name: &str, service_type: &str, port: i32) -> Service {
+		let host = format!("{}-myproject.aivencloud.com", service_name);
+		Service {
+			components: vec![Component {
+				component: service_type.to_owned(),
+				host: host.clone(),
+				port,
+				usage: "primary".to_owned(),
+				..Default::default()
+			}],
+			service_name: service_name.to_owned(),
+			service_type: service_type.to_owned(),
+			service_uri: format!("{}://avnadmin:pw@{}:{}", service_type, host, port),
+			service_uri_params: serde_json::from_value(json!({
+				"host": host,
+				"port": port.to_string(),
+				"user": "avnadmin",
+				"password": "pw"
+			}))
+			.unwrap(),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn test_redis_category_allowed() {
+		let access = RedisAccessControl {
+			categories: vec!["+@all".into(), "-@dangerous".into(), "é".into(), "+".into()],
+			..Default::default()
+		};
+		assert!(access.category_allowed("read"));
+		assert!(!access.category_allowed("dangerous"));
+		assert!(!RedisAccessControl::default().category_allowed("read"));
+	}
+
+	fn with_connection_info(mut service: Service, info: serde_json::Value) -> Service {
+		service.connection_info = serde_json::from_value(info).unwrap();
+		service
+	}
+
+	#[test]
+	fn test_kafka_connection_info() {
+		let service = with_connection_info(
+			service("events", "kafka", 24949),
+			json!({
+				"kafka": ["events-myproject.aivencloud.com:24949"],
+				"kafka_access_cert": "CERT",
+				"kafka_access_key": "KEY",
+				"kafka_connect_uri": "https://avnadmin:pw@events-myproject.aivencloud.com:24950",
+				"schema_registry_uri": "https://avnadmin:pw@events-myproject.aivencloud.com:24952"
+			}),
+		);
+		let info = service.kafka_connection_info().unwrap();
+		assert!(info.bootstrap_servers == vec!["events-myproject.aivencloud.com:24949"]);
+		assert!(info.access_key.unwrap().expose() == "KEY");
+		assert!(info.connect_uri.unwrap().expose().ends_with(":24950"));
+		assert!(info.rest_uri.is_none());
+		assert!(info
+			.schema_registry_uri
+			.unwrap()
+			.expose()
+			.ends_with(":24952"));
+		assert!(!format!("{:?}", service.kafka_connection_info()).contains(":pw@"));
+	}
+
+	#[test]
+	fn test_kafka_connection_info_from_components() {
+		let mut service = service("legacy", "kafka", 24949);
+		let registry = Component {
+			component: "schema_registry".to_owned(),
+			host: "legacy.example.com".to_owned(),
+			port: 24952,
+			usage: "primary".to_owned(),
+			..Default::default()
+		};
+		service.components.push(registry);
+		let info = service.kafka_connection_info().unwrap();
+		assert!(info.bootstrap_servers.len() == 1);
+		assert!(info.bootstrap_servers[0].ends_with(":24949"));
+		assert!(info.schema_registry_uri == Some(Secret::new("https://legacy.example.com:24952")));
+		assert!(info.connect_uri.is_none());
+	}
+
+	#[test]
+	fn test_database_connection_info() {
+		let service = with_connection_info(
+			service("orders", "pg", 24946),
+			json!({
+				"pg": ["postgres://avnadmin:pw@orders.example.com:24946/defaultdb?sslmode=require"],
+				"pg_params": [{
+					"dbname": "defaultdb",
+					"host": "orders.example.com",
+					"password": "pw",
+					"port": "24946",
+					"sslmode": "require",
+					"user": "avnadmin"
+				}],
+				"pg_standby": [],
+				"pgbouncer": null
+			}),
+		);
+		let info = service.database_connection_info().unwrap();
+		assert!(info.uris.len() == 1);
+		assert!(info.params[0].port == "24946");
+		assert!(info.params[0].dbname.as_deref() == Some("defaultdb"));
+		assert!(info.standby_uris.is_empty());
+		assert!(info.pgbouncer_uri.is_none());
+	}
+
+	#[test]
+	fn test_database_connection_info_fallback() {
+		let mysql = service("legacy-db", "mysql", 24947);
+		let info = mysql.database_connection_info().unwrap();
+		assert!(info.uris == vec![Secret::new(mysql.service_uri.clone())]);
+		assert!(info.params[0].port == "24947");
+		assert!(info.params[0].user == "avnadmin");
+
+		match service("orders", "pg", 24946).kafka_connection_info() {
+			Err(AivenError::InvalidInput { field, .. }) => assert!(field == "service_type"),
+			other => assert!(false, "{:?}", other),
+		}
+	}
+}
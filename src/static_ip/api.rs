@@ -0,0 +1,314 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+	client::{encode_param, HTTPClient},
+	errors::AivenError,
+	make_json_request, make_request,
+	static_ip::types,
+};
+use serde_json::json;
+
+pub struct ProjectStaticIpApi {
+	http_client: HTTPClient,
+}
+
+impl ProjectStaticIpApi {
+	pub(crate) fn new(client: HTTPClient) -> Self {
+		Self {
+			http_client: client,
+		}
+	}
+
+	/// Create a static IP address
+	///
+	/// https://api.aiven.io/doc/#operation/ProjectStaticIPCreate
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `request` - Cloud and termination protection of the address
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::static_ip::types::StaticIpCreateRequest;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let request = StaticIpCreateRequest::new("google-europe-west1");
+	/// let response = client
+	///         .static_ip()
+	///         .create("my-project", &request)
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn create(
+		&self,
+		project: &str,
+		request: &types::StaticIpCreateRequest,
+	) -> Result<types::StaticIp, AivenError> {
+		let url = format!(
+			"project/{project}/static-ips",
+			project = encode_param(project)
+		);
+		let response = make_json_request!(self, reqwest::Method::POST, &url, request)?;
+		response.json().await
+	}
+
+	/// List the static IP addresses of a project
+	///
+	/// https://api.aiven.io/doc/#operation/StaticIPList
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .static_ip()
+	///         .list("my-project")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list(&self, project: &str) -> Result<types::ResStaticIps, AivenError> {
+		let url = format!(
+			"project/{project}/static-ips",
+			project = encode_param(project)
+		);
+		let response = make_request!(self, reqwest::Method::GET, &url)?;
+		response.json().await
+	}
+
+	/// Associate a static IP address with a service
+	///
+	/// https://api.aiven.io/doc/#operation/ProjectStaticIPAssociate
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `static_ip_address_id` - Id of the address
+	/// * `service_name` - Service to associate the address with
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .static_ip()
+	///         .associate("my-project", "ip359373e5e56", "my-pg")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn associate(
+		&self,
+		project: &str,
+		static_ip_address_id: &str,
+		service_name: &str,
+	) -> Result<types::StaticIp, AivenError> {
+		let url = format!(
+			"project/{project}/static-ips/{static_ip_address_id}/association",
+			project = encode_param(project),
+			static_ip_address_id = encode_param(static_ip_address_id),
+		);
+		let body = json!({ "service_name": service_name });
+		let json_body = &body;
+		let response = make_json_request!(self, reqwest::Method::POST, &url, json_body)?;
+		response.json().await
+	}
+
+	/// Dissociate a static IP address from its service
+	///
+	/// https://api.aiven.io/doc/#operation/ProjectStaticIPDissociate
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `static_ip_address_id` - Id of the address
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// let response = client
+	///         .static_ip()
+	///         .dissociate("my-project", "ip359373e5e56")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn dissociate(
+		&self,
+		project: &str,
+		static_ip_address_id: &str,
+	) -> Result<types::StaticIp, AivenError> {
+		let url = format!(
+			"project/{project}/static-ips/{static_ip_address_id}/association",
+			project = encode_param(project),
+			static_ip_address_id = encode_param(static_ip_address_id),
+		);
+		let response = make_request!(self, reqwest::Method::DELETE, &url)?;
+		response.json().await
+	}
+
+	/// Delete a static IP address
+	///
+	/// https://api.aiven.io/doc/#operation/ProjectStaticIPDelete
+	///
+	/// # Arguments
+	///
+	/// * `project` - Project name
+	/// * `static_ip_address_id` - Id of the address
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::from_token("https://api.aiven.io", "v1", "aiven-token");
+	/// client
+	///         .static_ip()
+	///         .delete("my-project", "ip359373e5e56")
+	///         .await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn delete(
+		&self,
+		project: &str,
+		static_ip_address_id: &str,
+	) -> Result<(), AivenError> {
+		let url = format!(
+			"project/{project}/static-ips/{static_ip_address_id}",
+			project = encode_param(project),
+			static_ip_address_id = encode_param(static_ip_address_id),
+		);
+		let _response = make_request!(self, reqwest::Method::DELETE, &url)?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{static_ip::types::StaticIpCreateRequest, testutil};
+
+	#[tokio::test]
+	async fn test_static_ip_create() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/myproject/static-ips";
+		let test_data = testutil::get_test_data("tests/testdata/static_ip/create.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "POST");
+
+		let request = StaticIpCreateRequest::new("google-europe-west1");
+		match client.static_ip().create("myproject", &request).await {
+			Ok(ip) => {
+				assert!(ip.static_ip_address_id == "ip359373e5e56");
+				assert!(ip.service_name.is_none());
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_static_ip_list() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/myproject/static-ips";
+		let test_data = testutil::get_test_data("tests/testdata/static_ip/list.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		match client.static_ip().list("myproject").await {
+			Ok(response) => {
+				assert!(response.static_ips.len() == 2);
+				assert!(response.static_ips[1].is_available());
+				assert!(response.of_service("mypg").count() == 1);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_static_ip_associate() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/myproject/static-ips/ip359373e5e56/association";
+		let test_data = testutil::get_test_data("tests/testdata/static_ip/associate.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "POST");
+
+		match client
+			.static_ip()
+			.associate("myproject", "ip359373e5e56", "mypg")
+			.await
+		{
+			Ok(ip) => assert!(ip.service_name.as_deref() == Some("mypg")),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_static_ip_dissociate() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/myproject/static-ips/ip359373e5e56/association";
+		let test_data = testutil::get_test_data("tests/testdata/static_ip/create.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "DELETE");
+
+		match client
+			.static_ip()
+			.dissociate("myproject", "ip359373e5e56")
+			.await
+		{
+			Ok(ip) => assert!(ip.service_name.is_none()),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_static_ip_delete() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/myproject/static-ips/ip359373e5e56";
+		let _m = testutil::create_mock_server(query_url, "{}", "DELETE");
+
+		match client
+			.static_ip()
+			.delete("myproject", "ip359373e5e56")
+			.await
+		{
+			Ok(_) => assert!(true),
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+}
@@ -0,0 +1,80 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct StaticIp {
+	pub cloud_name: String,
+	pub ip_address: String,
+	/// Service the address is associated with, if any
+	#[serde(default)]
+	pub service_name: Option<String>,
+	/// One of `creating`, `created`, `available`, `assigned`, `deleting`
+	/// or `deleted`
+	pub state: String,
+	pub static_ip_address_id: String,
+	#[serde(default)]
+	pub termination_protection: bool,
+}
+
+impl StaticIp {
+	/// True when the address can be associated with a service
+	pub fn is_available(&self) -> bool {
+		self.state == "created" && self.service_name.is_none()
+	}
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResStaticIps {
+	pub static_ips: Vec<StaticIp>,
+}
+
+impl ResStaticIps {
+	/// The addresses associated with `service_name`
+	pub fn of_service<'a>(&'a self, service_name: &'a str) -> impl Iterator<Item = &'a StaticIp> {
+		self.static_ips
+			.iter()
+			.filter(move |ip| ip.service_name.as_deref() == Some(service_name))
+	}
+}
+
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct StaticIpCreateRequest {
+	pub cloud_name: String,
+	pub termination_protection: bool,
+}
+
+impl StaticIpCreateRequest {
+	pub fn new<S: Into<String>>(cloud_name: S) -> Self {
+		StaticIpCreateRequest {
+			cloud_name: cloud_name.into(),
+			termination_protection: false,
+		}
+	}
+
+	/// Prevent the address from being deleted until the protection is lifted
+	pub fn termination_protection(mut self, enabled: bool) -> Self {
+		self.termination_protection = enabled;
+		self
+	}
+}
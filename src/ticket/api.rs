@@ -68,29 +68,113 @@ impl TicketApi {
 	) -> Result<Ticket, AivenError> {
 		let url: &str = &format!("project/{project}/tickets", project = encode_param(project));
 		let response = make_json_request!(self, reqwest::Method::POST, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
+	}
+
+	/// Open a support ticket from a typed request.
+	///
+	/// https://api.aiven.io/doc/#operation/ProjectTicketCreate
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::ticket::types::{Severity, TicketCreateRequest};
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::new("https://api.aiven.io", "v1");
+	/// let request = TicketCreateRequest::new(
+	///     Severity::High,
+	///     "Replication lag",
+	///     "Replication lag keeps growing on the standby",
+	/// )
+	/// .service("pg-main");
+	/// let output = client.ticket().open("project-name", &request).await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn open(
+		&self,
+		project: &str,
+		request: &TicketCreateRequest,
+	) -> Result<Ticket, AivenError> {
+		self.create(project, request).await
+	}
+
+	/// List the support tickets of a project with typed severity and state.
+	///
+	/// https://api.aiven.io/doc/#operation/ProjectTicketList
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::new("https://api.aiven.io", "v1");
+	/// let output = client.ticket().list("some-project-name").await?;
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list(&self, project: &str) -> Result<ResTickets, AivenError> {
+		let url: &str = &format!("project/{project}/tickets", project = encode_param(project));
+		let response = make_request!(self, reqwest::Method::GET, url)?;
+		response.json().await
+	}
+
+	/// List the support tickets of a project which are in the given state.
+	///
+	/// https://api.aiven.io/doc/#operation/ProjectTicketList
+	///
+	/// # Examples
+	/// Basic usage:
+	///
+	/// ```rust,no_run
+	/// use aiven_rs::ticket::types::TicketState;
+	/// #[tokio::main]
+	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
+	/// let client = aiven_rs::AivenClient::new("https://api.aiven.io", "v1");
+	/// let open = client
+	///     .ticket()
+	///     .list_by_state("some-project-name", TicketState::Open)
+	///     .await?;
+	/// println!("{} open ticket(s)", open.len());
+	/// Ok(())
+	/// }
+	/// ```
+	pub async fn list_by_state(
+		&self,
+		project: &str,
+		state: TicketState,
+	) -> Result<Vec<TicketSummary>, AivenError> {
+		let tickets = self.list(project).await?.tickets;
+		Ok(tickets
+			.into_iter()
+			.filter(|ticket| ticket.state == state)
+			.collect())
 	}
 
 	/// List support tickets.
 	///
+	/// Deprecated, forwards to [`TicketApi::list`].
+	///
 	/// https://api.aiven.io/doc/#operation/ProjectTicketList.
 	///
 	/// # Examples
 	/// Basic usage:
 	///
 	/// ```rust,no_run
-	/// use std::collections::HashMap;
 	/// #[tokio::main]
 	/// async fn main()-> Result<(), Box<dyn std::error::Error>>{
 	/// let client = aiven_rs::AivenClient::new("https://api.aiven.io", "v1");
+	/// #[allow(deprecated)]
 	/// let output = client.ticket().get("some-project-name").await?;
 	/// Ok(())
 	/// }
 	/// ```
-	pub async fn get(&self, project: &str) -> Result<Ticket, AivenError> {
-		let url: &str = &format!("project/{project}/tickets", project = encode_param(project));
-		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+	#[deprecated(note = "use `TicketApi::list`")]
+	pub async fn get(&self, project: &str) -> Result<ResTickets, AivenError> {
+		self.list(project).await
 	}
 
 	/// Invite a user to a ticket.
@@ -158,12 +242,9 @@ mod tests {
 
 		match client.ticket().create(project_name, &json_body).await {
 			Ok(response) => {
-				assert!(
-					response.user_email == "some-email.de",
-					format!("{:?}", response)
-				);
+				assert!(response.user_email == "some-email.de", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("Error during creating ticket {:?}", e)),
+			Err(e) => assert!(false, "Error during creating ticket {:?}", e),
 		}
 	}
 
@@ -177,20 +258,55 @@ mod tests {
 			project = encode_param(project_name)
 		);
 
-		let test_data = testutil::get_test_data("tests/testdata/ticket/all_tickets.json");
+		let test_data = testutil::get_test_data("tests/testdata/ticket/list_tickets.json");
 		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
 
+		#[allow(deprecated)]
 		match client.ticket().get(project_name).await {
 			Ok(response) => {
-				assert!(
-					response.user_email == "some-email.de",
-					format!("{:?}", response)
-				);
+				assert!(response.tickets[0].ticket_id == "T-1042", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("Error during creating ticket {:?}", e)),
+			Err(e) => assert!(false, "Error during creating ticket {:?}", e),
 		}
 	}
 
+	#[tokio::test]
+	async fn test_tickets_list_by_state() {
+		let client = testutil::prepare_test_client();
+		let query_url = "/project/ticketstateproject/tickets";
+		let test_data = testutil::get_test_data("tests/testdata/ticket/list_tickets.json");
+		let _m = testutil::create_mock_server(query_url, &test_data, "GET");
+
+		match client
+			.ticket()
+			.list_by_state("ticketstateproject", TicketState::Open)
+			.await
+		{
+			Ok(tickets) => {
+				assert!(tickets.len() == 1);
+				assert!(tickets[0].ticket_id == "T-1042");
+				assert!(tickets[0].severity == Severity::High);
+			}
+			Err(e) => assert!(false, "{:?}", e),
+		}
+	}
+
+	#[test]
+	fn test_ticket_create_request_serialize() {
+		let request = TicketCreateRequest::new(Severity::Critical, "Down", "Service is down");
+		let json = serde_json::to_value(&request).unwrap();
+		assert!(json["severity"] == "critical");
+		assert!(json.get("service_name").is_none());
+		let json = serde_json::to_value(request.service("pg-main")).unwrap();
+		assert!(json["service_name"] == "pg-main");
+	}
+
+	#[test]
+	fn test_ticket_severity_unknown() {
+		let severity: Severity = serde_json::from_str(r#""urgent""#).unwrap();
+		assert!(severity == Severity::Unknown);
+	}
+
 	#[tokio::test]
 	async fn test_tickets_invite_user() {
 		let client = testutil::prepare_test_client();
@@ -213,10 +329,7 @@ mod tests {
 			Ok(_response) => {
 				assert!(true);
 			}
-			Err(e) => assert!(
-				false,
-				format!("Error during inviting user to ticket {:?}", e)
-			),
+			Err(e) => assert!(false, "Error during inviting user to ticket {:?}", e),
 		}
 	}
 }
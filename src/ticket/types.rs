@@ -67,3 +67,75 @@ pub struct TicketList {
 	pub update_time: String,
 	pub user_real_name: String,
 }
+
+/// Severity of a support ticket
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+	Critical,
+	High,
+	#[default]
+	Low,
+	#[serde(other)]
+	Unknown,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TicketState {
+	Open,
+	Closed,
+	#[default]
+	#[serde(other)]
+	Unknown,
+}
+
+/// Body of a new support ticket, see `TicketApi::open`
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct TicketCreateRequest {
+	pub severity: Severity,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub service_name: Option<String>,
+	pub title: String,
+	pub description: String,
+}
+
+impl TicketCreateRequest {
+	pub fn new<T: Into<String>, D: Into<String>>(
+		severity: Severity,
+		title: T,
+		description: D,
+	) -> Self {
+		TicketCreateRequest {
+			severity,
+			service_name: None,
+			title: title.into(),
+			description: description.into(),
+		}
+	}
+
+	/// Associate the ticket with a service of the project
+	pub fn service<S: Into<String>>(mut self, service_name: S) -> Self {
+		self.service_name = Some(service_name.into());
+		self
+	}
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct TicketSummary {
+	pub ticket_id: String,
+	pub title: String,
+	pub description: String,
+	pub project_name: String,
+	pub service_name: Option<String>,
+	pub severity: Severity,
+	pub state: TicketState,
+	pub create_time: String,
+	pub update_time: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct ResTickets {
+	pub tickets: Vec<TicketSummary>,
+}
@@ -0,0 +1,99 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//! RFC 3339 timestamps as returned by the API, e.g. event and metric times
+
+/// A point in time, with nanosecond precision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Timestamp {
+	seconds: i64,
+	nanos: u32,
+}
+
+impl Timestamp {
+	/// Parse a `YYYY-MM-DDTHH:MM:SS[.fff](Z|+HH:MM)` time, a missing zone
+	/// means UTC
+	pub(crate) fn parse(time: &str) -> Option<Timestamp> {
+		let field = |range: std::ops::Range<usize>| time.get(range)?.parse::<i64>().ok();
+		let (year, month, day) = (field(0..4)?, field(5..7)?, field(8..10)?);
+		let (hour, minute, second) = (field(11..13)?, field(14..16)?, field(17..19)?);
+		let rest = time.get(19..)?;
+		let zone_start = rest.find(['Z', 'z', '+', '-']).unwrap_or(rest.len());
+		let nanos = match rest[..zone_start].strip_prefix('.') {
+			Some(digits) if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) => {
+				// Digits past nanoseconds are cut off
+				let digits = &digits[..digits.len().min(9)];
+				digits.parse::<u32>().ok()? * 10u32.pow(9 - digits.len() as u32)
+			}
+			Some(_) => return None,
+			None if zone_start == 0 => 0,
+			None => return None,
+		};
+		let offset = match &rest[zone_start..] {
+			"" | "Z" | "z" => 0,
+			zone => {
+				let sign = if zone.starts_with('-') { -1 } else { 1 };
+				let hours = zone.get(1..3)?.parse::<i64>().ok()?;
+				let minutes = zone.get(4..6)?.parse::<i64>().ok()?;
+				sign * (hours * 3600 + minutes * 60)
+			}
+		};
+		// Days from 1970-01-01 to the civil date, proleptic Gregorian calendar
+		let y = if month <= 2 { year - 1 } else { year };
+		let era = y.div_euclid(400);
+		let year_of_era = y - era * 400;
+		let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+		let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+		let days = era * 146097 + day_of_era - 719468;
+		Some(Timestamp {
+			seconds: days * 86400 + hour * 3600 + minute * 60 + second - offset,
+			nanos,
+		})
+	}
+
+	/// Seconds since the epoch
+	pub(crate) fn as_secs_f64(&self) -> f64 {
+		self.seconds as f64 + f64::from(self.nanos) / 1e9
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Timestamp;
+
+	#[test]
+	fn test_timestamp_parse() {
+		let seconds = |time| Timestamp::parse(time).map(|t| t.as_secs_f64());
+		assert!(seconds("1970-01-01T00:00:00Z") == Some(0.0));
+		assert!(seconds("2021-03-01T10:30:00Z") == Some(1614594600.0));
+		assert!(seconds("2021-03-01T12:30:00.5+02:00") == Some(1614594600.5));
+		assert!(seconds("yesterday").is_none());
+		assert!(seconds("2021-03-01T10:30:00.Z").is_none());
+	}
+
+	#[test]
+	fn test_timestamp_order_ignores_precision() {
+		let parse = |time| Timestamp::parse(time).unwrap();
+		assert!(parse("2021-03-01T10:30:00Z") < parse("2021-03-01T10:30:00.5Z"));
+		assert!(parse("2021-03-01T10:30:00.500000Z") == parse("2021-03-01T10:30:00.5Z"));
+		assert!(parse("2021-03-01T10:30:00.000001Z") > parse("2021-03-01T10:30:00Z"));
+	}
+}
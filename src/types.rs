@@ -0,0 +1,119 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Stable paths to the request and response types of all APIs.
+//!
+//! The types are defined next to the API using them, e.g.
+//! `service::types_kafka`, and those files move around as APIs get typed
+//! requests. Code importing from here keeps compiling when they do.
+//!
+//! ```rust
+//! use aiven_rs::types::{kafka::KafkaTopicCreateRequest, project::MemberType};
+//!
+//! let request = KafkaTopicCreateRequest::new("orders").partitions(3);
+//! let member_type = MemberType::Developer;
+//! ```
+
+pub mod account {
+	pub use crate::account::types::*;
+}
+
+pub mod billing {
+	pub use crate::billing::types::*;
+}
+
+pub mod billing_group {
+	pub use crate::billing_group::types::*;
+}
+
+pub mod cloud {
+	pub use crate::cloud::types::*;
+}
+
+pub mod key_mgmt {
+	pub use crate::key_mgmt::types::*;
+}
+
+pub mod organization {
+	pub use crate::organization::types::*;
+}
+
+pub mod payment {
+	pub use crate::payment::types::*;
+}
+
+pub mod project {
+	pub use crate::project::types::*;
+}
+
+pub mod static_ip {
+	pub use crate::static_ip::types::*;
+}
+
+pub mod ticket {
+	pub use crate::ticket::types::*;
+}
+
+pub mod user {
+	pub use crate::user::types::*;
+}
+
+/// Types shared by all services, from `service::types_service`
+pub mod service {
+	pub use crate::service::types_service::*;
+}
+
+pub mod elasticsearch {
+	pub use crate::service::types_elasticsearch::*;
+}
+
+pub mod flink {
+	pub use crate::service::types_flink::*;
+}
+
+pub mod grafana {
+	pub use crate::service::types_grafana::*;
+}
+
+pub mod influxdb {
+	pub use crate::service::types_influxdb::*;
+}
+
+pub mod integrations {
+	pub use crate::service::types_integrations::*;
+}
+
+pub mod kafka {
+	pub use crate::service::types_kafka::*;
+}
+
+pub mod mirrormaker {
+	pub use crate::service::types_mirrormaker::*;
+}
+
+pub mod mysql {
+	pub use crate::service::types_mysql::*;
+}
+
+pub mod postgres {
+	pub use crate::service::types_postgres::*;
+}
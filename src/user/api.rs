@@ -67,12 +67,14 @@ impl UserApi {
 	) -> Result<UserAuth, AivenError> {
 		let url: &str = "userauth";
 		let response = make_json_request!(self, reqwest::Method::POST, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Sets a new password for the user. Immediately expires all existing
 	/// authentication tokens.
 	///
+	/// https://api.aiven.io/doc/#operation/UserPasswordChange
+	///
 	/// # Examples
 	/// Basic usage:
 	///
@@ -94,7 +96,7 @@ impl UserApi {
 	) -> Result<ResUserPasswordChange, AivenError> {
 		let url = "me/password";
 		let response = make_json_request!(self, reqwest::Method::PUT, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Complete one-time password configuration.
@@ -123,7 +125,7 @@ impl UserApi {
 	) -> Result<ResCompleteOTPConfig, AivenError> {
 		let url = "me/2fa/otp";
 		let response = make_json_request!(self, reqwest::Method::PUT, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Configure two-factor authentication.
@@ -151,7 +153,7 @@ impl UserApi {
 	) -> Result<ResConfigure2fa, AivenError> {
 		let url = "me/2fa";
 		let response = make_json_request!(self, reqwest::Method::PUT, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Confirm user email address.
@@ -176,7 +178,7 @@ impl UserApi {
 	) -> Result<ResConfirmUseremailAddress, AivenError> {
 		let url = &format!("user/verify_email/{}", encode_param(verification_code));
 		let response = make_request!(self, reqwest::Method::POST, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Confirm user password reset.
@@ -235,7 +237,7 @@ impl UserApi {
 	) -> Result<ResUserCreate, AivenError> {
 		let url = "user";
 		let response = make_json_request!(self, reqwest::Method::POST, url, user_config)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Create new access token
@@ -264,7 +266,7 @@ impl UserApi {
 	) -> Result<AccessToken, AivenError> {
 		let url = "access_token";
 		let response = make_json_request!(self, reqwest::Method::POST, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Delete linked authentication method, and revoke all associated access
@@ -333,7 +335,7 @@ impl UserApi {
 		let url = "me";
 		let json_body: &HashMap<&str, String> = &HashMap::new();
 		let response = make_json_request!(self, reqwest::Method::GET, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Get available login options
@@ -358,7 +360,7 @@ impl UserApi {
 	) -> Result<Vec<UserAuthLoginOptions>, AivenError> {
 		let url = "userauth/login_options";
 		let response = make_json_request!(self, reqwest::Method::POST, url, json_body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List all valid access tokens
@@ -382,7 +384,7 @@ impl UserApi {
 	pub async fn list_access_tokens(&self) -> Result<AccessTokens, AivenError> {
 		let url = "access_token";
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List linked authentication methods
@@ -407,7 +409,7 @@ impl UserApi {
 	pub async fn list_linked_auth_methods(&self) -> Result<AuthenticationMethods, AivenError> {
 		let url = "me/authentication_methods";
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Logout user, removing current authentication token.
@@ -524,7 +526,7 @@ impl UserApi {
 		let body = &json_body;
 
 		let response = make_json_request!(self, reqwest::Method::PUT, url, body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Check password strength for an existing user.
@@ -558,7 +560,7 @@ impl UserApi {
 		let body = &json_body;
 
 		let response = make_json_request!(self, reqwest::Method::POST, url, body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Check password strength for a new user.
@@ -593,7 +595,7 @@ impl UserApi {
 			"real_name": real_name,
 		});
 		let response = make_json_request!(self, reqwest::Method::POST, url, body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Accept all invites for a single account.
@@ -627,7 +629,7 @@ impl UserApi {
 		});
 
 		let response = make_json_request!(self, reqwest::Method::POST, url, body)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// List pending account invites.
@@ -652,7 +654,7 @@ impl UserApi {
 	pub async fn list_pending_account_invites(&self) -> Result<ResAccountInvites, AivenError> {
 		let url = "/me/account/invites";
 		let response = make_request!(self, reqwest::Method::GET, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 
 	/// Reject invite to a team.
@@ -677,7 +679,7 @@ impl UserApi {
 	pub async fn reject_invite_to_team(&self) -> Result<ResAccountInvites, AivenError> {
 		let url = "/me/account/invites/reject";
 		let response = make_request!(self, reqwest::Method::POST, url)?;
-		Ok(response.json().await?)
+		response.json().await
 	}
 }
 
@@ -698,12 +700,9 @@ mod tests {
 		json_body.insert("password", "my_pass".to_owned());
 		match client.user().authenticate(&json_body).await {
 			Ok(response) => {
-				assert!(
-					response.user_email == "jane@example.com",
-					format!("{:?}", response)
-				);
+				assert!(response.user_email == "jane@example.com", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -719,9 +718,9 @@ mod tests {
 		json_body.insert("password", "abc123".to_owned());
 		match client.user().password_change(&json_body).await {
 			Ok(response) => {
-				assert!(response.token == "some-token", format!("{:?}", response));
+				assert!(response.token == "some-token", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -741,9 +740,9 @@ mod tests {
 		);
 		match client.user().complete_otp_config(&json_body).await {
 			Ok(response) => {
-				assert!(response.token == "some-token", format!("{:?}", response));
+				assert!(response.token == "some-token", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -760,10 +759,10 @@ mod tests {
 
 		match client.user().configure_2fa(&json_body).await {
 			Ok(response) => {
-				assert!(response.method == "otp", format!("{:?}", response));
-				assert!(response.uri == "some-url", format!("{:?}", response));
+				assert!(response.method == "otp", "{:?}", response);
+				assert!(response.uri == "some-url", "{:?}", response);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -782,10 +781,11 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.invite_details.user_email == "jane@example.com",
-					format!("{:?}", response)
+					"{:?}",
+					response
 				);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -803,7 +803,7 @@ mod tests {
 			Ok(_) => {
 				assert!(true);
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -826,7 +826,7 @@ mod tests {
 			Ok(response) => {
 				assert!(response.user_email == "jane@example.com");
 			}
-			Err(e) => assert!(false, format!("{:?}", e)),
+			Err(e) => assert!(false, "{:?}", e),
 		}
 	}
 
@@ -897,7 +897,7 @@ mod tests {
 			.await
 			.expect("Failed to authenticate");
 		assert!(
-			output.len() > 0,
+			!output.is_empty(),
 			"Should have received atleast one user-login option"
 		);
 	}
@@ -937,7 +937,7 @@ mod tests {
 				// 		}
 				// 	}
 				// }
-				assert!(response.tokens.len() > 0, "Output was {:?}", response)
+				assert!(!response.tokens.is_empty(), "Output was {:?}", response)
 			}
 			Err(e) => {
 				assert!(false, "Error was {:?}", e);
@@ -956,7 +956,7 @@ mod tests {
 		let user_client = client.user();
 		match user_client.list_linked_auth_methods().await {
 			Ok(response) => assert!(
-				response.authentication_methods.len() > 0,
+				!response.authentication_methods.is_empty(),
 				"Output was {:?}",
 				response
 			),
@@ -1068,7 +1068,8 @@ mod tests {
 			Ok(response) => {
 				assert!(
 					response.password_strength.score == 3,
-					format!("found response {:?}", response)
+					"found response {:?}",
+					response
 				)
 			}
 			Err(e) => {
@@ -1090,7 +1091,7 @@ mod tests {
 			.await
 		{
 			Ok(rep) => {
-				assert!(rep.account_invites.len() > 0);
+				assert!(!rep.account_invites.is_empty());
 				assert!(true)
 			}
 			Err(e) => {
@@ -1110,7 +1111,7 @@ mod tests {
 		let user_client = client.user();
 		match user_client.list_pending_account_invites().await {
 			Ok(rep) => {
-				assert!(rep.account_invites.len() > 0);
+				assert!(!rep.account_invites.is_empty());
 				assert!(true)
 			}
 			Err(e) => {
@@ -1129,7 +1130,7 @@ mod tests {
 		let user_client = client.user();
 		match user_client.reject_invite_to_team().await {
 			Ok(rep) => {
-				assert!(rep.account_invites.len() > 0);
+				assert!(!rep.account_invites.is_empty());
 				assert!(true)
 			}
 			Err(e) => {
@@ -176,6 +176,8 @@ pub struct ResUserCreate {
 pub struct UserCreateConfig {
 	pub credit_code: String,
 	pub email: String,
+	// Communication opt-ins can only be chosen at sign-up, the API has no
+	// endpoint to read or change them for an existing user.
 	pub email_communication_categories: Vec<String>,
 	pub origin: String,
 	pub password: String,
@@ -0,0 +1,122 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Makes sure every API method has a blocking version in
+//! `src/blocking/methods.rs`.
+
+use std::{
+	collections::BTreeSet,
+	fs,
+	path::{Path, PathBuf},
+};
+
+/// API methods which have no blocking version, see the `blocking` module docs
+const ASYNC_ONLY: &[(&str, &str)] = &[
+	("ServiceKafkaApi", "topic_producer"),
+	("ServiceKafkaApi", "topic_reader"),
+];
+
+fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+	for entry in fs::read_dir(dir).unwrap() {
+		let path = entry.unwrap().path();
+		if path.is_dir() {
+			rust_files(&path, files);
+		} else if path.extension().is_some_and(|ext| ext == "rs") {
+			files.push(path);
+		}
+	}
+}
+
+fn ident(text: &str) -> String {
+	text.chars()
+		.take_while(|c| c.is_alphanumeric() || *c == '_')
+		.collect()
+}
+
+/// `(api, method)` of every public method of the `impl` blocks in `source`
+/// whose type is accepted by `api_name`
+fn methods(
+	source: &str,
+	indent: &str,
+	api_name: impl Fn(&str) -> Option<String>,
+) -> Vec<(String, String)> {
+	let mut found = Vec::new();
+	let mut api = None;
+	for line in source.lines() {
+		if line.starts_with("#[cfg(test)]") {
+			break;
+		}
+		let line = match line.strip_prefix(indent) {
+			Some(line) => line,
+			None => continue,
+		};
+		if line == "}" {
+			api = None;
+		} else if let Some(rest) = line.strip_prefix("impl ") {
+			api = api_name(rest);
+		} else if let (Some(api), Some(method)) = (
+			&api,
+			line.strip_prefix("\tpub async fn ")
+				.or_else(|| line.strip_prefix("\tpub fn ")),
+		) {
+			found.push((api.clone(), ident(method)));
+		}
+	}
+	found
+}
+
+#[test]
+fn every_api_method_has_a_blocking_version() {
+	let mut files = Vec::new();
+	rust_files(Path::new("src"), &mut files);
+	let api_methods: BTreeSet<_> = files
+		.iter()
+		.filter(|f| !f.starts_with("src/blocking"))
+		.flat_map(|f| {
+			methods(&fs::read_to_string(f).unwrap(), "", |rest| {
+				let name = ident(rest);
+				if name.ends_with("Api") || name == "ServiceKafkaMirrorMaker" {
+					Some(name)
+				} else {
+					None
+				}
+			})
+		})
+		.filter(|(api, method)| !ASYNC_ONLY.contains(&(api.as_str(), method.as_str())))
+		.collect();
+	let blocking_methods: BTreeSet<_> = methods(
+		&fs::read_to_string("src/blocking/methods.rs").unwrap(),
+		"\t",
+		|rest| rest.strip_prefix("Blocking<").map(ident),
+	)
+	.into_iter()
+	.collect();
+
+	let missing: Vec<_> = api_methods.difference(&blocking_methods).collect();
+	let stale: Vec<_> = blocking_methods.difference(&api_methods).collect();
+	assert!(
+		missing.is_empty() && stale.is_empty(),
+		"src/blocking/methods.rs is out of date\nmissing: {:#?}\nstale: {:#?}",
+		missing,
+		stale
+	);
+}
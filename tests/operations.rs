@@ -0,0 +1,206 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Keeps `aiven_rs::operations()` in sync with the operation links in the
+//! doc comments of the API methods, and makes sure every API method has one
+//! unless it's a helper combining several operations.
+
+use std::{
+	collections::BTreeSet,
+	fs,
+	path::{Path, PathBuf},
+};
+
+const OPERATION_LINK: &str = "https://api.aiven.io/doc/#operation/";
+
+/// Public API methods which combine several operations, or don't call one
+/// themselves
+const HELPERS: &[(&str, &str)] = &[
+	("CloudApi", "capability_matrix"),
+	("OrganizationUserGroupApi", "assign_user_group_to_project"),
+	("OrganizationUserGroupApi", "revoke_user_group_from_project"),
+	("ProjectApi", "apply_member_sync"),
+	("ProjectApi", "plan_member_sync"),
+	("RawApi", "delete"),
+	("RawApi", "delete_json"),
+	("RawApi", "get"),
+	("RawApi", "post"),
+	("RawApi", "put"),
+	("RawApi", "request"),
+	("SchemaRegistryApi", "request"),
+	("ServiceApi", "change_plan"),
+	("ServiceApi", "change_plan_and_wait"),
+	("ServiceIntegrationsApi", "create_dedicated_kafka_connect"),
+	("ServiceIntegrationsApi", "create_prometheus_integration"),
+	("ServiceIntegrationsApi", "get_autoscaler_config"),
+	(
+		"ServiceIntegrationsApi",
+		"list_integration_types_for_services",
+	),
+	("ServiceIntegrationsApi", "topology"),
+	("ServiceKafkaApi", "create_kafka_connector_and_wait"),
+	("ServiceKafkaApi", "delete_subjects_matching"),
+	(
+		"ServiceKafkaApi",
+		"get_schema_registry_subject_version_details",
+	),
+	("ServiceKafkaApi", "list_all_acl_entries"),
+	("ServiceKafkaApi", "rename_acl_user"),
+	("ServiceKafkaApi", "schema_registry"),
+	("ServiceKafkaApi", "topic_producer"),
+	("ServiceKafkaApi", "topic_reader"),
+];
+
+fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+	for entry in fs::read_dir(dir).unwrap() {
+		let path = entry.unwrap().path();
+		if path.is_dir() {
+			rust_files(&path, files);
+		} else if path.extension().is_some_and(|ext| ext == "rs") {
+			files.push(path);
+		}
+	}
+}
+
+fn ident(text: &str) -> String {
+	text.chars()
+		.take_while(|c| c.is_alphanumeric() || *c == '_')
+		.collect()
+}
+
+/// `(api, method, operation_id)` of every documented method in `file`
+fn documented_operations(file: &Path) -> Vec<(String, String, String)> {
+	let source = fs::read_to_string(file).unwrap();
+	let mut found = Vec::new();
+	let mut api = None;
+	let mut operation_id = None;
+	for line in source.lines() {
+		if line.starts_with("#[cfg(test)]") {
+			break;
+		}
+		if let Some(rest) = line.strip_prefix("impl") {
+			// Skip generic parameters, e.g. `impl<S: Store> Forwarder<S>`
+			let rest = match rest.strip_prefix('<') {
+				Some(generics) => generics.split_once('>').map_or("", |(_, r)| r),
+				None => rest,
+			};
+			api = Some(ident(rest.trim_start()));
+		}
+		let line = line.trim();
+		if let Some(doc) = line.strip_prefix("///") {
+			if let Some((_, id)) = doc.split_once(OPERATION_LINK) {
+				operation_id = Some(ident(id));
+			}
+			continue;
+		}
+		let method = line
+			.strip_prefix("pub async fn ")
+			.or_else(|| line.strip_prefix("pub fn "));
+		if let Some(method) = method {
+			if let (Some(api), Some(id)) = (&api, operation_id.take()) {
+				found.push((api.clone(), ident(method), id));
+			}
+		} else if !line.is_empty() && !line.starts_with("#[") {
+			// The link documented something other than a method
+			operation_id = None;
+		}
+	}
+	found
+}
+
+/// `(api, method)` of every public method of the API structs in `file`
+fn api_methods(file: &Path) -> Vec<(String, String)> {
+	let source = fs::read_to_string(file).unwrap();
+	let mut found = Vec::new();
+	let mut api = None;
+	for line in source.lines() {
+		if line.starts_with("#[cfg(test)]") {
+			break;
+		}
+		if line == "}" {
+			api = None;
+		} else if let Some(rest) = line.strip_prefix("impl ") {
+			let name = ident(rest);
+			if name.ends_with("Api") || name == "ServiceKafkaMirrorMaker" {
+				api = Some(name);
+			}
+		} else if let (Some(api), Some(method)) = (
+			&api,
+			line.strip_prefix("\tpub async fn ")
+				.or_else(|| line.strip_prefix("\tpub fn ")),
+		) {
+			found.push((api.clone(), ident(method)));
+		}
+	}
+	found
+}
+
+#[test]
+fn operations_match_doc_links() {
+	let mut files = Vec::new();
+	rust_files(Path::new("src"), &mut files);
+	let documented: BTreeSet<_> = files
+		.iter()
+		.flat_map(|f| documented_operations(f))
+		.collect();
+	let registered: BTreeSet<_> = aiven_rs::operations()
+		.iter()
+		.map(|op| {
+			(
+				op.api.to_string(),
+				op.method.to_string(),
+				op.operation_id.to_string(),
+			)
+		})
+		.collect();
+
+	let missing: Vec<_> = documented.difference(&registered).collect();
+	let stale: Vec<_> = registered.difference(&documented).collect();
+	assert!(
+		missing.is_empty() && stale.is_empty(),
+		"src/operations.rs is out of date\nmissing: {:#?}\nstale: {:#?}",
+		missing,
+		stale
+	);
+}
+
+#[test]
+fn every_api_method_has_an_operation() {
+	let mut files = Vec::new();
+	rust_files(Path::new("src"), &mut files);
+	let undocumented: Vec<_> = files
+		.iter()
+		.flat_map(|f| api_methods(f))
+		.filter(|(api, method)| {
+			aiven_rs::operation(api, method).is_none()
+				&& !HELPERS.contains(&(api.as_str(), method.as_str()))
+		})
+		.collect();
+	assert!(
+		undocumented.is_empty(),
+		"API methods without an operation link: {:#?}",
+		undocumented
+	);
+	assert!(HELPERS
+		.iter()
+		.all(|(api, method)| aiven_rs::operation(api, method).is_none()));
+}
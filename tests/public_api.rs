@@ -0,0 +1,57 @@
+// MIT License
+//
+// Copyright (c) 2020 Ankur Srivastava
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Snapshot of the public API surface.
+//!
+//! Builds the rustdoc json of the crate with all features and compares the
+//! items `public-api` lists against `tests/public-api.txt`, so changes to
+//! the public API show up in review instead of in downstream builds.
+//!
+//! Needs a nightly toolchain for the rustdoc json, so it is ignored by
+//! default:
+//!
+//! ```sh
+//! cargo test --test public_api -- --ignored
+//! ```
+//!
+//! After an intended change refresh the snapshot with
+//!
+//! ```sh
+//! UPDATE_SNAPSHOTS=1 cargo test --test public_api -- --ignored
+//! ```
+
+#[test]
+#[ignore = "needs a nightly toolchain, run with `--ignored`"]
+fn test_public_api_snapshot() {
+	let json = rustdoc_json::Builder::default()
+		.toolchain("nightly")
+		.manifest_path("Cargo.toml")
+		.all_features(true)
+		.silent(true)
+		.build()
+		.expect("Failed to build the rustdoc json, is a nightly toolchain installed?");
+	public_api::Builder::from_rustdoc_json(json)
+		.omit_blanket_impls(true)
+		.build()
+		.expect("Failed to read the rustdoc json")
+		.assert_eq_or_update("tests/public-api.txt");
+}